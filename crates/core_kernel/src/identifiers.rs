@@ -101,6 +101,7 @@ define_id!(AccountId, "ACC");
 define_id!(JournalEntryId, "JNL");
 define_id!(PostingId, "PST");
 define_id!(InvoiceId, "INV");
+define_id!(MandateId, "MDT");
 
 // Fund domain identifiers
 define_id!(FundId, "FND");
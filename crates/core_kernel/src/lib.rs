@@ -36,13 +36,13 @@ pub mod ports;
 pub mod registry;
 
 pub use money::{Money, Currency, MoneyError};
-pub use temporal::{ValidPeriod, SystemPeriod, BiTemporalRecord, Timezone};
+pub use temporal::{ValidPeriod, SystemPeriod, BiTemporalRecord, Timezone, utc_start_of_day};
 pub use identifiers::{
     PolicyId, ClaimId, PartyId, AccountId, JournalEntryId,
     FundId, UnitHoldingId, VersionId, AgentId, AddressId,
     PolicyVersionId, CoverageId, EndorsementId, ClaimLineId,
     ReserveId, PaymentId, ContactId, PostingId, InvoiceId,
-    NavId, TransactionId, AuditEventId, RiskObjectId,
+    NavId, TransactionId, AuditEventId, RiskObjectId, MandateId,
 };
 pub use error::CoreError;
 pub use ports::{
@@ -74,6 +74,26 @@ impl fmt::Display for Currency {
     }
 }
 
+impl std::str::FromStr for Currency {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "USD" => Ok(Currency::USD),
+            "EUR" => Ok(Currency::EUR),
+            "GBP" => Ok(Currency::GBP),
+            "JPY" => Ok(Currency::JPY),
+            "CHF" => Ok(Currency::CHF),
+            "INR" => Ok(Currency::INR),
+            "AUD" => Ok(Currency::AUD),
+            "CAD" => Ok(Currency::CAD),
+            "SGD" => Ok(Currency::SGD),
+            "HKD" => Ok(Currency::HKD),
+            other => Err(MoneyError::UnknownCurrency(other.to_string())),
+        }
+    }
+}
+
 /// Errors that can occur during money operations
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum MoneyError {
@@ -88,6 +108,9 @@ pub enum MoneyError {
 
     #[error("Overflow during calculation")]
     Overflow,
+
+    #[error("Unknown currency code: {0}")]
+    UnknownCurrency(String),
 }
 
 /// A monetary amount with associated currency
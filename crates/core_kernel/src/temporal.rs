@@ -68,6 +68,13 @@ impl Timezone {
     }
 }
 
+/// Gets the start of day (00:00:00) in UTC
+///
+/// Used to date postings and events that only carry a business date.
+pub fn utc_start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+}
+
 impl Default for Timezone {
     fn default() -> Self {
         Self(chrono_tz::UTC)
@@ -8,7 +8,7 @@ use core_kernel::{
     FundId, UnitHoldingId, VersionId, PolicyVersionId, CoverageId,
     RiskObjectId, EndorsementId, ClaimLineId, ReserveId, PaymentId,
     AddressId, ContactId, AgentId, PostingId, InvoiceId, NavId,
-    TransactionId, AuditEventId,
+    TransactionId, AuditEventId, MandateId,
 };
 use uuid::Uuid;
 
@@ -211,6 +211,7 @@ mod cross_type_tests {
             JournalEntryId::prefix(),
             PostingId::prefix(),
             InvoiceId::prefix(),
+            MandateId::prefix(),
             FundId::prefix(),
             UnitHoldingId::prefix(),
             NavId::prefix(),
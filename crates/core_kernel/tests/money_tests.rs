@@ -373,6 +373,13 @@ mod currency {
         assert_eq!(format!("{}", Currency::USD), "USD");
         assert_eq!(format!("{}", Currency::EUR), "EUR");
     }
+
+    #[test]
+    fn test_currency_from_str() {
+        assert_eq!("USD".parse::<Currency>().unwrap(), Currency::USD);
+        assert_eq!(" eur ".parse::<Currency>().unwrap(), Currency::EUR);
+        assert!(matches!("XYZ".parse::<Currency>(), Err(MoneyError::UnknownCurrency(_))));
+    }
 }
 
 mod display {
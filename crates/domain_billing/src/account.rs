@@ -41,6 +41,8 @@ pub enum AccountCategory {
     FixedAssets,
     /// Accounts payable
     Payables,
+    /// Taxes and levies collected for remittance
+    TaxesPayable,
     /// Insurance reserves
     Reserves,
     /// Unearned premium
@@ -139,16 +141,34 @@ impl InsuranceChartOfAccounts {
                 .with_category(AccountCategory::Receivables),
             Account::new(AccountId::new(), "1300", "Investments", AccountType::Asset)
                 .with_category(AccountCategory::Investments),
+            Account::new(AccountId::new(), "1400", "Policy Loans", AccountType::Asset)
+                .with_category(AccountCategory::Receivables),
+            Account::new(AccountId::new(), "1410", "Policy Loan Interest Receivable", AccountType::Asset)
+                .with_category(AccountCategory::Receivables),
 
             // Liabilities
             Account::new(AccountId::new(), "2000", "Unearned Premium Reserve", AccountType::Liability)
                 .with_category(AccountCategory::UnearnedPremium),
             Account::new(AccountId::new(), "2100", "Loss Reserves", AccountType::Liability)
                 .with_category(AccountCategory::Reserves),
+            Account::new(AccountId::new(), "2150", "Waiver of Premium Reserve", AccountType::Liability)
+                .with_category(AccountCategory::Reserves),
             Account::new(AccountId::new(), "2200", "IBNR Reserve", AccountType::Liability)
                 .with_category(AccountCategory::Reserves),
             Account::new(AccountId::new(), "2300", "Commission Payable", AccountType::Liability)
                 .with_category(AccountCategory::Payables),
+            Account::new(AccountId::new(), "2400", "Premium Suspense", AccountType::Liability)
+                .with_category(AccountCategory::Payables),
+            Account::new(AccountId::new(), "2500", "Premium Deposits", AccountType::Liability)
+                .with_category(AccountCategory::Payables),
+            Account::new(AccountId::new(), "2600", "Premium Tax Payable", AccountType::Liability)
+                .with_category(AccountCategory::TaxesPayable),
+            Account::new(AccountId::new(), "2610", "GST Payable", AccountType::Liability)
+                .with_category(AccountCategory::TaxesPayable),
+            Account::new(AccountId::new(), "2620", "Stamp Duty Payable", AccountType::Liability)
+                .with_category(AccountCategory::TaxesPayable),
+            Account::new(AccountId::new(), "2630", "Regulatory Levies Payable", AccountType::Liability)
+                .with_category(AccountCategory::TaxesPayable),
 
             // Equity
             Account::new(AccountId::new(), "3000", "Retained Earnings", AccountType::Equity),
@@ -162,6 +182,8 @@ impl InsuranceChartOfAccounts {
                 .with_category(AccountCategory::InvestmentIncome),
             Account::new(AccountId::new(), "4300", "Policy Fees", AccountType::Revenue)
                 .with_category(AccountCategory::FeeIncome),
+            Account::new(AccountId::new(), "4400", "Policy Loan Interest", AccountType::Revenue)
+                .with_category(AccountCategory::InvestmentIncome),
 
             // Expenses
             Account::new(AccountId::new(), "5000", "Incurred Losses", AccountType::Expense)
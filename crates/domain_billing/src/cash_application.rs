@@ -0,0 +1,500 @@
+//! Cash application
+//!
+//! This module matches incoming payments to open invoices and posts the
+//! resulting ledger entries.
+//!
+//! # Matching
+//!
+//! A payment is matched, in order of confidence, by:
+//! 1. The invoice it already references
+//! 2. An invoice number or policy reference in its external reference
+//! 3. Its payer and an open invoice for exactly the amount received
+//! 4. Its payer alone
+//!
+//! Matched payments are allocated across the payer's open invoices
+//! oldest-first. Any excess is held as a premium deposit or customer credit.
+//! Payments that cannot be matched are parked in the suspense account until
+//! they are allocated manually.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use core_kernel::{AccountId, JournalEntryId, Money, PartyId, PaymentId};
+
+use crate::error::BillingError;
+use crate::invoice::Invoice;
+use crate::ledger::Ledger;
+use crate::payment::{Payment, PaymentAllocation, PaymentStatus};
+use crate::transaction::Transaction;
+
+/// How a payment was matched to its invoices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchMethod {
+    /// Payment carried the invoice ID
+    InvoiceId,
+    /// External reference quoted an invoice number or policy
+    Reference,
+    /// Payer and exact invoice amount
+    PayerAndAmount,
+    /// Payer only
+    Payer,
+    /// Allocated manually from suspense
+    Manual,
+}
+
+/// Treatment of the amount received in excess of open invoices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverpaymentTreatment {
+    /// Hold the excess as a premium deposit against future premiums
+    PremiumDeposit,
+    /// Leave the excess as a credit balance on the customer's receivable
+    CustomerCredit,
+}
+
+/// Ledger accounts used by cash application
+#[derive(Debug, Clone, Copy)]
+pub struct CashApplicationAccounts {
+    /// Cash at bank
+    pub cash: AccountId,
+    /// Premium receivable
+    pub premium_receivable: AccountId,
+    /// Unidentified receipts awaiting allocation
+    pub suspense: AccountId,
+    /// Premium deposits held for policyholders
+    pub premium_deposit: AccountId,
+}
+
+/// A receipt parked in suspense
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspenseItem {
+    /// Payment that was received
+    pub payment_id: PaymentId,
+    /// Unallocated amount
+    pub amount: Money,
+    /// Payer, if known
+    pub payer_id: Option<PartyId>,
+    /// External reference on the receipt
+    pub reference: Option<String>,
+    /// When the receipt was parked
+    pub parked_at: DateTime<Utc>,
+}
+
+/// Result of applying a payment
+#[derive(Debug, Clone)]
+pub struct CashApplicationResult {
+    /// Payment that was applied
+    pub payment_id: PaymentId,
+    /// How the payment was matched (None if parked in suspense)
+    pub match_method: Option<MatchMethod>,
+    /// Allocations to invoices
+    pub allocations: Vec<PaymentAllocation>,
+    /// Amount held as deposit or credit
+    pub overpayment: Option<Money>,
+    /// Amount parked in suspense
+    pub suspense: Option<Money>,
+    /// Journal entry recording the receipt
+    pub journal_entry_id: JournalEntryId,
+}
+
+/// Engine matching received payments to open invoices
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut engine = CashApplicationEngine::new(accounts);
+/// let result = engine.apply(&mut ledger, &mut payment, &mut open_invoices)?;
+/// if result.match_method.is_none() {
+///     println!("Receipt parked in suspense");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CashApplicationEngine {
+    /// Ledger accounts
+    accounts: CashApplicationAccounts,
+    /// Treatment of overpayments
+    overpayment_treatment: OverpaymentTreatment,
+    /// Receipts awaiting manual allocation
+    suspense: Vec<SuspenseItem>,
+}
+
+impl CashApplicationEngine {
+    /// Creates an engine holding overpayments as premium deposits
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - Ledger accounts to post to
+    pub fn new(accounts: CashApplicationAccounts) -> Self {
+        Self {
+            accounts,
+            overpayment_treatment: OverpaymentTreatment::PremiumDeposit,
+            suspense: Vec::new(),
+        }
+    }
+
+    /// Sets the overpayment treatment
+    pub fn with_overpayment_treatment(mut self, treatment: OverpaymentTreatment) -> Self {
+        self.overpayment_treatment = treatment;
+        self
+    }
+
+    /// Returns receipts awaiting manual allocation
+    pub fn suspense_items(&self) -> &[SuspenseItem] {
+        &self.suspense
+    }
+
+    /// Applies a received payment to open invoices
+    ///
+    /// This method:
+    /// 1. Matches the payment to a payer's open invoices
+    /// 2. Allocates the amount oldest-first, updating each invoice
+    /// 3. Treats any excess as a deposit or credit
+    /// 4. Parks unmatched receipts in suspense
+    /// 5. Posts the receipt to the ledger
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `payment` - The received payment
+    /// * `invoices` - Candidate invoices; only open invoices are considered
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the payment is not positive, was already applied,
+    /// or the ledger posting fails
+    pub fn apply(
+        &mut self,
+        ledger: &mut Ledger,
+        payment: &mut Payment,
+        invoices: &mut [Invoice],
+    ) -> Result<CashApplicationResult, BillingError> {
+        if payment.is_disbursement() {
+            return Err(BillingError::InvalidOperation(format!(
+                "Payment {} is a disbursement, not a receipt",
+                payment.id
+            )));
+        }
+        check_pending(payment)?;
+
+        let Some((match_method, order)) = self.match_invoices(payment, invoices) else {
+            return self.park_in_suspense(ledger, payment);
+        };
+
+        let plan = plan_allocation(payment.amount, &order, invoices);
+        let allocated = plan_total(&plan, Money::zero(payment.amount.currency()));
+        let excess = payment.amount - allocated;
+
+        let mut transaction = Transaction::new(cash_description("Premium receipt applied", payment))
+            .with_reference("payment", *payment.id.as_uuid())
+            .dated(payment.payment_date)
+            .debit(self.accounts.cash, payment.amount);
+        if allocated.is_positive() {
+            transaction = transaction.credit(self.accounts.premium_receivable, allocated);
+        }
+        if excess.is_positive() {
+            let account = match self.overpayment_treatment {
+                OverpaymentTreatment::PremiumDeposit => self.accounts.premium_deposit,
+                OverpaymentTreatment::CustomerCredit => self.accounts.premium_receivable,
+            };
+            transaction = transaction.credit(account, excess);
+        }
+        let journal_entry_id = ledger.post(transaction)?;
+
+        let allocations = record_allocations(payment.id, &plan, invoices);
+        if let Some(first) = allocations.first() {
+            payment.invoice_id.get_or_insert(first.invoice_id);
+        }
+        if let Some(&index) = order.first() {
+            payment.payer_id.get_or_insert(invoices[index].customer_id);
+        }
+        payment.complete();
+
+        Ok(CashApplicationResult {
+            payment_id: payment.id,
+            match_method: Some(match_method),
+            allocations,
+            overpayment: excess.is_positive().then_some(excess),
+            suspense: None,
+            journal_entry_id,
+        })
+    }
+
+    /// Pays out a disbursement
+    ///
+    /// Settles the liability the disbursement was raised against, such as
+    /// refunds or dividends payable, from cash at bank.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `payment` - The outgoing payment
+    /// * `liability` - Account holding the amount owed to the payee
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the payment is not a pending disbursement or the
+    /// ledger posting fails
+    pub fn disburse(
+        &self,
+        ledger: &mut Ledger,
+        payment: &mut Payment,
+        liability: AccountId,
+    ) -> Result<JournalEntryId, BillingError> {
+        if !payment.is_disbursement() {
+            return Err(BillingError::InvalidOperation(format!(
+                "Payment {} is a receipt, not a disbursement",
+                payment.id
+            )));
+        }
+        check_pending(payment)?;
+
+        let transaction = Transaction::new(cash_description("Disbursement paid", payment))
+            .with_reference("payment", *payment.id.as_uuid())
+            .dated(payment.payment_date)
+            .debit(liability, payment.amount)
+            .credit(self.accounts.cash, payment.amount);
+        let journal_entry_id = ledger.post(transaction)?;
+        payment.complete();
+        Ok(journal_entry_id)
+    }
+
+    /// Allocates a suspense item manually to a customer's invoices
+    ///
+    /// The amount is allocated oldest-first; anything left over stays in
+    /// suspense.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `payment_id` - The parked payment
+    /// * `customer_id` - Customer the receipt has been identified as
+    /// * `invoices` - Candidate invoices; only the customer's open invoices are used
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the payment is not in suspense, nothing could be
+    /// allocated, or the ledger posting fails
+    pub fn allocate_from_suspense(
+        &mut self,
+        ledger: &mut Ledger,
+        payment_id: PaymentId,
+        customer_id: PartyId,
+        invoices: &mut [Invoice],
+    ) -> Result<CashApplicationResult, BillingError> {
+        let position = self
+            .suspense
+            .iter()
+            .position(|item| item.payment_id == payment_id)
+            .ok_or_else(|| BillingError::PaymentNotFound(payment_id.to_string()))?;
+        let amount = self.suspense[position].amount;
+
+        let order = oldest_first(invoices, |invoice| {
+            invoice.customer_id == customer_id && invoice.currency == amount.currency()
+        });
+        let plan = plan_allocation(amount, &order, invoices);
+        if plan.is_empty() {
+            return Err(BillingError::InvalidOperation(format!(
+                "No open invoices to allocate payment {} against",
+                payment_id
+            )));
+        }
+        let allocated = plan_total(&plan, Money::zero(amount.currency()));
+
+        let transaction = Transaction::new("Suspense receipt allocated")
+            .with_reference("payment", *payment_id.as_uuid())
+            .debit(self.accounts.suspense, allocated)
+            .credit(self.accounts.premium_receivable, allocated);
+        let journal_entry_id = ledger.post(transaction)?;
+        let allocations = record_allocations(payment_id, &plan, invoices);
+
+        let remaining = amount - allocated;
+        if remaining.is_positive() {
+            self.suspense[position].amount = remaining;
+        } else {
+            self.suspense.remove(position);
+        }
+
+        Ok(CashApplicationResult {
+            payment_id,
+            match_method: Some(MatchMethod::Manual),
+            allocations,
+            overpayment: None,
+            suspense: remaining.is_positive().then_some(remaining),
+            journal_entry_id,
+        })
+    }
+
+    /// Finds the invoices a payment should be allocated to, in order
+    fn match_invoices(&self, payment: &Payment, invoices: &[Invoice]) -> Option<(MatchMethod, Vec<usize>)> {
+        let currency = payment.amount.currency();
+        let candidate = |invoice: &Invoice| collectable(invoice) && invoice.currency == currency;
+
+        let direct = payment
+            .invoice_id
+            .and_then(|id| invoices.iter().position(|i| i.id == id && candidate(i)))
+            .map(|index| (MatchMethod::InvoiceId, index));
+
+        let by_reference = || {
+            let tokens = reference_tokens(payment.external_reference.as_deref()?);
+            let quoted = |value: &str| tokens.iter().any(|token| token.eq_ignore_ascii_case(value));
+            invoices
+                .iter()
+                .position(|i| candidate(i) && (quoted(&i.invoice_number) || quoted(&i.policy_id.to_string())))
+                .map(|index| (MatchMethod::Reference, index))
+        };
+
+        let by_payer_and_amount = || {
+            let payer = payment.payer_id?;
+            invoices
+                .iter()
+                .position(|i| candidate(i) && i.customer_id == payer && i.balance_due() == payment.amount)
+                .map(|index| (MatchMethod::PayerAndAmount, index))
+        };
+
+        if let Some((method, index)) = direct.or_else(by_reference).or_else(by_payer_and_amount) {
+            let customer = invoices[index].customer_id;
+            let mut order = vec![index];
+            order.extend(
+                oldest_first(invoices, |i| i.customer_id == customer && i.currency == currency)
+                    .into_iter()
+                    .filter(|&i| i != index),
+            );
+            return Some((method, order));
+        }
+
+        let payer = payment.payer_id?;
+        let order = oldest_first(invoices, |i| i.customer_id == payer && i.currency == currency);
+        (!order.is_empty()).then_some((MatchMethod::Payer, order))
+    }
+
+    /// Posts an unidentified receipt to suspense
+    fn park_in_suspense(
+        &mut self,
+        ledger: &mut Ledger,
+        payment: &mut Payment,
+    ) -> Result<CashApplicationResult, BillingError> {
+        let transaction = Transaction::new(cash_description("Unidentified receipt held in suspense", payment))
+            .with_reference("payment", *payment.id.as_uuid())
+            .dated(payment.payment_date)
+            .debit(self.accounts.cash, payment.amount)
+            .credit(self.accounts.suspense, payment.amount);
+        let journal_entry_id = ledger.post(transaction)?;
+
+        self.suspense.push(SuspenseItem {
+            payment_id: payment.id,
+            amount: payment.amount,
+            payer_id: payment.payer_id,
+            reference: payment.external_reference.clone(),
+            parked_at: Utc::now(),
+        });
+        payment.status = PaymentStatus::OnHold;
+        payment.notes = Some("Unidentified receipt held in suspense".to_string());
+
+        tracing::info!(payment_id = %payment.id, amount = %payment.amount, "Receipt parked in suspense");
+
+        Ok(CashApplicationResult {
+            payment_id: payment.id,
+            match_method: None,
+            allocations: Vec::new(),
+            overpayment: None,
+            suspense: Some(payment.amount),
+            journal_entry_id,
+        })
+    }
+}
+
+/// Describes a cash posting with the payment's external reference, so bank
+/// reconciliation can match it to the statement line
+fn cash_description(label: &str, payment: &Payment) -> String {
+    match &payment.external_reference {
+        Some(reference) => format!("{}: {}", label, reference),
+        None => label.to_string(),
+    }
+}
+
+/// Checks that a payment has a positive amount and has not been processed
+fn check_pending(payment: &Payment) -> Result<(), BillingError> {
+    if !payment.amount.is_positive() {
+        return Err(BillingError::InvalidOperation(format!(
+            "Payment {} has no amount to apply",
+            payment.id
+        )));
+    }
+    if payment.status != PaymentStatus::Pending {
+        return Err(BillingError::InvalidOperation(format!(
+            "Payment {} has already been processed",
+            payment.id
+        )));
+    }
+    Ok(())
+}
+
+/// Returns true if an invoice still has an amount to collect
+fn collectable(invoice: &Invoice) -> bool {
+    invoice.is_open() && invoice.balance_due().is_positive()
+}
+
+/// Splits a remittance reference into the words it quotes
+///
+/// Words are separated by whitespace and punctuation other than hyphens,
+/// so invoice numbers and policy IDs stay whole.
+fn reference_tokens(reference: &str) -> Vec<&str> {
+    reference
+        .split(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// Returns indices of open invoices matching a filter, oldest due date first
+fn oldest_first(invoices: &[Invoice], filter: impl Fn(&Invoice) -> bool) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..invoices.len())
+        .filter(|&i| collectable(&invoices[i]) && filter(&invoices[i]))
+        .collect();
+    order.sort_by_key(|&i| (invoices[i].due_date, invoices[i].invoice_date));
+    order
+}
+
+/// Plans the allocation of an amount across invoices in the given order
+///
+/// Returns the invoice index and amount for each allocation without
+/// modifying the invoices, so nothing changes if posting fails.
+fn plan_allocation(amount: Money, order: &[usize], invoices: &[Invoice]) -> Vec<(usize, Money)> {
+    let mut remaining = amount;
+    let mut plan = Vec::new();
+
+    for &index in order {
+        if !remaining.is_positive() {
+            break;
+        }
+        let balance = invoices[index].balance_due();
+        let applied = if remaining.amount() < balance.amount() { remaining } else { balance };
+        remaining = remaining - applied;
+        plan.push((index, applied));
+    }
+
+    plan
+}
+
+/// Records planned allocations against the invoices
+fn record_allocations(
+    payment_id: PaymentId,
+    plan: &[(usize, Money)],
+    invoices: &mut [Invoice],
+) -> Vec<PaymentAllocation> {
+    plan.iter()
+        .map(|&(index, amount)| {
+            let invoice = &mut invoices[index];
+            invoice.record_payment(amount);
+            PaymentAllocation {
+                payment_id,
+                invoice_id: invoice.id,
+                amount,
+                allocated_at: Utc::now(),
+            }
+        })
+        .collect()
+}
+
+/// Sums the amounts in an allocation plan
+fn plan_total(plan: &[(usize, Money)], zero: Money) -> Money {
+    plan.iter().fold(zero, |acc, &(_, amount)| acc + amount)
+}
@@ -0,0 +1,500 @@
+//! Collection runs and settlement
+//!
+//! A collection run selects open invoices that are due, pairs each with
+//! an active mandate of the customer, and collects each mandate's invoices
+//! in one instruction of a pain.008 file. Settlement books the collected
+//! cash and records the collection on the mandate; rejections and returns
+//! reverse it and re-open the invoices.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{utc_start_of_day, AccountId, Currency, InvoiceId, JournalEntryId, MandateId, Money, PartyId};
+
+use super::pain008;
+use super::returns::{ReturnAction, ReturnNotice, ReturnOutcome};
+use super::{BankAccount, Mandate, SequenceType};
+use crate::error::BillingError;
+use crate::invoice::Invoice;
+use crate::ledger::Ledger;
+use crate::payment::{Payment, PaymentMethod, PaymentStatus};
+use crate::transaction::Transaction;
+
+/// The insurer as direct debit creditor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Creditor {
+    /// Creditor name
+    pub name: String,
+    /// SEPA creditor identifier
+    pub creditor_id: String,
+    /// Account collections are credited to
+    pub account: BankAccount,
+}
+
+/// Ledger accounts used by direct debit processing
+#[derive(Debug, Clone, Copy)]
+pub struct DirectDebitAccounts {
+    /// Cash at bank
+    pub cash: AccountId,
+    /// Premium receivable
+    pub premium_receivable: AccountId,
+}
+
+/// Maximum length of unstructured remittance information
+const REMITTANCE_INFO_MAX_LEN: usize = 140;
+
+/// An invoice collected by an instruction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectedInvoice {
+    /// Invoice
+    pub invoice_id: InvoiceId,
+    /// Invoice number
+    pub invoice_number: String,
+    /// Amount collected for the invoice
+    pub amount: Money,
+}
+
+/// A single collection in a run
+///
+/// All of a mandate's due invoices are collected together, so each
+/// mandate is debited once per run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionInstruction {
+    /// End-to-end ID quoted back on rejects and returns
+    pub end_to_end_id: String,
+    /// Invoices being collected
+    pub invoices: Vec<CollectedInvoice>,
+    /// Debtor party
+    pub party_id: PartyId,
+    /// Mandate collected against
+    pub mandate_id: MandateId,
+    /// Mandate reference
+    pub mandate_reference: String,
+    /// Mandate signature date
+    pub signature_date: NaiveDate,
+    /// Account debited
+    pub debtor_account: BankAccount,
+    /// Amount collected
+    pub amount: Money,
+    /// Sequence type
+    pub sequence_type: SequenceType,
+    /// Remittance information (invoice numbers)
+    pub remittance_info: String,
+}
+
+impl CollectionInstruction {
+    /// Adds an invoice to the collection
+    fn add_invoice(&mut self, invoice: &Invoice) {
+        let amount = invoice.balance_due();
+        self.invoices.push(CollectedInvoice {
+            invoice_id: invoice.id,
+            invoice_number: invoice.invoice_number.clone(),
+            amount,
+        });
+        self.amount = self.amount + amount;
+        self.remittance_info = self
+            .invoices
+            .iter()
+            .map(|i| i.invoice_number.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(REMITTANCE_INFO_MAX_LEN)
+            .collect();
+    }
+}
+
+/// Why an invoice was left out of a run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SkipReason {
+    /// Customer has no collectable mandate for the policy
+    NoActiveMandate,
+    /// SEPA collections are EUR only
+    UnsupportedCurrency(Currency),
+}
+
+/// An invoice left out of a run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedInvoice {
+    /// Invoice
+    pub invoice_id: InvoiceId,
+    /// Reason
+    pub reason: SkipReason,
+}
+
+/// A batch of collections for one requested collection date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionRun {
+    /// Message ID of the pain.008 file
+    pub message_id: String,
+    /// Creditor
+    pub creditor: Creditor,
+    /// Requested collection date
+    pub collection_date: NaiveDate,
+    /// Collections
+    pub instructions: Vec<CollectionInstruction>,
+    /// Due invoices that could not be collected
+    pub skipped: Vec<SkippedInvoice>,
+    /// Created timestamp
+    pub created_at: DateTime<Utc>,
+}
+
+impl CollectionRun {
+    /// Returns the total amount to be collected
+    pub fn total(&self) -> Money {
+        self.instructions
+            .iter()
+            .fold(Money::zero(Currency::EUR), |acc, i| acc + i.amount)
+    }
+
+    /// Builds the pain.008 customer direct debit initiation file
+    pub fn to_pain008(&self) -> String {
+        pain008::write(self)
+    }
+
+    /// Creates a pending payment for each invoice collected
+    ///
+    /// Each payment carries its collection's end-to-end ID as its external
+    /// reference so that rejects and returns can be matched back to it.
+    pub fn payments(&self) -> Vec<Payment> {
+        self.instructions
+            .iter()
+            .flat_map(|instruction| {
+                instruction.invoices.iter().map(|invoice| {
+                    let mut payment = Payment::new(
+                        invoice.invoice_id,
+                        instruction.party_id,
+                        invoice.amount,
+                        PaymentMethod::DirectDebit,
+                    )
+                    .with_reference(instruction.end_to_end_id.clone());
+                    if let Some(date) = self.collection_date.and_hms_opt(0, 0, 0) {
+                        payment.payment_date = date.and_utc();
+                    }
+                    payment
+                })
+            })
+            .collect()
+    }
+}
+
+/// Engine running direct debit collections
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = DirectDebitEngine::new(creditor, accounts);
+/// let run = engine.collection_run(&mandates, &invoices, collection_date);
+/// submit_to_bank(run.to_pain008());
+/// let mut payments = run.payments();
+/// engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirectDebitEngine {
+    /// Creditor details
+    creditor: Creditor,
+    /// Ledger accounts
+    accounts: DirectDebitAccounts,
+}
+
+impl DirectDebitEngine {
+    /// Creates a new engine
+    ///
+    /// # Arguments
+    ///
+    /// * `creditor` - The insurer's creditor details
+    /// * `accounts` - Ledger accounts to post to
+    pub fn new(creditor: Creditor, accounts: DirectDebitAccounts) -> Self {
+        Self { creditor, accounts }
+    }
+
+    /// Builds a collection run from due invoices
+    ///
+    /// Open invoices due on or before the collection date are collected
+    /// for their balance due. A mandate restricted to the invoice's policy
+    /// is preferred over a general one. The invoices collected against one
+    /// mandate are grouped into a single instruction. Mandates are left
+    /// unchanged until the collection settles.
+    ///
+    /// # Arguments
+    ///
+    /// * `mandates` - Mandates on file
+    /// * `invoices` - Candidate invoices
+    /// * `collection_date` - Requested collection date
+    pub fn collection_run(
+        &self,
+        mandates: &[Mandate],
+        invoices: &[Invoice],
+        collection_date: NaiveDate,
+    ) -> CollectionRun {
+        let message_id = format!(
+            "DD-{}-{}",
+            collection_date.format("%Y%m%d"),
+            &Uuid::new_v4().simple().to_string()[..8]
+        );
+        let mut instructions: Vec<CollectionInstruction> = Vec::new();
+        let mut skipped = Vec::new();
+
+        let mut due: Vec<&Invoice> = invoices
+            .iter()
+            .filter(|invoice| {
+                invoice.is_open()
+                    && invoice.due_date <= collection_date
+                    && invoice.balance_due().is_positive()
+            })
+            .collect();
+        due.sort_by_key(|invoice| (invoice.due_date, invoice.created_at));
+
+        for invoice in due {
+            if invoice.currency != Currency::EUR {
+                skipped.push(SkippedInvoice {
+                    invoice_id: invoice.id,
+                    reason: SkipReason::UnsupportedCurrency(invoice.currency),
+                });
+                continue;
+            }
+
+            let Some(mandate) = select_mandate(mandates, invoice, collection_date) else {
+                skipped.push(SkippedInvoice {
+                    invoice_id: invoice.id,
+                    reason: SkipReason::NoActiveMandate,
+                });
+                continue;
+            };
+
+            if let Some(instruction) = instructions.iter_mut().find(|i| i.mandate_id == mandate.id) {
+                instruction.add_invoice(invoice);
+                continue;
+            }
+            let mut instruction = CollectionInstruction {
+                end_to_end_id: format!("{}-{:04}", message_id, instructions.len() + 1),
+                invoices: Vec::new(),
+                party_id: invoice.customer_id,
+                mandate_id: mandate.id,
+                mandate_reference: mandate.mandate_reference.clone(),
+                signature_date: mandate.signature_date,
+                debtor_account: mandate.account.clone(),
+                amount: Money::zero(Currency::EUR),
+                sequence_type: mandate.next_sequence_type(),
+                remittance_info: String::new(),
+            };
+            instruction.add_invoice(invoice);
+            instructions.push(instruction);
+        }
+
+        CollectionRun {
+            message_id,
+            creditor: self.creditor.clone(),
+            collection_date,
+            instructions,
+            skipped,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Books settled collections
+    ///
+    /// Every pending direct debit payment of the run is completed, applied
+    /// to its invoice and posted as cash received against premium
+    /// receivable. Each mandate collected against records the collection,
+    /// so the next run uses the correct sequence type.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `run` - The collection run that settled
+    /// * `payments` - Payments from the collection run
+    /// * `invoices` - Invoices being collected
+    /// * `mandates` - Mandates on file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a payment's invoice is missing or the posting fails
+    pub fn settle(
+        &self,
+        ledger: &mut Ledger,
+        run: &CollectionRun,
+        payments: &mut [Payment],
+        invoices: &mut [Invoice],
+        mandates: &mut [Mandate],
+    ) -> Result<Vec<JournalEntryId>, BillingError> {
+        let mut entries = Vec::new();
+
+        for payment in payments.iter_mut().filter(|p| {
+            p.method == PaymentMethod::DirectDebit
+                && p.status == PaymentStatus::Pending
+                && run
+                    .instructions
+                    .iter()
+                    .any(|i| p.external_reference.as_deref() == Some(i.end_to_end_id.as_str()))
+        }) {
+            let invoice_id = payment
+                .invoice_id
+                .ok_or_else(|| BillingError::InvoiceNotFound(format!("payment {}", payment.id)))?;
+            let invoice = invoices
+                .iter_mut()
+                .find(|i| i.id == invoice_id)
+                .ok_or_else(|| BillingError::InvoiceNotFound(invoice_id.to_string()))?;
+
+            let transaction = Transaction::new("Direct debit collected")
+                .with_reference("payment", *payment.id.as_uuid())
+                .dated(payment.payment_date)
+                .debit(self.accounts.cash, payment.amount)
+                .credit(self.accounts.premium_receivable, payment.amount);
+            entries.push(ledger.post(transaction)?);
+
+            invoice.record_payment(payment.amount);
+            payment.complete();
+        }
+
+        for instruction in &run.instructions {
+            let settled = payments.iter().any(|p| {
+                p.external_reference.as_deref() == Some(instruction.end_to_end_id.as_str())
+                    && p.status == PaymentStatus::Completed
+            });
+            if !settled {
+                continue;
+            }
+            // A run settling after a later one must not move the mandate back
+            if let Some(mandate) = mandates
+                .iter_mut()
+                .find(|m| m.id == instruction.mandate_id && m.last_collection_date < Some(run.collection_date))
+            {
+                mandate.record_collection(run.collection_date);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Processes rejects, returns and refunds
+    ///
+    /// For each notice the payments of the collection are found by
+    /// end-to-end ID, and an outcome reported for each:
+    /// - A pending payment (rejected before settlement) is marked failed
+    /// - A completed payment (returned, refunded, or rejected after being
+    ///   booked) is reversed in the ledger on the notice's return date and
+    ///   its invoice re-opened with the reason code
+    ///
+    /// Reason codes indicating the mandate can no longer be used (closed
+    /// account, no mandate, debtor deceased, ...) revoke the mandate as of
+    /// the return date. The mandate is the one quoted on the notice, or
+    /// else the one the run collected the instruction against.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post reversals to
+    /// * `run` - The collection run the notices refer to
+    /// * `notices` - Parsed rejects and returns
+    /// * `payments` - Direct debit payments
+    /// * `invoices` - Invoices collected
+    /// * `mandates` - Mandates on file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a reversal posting fails
+    pub fn process_returns(
+        &self,
+        ledger: &mut Ledger,
+        run: &CollectionRun,
+        notices: &[ReturnNotice],
+        payments: &mut [Payment],
+        invoices: &mut [Invoice],
+        mandates: &mut [Mandate],
+    ) -> Result<Vec<ReturnOutcome>, BillingError> {
+        let mut outcomes = Vec::new();
+
+        for notice in notices {
+            let outcome = ReturnOutcome {
+                end_to_end_id: notice.end_to_end_id.clone(),
+                action: ReturnAction::Unmatched,
+                reason: notice.reason.clone(),
+                payment_id: None,
+                invoice_id: None,
+                journal_entry_id: None,
+                revoked_mandate: None,
+            };
+            let description = format!("Direct debit {} {}", notice.kind, notice.reason);
+            let first = outcomes.len();
+
+            for payment in payments
+                .iter_mut()
+                .filter(|p| p.external_reference.as_deref() == Some(notice.end_to_end_id.as_str()))
+            {
+                let mut outcome = outcome.clone();
+                outcome.payment_id = Some(payment.id);
+                outcome.invoice_id = payment.invoice_id;
+
+                match payment.status {
+                    PaymentStatus::Pending => {
+                        payment.fail(&description);
+                        outcome.action = ReturnAction::Rejected;
+                    }
+                    PaymentStatus::Completed => {
+                        let transaction = Transaction::new(description.clone())
+                            .with_reference("payment", *payment.id.as_uuid())
+                            .dated(utc_start_of_day(notice.return_date))
+                            .debit(self.accounts.premium_receivable, payment.amount)
+                            .credit(self.accounts.cash, payment.amount);
+                        outcome.journal_entry_id = Some(ledger.post(transaction)?);
+
+                        if let Some(invoice) = payment
+                            .invoice_id
+                            .and_then(|id| invoices.iter_mut().find(|i| i.id == id))
+                        {
+                            invoice.reverse_payment(payment.amount, &description);
+                        }
+                        payment.reverse(&format!("{} {}", notice.kind, notice.reason));
+                        outcome.action = ReturnAction::Reversed;
+                    }
+                    _ => outcome.action = ReturnAction::AlreadyProcessed,
+                }
+                outcomes.push(outcome);
+            }
+
+            let processed = &mut outcomes[first..];
+            if processed.is_empty() {
+                outcomes.push(outcome);
+                continue;
+            }
+            if !notice.reason.revokes_mandate() || processed.iter().all(|o| o.action == ReturnAction::AlreadyProcessed) {
+                continue;
+            }
+            let mandate_id = run
+                .instructions
+                .iter()
+                .find(|i| i.end_to_end_id == notice.end_to_end_id)
+                .map(|i| i.mandate_id);
+            let mandate = mandates.iter_mut().find(|m| match &notice.mandate_reference {
+                Some(reference) => &m.mandate_reference == reference,
+                None => Some(m.id) == mandate_id,
+            });
+            if let Some(mandate) = mandate {
+                if mandate.revoke(notice.return_date, &notice.reason.to_string()).is_ok() {
+                    for outcome in processed {
+                        outcome.revoked_mandate = Some(mandate.id);
+                    }
+                }
+            }
+        }
+
+        Ok(outcomes)
+    }
+}
+
+/// Picks the mandate to collect an invoice against
+fn select_mandate<'a>(
+    mandates: &'a [Mandate],
+    invoice: &Invoice,
+    collection_date: NaiveDate,
+) -> Option<&'a Mandate> {
+    mandates
+        .iter()
+        .filter(|m| {
+            m.party_id == invoice.customer_id
+                && m.covers(invoice.policy_id)
+                && m.is_collectable(collection_date)
+        })
+        .min_by_key(|m| (m.policy_id.is_none(), m.signature_date))
+}
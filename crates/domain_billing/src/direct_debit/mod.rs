@@ -0,0 +1,315 @@
+//! Direct debit collections
+//!
+//! This module manages SEPA Core direct debit mandates, builds pain.008
+//! collection files from due invoices, and processes rejections (pain.002)
+//! and returns (pacs.004) that reverse collected payments.
+//!
+//! # Collection Lifecycle
+//!
+//! ```text
+//! Mandate ──► CollectionRun ──► pain.008 ──► settle ──► Payment (Completed)
+//!                                   │                         │
+//!                                   ▼                         ▼
+//!                         pain.002 reject             pacs.004 return
+//!                         (Payment Failed)     (Payment Reversed, invoice re-opened)
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use domain_billing::direct_debit::{DirectDebitEngine, returns};
+//!
+//! let run = engine.collection_run(&mandates, &invoices, collection_date);
+//! let file = run.to_pain008();
+//! let mut payments = run.payments();
+//!
+//! // On settlement
+//! engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates)?;
+//!
+//! // When the bank returns a collection
+//! let notices = returns::parse_pacs004(&return_file)?;
+//! engine.process_returns(&mut ledger, &run, &notices, &mut payments, &mut invoices, &mut mandates)?;
+//! ```
+
+pub mod collection;
+pub mod pain008;
+pub mod returns;
+
+use chrono::{DateTime, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use core_kernel::{MandateId, PartyId, PolicyId};
+
+use crate::error::BillingError;
+
+pub use collection::{
+    CollectionInstruction, CollectionRun, Creditor, DirectDebitAccounts, DirectDebitEngine,
+    SkipReason, SkippedInvoice,
+};
+pub use returns::{ReturnAction, ReturnKind, ReturnNotice, ReturnOutcome, ReturnReason};
+
+/// Months without a collection after which a SEPA mandate lapses
+pub const MANDATE_DORMANCY_MONTHS: u32 = 36;
+
+/// A bank account identified by IBAN
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BankAccount {
+    /// Account holder name
+    pub holder_name: String,
+    /// IBAN (normalised: uppercase, no spaces)
+    pub iban: String,
+    /// BIC of the account servicing bank
+    pub bic: Option<String>,
+}
+
+impl BankAccount {
+    /// Creates a bank account after validating the IBAN
+    ///
+    /// # Arguments
+    ///
+    /// * `holder_name` - Account holder name
+    /// * `iban` - IBAN, with or without spaces
+    ///
+    /// # Errors
+    ///
+    /// Returns `BillingError::InvalidMandate` if the IBAN fails the
+    /// ISO 13616 check digit test
+    pub fn new(holder_name: impl Into<String>, iban: &str) -> Result<Self, BillingError> {
+        let iban: String = iban
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        if !is_valid_iban(&iban) {
+            return Err(BillingError::InvalidMandate(format!("Invalid IBAN '{}'", iban)));
+        }
+
+        Ok(Self {
+            holder_name: holder_name.into(),
+            iban,
+            bic: None,
+        })
+    }
+
+    /// Sets the BIC
+    pub fn with_bic(mut self, bic: impl Into<String>) -> Self {
+        self.bic = Some(bic.into().to_uppercase());
+        self
+    }
+}
+
+/// Whether a mandate covers a single or repeated collections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MandateType {
+    /// Single collection
+    OneOff,
+    /// Repeated collections
+    Recurring,
+}
+
+/// Mandate status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MandateStatus {
+    /// Mandate can be collected against
+    Active,
+    /// One-off mandate that has been collected
+    Used,
+    /// Revoked by the debtor, the bank or the insurer
+    Revoked,
+}
+
+/// SEPA sequence type of a collection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SequenceType {
+    /// First collection under a recurring mandate
+    First,
+    /// Subsequent collection under a recurring mandate
+    Recurring,
+    /// Collection under a one-off mandate
+    OneOff,
+}
+
+impl SequenceType {
+    /// Returns the ISO 20022 sequence type code
+    pub fn code(&self) -> &'static str {
+        match self {
+            SequenceType::First => "FRST",
+            SequenceType::Recurring => "RCUR",
+            SequenceType::OneOff => "OOFF",
+        }
+    }
+}
+
+/// A direct debit mandate signed by a party
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mandate {
+    /// Unique identifier
+    pub id: MandateId,
+    /// Unique mandate reference (UMR) quoted on every collection
+    pub mandate_reference: String,
+    /// Party who signed the mandate
+    pub party_id: PartyId,
+    /// Policy the mandate is restricted to (None covers all the party's policies)
+    pub policy_id: Option<PolicyId>,
+    /// Account to be debited
+    pub account: BankAccount,
+    /// Date the mandate was signed
+    pub signature_date: NaiveDate,
+    /// One-off or recurring
+    pub mandate_type: MandateType,
+    /// Status
+    pub status: MandateStatus,
+    /// Date of the most recent collection
+    pub last_collection_date: Option<NaiveDate>,
+    /// Date the mandate was revoked
+    pub revoked_on: Option<NaiveDate>,
+    /// Reason for revocation
+    pub revocation_reason: Option<String>,
+    /// Created timestamp
+    pub created_at: DateTime<Utc>,
+    /// Updated timestamp
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Mandate {
+    /// Creates an active mandate
+    ///
+    /// # Arguments
+    ///
+    /// * `mandate_reference` - Unique mandate reference (max 35 characters)
+    /// * `party_id` - Party who signed the mandate
+    /// * `account` - Account to be debited
+    /// * `signature_date` - Date the mandate was signed
+    /// * `mandate_type` - One-off or recurring
+    ///
+    /// # Errors
+    ///
+    /// Returns `BillingError::InvalidMandate` if the reference is empty,
+    /// too long or contains characters outside the SEPA character set
+    pub fn new(
+        mandate_reference: impl Into<String>,
+        party_id: PartyId,
+        account: BankAccount,
+        signature_date: NaiveDate,
+        mandate_type: MandateType,
+    ) -> Result<Self, BillingError> {
+        let mandate_reference = mandate_reference.into();
+        let valid_chars = mandate_reference
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/-?:().,'+ ".contains(c));
+        if mandate_reference.is_empty() || mandate_reference.len() > 35 || !valid_chars {
+            return Err(BillingError::InvalidMandate(format!(
+                "Invalid mandate reference '{}'",
+                mandate_reference
+            )));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: MandateId::new_v7(),
+            mandate_reference,
+            party_id,
+            policy_id: None,
+            account,
+            signature_date,
+            mandate_type,
+            status: MandateStatus::Active,
+            last_collection_date: None,
+            revoked_on: None,
+            revocation_reason: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Restricts the mandate to a single policy
+    pub fn for_policy(mut self, policy_id: PolicyId) -> Self {
+        self.policy_id = Some(policy_id);
+        self
+    }
+
+    /// Checks whether the mandate can be collected against on a date
+    ///
+    /// The mandate must be active, signed on or before the date, and not
+    /// dormant for [`MANDATE_DORMANCY_MONTHS`] since its last use.
+    pub fn is_collectable(&self, as_of: NaiveDate) -> bool {
+        if self.status != MandateStatus::Active || self.signature_date > as_of {
+            return false;
+        }
+        let last_use = self.last_collection_date.unwrap_or(self.signature_date);
+        last_use
+            .checked_add_months(Months::new(MANDATE_DORMANCY_MONTHS))
+            .map(|lapse| as_of < lapse)
+            .unwrap_or(false)
+    }
+
+    /// Checks whether the mandate covers a policy
+    pub fn covers(&self, policy_id: PolicyId) -> bool {
+        self.policy_id.is_none_or(|id| id == policy_id)
+    }
+
+    /// Returns the sequence type for the next collection
+    pub fn next_sequence_type(&self) -> SequenceType {
+        match (self.mandate_type, self.last_collection_date) {
+            (MandateType::OneOff, _) => SequenceType::OneOff,
+            (MandateType::Recurring, None) => SequenceType::First,
+            (MandateType::Recurring, Some(_)) => SequenceType::Recurring,
+        }
+    }
+
+    /// Records a collection; one-off mandates become `Used`
+    pub fn record_collection(&mut self, collection_date: NaiveDate) {
+        self.last_collection_date = Some(collection_date);
+        if self.mandate_type == MandateType::OneOff {
+            self.status = MandateStatus::Used;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// Revokes the mandate
+    ///
+    /// # Arguments
+    ///
+    /// * `revoked_on` - Effective date of revocation
+    /// * `reason` - Reason for revocation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the mandate is already revoked
+    pub fn revoke(&mut self, revoked_on: NaiveDate, reason: &str) -> Result<(), BillingError> {
+        if self.status == MandateStatus::Revoked {
+            return Err(BillingError::InvalidMandate(format!(
+                "Mandate {} is already revoked",
+                self.mandate_reference
+            )));
+        }
+        self.status = MandateStatus::Revoked;
+        self.revoked_on = Some(revoked_on);
+        self.revocation_reason = Some(reason.to_string());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// Validates an IBAN using the ISO 7064 mod 97-10 check
+fn is_valid_iban(iban: &str) -> bool {
+    if iban.len() < 15 || iban.len() > 34 || !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+    let (country, rest) = iban.split_at(2);
+    if !country.chars().all(|c| c.is_ascii_uppercase()) || !rest[..2].chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let rearranged = iban[4..].chars().chain(iban[..4].chars());
+    let mut remainder: u32 = 0;
+    for c in rearranged {
+        let value = c.to_digit(36).unwrap_or(0);
+        remainder = if value >= 10 {
+            (remainder * 100 + value) % 97
+        } else {
+            (remainder * 10 + value) % 97
+        };
+    }
+    remainder == 1
+}
@@ -0,0 +1,112 @@
+//! ISO 20022 pain.008 (Customer Direct Debit Initiation) writer
+//!
+//! Produces a SEPA Core pain.008.001.02 document with one payment
+//! information block per sequence type, as required by the scheme.
+
+use std::fmt::Write;
+
+use super::collection::{CollectionInstruction, CollectionRun};
+use super::SequenceType;
+use quick_xml::escape::escape;
+
+/// Namespace of the generated document
+pub const NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:pain.008.001.02";
+
+/// Writes a collection run as a pain.008 document
+///
+/// # Arguments
+///
+/// * `run` - The collection run
+pub fn write(run: &CollectionRun) -> String {
+    let mut out = String::new();
+    let creditor = &run.creditor;
+
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(out, "<Document xmlns=\"{}\">", NAMESPACE);
+    out.push_str("  <CstmrDrctDbtInitn>\n");
+    out.push_str("    <GrpHdr>\n");
+    let _ = writeln!(out, "      <MsgId>{}</MsgId>", escape(&run.message_id));
+    let _ = writeln!(out, "      <CreDtTm>{}</CreDtTm>", run.created_at.format("%Y-%m-%dT%H:%M:%S"));
+    let _ = writeln!(out, "      <NbOfTxs>{}</NbOfTxs>", run.instructions.len());
+    let _ = writeln!(out, "      <CtrlSum>{:.2}</CtrlSum>", run.total().amount());
+    let _ = writeln!(out, "      <InitgPty><Nm>{}</Nm></InitgPty>", escape(&creditor.name));
+    out.push_str("    </GrpHdr>\n");
+
+    for sequence_type in [SequenceType::First, SequenceType::Recurring, SequenceType::OneOff] {
+        let batch: Vec<&CollectionInstruction> = run
+            .instructions
+            .iter()
+            .filter(|i| i.sequence_type == sequence_type)
+            .collect();
+        if batch.is_empty() {
+            continue;
+        }
+        let control_sum = batch
+            .iter()
+            .fold(rust_decimal::Decimal::ZERO, |acc, i| acc + i.amount.amount());
+
+        out.push_str("    <PmtInf>\n");
+        let _ = writeln!(out, "      <PmtInfId>{}-{}</PmtInfId>", escape(&run.message_id), sequence_type.code());
+        out.push_str("      <PmtMtd>DD</PmtMtd>\n");
+        let _ = writeln!(out, "      <NbOfTxs>{}</NbOfTxs>", batch.len());
+        let _ = writeln!(out, "      <CtrlSum>{:.2}</CtrlSum>", control_sum);
+        out.push_str("      <PmtTpInf>\n");
+        out.push_str("        <SvcLvl><Cd>SEPA</Cd></SvcLvl>\n");
+        out.push_str("        <LclInstrm><Cd>CORE</Cd></LclInstrm>\n");
+        let _ = writeln!(out, "        <SeqTp>{}</SeqTp>", sequence_type.code());
+        out.push_str("      </PmtTpInf>\n");
+        let _ = writeln!(out, "      <ReqdColltnDt>{}</ReqdColltnDt>", run.collection_date.format("%Y-%m-%d"));
+        let _ = writeln!(out, "      <Cdtr><Nm>{}</Nm></Cdtr>", escape(&creditor.name));
+        let _ = writeln!(out, "      <CdtrAcct><Id><IBAN>{}</IBAN></Id></CdtrAcct>", escape(&creditor.account.iban));
+        write_agent(&mut out, "      ", "CdtrAgt", creditor.account.bic.as_deref());
+        out.push_str("      <ChrgBr>SLEV</ChrgBr>\n");
+        out.push_str("      <CdtrSchmeId><Id><PrvtId><Othr>\n");
+        let _ = writeln!(out, "        <Id>{}</Id>", escape(&creditor.creditor_id));
+        out.push_str("        <SchmeNm><Prtry>SEPA</Prtry></SchmeNm>\n");
+        out.push_str("      </Othr></PrvtId></Id></CdtrSchmeId>\n");
+
+        for instruction in batch {
+            write_transaction(&mut out, instruction);
+        }
+
+        out.push_str("    </PmtInf>\n");
+    }
+
+    out.push_str("  </CstmrDrctDbtInitn>\n");
+    out.push_str("</Document>\n");
+    out
+}
+
+/// Writes a single `DrctDbtTxInf` block
+fn write_transaction(out: &mut String, instruction: &CollectionInstruction) {
+    out.push_str("      <DrctDbtTxInf>\n");
+    let _ = writeln!(out, "        <PmtId><EndToEndId>{}</EndToEndId></PmtId>", escape(&instruction.end_to_end_id));
+    let _ = writeln!(
+        out,
+        "        <InstdAmt Ccy=\"{}\">{:.2}</InstdAmt>",
+        instruction.amount.currency(),
+        instruction.amount.amount()
+    );
+    out.push_str("        <DrctDbtTx><MndtRltdInf>\n");
+    let _ = writeln!(out, "          <MndtId>{}</MndtId>", escape(&instruction.mandate_reference));
+    let _ = writeln!(out, "          <DtOfSgntr>{}</DtOfSgntr>", instruction.signature_date.format("%Y-%m-%d"));
+    out.push_str("        </MndtRltdInf></DrctDbtTx>\n");
+    write_agent(out, "        ", "DbtrAgt", instruction.debtor_account.bic.as_deref());
+    let _ = writeln!(out, "        <Dbtr><Nm>{}</Nm></Dbtr>", escape(&instruction.debtor_account.holder_name));
+    let _ = writeln!(
+        out,
+        "        <DbtrAcct><Id><IBAN>{}</IBAN></Id></DbtrAcct>",
+        escape(&instruction.debtor_account.iban)
+    );
+    let _ = writeln!(out, "        <RmtInf><Ustrd>{}</Ustrd></RmtInf>", escape(&instruction.remittance_info));
+    out.push_str("      </DrctDbtTxInf>\n");
+}
+
+/// Writes a financial institution element, or NOTPROVIDED without a BIC
+fn write_agent(out: &mut String, indent: &str, name: &str, bic: Option<&str>) {
+    let institution = match bic {
+        Some(bic) => format!("<BIC>{}</BIC>", escape(bic)),
+        None => "<Othr><Id>NOTPROVIDED</Id></Othr>".to_string(),
+    };
+    let _ = writeln!(out, "{}<{}><FinInstnId>{}</FinInstnId></{}>", indent, name, institution, name);
+}
@@ -0,0 +1,346 @@
+//! Direct debit rejections and returns (R-transactions)
+//!
+//! Rejections arrive before settlement in a pain.002 status report; returns
+//! and refunds arrive after settlement in a pacs.004 payment return. Both
+//! are reduced to [`ReturnNotice`]s identified by the original end-to-end ID.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use core_kernel::{Currency, InvoiceId, JournalEntryId, MandateId, Money, PaymentId};
+use rust_decimal::Decimal;
+
+use crate::error::BillingError;
+use crate::xml::{self, Element};
+
+/// Kind of R-transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReturnKind {
+    /// Refused before settlement
+    Reject,
+    /// Returned by the debtor bank after settlement
+    Return,
+    /// Refunded at the debtor's request after settlement
+    Refund,
+}
+
+impl fmt::Display for ReturnKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReturnKind::Reject => write!(f, "reject"),
+            ReturnKind::Return => write!(f, "return"),
+            ReturnKind::Refund => write!(f, "refund"),
+        }
+    }
+}
+
+/// ISO 20022 reason code for a reject or return
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReturnReason {
+    /// AC01: account identifier incorrect
+    IncorrectAccountNumber,
+    /// AC04: account closed
+    ClosedAccount,
+    /// AC06: account blocked
+    BlockedAccount,
+    /// AG01: direct debits forbidden on this account
+    TransactionForbidden,
+    /// AM04: insufficient funds
+    InsufficientFunds,
+    /// AM05: duplicate collection
+    Duplicate,
+    /// MD01: no valid mandate
+    NoMandate,
+    /// MD02: mandate data missing or incorrect
+    MissingMandateData,
+    /// MD06: refund requested by the debtor
+    RefundRequest,
+    /// MD07: debtor deceased
+    DebtorDeceased,
+    /// MS02: refused by the debtor
+    RefusedByDebtor,
+    /// MS03: reason not specified
+    NotSpecified,
+    /// SL01: specific service offered by the debtor bank
+    DebtorBankService,
+    /// Any other code
+    Other(String),
+}
+
+impl ReturnReason {
+    /// Maps an ISO reason code
+    pub fn from_code(code: &str) -> Self {
+        match code.trim().to_uppercase().as_str() {
+            "AC01" => ReturnReason::IncorrectAccountNumber,
+            "AC04" => ReturnReason::ClosedAccount,
+            "AC06" => ReturnReason::BlockedAccount,
+            "AG01" => ReturnReason::TransactionForbidden,
+            "AM04" => ReturnReason::InsufficientFunds,
+            "AM05" => ReturnReason::Duplicate,
+            "MD01" => ReturnReason::NoMandate,
+            "MD02" => ReturnReason::MissingMandateData,
+            "MD06" => ReturnReason::RefundRequest,
+            "MD07" => ReturnReason::DebtorDeceased,
+            "MS02" => ReturnReason::RefusedByDebtor,
+            "MS03" => ReturnReason::NotSpecified,
+            "SL01" => ReturnReason::DebtorBankService,
+            other => ReturnReason::Other(other.to_string()),
+        }
+    }
+
+    /// Returns the ISO reason code
+    pub fn code(&self) -> &str {
+        match self {
+            ReturnReason::IncorrectAccountNumber => "AC01",
+            ReturnReason::ClosedAccount => "AC04",
+            ReturnReason::BlockedAccount => "AC06",
+            ReturnReason::TransactionForbidden => "AG01",
+            ReturnReason::InsufficientFunds => "AM04",
+            ReturnReason::Duplicate => "AM05",
+            ReturnReason::NoMandate => "MD01",
+            ReturnReason::MissingMandateData => "MD02",
+            ReturnReason::RefundRequest => "MD06",
+            ReturnReason::DebtorDeceased => "MD07",
+            ReturnReason::RefusedByDebtor => "MS02",
+            ReturnReason::NotSpecified => "MS03",
+            ReturnReason::DebtorBankService => "SL01",
+            ReturnReason::Other(code) => code,
+        }
+    }
+
+    /// Returns a human-readable description
+    pub fn description(&self) -> &str {
+        match self {
+            ReturnReason::IncorrectAccountNumber => "Account identifier incorrect",
+            ReturnReason::ClosedAccount => "Account closed",
+            ReturnReason::BlockedAccount => "Account blocked",
+            ReturnReason::TransactionForbidden => "Direct debit forbidden on account",
+            ReturnReason::InsufficientFunds => "Insufficient funds",
+            ReturnReason::Duplicate => "Duplicate collection",
+            ReturnReason::NoMandate => "No valid mandate",
+            ReturnReason::MissingMandateData => "Mandate data missing or incorrect",
+            ReturnReason::RefundRequest => "Refund requested by debtor",
+            ReturnReason::DebtorDeceased => "Debtor deceased",
+            ReturnReason::RefusedByDebtor => "Refused by debtor",
+            ReturnReason::NotSpecified => "Reason not specified",
+            ReturnReason::DebtorBankService => "Specific service offered by debtor bank",
+            ReturnReason::Other(_) => "Other reason",
+        }
+    }
+
+    /// Checks whether the mandate can no longer be collected against
+    pub fn revokes_mandate(&self) -> bool {
+        matches!(
+            self,
+            ReturnReason::IncorrectAccountNumber
+                | ReturnReason::ClosedAccount
+                | ReturnReason::TransactionForbidden
+                | ReturnReason::NoMandate
+                | ReturnReason::DebtorDeceased
+        )
+    }
+}
+
+impl fmt::Display for ReturnReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.code(), self.description())
+    }
+}
+
+/// A reject, return or refund of a single collection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReturnNotice {
+    /// End-to-end ID of the original collection
+    pub end_to_end_id: String,
+    /// Kind of R-transaction
+    pub kind: ReturnKind,
+    /// Reason
+    pub reason: ReturnReason,
+    /// Mandate reference quoted on the original collection
+    pub mandate_reference: Option<String>,
+    /// Amount returned, if stated
+    pub amount: Option<Money>,
+    /// Date of the reject, or settlement date of the return
+    pub return_date: NaiveDate,
+}
+
+impl ReturnNotice {
+    /// Creates a notice
+    pub fn new(
+        end_to_end_id: impl Into<String>,
+        kind: ReturnKind,
+        reason: ReturnReason,
+        return_date: NaiveDate,
+    ) -> Self {
+        Self {
+            end_to_end_id: end_to_end_id.into(),
+            kind,
+            reason,
+            mandate_reference: None,
+            amount: None,
+            return_date,
+        }
+    }
+}
+
+/// What was done with a notice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReturnAction {
+    /// Pending payment marked as failed
+    Rejected,
+    /// Completed payment reversed and invoice re-opened
+    Reversed,
+    /// No matching payment found
+    Unmatched,
+    /// Payment was already failed or reversed
+    AlreadyProcessed,
+}
+
+/// Result of processing a notice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnOutcome {
+    /// End-to-end ID from the notice
+    pub end_to_end_id: String,
+    /// Action taken
+    pub action: ReturnAction,
+    /// Reason
+    pub reason: ReturnReason,
+    /// Payment affected
+    pub payment_id: Option<PaymentId>,
+    /// Invoice re-opened
+    pub invoice_id: Option<InvoiceId>,
+    /// Reversing journal entry
+    pub journal_entry_id: Option<JournalEntryId>,
+    /// Mandate revoked because of the reason code
+    pub revoked_mandate: Option<MandateId>,
+}
+
+/// Parses a pain.002 customer payment status report
+///
+/// Returns a `Reject` notice for every transaction with status `RJCT`,
+/// dated on the report's creation date. Accepted and pending transactions
+/// are ignored.
+///
+/// # Errors
+///
+/// Returns `BillingError::CollectionFileParse` if the document is not a
+/// valid pain.002 report
+pub fn parse_pain002(input: &str) -> Result<Vec<ReturnNotice>, BillingError> {
+    let document = xml::parse(input).map_err(|e| parse_error("pain.002", &e))?;
+    let report = document
+        .find("CstmrPmtStsRpt")
+        .ok_or_else(|| parse_error("pain.002", "missing CstmrPmtStsRpt element"))?;
+    let report_date = parse_date(report, &["GrpHdr", "CreDtTm"], "pain.002")?
+        .ok_or_else(|| parse_error("pain.002", "missing CreDtTm element"))?;
+
+    let mut notices = Vec::new();
+    for payment_info in report.children("OrgnlPmtInfAndSts") {
+        for tx in payment_info.children("TxInfAndSts") {
+            if tx.text_at(&["TxSts"]).as_deref() != Some("RJCT") {
+                continue;
+            }
+            notices.push(parse_transaction(
+                tx,
+                ReturnKind::Reject,
+                report_date,
+                "pain.002",
+                &["StsRsnInf", "Rsn", "Cd"],
+            )?);
+        }
+    }
+    Ok(notices)
+}
+
+/// Parses a pacs.004 payment return
+///
+/// Transactions with reason MD06 are refunds; all others are returns.
+/// Each is dated on its interbank settlement date, or the message's if
+/// the transaction has none, or else the message's creation date.
+///
+/// # Errors
+///
+/// Returns `BillingError::CollectionFileParse` if the document is not a
+/// valid pacs.004 return
+pub fn parse_pacs004(input: &str) -> Result<Vec<ReturnNotice>, BillingError> {
+    let document = xml::parse(input).map_err(|e| parse_error("pacs.004", &e))?;
+    let message = document
+        .find("PmtRtr")
+        .ok_or_else(|| parse_error("pacs.004", "missing PmtRtr element"))?;
+    let message_date = match parse_date(message, &["GrpHdr", "IntrBkSttlmDt"], "pacs.004")? {
+        Some(date) => date,
+        None => parse_date(message, &["GrpHdr", "CreDtTm"], "pacs.004")?
+            .ok_or_else(|| parse_error("pacs.004", "missing CreDtTm element"))?,
+    };
+
+    message
+        .children("TxInf")
+        .map(|tx| {
+            let return_date = parse_date(tx, &["IntrBkSttlmDt"], "pacs.004")?.unwrap_or(message_date);
+            let mut notice =
+                parse_transaction(tx, ReturnKind::Return, return_date, "pacs.004", &["RtrRsnInf", "Rsn", "Cd"])?;
+            if notice.reason == ReturnReason::RefundRequest {
+                notice.kind = ReturnKind::Refund;
+            }
+            if let Some(amount) = tx.child("RtrdIntrBkSttlmAmt") {
+                notice.amount = Some(parse_amount(amount, "pacs.004")?);
+            }
+            Ok(notice)
+        })
+        .collect()
+}
+
+/// Reads the fields shared by pain.002 and pacs.004 transactions
+fn parse_transaction(
+    tx: &Element,
+    kind: ReturnKind,
+    return_date: NaiveDate,
+    format: &str,
+    reason_path: &[&str],
+) -> Result<ReturnNotice, BillingError> {
+    let end_to_end_id = tx
+        .text_at(&["OrgnlEndToEndId"])
+        .ok_or_else(|| parse_error(format, "transaction without OrgnlEndToEndId"))?;
+    let reason = tx
+        .text_at(reason_path)
+        .map(|code| ReturnReason::from_code(&code))
+        .unwrap_or(ReturnReason::NotSpecified);
+
+    let mut notice = ReturnNotice::new(end_to_end_id, kind, reason, return_date);
+    notice.mandate_reference = tx.text_at(&["OrgnlTxRef", "MndtRltdInf", "MndtId"]);
+    if let Some(amount) = tx.path(&["OrgnlTxRef", "Amt", "InstdAmt"]) {
+        notice.amount = Some(parse_amount(amount, format)?);
+    }
+    Ok(notice)
+}
+
+/// Parses an amount element with its `Ccy` attribute
+fn parse_amount(element: &Element, format: &str) -> Result<Money, BillingError> {
+    let currency: Currency = element
+        .attribute("Ccy")
+        .ok_or_else(|| parse_error(format, "amount without Ccy attribute"))?
+        .parse()
+        .map_err(|e: core_kernel::MoneyError| parse_error(format, &e.to_string()))?;
+    let value: Decimal = element
+        .text
+        .trim()
+        .parse()
+        .map_err(|_| parse_error(format, &format!("invalid amount '{}'", element.text.trim())))?;
+    Ok(Money::new(value, currency))
+}
+
+/// Parses the date of an ISO date or date-time element, if present
+fn parse_date(element: &Element, path: &[&str], format: &str) -> Result<Option<NaiveDate>, BillingError> {
+    element
+        .text_at(path)
+        .map(|text| {
+            text.get(..10)
+                .and_then(|date| date.parse().ok())
+                .ok_or_else(|| parse_error(format, &format!("invalid date '{}'", text)))
+        })
+        .transpose()
+}
+
+fn parse_error(format: &str, message: &str) -> BillingError {
+    BillingError::CollectionFileParse(format!("{}: {}", format, message))
+}
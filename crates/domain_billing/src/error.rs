@@ -48,4 +48,16 @@ pub enum BillingError {
     /// Invalid operation
     #[error("Invalid operation: {0}")]
     InvalidOperation(String),
+
+    /// Bank statement could not be parsed
+    #[error("Statement parse error: {0}")]
+    StatementParse(String),
+
+    /// Mandate or bank account is invalid, or cannot be used
+    #[error("Invalid mandate: {0}")]
+    InvalidMandate(String),
+
+    /// Direct debit status or return file could not be parsed
+    #[error("Collection file parse error: {0}")]
+    CollectionFileParse(String),
 }
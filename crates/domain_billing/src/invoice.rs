@@ -118,6 +118,13 @@ impl Invoice {
         self
     }
 
+    /// Replaces the tax amount on an existing invoice
+    pub fn set_tax(&mut self, tax: Money) {
+        self.tax = Some(tax);
+        self.recalculate_totals();
+        self.updated_at = Utc::now();
+    }
+
     /// Issues the invoice
     pub fn issue(&mut self) {
         self.status = InvoiceStatus::Issued;
@@ -136,12 +143,77 @@ impl Invoice {
         }
     }
 
+    /// Reverses a previously recorded payment, re-opening the invoice
+    ///
+    /// Used when a collected payment is returned by the bank. The status
+    /// falls back to `PartiallyPaid`, or `Issued` if nothing remains paid;
+    /// overdue invoices are picked up again by delinquency processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount being reversed
+    /// * `reason` - Reason recorded in the invoice notes
+    pub fn reverse_payment(&mut self, amount: Money, reason: &str) {
+        self.amount_paid = self.amount_paid - amount;
+        if self.amount_paid.is_negative() {
+            self.amount_paid = Money::zero(self.currency);
+        }
+        self.notes = Some(reason.to_string());
+        self.updated_at = Utc::now();
+
+        if matches!(self.status, InvoiceStatus::Paid | InvoiceStatus::PartiallyPaid) {
+            self.status = if self.amount_paid.is_zero() {
+                InvoiceStatus::Issued
+            } else {
+                InvoiceStatus::PartiallyPaid
+            };
+        }
+    }
+
+    /// Checks if the invoice can still receive a payment
+    ///
+    /// Issued, sent, partially paid and overdue invoices are open.
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            InvoiceStatus::Issued | InvoiceStatus::Sent | InvoiceStatus::PartiallyPaid | InvoiceStatus::Overdue
+        )
+    }
+
     /// Checks if invoice is overdue
     pub fn is_overdue(&self) -> bool {
         let today = Utc::now().date_naive();
         today > self.due_date && !matches!(self.status, InvoiceStatus::Paid | InvoiceStatus::Cancelled)
     }
 
+    /// Marks the invoice as overdue
+    ///
+    /// Only open invoices (issued, sent or partially paid) move to `Overdue`;
+    /// invoices in any other status are left untouched.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the status changed
+    pub fn mark_overdue(&mut self) -> bool {
+        match self.status {
+            InvoiceStatus::Issued | InvoiceStatus::Sent | InvoiceStatus::PartiallyPaid => {
+                self.status = InvoiceStatus::Overdue;
+                self.updated_at = Utc::now();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the number of days the invoice is past due as of a date
+    ///
+    /// # Arguments
+    ///
+    /// * `as_of` - The date to measure against
+    pub fn days_overdue(&self, as_of: NaiveDate) -> u32 {
+        (as_of - self.due_date).num_days().max(0) as u32
+    }
+
     /// Returns the balance due
     pub fn balance_due(&self) -> Money {
         self.total - self.amount_paid
@@ -224,6 +296,8 @@ pub enum InvoiceItemType {
     ReinstatementFee,
     /// Late payment fee
     LateFee,
+    /// Interest charged on overdue amounts
+    Interest,
     /// Tax
     Tax,
     /// Other charge
@@ -99,6 +99,11 @@ impl Ledger {
         self.balances.get(id).copied()
     }
 
+    /// Returns all journal entries in posting order
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.journal_entries
+    }
+
     /// Posts a transaction to the ledger
     ///
     /// This method validates that the transaction is balanced and
@@ -38,11 +38,20 @@ pub mod account;
 pub mod transaction;
 pub mod invoice;
 pub mod payment;
+pub mod cash_application;
+pub mod statement;
+pub mod direct_debit;
+pub mod tax;
 pub mod error;
+mod xml;
 
 pub use ledger::Ledger;
 pub use account::{Account, AccountType, AccountCategory};
 pub use transaction::{Transaction, Posting, PostingType};
 pub use invoice::{Invoice, InvoiceItem, InvoiceStatus};
-pub use payment::{Payment, PaymentMethod, PaymentStatus};
+pub use payment::{Payment, PaymentDirection, PaymentMethod, PaymentStatus};
+pub use cash_application::{CashApplicationEngine, CashApplicationAccounts, CashApplicationResult, MatchMethod, OverpaymentTreatment};
+pub use statement::{BankStatement, StatementLine, EntryDirection, BankReconciler, ReconciliationReport};
+pub use direct_debit::{DirectDebitEngine, Mandate, MandateStatus, MandateType, CollectionRun, ReturnNotice, ReturnReason};
+pub use tax::{TaxEngine, TaxRule, TaxType, TaxJurisdiction, TaxAssessment, TaxExemption};
 pub use error::BillingError;
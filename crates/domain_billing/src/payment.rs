@@ -44,15 +44,31 @@ pub enum PaymentStatus {
     OnHold,
 }
 
+/// Direction of money movement
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentDirection {
+    /// Money received from a payer
+    #[default]
+    Incoming,
+    /// Money paid out to a payee (refunds, dividends)
+    Outgoing,
+}
+
 /// A payment record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payment {
     /// Unique identifier
     pub id: PaymentId,
-    /// Invoice being paid
-    pub invoice_id: InvoiceId,
-    /// Payer ID
-    pub payer_id: PartyId,
+    /// Invoice being paid (None until the payment is matched)
+    pub invoice_id: Option<InvoiceId>,
+    /// Payer ID (None for unidentified receipts)
+    pub payer_id: Option<PartyId>,
+    /// Payee ID for outgoing payments
+    #[serde(default)]
+    pub payee_id: Option<PartyId>,
+    /// Whether money is received or paid out
+    #[serde(default)]
+    pub direction: PaymentDirection,
     /// Payment amount
     pub amount: Money,
     /// Payment method
@@ -86,12 +102,30 @@ impl Payment {
         amount: Money,
         method: PaymentMethod,
     ) -> Self {
+        let mut payment = Self::received(amount, method);
+        payment.invoice_id = Some(invoice_id);
+        payment.payer_id = Some(payer_id);
+        payment
+    }
+
+    /// Creates a received payment that is not yet matched to an invoice
+    ///
+    /// Used for incoming receipts whose invoice, and possibly payer, are
+    /// determined later by cash application.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount received
+    /// * `method` - Payment method
+    pub fn received(amount: Money, method: PaymentMethod) -> Self {
         let now = Utc::now();
 
         Self {
             id: PaymentId::new_v7(),
-            invoice_id,
-            payer_id,
+            invoice_id: None,
+            payer_id: None,
+            payee_id: None,
+            direction: PaymentDirection::Incoming,
             amount,
             method,
             external_reference: None,
@@ -103,12 +137,40 @@ impl Payment {
         }
     }
 
+    /// Creates an outgoing payment to a payee
+    ///
+    /// Used for money paid out rather than collected, such as premium
+    /// refunds and cash dividends.
+    ///
+    /// # Arguments
+    ///
+    /// * `payee_id` - Who is being paid
+    /// * `amount` - Amount paid out
+    /// * `method` - Payment method
+    pub fn disbursement(payee_id: PartyId, amount: Money, method: PaymentMethod) -> Self {
+        let mut payment = Self::received(amount, method);
+        payment.payee_id = Some(payee_id);
+        payment.direction = PaymentDirection::Outgoing;
+        payment
+    }
+
+    /// Returns true if the payment pays money out
+    pub fn is_disbursement(&self) -> bool {
+        self.direction == PaymentDirection::Outgoing
+    }
+
     /// Sets the external reference
     pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
         self.external_reference = Some(reference.into());
         self
     }
 
+    /// Sets the payer
+    pub fn with_payer(mut self, payer_id: PartyId) -> Self {
+        self.payer_id = Some(payer_id);
+        self
+    }
+
     /// Marks the payment as completed
     pub fn complete(&mut self) {
         self.status = PaymentStatus::Completed;
@@ -0,0 +1,205 @@
+//! ISO 20022 camt.053 (Bank-to-Customer Statement) parser
+//!
+//! Reads the booked entries (`Ntry`) of each `Stmt` in a
+//! `BkToCstmrStmt` document. Pending and informational entries are skipped.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use core_kernel::{Currency, Money};
+
+use super::{BankStatement, EntryDirection, StatementLine};
+use crate::error::BillingError;
+use crate::xml::{self, Element};
+
+/// Parses a camt.053 document into bank statements
+///
+/// # Arguments
+///
+/// * `input` - The XML document
+///
+/// # Errors
+///
+/// Returns `BillingError::StatementParse` if the document is not a valid
+/// camt.053 statement
+pub fn parse(input: &str) -> Result<Vec<BankStatement>, BillingError> {
+    let document = xml::parse(input).map_err(|e| parse_error(&e))?;
+    let report = document
+        .find("BkToCstmrStmt")
+        .ok_or_else(|| parse_error("missing BkToCstmrStmt element"))?;
+
+    report.children("Stmt").map(parse_statement).collect()
+}
+
+/// Parses a single `Stmt` element
+fn parse_statement(stmt: &Element) -> Result<BankStatement, BillingError> {
+    let statement_id = stmt
+        .text_at(&["Id"])
+        .ok_or_else(|| parse_error("statement without Id"))?;
+    let account = stmt
+        .text_at(&["Acct", "Id", "IBAN"])
+        .or_else(|| stmt.text_at(&["Acct", "Id", "Othr", "Id"]))
+        .ok_or_else(|| parse_error("statement without account identifier"))?;
+
+    let mut lines = Vec::new();
+    for entry in stmt.children("Ntry") {
+        if is_booked(entry) {
+            lines.push(parse_entry(entry)?);
+        }
+    }
+
+    let currency = match stmt.text_at(&["Acct", "Ccy"]) {
+        Some(code) => parse_currency(&code)?,
+        None => lines
+            .first()
+            .map(|l| l.amount.currency())
+            .ok_or_else(|| parse_error("statement without currency"))?,
+    };
+
+    let mut opening_balance = None;
+    let mut closing_balance = None;
+    for balance in stmt.children("Bal") {
+        let code = balance.text_at(&["Tp", "CdOrPrtry", "Cd"]);
+        let amount = signed_amount(balance)?;
+        match code.as_deref() {
+            Some("OPBD") | Some("PRCD") => opening_balance = Some(amount),
+            Some("CLBD") => closing_balance = Some(amount),
+            _ => {}
+        }
+    }
+
+    let from_date = stmt
+        .text_at(&["FrToDt", "FrDtTm"])
+        .map(|d| parse_date(&d))
+        .transpose()?
+        .or_else(|| lines.iter().map(|l| l.booking_date).min())
+        .ok_or_else(|| parse_error("cannot determine statement period"))?;
+    let to_date = stmt
+        .text_at(&["FrToDt", "ToDtTm"])
+        .map(|d| parse_date(&d))
+        .transpose()?
+        .or_else(|| lines.iter().map(|l| l.booking_date).max())
+        .unwrap_or(from_date);
+
+    Ok(BankStatement {
+        statement_id,
+        account,
+        currency,
+        from_date,
+        to_date,
+        opening_balance,
+        closing_balance,
+        lines,
+    })
+}
+
+/// Parses a booked `Ntry` element
+fn parse_entry(entry: &Element) -> Result<StatementLine, BillingError> {
+    let amount = parse_amount(entry.child("Amt").ok_or_else(|| parse_error("entry without Amt"))?)?;
+    let direction = parse_direction(entry)?;
+    let booking_date = entry
+        .text_at(&["BookgDt", "Dt"])
+        .or_else(|| entry.text_at(&["BookgDt", "DtTm"]))
+        .ok_or_else(|| parse_error("entry without booking date"))
+        .and_then(|d| parse_date(&d))?;
+    let value_date = entry
+        .text_at(&["ValDt", "Dt"])
+        .or_else(|| entry.text_at(&["ValDt", "DtTm"]))
+        .map(|d| parse_date(&d))
+        .transpose()?;
+
+    let details = entry.path(&["NtryDtls", "TxDtls"]);
+    let detail_text = |path: &[&str]| details.and_then(|d| d.text_at(path));
+
+    let remittance_info = details.and_then(|d| d.child("RmtInf")).and_then(|rmt| {
+        let parts: Vec<String> = rmt
+            .children("Ustrd")
+            .map(|u| u.text.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if parts.is_empty() {
+            rmt.text_at(&["Strd", "CdtrRefInf", "Ref"])
+        } else {
+            Some(parts.join(" "))
+        }
+    });
+
+    let counterparty_name = match direction {
+        EntryDirection::Credit => detail_text(&["RltdPties", "Dbtr", "Nm"])
+            .or_else(|| detail_text(&["RltdPties", "Dbtr", "Pty", "Nm"])),
+        EntryDirection::Debit => detail_text(&["RltdPties", "Cdtr", "Nm"])
+            .or_else(|| detail_text(&["RltdPties", "Cdtr", "Pty", "Nm"])),
+    };
+
+    Ok(StatementLine {
+        booking_date,
+        value_date,
+        amount,
+        direction,
+        bank_reference: entry
+            .text_at(&["AcctSvcrRef"])
+            .or_else(|| entry.text_at(&["NtryRef"])),
+        customer_reference: detail_text(&["Refs", "EndToEndId"])
+            .filter(|r| r != "NOTPROVIDED"),
+        remittance_info,
+        counterparty_name,
+    })
+}
+
+/// Returns true if an entry is booked (status BOOK)
+fn is_booked(entry: &Element) -> bool {
+    entry
+        .text_at(&["Sts"])
+        .or_else(|| entry.text_at(&["Sts", "Cd"]))
+        .map(|status| status == "BOOK")
+        .unwrap_or(true)
+}
+
+/// Parses an amount element with its `Ccy` attribute
+fn parse_amount(element: &Element) -> Result<Money, BillingError> {
+    let currency = parse_currency(
+        element
+            .attribute("Ccy")
+            .ok_or_else(|| parse_error("amount without Ccy attribute"))?,
+    )?;
+    let value: Decimal = element
+        .text
+        .trim()
+        .parse()
+        .map_err(|_| parse_error(&format!("invalid amount '{}'", element.text.trim())))?;
+    Ok(Money::new(value, currency))
+}
+
+/// Parses a balance amount, negated for debit balances
+fn signed_amount(balance: &Element) -> Result<Money, BillingError> {
+    let amount = parse_amount(balance.child("Amt").ok_or_else(|| parse_error("balance without Amt"))?)?;
+    Ok(match parse_direction(balance)? {
+        EntryDirection::Credit => amount,
+        EntryDirection::Debit => -amount,
+    })
+}
+
+/// Parses the `CdtDbtInd` of an element
+fn parse_direction(element: &Element) -> Result<EntryDirection, BillingError> {
+    match element.text_at(&["CdtDbtInd"]).as_deref() {
+        Some("CRDT") => Ok(EntryDirection::Credit),
+        Some("DBIT") => Ok(EntryDirection::Debit),
+        other => Err(parse_error(&format!("invalid CdtDbtInd {:?}", other))),
+    }
+}
+
+/// Parses an ISO date or date-time, keeping the date part
+fn parse_date(value: &str) -> Result<NaiveDate, BillingError> {
+    let date = value.get(..10).unwrap_or(value);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| parse_error(&format!("invalid date '{}'", value)))
+}
+
+fn parse_currency(code: &str) -> Result<Currency, BillingError> {
+    code.parse()
+        .map_err(|e: core_kernel::MoneyError| parse_error(&e.to_string()))
+}
+
+fn parse_error(message: &str) -> BillingError {
+    BillingError::StatementParse(format!("camt.053: {}", message))
+}
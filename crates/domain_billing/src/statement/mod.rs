@@ -0,0 +1,145 @@
+//! Bank statement import and reconciliation
+//!
+//! This module parses daily bank statements into a common representation,
+//! turns incoming credits into payment candidates for cash application, and
+//! reconciles the cash-at-bank ledger account against the statement.
+//!
+//! # Supported Formats
+//!
+//! - **camt.053**: ISO 20022 Bank-to-Customer Statement (XML)
+//! - **MT940**: SWIFT Customer Statement Message
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use domain_billing::statement::{camt053, BankReconciler};
+//!
+//! let statements = camt053::parse(&xml)?;
+//! for statement in &statements {
+//!     let candidates = statement.payment_candidates();
+//!     let report = BankReconciler::new(cash_account).reconcile(statement, &ledger);
+//! }
+//! ```
+
+pub mod camt053;
+pub mod mt940;
+pub mod reconciliation;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use core_kernel::{Currency, Money};
+
+use crate::payment::{Payment, PaymentMethod};
+
+pub use reconciliation::{BankReconciler, LedgerItem, ReconciledItem, ReconciliationReport};
+
+/// Direction of a statement entry from the account holder's perspective
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntryDirection {
+    /// Money received into the account
+    Credit,
+    /// Money paid out of the account
+    Debit,
+}
+
+/// A bank statement for one account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankStatement {
+    /// Statement identifier assigned by the bank
+    pub statement_id: String,
+    /// Account identifier (IBAN or bank account number)
+    pub account: String,
+    /// Statement currency
+    pub currency: Currency,
+    /// First day covered by the statement
+    pub from_date: NaiveDate,
+    /// Last day covered by the statement
+    pub to_date: NaiveDate,
+    /// Opening booked balance (signed)
+    pub opening_balance: Option<Money>,
+    /// Closing booked balance (signed)
+    pub closing_balance: Option<Money>,
+    /// Booked entries
+    pub lines: Vec<StatementLine>,
+}
+
+impl BankStatement {
+    /// Returns payment candidates for every incoming credit
+    ///
+    /// Candidates are unmatched `Payment`s ready for cash application.
+    pub fn payment_candidates(&self) -> Vec<Payment> {
+        self.lines
+            .iter()
+            .filter_map(StatementLine::to_payment_candidate)
+            .collect()
+    }
+
+    /// Sums the signed amounts of all lines
+    pub fn net_movement(&self) -> Money {
+        self.lines
+            .iter()
+            .fold(Money::zero(self.currency), |acc, line| acc + line.signed_amount())
+    }
+}
+
+/// A single booked entry on a bank statement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementLine {
+    /// Booking date
+    pub booking_date: NaiveDate,
+    /// Value date
+    pub value_date: Option<NaiveDate>,
+    /// Amount (always positive)
+    pub amount: Money,
+    /// Credit or debit
+    pub direction: EntryDirection,
+    /// Reference assigned by the bank
+    pub bank_reference: Option<String>,
+    /// Reference supplied by the counterparty (end-to-end or customer reference)
+    pub customer_reference: Option<String>,
+    /// Unstructured remittance information
+    pub remittance_info: Option<String>,
+    /// Name of the counterparty
+    pub counterparty_name: Option<String>,
+}
+
+impl StatementLine {
+    /// Returns the amount signed by direction (credits positive)
+    pub fn signed_amount(&self) -> Money {
+        match self.direction {
+            EntryDirection::Credit => self.amount,
+            EntryDirection::Debit => -self.amount,
+        }
+    }
+
+    /// Converts an incoming credit into a payment candidate
+    ///
+    /// The candidate carries the remittance information (or, failing that,
+    /// the customer reference) as its external reference so that cash
+    /// application can match it to an invoice. Debits return `None`.
+    pub fn to_payment_candidate(&self) -> Option<Payment> {
+        if self.direction != EntryDirection::Credit {
+            return None;
+        }
+
+        let mut payment = Payment::received(self.amount, PaymentMethod::BankTransfer);
+        if let Some(reference) = self
+            .remittance_info
+            .as_ref()
+            .or(self.customer_reference.as_ref())
+            .or(self.bank_reference.as_ref())
+        {
+            payment = payment.with_reference(reference.clone());
+        }
+        if let Some(date) = self.booking_date.and_hms_opt(0, 0, 0) {
+            payment.payment_date = date.and_utc();
+        }
+        payment.notes = self
+            .counterparty_name
+            .as_ref()
+            .map(|name| format!("Received from {}", name));
+
+        Some(payment)
+    }
+}
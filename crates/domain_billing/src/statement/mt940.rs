@@ -0,0 +1,301 @@
+//! SWIFT MT940 (Customer Statement Message) parser
+//!
+//! Reads the text block of one or more MT940 messages. Each message starts
+//! with a `:20:` transaction reference; entries are `:61:` statement lines,
+//! each optionally followed by `:86:` information to the account owner.
+//!
+//! Amounts use a comma as the decimal separator, and dates are `YYMMDD`.
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+
+use core_kernel::{Currency, Money};
+
+use super::{BankStatement, EntryDirection, StatementLine};
+use crate::error::BillingError;
+
+/// Parses an MT940 file into bank statements
+///
+/// SWIFT block wrappers (`{1:...}{4:` and `-}`) are tolerated.
+///
+/// # Arguments
+///
+/// * `input` - The MT940 text
+///
+/// # Errors
+///
+/// Returns `BillingError::StatementParse` if a message is malformed
+pub fn parse(input: &str) -> Result<Vec<BankStatement>, BillingError> {
+    let mut statements = Vec::new();
+    let mut current: Vec<(String, String)> = Vec::new();
+
+    for (tag, value) in fields(input) {
+        if tag == "20" && !current.is_empty() {
+            statements.push(build_statement(&current)?);
+            current.clear();
+        }
+        current.push((tag, value));
+    }
+    if !current.is_empty() {
+        statements.push(build_statement(&current)?);
+    }
+
+    Ok(statements)
+}
+
+/// Splits the text into (tag, value) fields, joining continuation lines
+fn fields(input: &str) -> Vec<(String, String)> {
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    for raw in input.lines() {
+        let line = raw.trim_end_matches('\r');
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed == "-}" || trimmed == "-" {
+            continue;
+        }
+
+        let tagged = line
+            .strip_prefix(':')
+            .and_then(|rest| rest.split_once(':'))
+            .filter(|(tag, _)| tag.len() <= 3 && tag.starts_with(|c: char| c.is_ascii_digit()));
+
+        match tagged {
+            Some((tag, value)) => fields.push((tag.to_string(), value.to_string())),
+            None => {
+                if let Some((_, value)) = fields.last_mut() {
+                    value.push('\n');
+                    value.push_str(line);
+                }
+            }
+        }
+    }
+
+    fields
+}
+
+/// Builds a statement from the fields of one message
+fn build_statement(fields: &[(String, String)]) -> Result<BankStatement, BillingError> {
+    let field = |tag: &str| fields.iter().find(|(t, _)| t == tag).map(|(_, v)| v.trim());
+
+    let statement_id = field("28C")
+        .or_else(|| field("28"))
+        .or_else(|| field("20"))
+        .ok_or_else(|| parse_error("message without :20: reference"))?
+        .to_string();
+    let account = field("25")
+        .ok_or_else(|| parse_error("message without :25: account"))?
+        .to_string();
+
+    let opening = field("60F")
+        .or_else(|| field("60M"))
+        .map(parse_balance)
+        .transpose()?;
+    let closing = field("62F")
+        .or_else(|| field("62M"))
+        .map(parse_balance)
+        .transpose()?;
+    let (currency, opening_date) = match &opening {
+        Some((_, date, amount)) => (amount.currency(), Some(*date)),
+        None => (
+            closing
+                .as_ref()
+                .map(|(_, _, amount)| amount.currency())
+                .ok_or_else(|| parse_error("message without :60F: or :62F: balance"))?,
+            None,
+        ),
+    };
+
+    let mut lines: Vec<StatementLine> = Vec::new();
+    for (tag, value) in fields {
+        match tag.as_str() {
+            "61" => lines.push(parse_statement_line(value, currency)?),
+            "86" => {
+                if let Some(line) = lines.last_mut() {
+                    let info = value.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if !info.is_empty() {
+                        line.remittance_info = Some(info);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let from_date = lines
+        .iter()
+        .map(|l| l.booking_date)
+        .min()
+        .or(opening_date)
+        .or_else(|| closing.as_ref().map(|(_, date, _)| *date))
+        .ok_or_else(|| parse_error("cannot determine statement period"))?;
+    let to_date = closing
+        .as_ref()
+        .map(|(_, date, _)| *date)
+        .or_else(|| lines.iter().map(|l| l.booking_date).max())
+        .unwrap_or(from_date);
+
+    Ok(BankStatement {
+        statement_id,
+        account,
+        currency,
+        from_date,
+        to_date,
+        opening_balance: opening.map(|(direction, _, amount)| signed(direction, amount)),
+        closing_balance: closing.map(|(direction, _, amount)| signed(direction, amount)),
+        lines,
+    })
+}
+
+/// Parses a balance field: `C240115EUR1000,00`
+fn parse_balance(value: &str) -> Result<(EntryDirection, NaiveDate, Money), BillingError> {
+    let value = value.trim();
+    let part = |from: usize, to: usize| {
+        value
+            .get(from..to)
+            .ok_or_else(|| parse_error(&format!("malformed balance '{}'", value)))
+    };
+    if value.len() < 11 {
+        return Err(parse_error(&format!("balance too short '{}'", value)));
+    }
+    let direction = match part(0, 1)? {
+        "C" => EntryDirection::Credit,
+        "D" => EntryDirection::Debit,
+        other => return Err(parse_error(&format!("invalid balance mark '{}'", other))),
+    };
+    let date = parse_yymmdd(part(1, 7)?)?;
+    let currency = parse_currency(part(7, 10)?)?;
+    let amount = parse_amount(part(10, value.len())?)?;
+    Ok((direction, date, Money::new(amount, currency)))
+}
+
+/// Parses a `:61:` statement line
+///
+/// Layout: value date (YYMMDD), optional entry date (MMDD), mark
+/// (C, D, RC, RD), optional funds code, amount, transaction type
+/// (4 chars), customer reference, optional `//` bank reference and an
+/// optional supplementary details line.
+fn parse_statement_line(value: &str, currency: Currency) -> Result<StatementLine, BillingError> {
+    let (first, supplementary) = match value.split_once('\n') {
+        Some((first, rest)) => (first.trim(), Some(rest.trim())),
+        None => (value.trim(), None),
+    };
+    let bytes = first.as_bytes();
+    if bytes.len() < 12 {
+        return Err(parse_error(&format!("statement line too short '{}'", first)));
+    }
+    let malformed = || parse_error(&format!("malformed statement line '{}'", first));
+    let from = |pos: usize| first.get(pos..).ok_or_else(malformed);
+
+    let value_date = parse_yymmdd(first.get(..6).ok_or_else(malformed)?)?;
+    let mut pos = 6;
+
+    let mut booking_date = value_date;
+    let entry = first
+        .get(pos..pos + 4)
+        .filter(|mmdd| bytes.len() > pos + 4 && mmdd.bytes().all(|b| b.is_ascii_digit()));
+    if let Some(mmdd) = entry {
+        booking_date = entry_date(value_date, mmdd)?;
+        pos += 4;
+    }
+
+    let direction = if from(pos)?.starts_with("RC") {
+        pos += 2;
+        EntryDirection::Debit
+    } else if from(pos)?.starts_with("RD") {
+        pos += 2;
+        EntryDirection::Credit
+    } else if from(pos)?.starts_with('C') {
+        pos += 1;
+        EntryDirection::Credit
+    } else if from(pos)?.starts_with('D') {
+        pos += 1;
+        EntryDirection::Debit
+    } else {
+        return Err(parse_error(&format!("invalid debit/credit mark in '{}'", first)));
+    };
+
+    if bytes.get(pos).is_some_and(u8::is_ascii_alphabetic) {
+        pos += 1;
+    }
+
+    let rest = from(pos)?;
+    let amount_len = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == ','))
+        .unwrap_or(rest.len());
+    let amount = parse_amount(&rest[..amount_len])?;
+    pos += amount_len;
+
+    // Transaction type identification code, e.g. NTRF
+    let references = from((pos + 4).min(first.len()))?;
+    let (customer_reference, bank_reference) = match references.split_once("//") {
+        Some((customer, bank)) => (customer, Some(bank)),
+        None => (references, None),
+    };
+
+    Ok(StatementLine {
+        booking_date,
+        value_date: Some(value_date),
+        amount: Money::new(amount, currency),
+        direction,
+        bank_reference: bank_reference
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty()),
+        customer_reference: Some(customer_reference.trim().to_string())
+            .filter(|r| !r.is_empty() && r != "NONREF"),
+        remittance_info: supplementary
+            .map(|s| s.to_string())
+            .filter(|s| !s.is_empty()),
+        counterparty_name: None,
+    })
+}
+
+/// Resolves an MMDD entry date relative to the value date's year
+///
+/// Entries booked across a year end can carry a December entry date with a
+/// January value date (or the reverse).
+fn entry_date(value_date: NaiveDate, mmdd: &str) -> Result<NaiveDate, BillingError> {
+    let (month, day) = mmdd
+        .get(..2)
+        .zip(mmdd.get(2..))
+        .ok_or_else(|| parse_error(&format!("invalid entry date '{}'", mmdd)))?;
+    let month: u32 = month.parse().map_err(|_| parse_error("invalid entry date"))?;
+    let day: u32 = day.parse().map_err(|_| parse_error("invalid entry date"))?;
+    let year = match (value_date.month(), month) {
+        (1, 12) => value_date.year() - 1,
+        (12, 1) => value_date.year() + 1,
+        _ => value_date.year(),
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| parse_error(&format!("invalid entry date '{}'", mmdd)))
+}
+
+/// Parses a YYMMDD date
+fn parse_yymmdd(value: &str) -> Result<NaiveDate, BillingError> {
+    NaiveDate::parse_from_str(value, "%y%m%d")
+        .map_err(|_| parse_error(&format!("invalid date '{}'", value)))
+}
+
+/// Parses an amount with a comma decimal separator
+fn parse_amount(value: &str) -> Result<Decimal, BillingError> {
+    let normalized = value.trim().replace(',', ".");
+    let normalized = normalized.strip_suffix('.').unwrap_or(&normalized);
+    normalized
+        .parse()
+        .map_err(|_| parse_error(&format!("invalid amount '{}'", value)))
+}
+
+fn signed(direction: EntryDirection, amount: Money) -> Money {
+    match direction {
+        EntryDirection::Credit => amount,
+        EntryDirection::Debit => -amount,
+    }
+}
+
+fn parse_currency(code: &str) -> Result<Currency, BillingError> {
+    code.parse()
+        .map_err(|e: core_kernel::MoneyError| parse_error(&e.to_string()))
+}
+
+fn parse_error(message: &str) -> BillingError {
+    BillingError::StatementParse(format!("MT940: {}", message))
+}
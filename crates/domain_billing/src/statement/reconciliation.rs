@@ -0,0 +1,226 @@
+//! Bank reconciliation
+//!
+//! Reconciles the cash-at-bank ledger account against a bank statement.
+//! Each statement line is matched to a cash posting of the same amount and
+//! direction booked within a date tolerance. Postings whose description
+//! carries the line's remittance information or references are preferred,
+//! then the closest date. Whatever is left on either side is reported as
+//! unreconciled.
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{AccountId, JournalEntryId, Money};
+
+use super::{BankStatement, EntryDirection, StatementLine};
+use crate::ledger::Ledger;
+use crate::transaction::PostingType;
+
+/// A posting to the cash-at-bank account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerItem {
+    /// Journal entry containing the posting
+    pub entry_id: JournalEntryId,
+    /// The posting
+    pub posting_id: Uuid,
+    /// Transaction date
+    pub date: NaiveDate,
+    /// Amount (always positive)
+    pub amount: Money,
+    /// Direction from the bank account's perspective
+    pub direction: EntryDirection,
+    /// Journal entry description
+    pub description: String,
+}
+
+/// A statement line matched to a ledger posting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconciledItem {
+    /// The statement line
+    pub line: StatementLine,
+    /// The matching ledger posting
+    pub ledger_item: LedgerItem,
+    /// Whether the posting's description carries the line's references
+    pub reference_matched: bool,
+}
+
+/// Result of reconciling a statement against the ledger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    /// Statement that was reconciled
+    pub statement_id: String,
+    /// First day of the statement period
+    pub from_date: NaiveDate,
+    /// Last day of the statement period
+    pub to_date: NaiveDate,
+    /// Matched items
+    pub matched: Vec<ReconciledItem>,
+    /// Statement lines with no ledger posting
+    pub unreconciled_statement_lines: Vec<StatementLine>,
+    /// Ledger postings in the period with no statement line
+    pub unreconciled_ledger_items: Vec<LedgerItem>,
+}
+
+impl ReconciliationReport {
+    /// Checks whether every item on both sides was matched
+    pub fn is_reconciled(&self) -> bool {
+        self.unreconciled_statement_lines.is_empty() && self.unreconciled_ledger_items.is_empty()
+    }
+}
+
+/// Reconciles bank statements against the cash-at-bank account
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let report = BankReconciler::new(cash_account)
+///     .with_date_tolerance(2)
+///     .reconcile(&statement, &ledger);
+/// for line in &report.unreconciled_statement_lines {
+///     println!("Not in ledger: {} on {}", line.amount, line.booking_date);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BankReconciler {
+    /// Cash-at-bank ledger account
+    cash_account: AccountId,
+    /// Maximum days between booking and ledger dates for a match
+    date_tolerance_days: i64,
+}
+
+impl BankReconciler {
+    /// Creates a reconciler with a three-day date tolerance
+    ///
+    /// # Arguments
+    ///
+    /// * `cash_account` - The cash-at-bank ledger account
+    pub fn new(cash_account: AccountId) -> Self {
+        Self {
+            cash_account,
+            date_tolerance_days: 3,
+        }
+    }
+
+    /// Sets the date tolerance in days
+    pub fn with_date_tolerance(mut self, days: u32) -> Self {
+        self.date_tolerance_days = days as i64;
+        self
+    }
+
+    /// Reconciles a statement against the ledger
+    ///
+    /// Ledger postings within the tolerance either side of the statement
+    /// period are eligible for matching, but only unmatched postings inside
+    /// the period are reported as unreconciled.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The bank statement
+    /// * `ledger` - The ledger holding the cash-at-bank account
+    pub fn reconcile(&self, statement: &BankStatement, ledger: &Ledger) -> ReconciliationReport {
+        let tolerance = Duration::days(self.date_tolerance_days);
+        let mut candidates: Vec<Option<LedgerItem>> = self
+            .ledger_items(
+                ledger,
+                statement.from_date - tolerance,
+                statement.to_date + tolerance,
+            )
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        let mut matched = Vec::new();
+        let mut unreconciled_statement_lines = Vec::new();
+
+        for line in &statement.lines {
+            let best = candidates
+                .iter()
+                .enumerate()
+                .filter_map(|(index, item)| item.as_ref().map(|item| (index, item)))
+                .filter(|(_, item)| item.direction == line.direction && item.amount == line.amount)
+                .map(|(index, item)| {
+                    let distance = (item.date - line.booking_date).num_days().abs();
+                    (index, distance, references_match(line, item))
+                })
+                .filter(|(_, distance, _)| *distance <= self.date_tolerance_days)
+                .min_by_key(|(index, distance, referenced)| (!*referenced, *distance, *index))
+                .map(|(index, _, referenced)| (index, referenced));
+
+            match best.and_then(|(index, referenced)| candidates[index].take().map(|item| (item, referenced))) {
+                Some((ledger_item, reference_matched)) => matched.push(ReconciledItem {
+                    line: line.clone(),
+                    ledger_item,
+                    reference_matched,
+                }),
+                None => unreconciled_statement_lines.push(line.clone()),
+            }
+        }
+
+        let unreconciled_ledger_items = candidates
+            .into_iter()
+            .flatten()
+            .filter(|item| item.date >= statement.from_date && item.date <= statement.to_date)
+            .collect();
+
+        ReconciliationReport {
+            statement_id: statement.statement_id.clone(),
+            from_date: statement.from_date,
+            to_date: statement.to_date,
+            matched,
+            unreconciled_statement_lines,
+            unreconciled_ledger_items,
+        }
+    }
+
+    /// Collects cash-at-bank postings dated within a range
+    fn ledger_items(&self, ledger: &Ledger, from: NaiveDate, to: NaiveDate) -> Vec<LedgerItem> {
+        ledger
+            .entries()
+            .iter()
+            .filter(|entry| {
+                let date = entry.transaction_date.date_naive();
+                date >= from && date <= to
+            })
+            .flat_map(|entry| {
+                entry
+                    .postings
+                    .iter()
+                    .filter(|posting| posting.account_id == self.cash_account)
+                    .map(move |posting| LedgerItem {
+                        entry_id: entry.id,
+                        posting_id: posting.id,
+                        date: entry.transaction_date.date_naive(),
+                        amount: posting.amount,
+                        direction: match posting.posting_type {
+                            PostingType::Debit => EntryDirection::Credit,
+                            PostingType::Credit => EntryDirection::Debit,
+                        },
+                        description: entry.description.clone(),
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Checks whether a posting's description carries a statement line's
+/// remittance information or references, ignoring case
+fn references_match(line: &StatementLine, item: &LedgerItem) -> bool {
+    let description = item.description.trim().to_uppercase();
+    if description.is_empty() {
+        return false;
+    }
+    let remittance = line
+        .remittance_info
+        .as_deref()
+        .map(|r| r.trim().to_uppercase())
+        .filter(|r| !r.is_empty());
+    if remittance.is_some_and(|r| r.contains(&description) || description.contains(&r)) {
+        return true;
+    }
+    [&line.customer_reference, &line.bank_reference]
+        .into_iter()
+        .flatten()
+        .map(|reference| reference.trim().to_uppercase())
+        .any(|reference| !reference.is_empty() && description.contains(&reference))
+}
@@ -0,0 +1,622 @@
+//! Premium taxes, stamp duty and levies
+//!
+//! This module computes the taxes charged on premium invoices from a set of
+//! dated tax rules, and posts the resulting liabilities to dedicated ledger
+//! accounts so they can be reported and remitted to each authority.
+//!
+//! # Rule Selection
+//!
+//! A rule applies to an invoice when:
+//! - Its jurisdiction matches the policyholder's country and, if the rule
+//!   names one, their state or region
+//! - It covers the policy's product line (rules without product lines
+//!   cover every line)
+//! - The assessment date falls within its effective period
+//! - The customer holds no valid exemption for its tax type
+//!
+//! Percentage rules are applied to each qualifying invoice item; flat rules
+//! (typically stamp duty) are charged once per invoice.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use domain_billing::tax::{TaxEngine, TaxJurisdiction};
+//!
+//! let jurisdiction = TaxJurisdiction::from_address(&policyholder_address);
+//! let assessment = engine.assess(&invoice, &jurisdiction, "life", invoice.invoice_date);
+//! engine.apply(&mut invoice, &assessment)?;
+//! engine.post(&mut ledger, &assessment, posting_date)?;
+//!
+//! let report = engine.remittance_report(quarter_start, quarter_end);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{AccountId, InvoiceId, JournalEntryId, Money, PartyId, utc_start_of_day};
+use domain_party::address::Address;
+
+use crate::error::BillingError;
+use crate::invoice::{Invoice, InvoiceItemType};
+use crate::ledger::Ledger;
+use crate::transaction::{Posting, Transaction};
+
+/// A taxing jurisdiction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TaxJurisdiction {
+    /// Country code (uppercase)
+    pub country: String,
+    /// State, province or region (uppercase), if taxed below country level
+    pub region: Option<String>,
+}
+
+impl TaxJurisdiction {
+    /// Creates a country-level jurisdiction
+    pub fn new(country: impl Into<String>) -> Self {
+        Self {
+            country: country.into().trim().to_uppercase(),
+            region: None,
+        }
+    }
+
+    /// Sets the state, province or region
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into().trim().to_uppercase());
+        self
+    }
+
+    /// Derives the jurisdiction from a party's address
+    pub fn from_address(address: &Address) -> Self {
+        let jurisdiction = Self::new(address.country.as_str());
+        match &address.state {
+            Some(state) if !state.trim().is_empty() => jurisdiction.with_region(state.as_str()),
+            _ => jurisdiction,
+        }
+    }
+
+    /// Checks whether a rule in this jurisdiction applies to a location
+    ///
+    /// A country-level jurisdiction covers all of the country's regions.
+    pub fn covers(&self, location: &TaxJurisdiction) -> bool {
+        self.country == location.country
+            && self
+                .region
+                .as_ref()
+                .is_none_or(|region| location.region.as_ref() == Some(region))
+    }
+}
+
+impl fmt::Display for TaxJurisdiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.region {
+            Some(region) => write!(f, "{}-{}", self.country, region),
+            None => write!(f, "{}", self.country),
+        }
+    }
+}
+
+/// Kind of tax
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TaxType {
+    /// Insurance premium tax
+    PremiumTax,
+    /// Goods and services tax or VAT on premium
+    Gst,
+    /// Stamp duty on the policy document
+    StampDuty,
+    /// Regulatory or fire-service levy
+    Levy,
+}
+
+impl fmt::Display for TaxType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TaxType::PremiumTax => "Premium tax",
+            TaxType::Gst => "GST",
+            TaxType::StampDuty => "Stamp duty",
+            TaxType::Levy => "Levy",
+        };
+        f.write_str(name)
+    }
+}
+
+/// How a rule calculates its tax
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TaxCalculation {
+    /// Percentage of each qualifying item
+    Percentage(Decimal),
+    /// Fixed amount charged once per invoice
+    FlatPerInvoice(Money),
+}
+
+/// A dated tax rule for one jurisdiction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxRule {
+    /// Rule code shown on remittance reports (e.g. "UK-IPT")
+    pub code: String,
+    /// Kind of tax
+    pub tax_type: TaxType,
+    /// Jurisdiction levying the tax
+    pub jurisdiction: TaxJurisdiction,
+    /// Product lines the rule covers (empty covers every line)
+    pub product_lines: Vec<String>,
+    /// How the tax is calculated
+    pub calculation: TaxCalculation,
+    /// Invoice item types that are taxable
+    pub taxable_items: Vec<InvoiceItemType>,
+    /// First day the rule applies
+    pub effective_from: NaiveDate,
+    /// Last day the rule applies (None if open-ended)
+    pub effective_to: Option<NaiveDate>,
+}
+
+impl TaxRule {
+    /// Creates a percentage rule on premium items
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Rule code
+    /// * `tax_type` - Kind of tax
+    /// * `jurisdiction` - Jurisdiction levying the tax
+    /// * `rate` - Rate as a fraction of the taxable amount
+    /// * `effective_from` - First day the rule applies
+    pub fn percentage(
+        code: impl Into<String>,
+        tax_type: TaxType,
+        jurisdiction: TaxJurisdiction,
+        rate: Decimal,
+        effective_from: NaiveDate,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            tax_type,
+            jurisdiction,
+            product_lines: Vec::new(),
+            calculation: TaxCalculation::Percentage(rate),
+            taxable_items: vec![InvoiceItemType::Premium],
+            effective_from,
+            effective_to: None,
+        }
+    }
+
+    /// Creates a flat per-invoice rule
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Rule code
+    /// * `tax_type` - Kind of tax
+    /// * `jurisdiction` - Jurisdiction levying the tax
+    /// * `amount` - Amount charged per invoice
+    /// * `effective_from` - First day the rule applies
+    pub fn flat(
+        code: impl Into<String>,
+        tax_type: TaxType,
+        jurisdiction: TaxJurisdiction,
+        amount: Money,
+        effective_from: NaiveDate,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            tax_type,
+            jurisdiction,
+            product_lines: Vec::new(),
+            calculation: TaxCalculation::FlatPerInvoice(amount),
+            taxable_items: vec![InvoiceItemType::Premium],
+            effective_from,
+            effective_to: None,
+        }
+    }
+
+    /// Restricts the rule to product lines
+    pub fn for_product_lines(mut self, lines: &[&str]) -> Self {
+        self.product_lines = lines.iter().map(|l| l.to_string()).collect();
+        self
+    }
+
+    /// Sets the taxable invoice item types
+    pub fn on_items(mut self, items: Vec<InvoiceItemType>) -> Self {
+        self.taxable_items = items;
+        self
+    }
+
+    /// Sets the last day the rule applies
+    pub fn until(mut self, effective_to: NaiveDate) -> Self {
+        self.effective_to = Some(effective_to);
+        self
+    }
+
+    /// Checks whether the rule applies to a location, product line and date
+    pub fn applies(&self, location: &TaxJurisdiction, product_line: &str, date: NaiveDate) -> bool {
+        self.jurisdiction.covers(location)
+            && (self.product_lines.is_empty() || self.product_lines.iter().any(|l| l == product_line))
+            && date >= self.effective_from
+            && self.effective_to.is_none_or(|to| date <= to)
+    }
+}
+
+/// A party's exemption from one or more taxes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxExemption {
+    /// Exempt party
+    pub party_id: PartyId,
+    /// Exempt tax types (empty exempts every tax)
+    pub tax_types: Vec<TaxType>,
+    /// Exemption certificate reference
+    pub certificate: String,
+    /// Last day the exemption is valid (None if open-ended)
+    pub valid_to: Option<NaiveDate>,
+}
+
+impl TaxExemption {
+    /// Creates an open-ended exemption from every tax
+    pub fn new(party_id: PartyId, certificate: impl Into<String>) -> Self {
+        Self {
+            party_id,
+            tax_types: Vec::new(),
+            certificate: certificate.into(),
+            valid_to: None,
+        }
+    }
+
+    /// Restricts the exemption to tax types
+    pub fn for_tax_types(mut self, tax_types: Vec<TaxType>) -> Self {
+        self.tax_types = tax_types;
+        self
+    }
+
+    /// Sets the last day the exemption is valid
+    pub fn valid_until(mut self, valid_to: NaiveDate) -> Self {
+        self.valid_to = Some(valid_to);
+        self
+    }
+
+    /// Checks whether the exemption covers a tax type on a date
+    pub fn exempts(&self, tax_type: TaxType, date: NaiveDate) -> bool {
+        (self.tax_types.is_empty() || self.tax_types.contains(&tax_type))
+            && self.valid_to.is_none_or(|to| date <= to)
+    }
+}
+
+/// Tax charged by one rule on one invoice item, or on the invoice
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxLine {
+    /// Rule that produced the line
+    pub rule_code: String,
+    /// Kind of tax
+    pub tax_type: TaxType,
+    /// Jurisdiction the tax is owed to
+    pub jurisdiction: TaxJurisdiction,
+    /// Invoice item taxed (None for per-invoice charges)
+    pub item_id: Option<Uuid>,
+    /// Amount the tax was calculated on
+    pub taxable_amount: Money,
+    /// Rate applied (None for flat charges)
+    pub rate: Option<Decimal>,
+    /// Tax amount
+    pub amount: Money,
+}
+
+/// Taxes assessed on one invoice
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxAssessment {
+    /// Invoice assessed
+    pub invoice_id: InvoiceId,
+    /// Customer on the invoice
+    pub customer_id: PartyId,
+    /// Jurisdiction of the customer
+    pub jurisdiction: TaxJurisdiction,
+    /// Product line of the policy
+    pub product_line: String,
+    /// Date the rules were evaluated at
+    pub assessment_date: NaiveDate,
+    /// Tax lines
+    pub lines: Vec<TaxLine>,
+    /// Codes of rules that applied but were waived by an exemption
+    pub exempted_rules: Vec<String>,
+    /// Total tax
+    pub total: Money,
+}
+
+impl TaxAssessment {
+    /// Returns the total of one kind of tax
+    pub fn total_for(&self, tax_type: TaxType) -> Money {
+        self.lines
+            .iter()
+            .filter(|l| l.tax_type == tax_type)
+            .fold(Money::zero(self.total.currency()), |acc, l| acc + l.amount)
+    }
+}
+
+/// Ledger accounts used for tax postings
+#[derive(Debug, Clone, Copy)]
+pub struct TaxAccounts {
+    /// Premium receivable debited with the tax billed
+    pub receivable: AccountId,
+    /// Premium tax payable
+    pub premium_tax: AccountId,
+    /// GST payable
+    pub gst: AccountId,
+    /// Stamp duty payable
+    pub stamp_duty: AccountId,
+    /// Regulatory levies payable
+    pub levies: AccountId,
+}
+
+impl TaxAccounts {
+    /// Returns the liability account for a kind of tax
+    pub fn liability_for(&self, tax_type: TaxType) -> AccountId {
+        match tax_type {
+            TaxType::PremiumTax => self.premium_tax,
+            TaxType::Gst => self.gst,
+            TaxType::StampDuty => self.stamp_duty,
+            TaxType::Levy => self.levies,
+        }
+    }
+}
+
+/// Tax owed to one authority under one rule over a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemittanceLine {
+    /// Jurisdiction the tax is owed to
+    pub jurisdiction: TaxJurisdiction,
+    /// Rule code
+    pub rule_code: String,
+    /// Kind of tax
+    pub tax_type: TaxType,
+    /// Liability account holding the tax
+    pub liability_account: AccountId,
+    /// Number of invoices taxed
+    pub invoice_count: usize,
+    /// Total taxable amount
+    pub taxable_amount: Money,
+    /// Total tax owed
+    pub tax_amount: Money,
+}
+
+/// A posted assessment, kept for remittance reporting
+#[derive(Debug, Clone)]
+struct PostedAssessment {
+    posting_date: NaiveDate,
+    assessment: TaxAssessment,
+}
+
+/// Engine assessing, posting and reporting premium taxes
+#[derive(Debug, Clone)]
+pub struct TaxEngine {
+    rules: Vec<TaxRule>,
+    exemptions: Vec<TaxExemption>,
+    accounts: TaxAccounts,
+    posted: Vec<PostedAssessment>,
+}
+
+impl TaxEngine {
+    /// Creates an engine with no rules
+    pub fn new(accounts: TaxAccounts) -> Self {
+        Self {
+            rules: Vec::new(),
+            exemptions: Vec::new(),
+            accounts,
+            posted: Vec::new(),
+        }
+    }
+
+    /// Adds a tax rule
+    pub fn with_rule(mut self, rule: TaxRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Registers a party's tax exemption
+    pub fn add_exemption(&mut self, exemption: TaxExemption) {
+        self.exemptions.push(exemption);
+    }
+
+    /// Returns the rules that apply to a location, product line and date
+    pub fn rules_for(&self, location: &TaxJurisdiction, product_line: &str, date: NaiveDate) -> Vec<&TaxRule> {
+        self.rules
+            .iter()
+            .filter(|r| r.applies(location, product_line, date))
+            .collect()
+    }
+
+    /// Checks whether a party is exempt from a tax type on a date
+    pub fn is_exempt(&self, party_id: PartyId, tax_type: TaxType, date: NaiveDate) -> bool {
+        self.exemptions
+            .iter()
+            .any(|e| e.party_id == party_id && e.exempts(tax_type, date))
+    }
+
+    /// Assesses the taxes due on an invoice
+    ///
+    /// # Arguments
+    ///
+    /// * `invoice` - The invoice to assess
+    /// * `location` - Jurisdiction of the policyholder
+    /// * `product_line` - Product line of the policy (e.g. "life", "health")
+    /// * `assessment_date` - Date the rules are evaluated at
+    pub fn assess(
+        &self,
+        invoice: &Invoice,
+        location: &TaxJurisdiction,
+        product_line: &str,
+        assessment_date: NaiveDate,
+    ) -> TaxAssessment {
+        let currency = invoice.currency;
+        let mut lines = Vec::new();
+        let mut exempted_rules = Vec::new();
+
+        for rule in self.rules_for(location, product_line, assessment_date) {
+            if self.is_exempt(invoice.customer_id, rule.tax_type, assessment_date) {
+                exempted_rules.push(rule.code.clone());
+                continue;
+            }
+
+            let taxable: Vec<_> = invoice
+                .items
+                .iter()
+                .filter(|item| rule.taxable_items.contains(&item.item_type) && item.total().is_positive())
+                .collect();
+            if taxable.is_empty() {
+                continue;
+            }
+
+            match &rule.calculation {
+                TaxCalculation::Percentage(rate) => {
+                    for item in taxable {
+                        let taxable_amount = item.total();
+                        lines.push(TaxLine {
+                            rule_code: rule.code.clone(),
+                            tax_type: rule.tax_type,
+                            jurisdiction: rule.jurisdiction.clone(),
+                            item_id: Some(item.id),
+                            taxable_amount,
+                            rate: Some(*rate),
+                            amount: taxable_amount.multiply(*rate).round_to_currency(),
+                        });
+                    }
+                }
+                TaxCalculation::FlatPerInvoice(amount) if amount.currency() == currency => {
+                    lines.push(TaxLine {
+                        rule_code: rule.code.clone(),
+                        tax_type: rule.tax_type,
+                        jurisdiction: rule.jurisdiction.clone(),
+                        item_id: None,
+                        taxable_amount: taxable.iter().fold(Money::zero(currency), |acc, i| acc + i.total()),
+                        rate: None,
+                        amount: *amount,
+                    });
+                }
+                // Flat charges are only levied on invoices in the rule's currency
+                TaxCalculation::FlatPerInvoice(_) => {}
+            }
+        }
+
+        let total = lines.iter().fold(Money::zero(currency), |acc, l| acc + l.amount);
+        TaxAssessment {
+            invoice_id: invoice.id,
+            customer_id: invoice.customer_id,
+            jurisdiction: location.clone(),
+            product_line: product_line.to_string(),
+            assessment_date,
+            lines,
+            exempted_rules,
+            total,
+        }
+    }
+
+    /// Sets the assessed tax on its invoice
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the assessment belongs to a different invoice
+    pub fn apply(&self, invoice: &mut Invoice, assessment: &TaxAssessment) -> Result<(), BillingError> {
+        if assessment.invoice_id != invoice.id {
+            return Err(BillingError::InvalidOperation(format!(
+                "Tax assessment for invoice {} applied to invoice {}",
+                assessment.invoice_id, invoice.id
+            )));
+        }
+        invoice.set_tax(assessment.total);
+        Ok(())
+    }
+
+    /// Posts the assessed tax as liabilities
+    ///
+    /// Debits premium receivable with the total tax and credits each tax
+    /// type's liability account. The assessment is kept for remittance
+    /// reporting.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `assessment` - The assessment to post
+    /// * `posting_date` - Accounting date of the posting
+    ///
+    /// # Returns
+    ///
+    /// The journal entry, or None if no tax was due
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the posting fails
+    pub fn post(
+        &mut self,
+        ledger: &mut Ledger,
+        assessment: &TaxAssessment,
+        posting_date: NaiveDate,
+    ) -> Result<Option<JournalEntryId>, BillingError> {
+        if !assessment.total.is_positive() {
+            return Ok(None);
+        }
+
+        let mut transaction = Transaction::new(format!("Premium taxes {}", assessment.jurisdiction))
+            .with_reference("invoice", *assessment.invoice_id.as_uuid())
+            .dated(utc_start_of_day(posting_date))
+            .debit(self.accounts.receivable, assessment.total);
+        let mut by_rule: BTreeMap<(&str, TaxType), Money> = BTreeMap::new();
+        for line in &assessment.lines {
+            let total = by_rule
+                .entry((line.rule_code.as_str(), line.tax_type))
+                .or_insert_with(|| Money::zero(assessment.total.currency()));
+            *total = *total + line.amount;
+        }
+        for ((rule_code, tax_type), amount) in by_rule {
+            transaction = transaction.posting(
+                Posting::credit(self.accounts.liability_for(tax_type), amount).with_description(rule_code),
+            );
+        }
+
+        let entry_id = ledger.post(transaction)?;
+        self.posted.push(PostedAssessment {
+            posting_date,
+            assessment: assessment.clone(),
+        });
+        Ok(Some(entry_id))
+    }
+
+    /// Summarises tax posted in a period by jurisdiction and rule
+    ///
+    /// Each line is reported under the jurisdiction of the rule that
+    /// charged it, so national and state taxes on the same invoice are
+    /// remitted separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - First posting date included
+    /// * `to` - Last posting date included
+    pub fn remittance_report(&self, from: NaiveDate, to: NaiveDate) -> Vec<RemittanceLine> {
+        type Key = (TaxJurisdiction, String, &'static str);
+        let mut report: BTreeMap<Key, RemittanceLine> = BTreeMap::new();
+
+        for posted in self.posted.iter().filter(|p| p.posting_date >= from && p.posting_date <= to) {
+            let assessment = &posted.assessment;
+            let mut counted: Vec<&str> = Vec::new();
+            for line in &assessment.lines {
+                let currency = line.amount.currency();
+                let key = (line.jurisdiction.clone(), line.rule_code.clone(), currency.code());
+                let entry = report.entry(key).or_insert_with(|| RemittanceLine {
+                    jurisdiction: line.jurisdiction.clone(),
+                    rule_code: line.rule_code.clone(),
+                    tax_type: line.tax_type,
+                    liability_account: self.accounts.liability_for(line.tax_type),
+                    invoice_count: 0,
+                    taxable_amount: Money::zero(currency),
+                    tax_amount: Money::zero(currency),
+                });
+                if !counted.contains(&line.rule_code.as_str()) {
+                    entry.invoice_count += 1;
+                    counted.push(&line.rule_code);
+                }
+                entry.taxable_amount = entry.taxable_amount + line.taxable_amount;
+                entry.tax_amount = entry.tax_amount + line.amount;
+            }
+        }
+
+        report.into_values().collect()
+    }
+}
@@ -0,0 +1,136 @@
+//! XML element tree for banking files
+//!
+//! Statement and payment files are small documents with no mixed content,
+//! so they are read with `quick-xml` into a simple element tree. Namespace
+//! prefixes are dropped, and processing instructions, comments and DOCTYPE
+//! declarations are skipped.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::fmt::Display;
+
+/// An XML element with its attributes, text and children
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Element {
+    /// Local name (namespace prefix removed)
+    pub name: String,
+    /// Attributes as (local name, value) pairs
+    pub attributes: Vec<(String, String)>,
+    /// Concatenated text content directly inside the element
+    pub text: String,
+    /// Child elements
+    pub children: Vec<Element>,
+}
+
+impl Element {
+    /// Returns the first child with the given name
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Returns all children with the given name
+    pub fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// Follows a path of child names
+    pub fn path(&self, path: &[&str]) -> Option<&Element> {
+        path.iter().try_fold(self, |element, name| element.child(name))
+    }
+
+    /// Returns the trimmed text at a path, if present and non-empty
+    pub fn text_at(&self, path: &[&str]) -> Option<String> {
+        self.path(path)
+            .map(|e| e.text.trim().to_string())
+            .filter(|t| !t.is_empty())
+    }
+
+    /// Returns an attribute value
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the first descendant (depth-first) with the given name
+    pub fn find(&self, name: &str) -> Option<&Element> {
+        self.children
+            .iter()
+            .find_map(|c| if c.name == name { Some(c) } else { c.find(name) })
+    }
+}
+
+/// Parses a document and returns its root element
+pub(crate) fn parse(input: &str) -> Result<Element, String> {
+    let mut reader = Reader::from_str(input);
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root: Option<Element> = None;
+
+    loop {
+        match reader.read_event().map_err(parse_error)? {
+            Event::Start(tag) => stack.push(element(&tag)?),
+            Event::Empty(tag) => attach(&mut stack, &mut root, element(&tag)?)?,
+            Event::End(_) => {
+                let element = stack.pop().ok_or_else(|| parse_error("unexpected closing tag"))?;
+                attach(&mut stack, &mut root, element)?;
+            }
+            Event::Text(text) => {
+                let text = text.unescape().map_err(parse_error)?;
+                if let Some(top) = stack.last_mut() {
+                    if !text.trim().is_empty() {
+                        top.text.push_str(&text);
+                    }
+                }
+            }
+            Event::CData(cdata) => {
+                let text = std::str::from_utf8(&cdata).map_err(parse_error)?;
+                if let Some(top) = stack.last_mut() {
+                    top.text.push_str(text);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(parse_error("document ended with unclosed elements"));
+    }
+    root.ok_or_else(|| parse_error("document has no root element"))
+}
+
+/// Builds an element from an opening tag
+fn element(tag: &BytesStart) -> Result<Element, String> {
+    let mut element = Element {
+        name: utf8(tag.local_name().as_ref())?,
+        ..Element::default()
+    };
+
+    for attribute in tag.attributes() {
+        let attribute = attribute.map_err(parse_error)?;
+        let key = utf8(attribute.key.local_name().as_ref())?;
+        let value = attribute.unescape_value().map_err(parse_error)?;
+        element.attributes.push((key, value.into_owned()));
+    }
+
+    Ok(element)
+}
+
+/// Adds a completed element to its parent, or makes it the root
+fn attach(stack: &mut [Element], root: &mut Option<Element>, element: Element) -> Result<(), String> {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(element),
+        None if root.is_none() => *root = Some(element),
+        None => return Err(parse_error("multiple root elements")),
+    }
+    Ok(())
+}
+
+fn utf8(bytes: &[u8]) -> Result<String, String> {
+    std::str::from_utf8(bytes).map(str::to_string).map_err(parse_error)
+}
+
+fn parse_error(message: impl Display) -> String {
+    format!("invalid XML: {}", message)
+}
@@ -204,6 +204,52 @@ mod invoice_tests {
         assert!(invoice.is_overdue());
     }
 
+    #[test]
+    fn test_invoice_mark_overdue() {
+        let mut invoice = create_test_invoice();
+        invoice.issue();
+
+        assert!(invoice.mark_overdue());
+        assert_eq!(invoice.status, InvoiceStatus::Overdue);
+        assert!(!invoice.mark_overdue());
+    }
+
+    #[test]
+    fn test_invoice_mark_overdue_ignores_paid() {
+        let mut invoice = create_test_invoice();
+        invoice.add_item(InvoiceItem::new("Premium", InvoiceItemType::Premium, Money::new(dec!(100), Currency::USD)));
+        invoice.record_payment(Money::new(dec!(100), Currency::USD));
+
+        assert!(!invoice.mark_overdue());
+        assert_eq!(invoice.status, InvoiceStatus::Paid);
+    }
+
+    #[test]
+    fn test_invoice_days_overdue() {
+        let due_date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let invoice = Invoice::new(PolicyId::new_v7(), PartyId::new_v7(), due_date, Currency::USD);
+
+        assert_eq!(invoice.days_overdue(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()), 0);
+        assert_eq!(invoice.days_overdue(NaiveDate::from_ymd_opt(2024, 3, 11).unwrap()), 10);
+    }
+
+    #[test]
+    fn test_invoice_reverse_payment_reopens() {
+        let mut invoice = create_test_invoice();
+        invoice.add_item(InvoiceItem::new("Premium", InvoiceItemType::Premium, Money::new(dec!(1000), Currency::USD)));
+        invoice.issue();
+        invoice.record_payment(Money::new(dec!(1000), Currency::USD));
+        assert_eq!(invoice.status, InvoiceStatus::Paid);
+
+        invoice.reverse_payment(Money::new(dec!(400), Currency::USD), "Returned");
+        assert_eq!(invoice.status, InvoiceStatus::PartiallyPaid);
+        assert_eq!(invoice.balance_due().amount(), dec!(400));
+
+        invoice.reverse_payment(Money::new(dec!(600), Currency::USD), "Returned");
+        assert_eq!(invoice.status, InvoiceStatus::Issued);
+        assert_eq!(invoice.notes.as_deref(), Some("Returned"));
+    }
+
     #[test]
     fn test_invoice_balance_due() {
         let mut invoice = create_test_invoice();
@@ -664,3 +710,1237 @@ mod ledger_tests {
         assert_eq!(cash_balance.amount(), Decimal::ZERO);
     }
 }
+
+// ============================================================================
+// Cash Application Tests
+// ============================================================================
+
+mod cash_application_tests {
+    use super::*;
+    use domain_billing::cash_application::{
+        CashApplicationAccounts, CashApplicationEngine, MatchMethod, OverpaymentTreatment,
+    };
+
+    fn setup() -> (Ledger, CashApplicationAccounts) {
+        let mut ledger = Ledger::new(Currency::USD);
+        let accounts = CashApplicationAccounts {
+            cash: AccountId::new(),
+            premium_receivable: AccountId::new(),
+            suspense: AccountId::new(),
+            premium_deposit: AccountId::new(),
+        };
+
+        ledger.add_account(Account::new(accounts.cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.suspense, "2400", "Premium Suspense", AccountType::Liability)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_deposit, "2500", "Premium Deposits", AccountType::Liability)).unwrap();
+
+        (ledger, accounts)
+    }
+
+    fn open_invoice(customer_id: PartyId, due_date: NaiveDate, amount: Decimal) -> Invoice {
+        let mut invoice = Invoice::new(PolicyId::new_v7(), customer_id, due_date, Currency::USD);
+        invoice.add_item(InvoiceItem::new("Premium", InvoiceItemType::Premium, Money::new(amount, Currency::USD)));
+        invoice.issue();
+        invoice
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_payment_received_is_unmatched() {
+        let payment = Payment::received(Money::new(dec!(100), Currency::USD), PaymentMethod::BankTransfer);
+
+        assert!(payment.invoice_id.is_none());
+        assert!(payment.payer_id.is_none());
+        assert_eq!(payment.status, PaymentStatus::Pending);
+    }
+
+    #[test]
+    fn test_match_by_reference() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let customer = PartyId::new_v7();
+        let mut invoices = vec![open_invoice(customer, date(1, 1), dec!(100))];
+        let reference = format!("Premium {}", invoices[0].invoice_number);
+        let mut payment = Payment::received(Money::new(dec!(100), Currency::USD), PaymentMethod::BankTransfer)
+            .with_reference(reference);
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert_eq!(result.match_method, Some(MatchMethod::Reference));
+        assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+        assert_eq!(payment.status, PaymentStatus::Completed);
+        assert_eq!(payment.invoice_id, Some(invoices[0].id));
+        assert_eq!(payment.payer_id, Some(customer));
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(100));
+        assert_eq!(ledger.get_balance(&accounts.premium_receivable).unwrap().amount(), dec!(-100));
+    }
+
+    #[test]
+    fn test_reference_must_quote_whole_invoice_number() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let mut invoices = vec![
+            open_invoice(PartyId::new_v7(), date(1, 1), dec!(100)),
+            open_invoice(PartyId::new_v7(), date(1, 1), dec!(100)),
+        ];
+        invoices[0].invoice_number = "INV-1".to_string();
+        invoices[1].invoice_number = "INV-10".to_string();
+        let mut payment = Payment::received(Money::new(dec!(100), Currency::USD), PaymentMethod::BankTransfer)
+            .with_reference("Premium inv-10, thank you");
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert_eq!(result.match_method, Some(MatchMethod::Reference));
+        assert_eq!(payment.invoice_id, Some(invoices[1].id));
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+        assert_eq!(invoices[1].status, InvoiceStatus::Paid);
+
+        invoices.truncate(1);
+        let mut payment = Payment::received(Money::new(dec!(100), Currency::USD), PaymentMethod::BankTransfer)
+            .with_reference("Premium INV-10");
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert!(result.match_method.is_none());
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+    }
+
+    #[test]
+    fn test_match_by_payer_and_amount() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let customer = PartyId::new_v7();
+        let mut invoices = vec![
+            open_invoice(customer, date(1, 1), dec!(100)),
+            open_invoice(customer, date(2, 1), dec!(250)),
+        ];
+        let mut payment = Payment::received(Money::new(dec!(250), Currency::USD), PaymentMethod::BankTransfer)
+            .with_payer(customer);
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert_eq!(result.match_method, Some(MatchMethod::PayerAndAmount));
+        assert_eq!(result.allocations.len(), 1);
+        assert_eq!(invoices[1].status, InvoiceStatus::Paid);
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+    }
+
+    #[test]
+    fn test_allocates_oldest_first_across_invoices() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let customer = PartyId::new_v7();
+        let mut invoices = vec![
+            open_invoice(customer, date(3, 1), dec!(100)),
+            open_invoice(customer, date(1, 1), dec!(100)),
+            open_invoice(customer, date(2, 1), dec!(100)),
+        ];
+        let mut payment = Payment::received(Money::new(dec!(150), Currency::USD), PaymentMethod::BankTransfer)
+            .with_payer(customer);
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert_eq!(result.match_method, Some(MatchMethod::Payer));
+        assert_eq!(result.allocations.len(), 2);
+        assert_eq!(invoices[1].status, InvoiceStatus::Paid);
+        assert_eq!(invoices[2].status, InvoiceStatus::PartiallyPaid);
+        assert_eq!(invoices[2].amount_paid.amount(), dec!(50));
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+        assert!(result.overpayment.is_none());
+    }
+
+    #[test]
+    fn test_overpayment_held_as_premium_deposit() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let customer = PartyId::new_v7();
+        let mut invoices = vec![open_invoice(customer, date(1, 1), dec!(100))];
+        let invoice_id = invoices[0].id;
+        let mut payment = Payment::new(invoice_id, customer, Money::new(dec!(130), Currency::USD), PaymentMethod::Check);
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert_eq!(result.match_method, Some(MatchMethod::InvoiceId));
+        assert_eq!(result.overpayment.unwrap().amount(), dec!(30));
+        assert_eq!(ledger.get_balance(&accounts.premium_deposit).unwrap().amount(), dec!(30));
+        assert_eq!(ledger.get_balance(&accounts.premium_receivable).unwrap().amount(), dec!(-100));
+    }
+
+    #[test]
+    fn test_overpayment_as_customer_credit() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts)
+            .with_overpayment_treatment(OverpaymentTreatment::CustomerCredit);
+        let customer = PartyId::new_v7();
+        let mut invoices = vec![open_invoice(customer, date(1, 1), dec!(100))];
+        let mut payment = Payment::received(Money::new(dec!(130), Currency::USD), PaymentMethod::BankTransfer)
+            .with_payer(customer);
+
+        engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert_eq!(ledger.get_balance(&accounts.premium_receivable).unwrap().amount(), dec!(-130));
+        assert_eq!(ledger.get_balance(&accounts.premium_deposit).unwrap().amount(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_unidentified_receipt_parked_in_suspense() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let mut invoices = vec![open_invoice(PartyId::new_v7(), date(1, 1), dec!(100))];
+        let mut payment = Payment::received(Money::new(dec!(75), Currency::USD), PaymentMethod::BankTransfer)
+            .with_reference("UNKNOWN REF");
+
+        let result = engine.apply(&mut ledger, &mut payment, &mut invoices).unwrap();
+
+        assert!(result.match_method.is_none());
+        assert_eq!(result.suspense.unwrap().amount(), dec!(75));
+        assert_eq!(payment.status, PaymentStatus::OnHold);
+        assert_eq!(engine.suspense_items().len(), 1);
+        assert_eq!(ledger.get_balance(&accounts.suspense).unwrap().amount(), dec!(75));
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+    }
+
+    #[test]
+    fn test_allocate_from_suspense() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let customer = PartyId::new_v7();
+        let mut payment = Payment::received(Money::new(dec!(150), Currency::USD), PaymentMethod::BankTransfer);
+        engine.apply(&mut ledger, &mut payment, &mut []).unwrap();
+        let mut invoices = vec![open_invoice(customer, date(1, 1), dec!(100))];
+
+        let result = engine
+            .allocate_from_suspense(&mut ledger, payment.id, customer, &mut invoices)
+            .unwrap();
+
+        assert_eq!(result.match_method, Some(MatchMethod::Manual));
+        assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+        assert_eq!(result.suspense.unwrap().amount(), dec!(50));
+        assert_eq!(engine.suspense_items()[0].amount.amount(), dec!(50));
+        assert_eq!(ledger.get_balance(&accounts.suspense).unwrap().amount(), dec!(50));
+    }
+
+    #[test]
+    fn test_allocate_unknown_suspense_item_fails() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+
+        let result = engine.allocate_from_suspense(&mut ledger, PaymentId::new_v7(), PartyId::new_v7(), &mut []);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_processed_payment_rejected() {
+        let (mut ledger, accounts) = setup();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let mut payment = Payment::received(Money::new(dec!(10), Currency::USD), PaymentMethod::Cash);
+        payment.complete();
+
+        assert!(engine.apply(&mut ledger, &mut payment, &mut []).is_err());
+    }
+
+    #[test]
+    fn test_disbursement_settles_liability_from_cash() {
+        let (mut ledger, accounts) = setup();
+        let refunds_payable = AccountId::new();
+        ledger.add_account(Account::new(refunds_payable, "2600", "Refunds Payable", AccountType::Liability)).unwrap();
+        ledger
+            .post(
+                Transaction::new("Refund due")
+                    .debit(accounts.premium_receivable, Money::new(dec!(40), Currency::USD))
+                    .credit(refunds_payable, Money::new(dec!(40), Currency::USD)),
+            )
+            .unwrap();
+        let mut engine = CashApplicationEngine::new(accounts);
+        let payee = PartyId::new_v7();
+        let mut refund = Payment::disbursement(payee, Money::new(dec!(40), Currency::USD), PaymentMethod::BankTransfer);
+
+        assert!(refund.is_disbursement());
+        assert_eq!(refund.payee_id, Some(payee));
+        assert!(engine.apply(&mut ledger, &mut refund, &mut []).is_err());
+
+        engine.disburse(&mut ledger, &mut refund, refunds_payable).unwrap();
+
+        assert_eq!(refund.status, PaymentStatus::Completed);
+        assert_eq!(ledger.get_balance(&refunds_payable), Some(Money::new(dec!(0), Currency::USD)));
+        assert_eq!(ledger.get_balance(&accounts.cash), Some(Money::new(dec!(-40), Currency::USD)));
+
+        let mut receipt = Payment::received(Money::new(dec!(40), Currency::USD), PaymentMethod::BankTransfer);
+        assert!(engine.disburse(&mut ledger, &mut receipt, refunds_payable).is_err());
+    }
+
+    #[test]
+    fn test_failed_posting_leaves_invoices_untouched() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let accounts = CashApplicationAccounts {
+            cash: AccountId::new(),
+            premium_receivable: AccountId::new(),
+            suspense: AccountId::new(),
+            premium_deposit: AccountId::new(),
+        };
+        let mut engine = CashApplicationEngine::new(accounts);
+        let customer = PartyId::new_v7();
+        let mut invoices = vec![open_invoice(customer, date(1, 1), dec!(100))];
+        let mut payment = Payment::received(Money::new(dec!(100), Currency::USD), PaymentMethod::Cash)
+            .with_payer(customer);
+
+        assert!(engine.apply(&mut ledger, &mut payment, &mut invoices).is_err());
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+    }
+}
+
+// ============================================================================
+// Bank Statement Tests
+// ============================================================================
+
+mod statement_tests {
+    use super::*;
+    use chrono::TimeZone;
+    use domain_billing::cash_application::{CashApplicationAccounts, CashApplicationEngine, MatchMethod};
+    use domain_billing::error::BillingError;
+    use domain_billing::statement::{camt053, mt940, BankReconciler, EntryDirection};
+
+    const CAMT053: &str = include_str!("fixtures/camt053_sample.xml");
+    const MT940: &str = include_str!("fixtures/mt940_sample.txt");
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    fn eur(amount: Decimal) -> Money {
+        Money::new(amount, Currency::EUR)
+    }
+
+    #[test]
+    fn test_parse_camt053() {
+        let statements = camt053::parse(CAMT053).unwrap();
+        assert_eq!(statements.len(), 1);
+
+        let statement = &statements[0];
+        assert_eq!(statement.statement_id, "STMT-2024-01-15");
+        assert_eq!(statement.account, "DE89370400440532013000");
+        assert_eq!(statement.currency, Currency::EUR);
+        assert_eq!(statement.from_date, date(15));
+        assert_eq!(statement.to_date, date(15));
+        assert_eq!(statement.opening_balance, Some(eur(dec!(10000.00))));
+        assert_eq!(statement.closing_balance, Some(eur(dec!(10275.50))));
+
+        // The pending entry is skipped
+        assert_eq!(statement.lines.len(), 3);
+
+        let first = &statement.lines[0];
+        assert_eq!(first.amount, eur(dec!(250.00)));
+        assert_eq!(first.direction, EntryDirection::Credit);
+        assert_eq!(first.bank_reference.as_deref(), Some("BANKREF-0001"));
+        assert_eq!(first.customer_reference.as_deref(), Some("E2E-POL-1001"));
+        assert_eq!(first.remittance_info.as_deref(), Some("Premium INV-1001"));
+        assert_eq!(first.counterparty_name.as_deref(), Some("Jane Smith & Co"));
+
+        assert!(statement.lines[1].customer_reference.is_none());
+        assert_eq!(statement.lines[2].direction, EntryDirection::Debit);
+        assert_eq!(statement.lines[2].counterparty_name.as_deref(), Some("Bank Charges"));
+    }
+
+    #[test]
+    fn test_parse_mt940() {
+        let statements = mt940::parse(MT940).unwrap();
+        assert_eq!(statements.len(), 1);
+
+        let statement = &statements[0];
+        assert_eq!(statement.statement_id, "00015/001");
+        assert_eq!(statement.account, "DE89370400440532013000");
+        assert_eq!(statement.currency, Currency::EUR);
+        assert_eq!(statement.from_date, date(15));
+        assert_eq!(statement.to_date, date(15));
+        assert_eq!(statement.opening_balance, Some(eur(dec!(10000.00))));
+        assert_eq!(statement.closing_balance, Some(eur(dec!(10275.50))));
+        assert_eq!(statement.lines.len(), 3);
+
+        let first = &statement.lines[0];
+        assert_eq!(first.amount, eur(dec!(250.00)));
+        assert_eq!(first.direction, EntryDirection::Credit);
+        assert_eq!(first.customer_reference.as_deref(), Some("E2E-POL-1001"));
+        assert_eq!(first.bank_reference.as_deref(), Some("BANKREF-0001"));
+        assert_eq!(first.remittance_info.as_deref(), Some("Premium INV-1001 Jane Smith"));
+
+        let second = &statement.lines[1];
+        assert_eq!(second.direction, EntryDirection::Debit);
+        assert!(second.customer_reference.is_none());
+
+        // Entry date differs from value date; funds code is skipped
+        let third = &statement.lines[2];
+        assert_eq!(third.booking_date, date(15));
+        assert_eq!(third.value_date, Some(date(16)));
+        assert_eq!(third.amount, eur(dec!(75.50)));
+    }
+
+    #[test]
+    fn test_formats_agree_on_net_movement() {
+        let camt = &camt053::parse(CAMT053).unwrap()[0];
+        let swift = &mt940::parse(MT940).unwrap()[0];
+
+        assert_eq!(camt.net_movement(), eur(dec!(275.50)));
+        assert_eq!(swift.net_movement(), camt.net_movement());
+        assert_eq!(
+            camt.opening_balance.unwrap() + camt.net_movement(),
+            camt.closing_balance.unwrap()
+        );
+    }
+
+    #[test]
+    fn test_payment_candidates_only_for_credits() {
+        let statement = &camt053::parse(CAMT053).unwrap()[0];
+        let candidates = statement.payment_candidates();
+
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].amount, eur(dec!(250.00)));
+        assert_eq!(candidates[0].method, PaymentMethod::BankTransfer);
+        assert_eq!(candidates[0].external_reference.as_deref(), Some("Premium INV-1001"));
+        assert_eq!(candidates[0].payment_date.date_naive(), date(15));
+        assert!(candidates[0].invoice_id.is_none());
+        assert_eq!(candidates[1].external_reference.as_deref(), Some("BANKREF-0002"));
+    }
+
+    #[test]
+    fn test_candidates_feed_cash_application() {
+        let mut ledger = Ledger::new(Currency::EUR);
+        let accounts = CashApplicationAccounts {
+            cash: AccountId::new(),
+            premium_receivable: AccountId::new(),
+            suspense: AccountId::new(),
+            premium_deposit: AccountId::new(),
+        };
+        ledger.add_account(Account::new(accounts.cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.suspense, "2400", "Premium Suspense", AccountType::Liability)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_deposit, "2500", "Premium Deposits", AccountType::Liability)).unwrap();
+
+        let mut invoice = Invoice::new(PolicyId::new_v7(), PartyId::new_v7(), date(1), Currency::EUR);
+        invoice.invoice_number = "INV-1001".to_string();
+        invoice.add_item(InvoiceItem::new("Premium", InvoiceItemType::Premium, eur(dec!(250.00))));
+        invoice.issue();
+        let mut invoices = vec![invoice];
+
+        let mut engine = CashApplicationEngine::new(accounts);
+        let statement = &camt053::parse(CAMT053).unwrap()[0];
+        let mut candidates = statement.payment_candidates();
+
+        let matched = engine.apply(&mut ledger, &mut candidates[0], &mut invoices).unwrap();
+        assert_eq!(matched.match_method, Some(MatchMethod::Reference));
+        assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+
+        let unmatched = engine.apply(&mut ledger, &mut candidates[1], &mut invoices).unwrap();
+        assert!(unmatched.match_method.is_none());
+        assert_eq!(engine.suspense_items().len(), 1);
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(325.50));
+    }
+
+    #[test]
+    fn test_reconciliation_reports_both_sides() {
+        let mut ledger = Ledger::new(Currency::EUR);
+        let cash = AccountId::new();
+        let receivable = AccountId::new();
+        let expense = AccountId::new();
+        ledger.add_account(Account::new(cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(expense, "6000", "Bank Charges", AccountType::Expense)).unwrap();
+
+        let at = |day: u32| Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+
+        // Premium received, booked the day before the bank
+        ledger.post(
+            Transaction::new("Premium INV-1001")
+                .dated(at(14))
+                .debit(cash, eur(dec!(250.00)))
+                .credit(receivable, eur(dec!(250.00))),
+        ).unwrap();
+        // Bank fee booked on the same day
+        ledger.post(
+            Transaction::new("Bank fee")
+                .dated(at(15))
+                .debit(expense, eur(dec!(50.00)))
+                .credit(cash, eur(dec!(50.00))),
+        ).unwrap();
+        // Receipt not on the statement
+        ledger.post(
+            Transaction::new("Cheque deposit")
+                .dated(at(15))
+                .debit(cash, eur(dec!(120.00)))
+                .credit(receivable, eur(dec!(120.00))),
+        ).unwrap();
+        // Outside the statement period and tolerance
+        ledger.post(
+            Transaction::new("Earlier premium")
+                .dated(at(2))
+                .debit(cash, eur(dec!(75.50)))
+                .credit(receivable, eur(dec!(75.50))),
+        ).unwrap();
+
+        let statement = &camt053::parse(CAMT053).unwrap()[0];
+        let report = BankReconciler::new(cash).reconcile(statement, &ledger);
+
+        assert!(!report.is_reconciled());
+        assert_eq!(report.matched.len(), 2);
+        assert_eq!(report.matched[0].ledger_item.description, "Premium INV-1001");
+        assert_eq!(report.matched[1].ledger_item.direction, EntryDirection::Debit);
+
+        assert_eq!(report.unreconciled_statement_lines.len(), 1);
+        assert_eq!(report.unreconciled_statement_lines[0].amount, eur(dec!(75.50)));
+
+        assert_eq!(report.unreconciled_ledger_items.len(), 1);
+        assert_eq!(report.unreconciled_ledger_items[0].description, "Cheque deposit");
+    }
+
+    #[test]
+    fn test_reconciliation_respects_date_tolerance() {
+        let mut ledger = Ledger::new(Currency::EUR);
+        let cash = AccountId::new();
+        let receivable = AccountId::new();
+        ledger.add_account(Account::new(cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+        ledger.post(
+            Transaction::new("Premium INV-1001")
+                .dated(Utc.with_ymd_and_hms(2024, 1, 13, 12, 0, 0).unwrap())
+                .debit(cash, eur(dec!(250.00)))
+                .credit(receivable, eur(dec!(250.00))),
+        ).unwrap();
+
+        let statement = &mt940::parse(MT940).unwrap()[0];
+
+        let lenient = BankReconciler::new(cash).reconcile(statement, &ledger);
+        assert_eq!(lenient.matched.len(), 1);
+
+        let strict = BankReconciler::new(cash).with_date_tolerance(1).reconcile(statement, &ledger);
+        assert!(strict.matched.is_empty());
+        // Outside the period, so not reported as unreconciled either
+        assert!(strict.unreconciled_ledger_items.is_empty());
+        assert_eq!(strict.unreconciled_statement_lines.len(), 3);
+    }
+
+    #[test]
+    fn test_malformed_input_is_rejected() {
+        assert!(matches!(camt053::parse("<Document><BkToCstmrStmt>"), Err(BillingError::StatementParse(_))));
+        assert!(matches!(camt053::parse("<Document></Other>"), Err(BillingError::StatementParse(_))));
+        assert!(matches!(camt053::parse("<Document/>"), Err(BillingError::StatementParse(_))));
+
+        let bad_amount = CAMT053.replace("<Amt Ccy=\"EUR\">250.00</Amt>", "<Amt Ccy=\"EUR\">abc</Amt>");
+        assert!(matches!(camt053::parse(&bad_amount), Err(BillingError::StatementParse(_))));
+
+        let bad_currency = CAMT053.replace("<Ccy>EUR</Ccy>", "<Ccy>XYZ</Ccy>");
+        assert!(matches!(camt053::parse(&bad_currency), Err(BillingError::StatementParse(_))));
+
+        let bad_mark = MT940.replace(":61:240115D50,00", ":61:240115X50,00");
+        assert!(matches!(mt940::parse(&bad_mark), Err(BillingError::StatementParse(_))));
+
+        let no_account = MT940.replace(":25:DE89370400440532013000\n", "");
+        assert!(matches!(mt940::parse(&no_account), Err(BillingError::StatementParse(_))));
+
+        let bad_date = MT940.replace(":60F:C240114", ":60F:C241314");
+        assert!(matches!(mt940::parse(&bad_date), Err(BillingError::StatementParse(_))));
+    }
+
+    #[test]
+    fn test_mt940_multibyte_fields_are_rejected() {
+        let balance = MT940.replace(":60F:C240114EUR", ":60F:€240114EUR");
+        assert!(matches!(mt940::parse(&balance), Err(BillingError::StatementParse(_))));
+
+        let line = MT940.replace(":61:240115D50,00", ":61:2401€5D50,00");
+        assert!(matches!(mt940::parse(&line), Err(BillingError::StatementParse(_))));
+
+        let entry_date = MT940.replace(":61:2401150115C250,00", ":61:240115€C250,00");
+        assert!(matches!(mt940::parse(&entry_date), Err(BillingError::StatementParse(_))));
+
+        let transaction_type = MT940.replace("C250,00NTRF", "C250,00NT€F");
+        assert!(matches!(mt940::parse(&transaction_type), Err(BillingError::StatementParse(_))));
+    }
+
+    #[test]
+    fn test_camt053_attribute_values_may_contain_gt() {
+        let xml = CAMT053.replace("<Stmt>", "<Stmt Note=\"a > b\">");
+
+        let statements = camt053::parse(&xml).unwrap();
+        assert_eq!(statements[0].statement_id, "STMT-2024-01-15");
+        assert_eq!(statements[0].lines.len(), 3);
+    }
+
+    #[test]
+    fn test_reconciliation_prefers_reference_match() {
+        let mut ledger = Ledger::new(Currency::EUR);
+        let cash = AccountId::new();
+        let receivable = AccountId::new();
+        ledger.add_account(Account::new(cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+
+        let at = |day: u32| Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+        // Same amount on the booking date, for another invoice
+        ledger.post(
+            Transaction::new("Premium INV-2002")
+                .dated(at(15))
+                .debit(cash, eur(dec!(250.00)))
+                .credit(receivable, eur(dec!(250.00))),
+        ).unwrap();
+        ledger.post(
+            Transaction::new("Premium INV-1001")
+                .dated(at(13))
+                .debit(cash, eur(dec!(250.00)))
+                .credit(receivable, eur(dec!(250.00))),
+        ).unwrap();
+
+        let statement = &camt053::parse(CAMT053).unwrap()[0];
+        let report = BankReconciler::new(cash).reconcile(statement, &ledger);
+
+        assert_eq!(report.matched[0].ledger_item.description, "Premium INV-1001");
+        assert!(report.matched[0].reference_matched);
+        assert_eq!(report.unreconciled_ledger_items.len(), 1);
+        assert_eq!(report.unreconciled_ledger_items[0].description, "Premium INV-2002");
+    }
+
+    #[test]
+    fn test_applied_receipts_reconcile_by_reference() {
+        let mut ledger = Ledger::new(Currency::EUR);
+        let accounts = CashApplicationAccounts {
+            cash: AccountId::new(),
+            premium_receivable: AccountId::new(),
+            suspense: AccountId::new(),
+            premium_deposit: AccountId::new(),
+        };
+        ledger.add_account(Account::new(accounts.cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.suspense, "2400", "Premium Suspense", AccountType::Liability)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_deposit, "2500", "Premium Deposits", AccountType::Liability)).unwrap();
+
+        let mut engine = CashApplicationEngine::new(accounts);
+        let statement = &camt053::parse(CAMT053).unwrap()[0];
+        for mut candidate in statement.payment_candidates() {
+            engine.apply(&mut ledger, &mut candidate, &mut []).unwrap();
+        }
+
+        let report = BankReconciler::new(accounts.cash).reconcile(statement, &ledger);
+        let credits: Vec<_> = report
+            .matched
+            .iter()
+            .filter(|item| item.line.direction == EntryDirection::Credit)
+            .collect();
+        assert_eq!(credits.len(), 2);
+        assert!(credits.iter().all(|item| item.reference_matched));
+        assert_eq!(
+            credits[0].ledger_item.description,
+            "Unidentified receipt held in suspense: Premium INV-1001"
+        );
+    }
+}
+
+// ============================================================================
+// Direct Debit Tests
+// ============================================================================
+
+mod direct_debit_tests {
+    use super::*;
+    use domain_billing::direct_debit::{
+        returns, BankAccount, Creditor, DirectDebitAccounts, DirectDebitEngine, Mandate,
+        MandateStatus, MandateType, ReturnAction, ReturnKind, ReturnReason, SequenceType, SkipReason,
+    };
+    use domain_billing::error::BillingError;
+
+    const PAIN002: &str = include_str!("fixtures/pain002_sample.xml");
+    const PACS004: &str = include_str!("fixtures/pacs004_sample.xml");
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    fn eur(amount: Decimal) -> Money {
+        Money::new(amount, Currency::EUR)
+    }
+
+    fn setup() -> (Ledger, DirectDebitAccounts, DirectDebitEngine) {
+        let mut ledger = Ledger::new(Currency::EUR);
+        let accounts = DirectDebitAccounts {
+            cash: AccountId::new(),
+            premium_receivable: AccountId::new(),
+        };
+        ledger.add_account(Account::new(accounts.cash, "1000", "Cash", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+
+        let creditor = Creditor {
+            name: "Open Insurance AG".to_string(),
+            creditor_id: "DE98ZZZ09999999999".to_string(),
+            account: BankAccount::new("Open Insurance AG", "DE89 3704 0044 0532 0130 00")
+                .unwrap()
+                .with_bic("COBADEFFXXX"),
+        };
+        (ledger, accounts, DirectDebitEngine::new(creditor, accounts))
+    }
+
+    fn mandate(reference: &str, party: PartyId, mandate_type: MandateType) -> Mandate {
+        let account = BankAccount::new("Jane Smith", "NL91ABNA0417164300").unwrap();
+        Mandate::new(reference, party, account, date(1, 1), mandate_type).unwrap()
+    }
+
+    fn invoice(party: PartyId, due_date: NaiveDate, amount: Decimal, currency: Currency) -> Invoice {
+        let mut invoice = Invoice::new(PolicyId::new_v7(), party, due_date, currency);
+        invoice.add_item(InvoiceItem::new("Premium", InvoiceItemType::Premium, Money::new(amount, currency)));
+        invoice.issue();
+        invoice
+    }
+
+    #[test]
+    fn test_bank_account_validates_iban() {
+        let account = BankAccount::new("Jane", "gb82 west 1234 5698 7654 32").unwrap();
+        assert_eq!(account.iban, "GB82WEST12345698765432");
+
+        assert!(matches!(BankAccount::new("Jane", "GB82WEST12345698765431"), Err(BillingError::InvalidMandate(_))));
+        assert!(BankAccount::new("Jane", "NOT-AN-IBAN").is_err());
+    }
+
+    #[test]
+    fn test_mandate_reference_validated() {
+        let account = BankAccount::new("Jane", "NL91ABNA0417164300").unwrap();
+        assert!(Mandate::new("", PartyId::new_v7(), account.clone(), date(1, 1), MandateType::Recurring).is_err());
+        assert!(Mandate::new("A".repeat(36), PartyId::new_v7(), account.clone(), date(1, 1), MandateType::Recurring).is_err());
+        assert!(Mandate::new("MDT#1", PartyId::new_v7(), account, date(1, 1), MandateType::Recurring).is_err());
+    }
+
+    #[test]
+    fn test_mandate_sequence_types() {
+        let party = PartyId::new_v7();
+        let mut recurring = mandate("MDT-0001", party, MandateType::Recurring);
+        assert_eq!(recurring.next_sequence_type(), SequenceType::First);
+        recurring.record_collection(date(2, 1));
+        assert_eq!(recurring.next_sequence_type(), SequenceType::Recurring);
+        assert!(recurring.is_collectable(date(3, 1)));
+
+        let mut one_off = mandate("MDT-0002", party, MandateType::OneOff);
+        assert_eq!(one_off.next_sequence_type(), SequenceType::OneOff);
+        one_off.record_collection(date(2, 1));
+        assert_eq!(one_off.status, MandateStatus::Used);
+        assert!(!one_off.is_collectable(date(3, 1)));
+    }
+
+    #[test]
+    fn test_mandate_revocation_and_dormancy() {
+        let mut mandate = mandate("MDT-0001", PartyId::new_v7(), MandateType::Recurring);
+        assert!(!mandate.is_collectable(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap()));
+        assert!(!mandate.is_collectable(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()));
+
+        mandate.revoke(date(6, 1), "Customer request").unwrap();
+        assert_eq!(mandate.status, MandateStatus::Revoked);
+        assert!(!mandate.is_collectable(date(6, 2)));
+        assert!(mandate.revoke(date(6, 2), "Again").is_err());
+    }
+
+    #[test]
+    fn test_collection_run_selects_due_invoices() {
+        let (mut ledger, _, engine) = setup();
+        let party = PartyId::new_v7();
+        let no_mandate_party = PartyId::new_v7();
+        let mut mandates = vec![mandate("MDT-0001", party, MandateType::Recurring)];
+        let mut invoices = vec![
+            invoice(party, date(2, 1), dec!(100), Currency::EUR),
+            invoice(party, date(3, 1), dec!(100), Currency::EUR),
+            invoice(no_mandate_party, date(1, 15), dec!(80), Currency::EUR),
+            invoice(party, date(1, 20), dec!(50), Currency::USD),
+        ];
+
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+
+        assert_eq!(run.instructions.len(), 1);
+        assert_eq!(run.instructions[0].invoices.len(), 1);
+        assert_eq!(run.instructions[0].invoices[0].invoice_id, invoices[0].id);
+        assert_eq!(run.instructions[0].sequence_type, SequenceType::First);
+        assert_eq!(run.instructions[0].mandate_reference, "MDT-0001");
+        assert_eq!(run.total(), eur(dec!(100)));
+        assert_eq!(run.skipped.len(), 2);
+        assert!(run.skipped.iter().any(|s| s.reason == SkipReason::NoActiveMandate));
+        assert!(run.skipped.iter().any(|s| s.reason == SkipReason::UnsupportedCurrency(Currency::USD)));
+
+        // The mandate is first used when the collection settles
+        assert_eq!(mandates[0].next_sequence_type(), SequenceType::First);
+        let mut payments = run.payments();
+        engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates).unwrap();
+        assert_eq!(mandates[0].last_collection_date, Some(date(2, 1)));
+
+        // Next run collects as a recurring debit
+        let run = engine.collection_run(&mandates, &invoices[1..], date(3, 1));
+        assert_eq!(run.instructions.len(), 1);
+        assert_eq!(run.instructions[0].sequence_type, SequenceType::Recurring);
+    }
+
+    #[test]
+    fn test_collection_run_groups_invoices_per_mandate() {
+        let (mut ledger, accounts, engine) = setup();
+        let party = PartyId::new_v7();
+        let mut mandates = vec![mandate("MDT-0001", party, MandateType::Recurring)];
+        let mut invoices = vec![
+            invoice(party, date(1, 1), dec!(100), Currency::EUR),
+            invoice(party, date(2, 1), dec!(60), Currency::EUR),
+        ];
+
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+
+        assert_eq!(run.instructions.len(), 1);
+        let instruction = &run.instructions[0];
+        assert_eq!(instruction.invoices.len(), 2);
+        assert_eq!(instruction.amount, eur(dec!(160)));
+        assert_eq!(instruction.sequence_type, SequenceType::First);
+        assert_eq!(
+            instruction.remittance_info,
+            format!("{} {}", invoices[0].invoice_number, invoices[1].invoice_number)
+        );
+
+        let mut payments = run.payments();
+        assert_eq!(payments.len(), 2);
+        engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates).unwrap();
+        assert!(invoices.iter().all(|i| i.status == InvoiceStatus::Paid));
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(160));
+
+        // A return of the collection reverses every invoice in it
+        let file = PACS004.replace("{E2E_1}", &instruction.end_to_end_id);
+        let notices = returns::parse_pacs004(&file).unwrap();
+        let outcomes = engine
+            .process_returns(&mut ledger, &run, &notices[..1], &mut payments, &mut invoices, &mut mandates)
+            .unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|o| o.action == ReturnAction::Reversed));
+        assert!(invoices.iter().all(|i| i.status == InvoiceStatus::Issued));
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(0));
+    }
+
+    #[test]
+    fn test_collection_run_prefers_policy_mandate() {
+        let (_, _, engine) = setup();
+        let party = PartyId::new_v7();
+        let due = invoice(party, date(2, 1), dec!(100), Currency::EUR);
+        let mandates = vec![
+            mandate("MDT-GENERAL", party, MandateType::Recurring),
+            mandate("MDT-POLICY", party, MandateType::Recurring).for_policy(due.policy_id),
+            mandate("MDT-OTHER", party, MandateType::Recurring).for_policy(PolicyId::new_v7()),
+        ];
+
+        let run = engine.collection_run(&mandates, &[due], date(2, 1));
+
+        assert_eq!(run.instructions[0].mandate_reference, "MDT-POLICY");
+    }
+
+    #[test]
+    fn test_pain008_document() {
+        let (_, _, engine) = setup();
+        let party = PartyId::new_v7();
+        let one_off_party = PartyId::new_v7();
+        let mut mandates = vec![
+            mandate("MDT-0001", party, MandateType::Recurring),
+            mandate("MDT-0002", one_off_party, MandateType::OneOff),
+        ];
+        mandates[0].record_collection(date(1, 1));
+        let mut invoices = vec![
+            invoice(party, date(2, 1), dec!(100), Currency::EUR),
+            invoice(one_off_party, date(2, 1), dec!(25.5), Currency::EUR),
+        ];
+        invoices[0].invoice_number = "INV-<1>".to_string();
+
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+        let xml = run.to_pain008();
+
+        assert!(xml.contains("urn:iso:std:iso:20022:tech:xsd:pain.008.001.02"));
+        assert!(xml.contains(&format!("<MsgId>{}</MsgId>", run.message_id)));
+        assert!(xml.contains("<NbOfTxs>2</NbOfTxs>"));
+        assert!(xml.contains("<CtrlSum>125.50</CtrlSum>"));
+        assert!(xml.contains("<SeqTp>RCUR</SeqTp>"));
+        assert!(xml.contains("<SeqTp>OOFF</SeqTp>"));
+        assert!(!xml.contains("<SeqTp>FRST</SeqTp>"));
+        assert!(xml.contains("<ReqdColltnDt>2024-02-01</ReqdColltnDt>"));
+        assert!(xml.contains("<Id>DE98ZZZ09999999999</Id>"));
+        assert!(xml.contains("<InstdAmt Ccy=\"EUR\">100.00</InstdAmt>"));
+        assert!(xml.contains("<MndtId>MDT-0001</MndtId>"));
+        assert!(xml.contains("<DtOfSgntr>2024-01-01</DtOfSgntr>"));
+        assert!(xml.contains("<IBAN>NL91ABNA0417164300</IBAN>"));
+        assert!(xml.contains("<Id>NOTPROVIDED</Id>"));
+        assert!(xml.contains("<Ustrd>INV-&lt;1&gt;</Ustrd>"));
+    }
+
+    #[test]
+    fn test_parse_pain002_rejections() {
+        let notices = returns::parse_pain002(PAIN002).unwrap();
+
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].end_to_end_id, "{E2E_1}");
+        assert_eq!(notices[0].kind, ReturnKind::Reject);
+        assert_eq!(notices[0].reason, ReturnReason::ClosedAccount);
+        assert_eq!(notices[0].mandate_reference.as_deref(), Some("MDT-0001"));
+        assert_eq!(notices[0].amount, Some(eur(dec!(100.00))));
+        assert_eq!(notices[0].return_date, date(2, 1));
+    }
+
+    #[test]
+    fn test_parse_pacs004_returns_and_refunds() {
+        let notices = returns::parse_pacs004(PACS004).unwrap();
+
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].kind, ReturnKind::Return);
+        assert_eq!(notices[0].reason, ReturnReason::InsufficientFunds);
+        assert_eq!(notices[1].kind, ReturnKind::Refund);
+        assert_eq!(notices[1].reason.code(), "MD06");
+        assert_eq!(notices[1].amount, Some(eur(dec!(60.00))));
+        assert!(notices.iter().all(|n| n.return_date == date(2, 5)));
+
+        let settled = PACS004.replace(
+            "<RtrId>RTR-0001-1</RtrId>",
+            "<RtrId>RTR-0001-1</RtrId><IntrBkSttlmDt>2024-02-06</IntrBkSttlmDt>",
+        );
+        let notices = returns::parse_pacs004(&settled).unwrap();
+        assert_eq!(notices[0].return_date, date(2, 6));
+        assert_eq!(notices[1].return_date, date(2, 5));
+    }
+
+    #[test]
+    fn test_malformed_collection_files_rejected() {
+        assert!(matches!(returns::parse_pain002("<Document>"), Err(BillingError::CollectionFileParse(_))));
+        assert!(matches!(returns::parse_pain002(PACS004), Err(BillingError::CollectionFileParse(_))));
+        assert!(matches!(returns::parse_pacs004(PAIN002), Err(BillingError::CollectionFileParse(_))));
+
+        let missing_id = PACS004.replace("<OrgnlEndToEndId>{E2E_1}</OrgnlEndToEndId>", "");
+        assert!(matches!(returns::parse_pacs004(&missing_id), Err(BillingError::CollectionFileParse(_))));
+    }
+
+    #[test]
+    fn test_reason_codes() {
+        assert_eq!(ReturnReason::from_code("am04"), ReturnReason::InsufficientFunds);
+        assert_eq!(ReturnReason::from_code("XX99"), ReturnReason::Other("XX99".to_string()));
+        assert_eq!(ReturnReason::Other("XX99".to_string()).code(), "XX99");
+        assert_eq!(ReturnReason::ClosedAccount.to_string(), "AC04 Account closed");
+        assert!(ReturnReason::NoMandate.revokes_mandate());
+        assert!(!ReturnReason::InsufficientFunds.revokes_mandate());
+    }
+
+    #[test]
+    fn test_settle_books_collections() {
+        let (mut ledger, accounts, engine) = setup();
+        let party = PartyId::new_v7();
+        let mut mandates = vec![mandate("MDT-0001", party, MandateType::Recurring)];
+        let mut invoices = vec![invoice(party, date(2, 1), dec!(100), Currency::EUR)];
+
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+        let mut payments = run.payments();
+        assert_eq!(payments[0].method, PaymentMethod::DirectDebit);
+        assert_eq!(payments[0].status, PaymentStatus::Pending);
+        assert_eq!(payments[0].external_reference.as_deref(), Some(run.instructions[0].end_to_end_id.as_str()));
+
+        let entries = engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(payments[0].status, PaymentStatus::Completed);
+        assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(100));
+
+        // Settling again books nothing
+        assert!(engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reject_before_settlement_fails_payment() {
+        let (mut ledger, accounts, engine) = setup();
+        let party = PartyId::new_v7();
+        let mut mandates = vec![mandate("MDT-0001", party, MandateType::Recurring)];
+        let mut invoices = vec![invoice(party, date(2, 1), dec!(100), Currency::EUR)];
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+        let mut payments = run.payments();
+
+        let file = PAIN002.replace("{E2E_1}", &run.instructions[0].end_to_end_id);
+        let notices = returns::parse_pain002(&file).unwrap();
+        let outcomes = engine
+            .process_returns(&mut ledger, &run, &notices, &mut payments, &mut invoices, &mut mandates)
+            .unwrap();
+
+        assert_eq!(outcomes[0].action, ReturnAction::Rejected);
+        assert!(outcomes[0].journal_entry_id.is_none());
+        assert_eq!(payments[0].status, PaymentStatus::Failed);
+        assert!(payments[0].notes.as_deref().unwrap().contains("AC04"));
+        assert_eq!(invoices[0].status, InvoiceStatus::Issued);
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(0));
+
+        // Closed account revokes the mandate on the date of the reject
+        assert_eq!(outcomes[0].revoked_mandate, Some(mandates[0].id));
+        assert_eq!(mandates[0].status, MandateStatus::Revoked);
+        assert_eq!(mandates[0].revoked_on, Some(date(2, 1)));
+        assert!(mandates[0].last_collection_date.is_none());
+    }
+
+    #[test]
+    fn test_reject_without_mandate_reference_revokes_collected_mandate() {
+        let (mut ledger, _, engine) = setup();
+        let party = PartyId::new_v7();
+        let mut invoices = vec![invoice(party, date(2, 1), dec!(100), Currency::EUR)];
+        let mut mandates = vec![
+            mandate("MDT-GENERAL", party, MandateType::Recurring),
+            mandate("MDT-POLICY", party, MandateType::Recurring).for_policy(invoices[0].policy_id),
+        ];
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+        let mut payments = run.payments();
+
+        let file = PAIN002
+            .replace("{E2E_1}", &run.instructions[0].end_to_end_id)
+            .replace("<MndtRltdInf><MndtId>MDT-0001</MndtId></MndtRltdInf>", "");
+        let notices = returns::parse_pain002(&file).unwrap();
+        assert!(notices[0].mandate_reference.is_none());
+        let outcomes = engine
+            .process_returns(&mut ledger, &run, &notices, &mut payments, &mut invoices, &mut mandates)
+            .unwrap();
+
+        assert_eq!(outcomes[0].revoked_mandate, Some(mandates[1].id));
+        assert_eq!(mandates[0].status, MandateStatus::Active);
+        assert_eq!(mandates[1].status, MandateStatus::Revoked);
+    }
+
+    #[test]
+    fn test_return_after_settlement_reverses_payment() {
+        let (mut ledger, accounts, engine) = setup();
+        let (party, other_party) = (PartyId::new_v7(), PartyId::new_v7());
+        let mut mandates = vec![
+            mandate("MDT-0001", party, MandateType::Recurring),
+            mandate("MDT-0002", other_party, MandateType::Recurring),
+        ];
+        let mut invoices = vec![
+            invoice(party, date(2, 1), dec!(100), Currency::EUR),
+            invoice(other_party, date(2, 1), dec!(60), Currency::EUR),
+        ];
+        let run = engine.collection_run(&mandates, &invoices, date(2, 1));
+        let mut payments = run.payments();
+        engine.settle(&mut ledger, &run, &mut payments, &mut invoices, &mut mandates).unwrap();
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(160));
+
+        let file = PACS004
+            .replace("{E2E_1}", &run.instructions[0].end_to_end_id)
+            .replace("{E2E_2}", &run.instructions[1].end_to_end_id);
+        let notices = returns::parse_pacs004(&file).unwrap();
+        let outcomes = engine
+            .process_returns(&mut ledger, &run, &notices, &mut payments, &mut invoices, &mut mandates)
+            .unwrap();
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|o| o.action == ReturnAction::Reversed));
+        assert!(outcomes.iter().all(|o| o.journal_entry_id.is_some()));
+        let reversal = ledger.entries().last().unwrap();
+        assert_eq!(reversal.transaction_date.date_naive(), date(2, 5));
+        assert!(payments.iter().all(|p| p.status == PaymentStatus::Reversed));
+        assert!(invoices.iter().all(|i| i.status == InvoiceStatus::Issued));
+        assert!(invoices[0].notes.as_deref().unwrap().contains("AM04 Insufficient funds"));
+        assert!(invoices[1].notes.as_deref().unwrap().contains("refund MD06"));
+        assert_eq!(ledger.get_balance(&accounts.cash).unwrap().amount(), dec!(0));
+        assert_eq!(ledger.get_balance(&accounts.premium_receivable).unwrap().amount(), dec!(0));
+
+        // Neither reason ends the mandates
+        assert!(outcomes.iter().all(|o| o.revoked_mandate.is_none()));
+        assert!(mandates.iter().all(|m| m.status == MandateStatus::Active));
+
+        // Processing the same file twice changes nothing
+        let again = engine
+            .process_returns(&mut ledger, &run, &notices, &mut payments, &mut invoices, &mut mandates)
+            .unwrap();
+        assert!(again.iter().all(|o| o.action == ReturnAction::AlreadyProcessed));
+    }
+
+    #[test]
+    fn test_unmatched_return() {
+        let (mut ledger, _, engine) = setup();
+        let run = engine.collection_run(&[], &[], date(2, 1));
+        let notices = returns::parse_pacs004(PACS004).unwrap();
+
+        let outcomes = engine
+            .process_returns(&mut ledger, &run, &notices, &mut [], &mut [], &mut [])
+            .unwrap();
+
+        assert!(outcomes.iter().all(|o| o.action == ReturnAction::Unmatched && o.payment_id.is_none()));
+    }
+}
+
+// ============================================================================
+// Premium Tax Tests
+// ============================================================================
+
+mod tax_tests {
+    use super::*;
+    use domain_billing::tax::{
+        TaxAccounts, TaxEngine, TaxExemption, TaxJurisdiction, TaxRule, TaxType,
+    };
+    use domain_party::address::{Address, AddressType};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn aud(amount: Decimal) -> Money {
+        Money::new(amount, Currency::AUD)
+    }
+
+    fn setup() -> (Ledger, TaxAccounts, TaxEngine) {
+        let mut ledger = Ledger::new(Currency::AUD);
+        let accounts = TaxAccounts {
+            receivable: AccountId::new(),
+            premium_tax: AccountId::new(),
+            gst: AccountId::new(),
+            stamp_duty: AccountId::new(),
+            levies: AccountId::new(),
+        };
+        ledger.add_account(Account::new(accounts.receivable, "1100", "Premium Receivable", AccountType::Asset)).unwrap();
+        ledger.add_account(Account::new(accounts.premium_tax, "2600", "Premium Tax Payable", AccountType::Liability)).unwrap();
+        ledger.add_account(Account::new(accounts.gst, "2610", "GST Payable", AccountType::Liability)).unwrap();
+        ledger.add_account(Account::new(accounts.stamp_duty, "2620", "Stamp Duty Payable", AccountType::Liability)).unwrap();
+        ledger.add_account(Account::new(accounts.levies, "2630", "Regulatory Levies Payable", AccountType::Liability)).unwrap();
+
+        let australia = TaxJurisdiction::new("AU");
+        let nsw = TaxJurisdiction::new("AU").with_region("NSW");
+        let engine = TaxEngine::new(accounts)
+            .with_rule(
+                TaxRule::percentage("AU-GST", TaxType::Gst, australia.clone(), dec!(0.10), date(2000, 7, 1))
+                    .for_product_lines(&["health"])
+                    .on_items(vec![InvoiceItemType::Premium, InvoiceItemType::PolicyFee]),
+            )
+            .with_rule(
+                TaxRule::percentage("NSW-SD", TaxType::StampDuty, nsw.clone(), dec!(0.05), date(2020, 1, 1))
+                    .until(date(2024, 6, 30)),
+            )
+            .with_rule(TaxRule::percentage("NSW-SD", TaxType::StampDuty, nsw.clone(), dec!(0.09), date(2024, 7, 1)))
+            .with_rule(TaxRule::flat("NSW-LEVY", TaxType::Levy, nsw, aud(dec!(2.50)), date(2020, 1, 1)));
+        (ledger, accounts, engine)
+    }
+
+    fn invoice(customer: PartyId) -> Invoice {
+        let mut invoice = Invoice::new(PolicyId::new(), customer, date(2024, 8, 1), Currency::AUD);
+        invoice.add_item(InvoiceItem::new("Premium", InvoiceItemType::Premium, aud(dec!(1000))));
+        invoice.add_item(InvoiceItem::new("Policy fee", InvoiceItemType::PolicyFee, aud(dec!(50))));
+        invoice
+    }
+
+    fn nsw_address() -> Address {
+        let mut address = Address::new(AddressType::Residential, "1 George St", "Sydney", "2000", "au");
+        address.state = Some("nsw".to_string());
+        address
+    }
+
+    #[test]
+    fn test_jurisdiction_from_address() {
+        let jurisdiction = TaxJurisdiction::from_address(&nsw_address());
+
+        assert_eq!(jurisdiction.to_string(), "AU-NSW");
+        assert!(TaxJurisdiction::new("AU").covers(&jurisdiction));
+        assert!(!TaxJurisdiction::new("AU").with_region("VIC").covers(&jurisdiction));
+        assert!(!jurisdiction.covers(&TaxJurisdiction::new("AU")));
+    }
+
+    #[test]
+    fn test_rules_selected_by_region_line_and_date() {
+        let (_, _, engine) = setup();
+        let nsw = TaxJurisdiction::from_address(&nsw_address());
+        let victoria = TaxJurisdiction::new("AU").with_region("VIC");
+
+        let codes = |location: &TaxJurisdiction, line: &str, on: NaiveDate| -> Vec<String> {
+            engine.rules_for(location, line, on).iter().map(|r| r.code.clone()).collect()
+        };
+
+        assert_eq!(codes(&nsw, "health", date(2024, 8, 1)), vec!["AU-GST", "NSW-SD", "NSW-LEVY"]);
+        assert_eq!(codes(&nsw, "life", date(2024, 8, 1)), vec!["NSW-SD", "NSW-LEVY"]);
+        assert_eq!(codes(&victoria, "health", date(2024, 8, 1)), vec!["AU-GST"]);
+        assert!(codes(&TaxJurisdiction::new("NZ"), "health", date(2024, 8, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_assessment_per_item() {
+        let (_, _, engine) = setup();
+        let invoice = invoice(PartyId::new());
+        let nsw = TaxJurisdiction::from_address(&nsw_address());
+
+        let assessment = engine.assess(&invoice, &nsw, "health", date(2024, 8, 1));
+
+        // GST on premium and fee, stamp duty on premium only, flat levy
+        assert_eq!(assessment.lines.len(), 4);
+        assert_eq!(assessment.total_for(TaxType::Gst), aud(dec!(105)));
+        assert_eq!(assessment.total_for(TaxType::StampDuty), aud(dec!(90)));
+        assert_eq!(assessment.total_for(TaxType::Levy), aud(dec!(2.50)));
+        assert_eq!(assessment.total, aud(dec!(197.50)));
+    }
+
+    #[test]
+    fn test_superseded_rate_used_for_earlier_date() {
+        let (_, _, engine) = setup();
+        let nsw = TaxJurisdiction::from_address(&nsw_address());
+
+        let assessment = engine.assess(&invoice(PartyId::new()), &nsw, "life", date(2024, 6, 30));
+
+        assert_eq!(assessment.total_for(TaxType::StampDuty), aud(dec!(50)));
+    }
+
+    #[test]
+    fn test_exempt_party() {
+        let (_, _, mut engine) = setup();
+        let charity = PartyId::new();
+        engine.add_exemption(
+            TaxExemption::new(charity, "EX-2024-17")
+                .for_tax_types(vec![TaxType::StampDuty])
+                .valid_until(date(2024, 12, 31)),
+        );
+        let nsw = TaxJurisdiction::from_address(&nsw_address());
+
+        let exempt = engine.assess(&invoice(charity), &nsw, "life", date(2024, 8, 1));
+        let expired = engine.assess(&invoice(charity), &nsw, "life", date(2025, 1, 1));
+
+        assert_eq!(exempt.exempted_rules, vec!["NSW-SD"]);
+        assert_eq!(exempt.total, aud(dec!(2.50)));
+        assert!(expired.exempted_rules.is_empty());
+        assert_eq!(expired.total, aud(dec!(92.50)));
+    }
+
+    #[test]
+    fn test_apply_sets_invoice_tax() {
+        let (_, _, engine) = setup();
+        let mut invoice = invoice(PartyId::new());
+        let nsw = TaxJurisdiction::from_address(&nsw_address());
+        let assessment = engine.assess(&invoice, &nsw, "health", date(2024, 8, 1));
+
+        engine.apply(&mut invoice, &assessment).unwrap();
+
+        assert_eq!(invoice.tax, Some(aud(dec!(197.50))));
+        assert_eq!(invoice.total, aud(dec!(1247.50)));
+        assert!(engine.apply(&mut Invoice::new(PolicyId::new(), PartyId::new(), date(2024, 8, 1), Currency::AUD), &assessment).is_err());
+    }
+
+    #[test]
+    fn test_post_and_remittance_report() {
+        let (mut ledger, accounts, mut engine) = setup();
+        let nsw = TaxJurisdiction::from_address(&nsw_address());
+        for posting_date in [date(2024, 8, 1), date(2024, 8, 15), date(2024, 10, 1)] {
+            let assessment = engine.assess(&invoice(PartyId::new()), &nsw, "health", posting_date);
+            assert!(engine.post(&mut ledger, &assessment, posting_date).unwrap().is_some());
+        }
+
+        assert_eq!(ledger.get_balance(&accounts.gst).unwrap().amount(), dec!(315));
+        assert_eq!(ledger.get_balance(&accounts.stamp_duty).unwrap().amount(), dec!(270));
+        assert_eq!(ledger.get_balance(&accounts.levies).unwrap().amount(), dec!(7.50));
+        assert_eq!(ledger.get_balance(&accounts.receivable).unwrap().amount(), dec!(592.50));
+
+        let report = engine.remittance_report(date(2024, 7, 1), date(2024, 9, 30));
+        assert_eq!(report.len(), 3);
+        let gst = report.iter().find(|l| l.rule_code == "AU-GST").unwrap();
+        assert_eq!(gst.invoice_count, 2);
+        assert_eq!(gst.taxable_amount, aud(dec!(2100)));
+        assert_eq!(gst.tax_amount, aud(dec!(210)));
+        assert_eq!(gst.liability_account, accounts.gst);
+        assert_eq!(gst.jurisdiction, TaxJurisdiction::new("AU"));
+        let stamp_duty = report.iter().find(|l| l.tax_type == TaxType::StampDuty).unwrap();
+        assert_eq!(stamp_duty.jurisdiction, nsw);
+    }
+
+    #[test]
+    fn test_nothing_posted_when_no_tax_due() {
+        let (mut ledger, _, mut engine) = setup();
+        let assessment = engine.assess(&invoice(PartyId::new()), &TaxJurisdiction::new("NZ"), "life", date(2024, 8, 1));
+
+        assert!(assessment.lines.is_empty());
+        assert!(engine.post(&mut ledger, &assessment, date(2024, 8, 1)).unwrap().is_none());
+        assert!(ledger.entries().is_empty());
+    }
+}
@@ -355,6 +355,29 @@ impl Individual {
 
         age as u32
     }
+
+    /// Checks whether two records describe the same person
+    ///
+    /// Records that both carry a tax identification number match on it,
+    /// ignoring punctuation. Otherwise they match on date of birth and
+    /// first and last name, ignoring case and surrounding whitespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The record to compare with
+    pub fn is_same_person(&self, other: &Individual) -> bool {
+        fn normalize_tax_id(tax_id: &str) -> String {
+            tax_id.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_ascii_uppercase()
+        }
+
+        if let (Some(a), Some(b)) = (&self.tax_id, &other.tax_id) {
+            return normalize_tax_id(a) == normalize_tax_id(b);
+        }
+
+        self.date_of_birth == other.date_of_birth
+            && self.first_name.trim().eq_ignore_ascii_case(other.first_name.trim())
+            && self.last_name.trim().eq_ignore_ascii_case(other.last_name.trim())
+    }
 }
 
 /// Gender enumeration for actuarial purposes
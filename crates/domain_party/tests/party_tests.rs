@@ -138,6 +138,33 @@ mod individual_party_tests {
         assert!(age == 39 || age == 40); // Depending on exact date
     }
 
+    #[test]
+    fn test_individual_same_person_by_tax_id() {
+        let individual = create_test_individual();
+        let mut other = create_test_individual();
+        other.first_name = "Johnny".to_string();
+        other.tax_id = Some("123456789".to_string());
+
+        assert!(individual.is_same_person(&other));
+
+        other.tax_id = Some("987-65-4321".to_string());
+        assert!(!individual.is_same_person(&other));
+    }
+
+    #[test]
+    fn test_individual_same_person_by_name_and_birth_date() {
+        let individual = create_test_individual();
+        let mut other = create_test_individual();
+        other.tax_id = None;
+        other.middle_name = None;
+        other.last_name = " DOE ".to_string();
+
+        assert!(individual.is_same_person(&other));
+
+        other.date_of_birth = NaiveDate::from_ymd_opt(1985, 6, 16).unwrap();
+        assert!(!individual.is_same_person(&other));
+    }
+
     #[test]
     fn test_individual_is_not_composite() {
         let party = Party::new_individual(create_test_individual());
@@ -10,21 +10,25 @@
 //! - Coverage cannot exceed product limits
 //! - State transitions must follow the allowed lifecycle
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Months, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use core_kernel::{
-    Money, Currency, PolicyId, PartyId, PolicyVersionId,
-    ValidPeriod, BiTemporalRecord,
+    Money, Currency, PolicyId, PartyId, PolicyVersionId, ClaimId,
+    ValidPeriod, BiTemporalRecord, utc_start_of_day,
 };
 
 use crate::coverage::{Coverage, CoverageType};
 use crate::endorsement::{Endorsement, EndorsementType};
 use crate::error::PolicyError;
-use crate::events::PolicyEvent;
-use crate::premium::{Premium, PremiumFrequency};
+use crate::events::{PolicyEvent, UnderwritingDecisionType};
+use crate::illustration::Illustration;
+use crate::premium::{PaymentStatus, Premium, PremiumFrequency, PremiumSchedule, RiderPremium};
+use crate::rider::Rider;
+use crate::waiver::{WaiverEndReason, WaiverPeriod};
+use crate::underwriting::{InsuredLife, RiskClass};
 
 /// Policy lifecycle states
 ///
@@ -86,6 +90,16 @@ pub enum PolicyState {
         premium_refunded: bool,
     },
 
+    /// Premiums have stopped and the cash value secures paid-up cover
+    PaidUp {
+        /// Nonforfeiture option applied
+        option: NonforfeitureOption,
+        /// Date the option took effect
+        effective_date: NaiveDate,
+        /// End of extended term cover; `None` for reduced paid-up insurance
+        expiry_date: Option<NaiveDate>,
+    },
+
     /// Policy naturally expired
     Expired {
         /// Date of expiry
@@ -99,6 +113,24 @@ pub enum PolicyState {
         /// Required documents
         required_documents: Vec<String>,
     },
+
+    /// Application was declined at underwriting
+    Declined {
+        /// Reason for the decline
+        reason: String,
+        /// Date of the decision
+        declined_date: DateTime<Utc>,
+    },
+}
+
+/// Nonforfeiture options available when premiums stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NonforfeitureOption {
+    /// Smaller sum assured for life with no further premiums
+    ReducedPaidUp,
+    /// Full sum assured, less any loan, for a limited term
+    ExtendedTerm,
 }
 
 /// Reasons for policy lapse
@@ -113,6 +145,13 @@ pub enum LapseReason {
     },
     /// Insufficient fund value (for ULIPs)
     InsufficientFundValue,
+    /// Policy loan balance exceeded the cash value
+    LoanExceedsCashValue {
+        /// Loan balance including accrued interest
+        loan_balance: Decimal,
+        /// Cash value at lapse
+        cash_value: Decimal,
+    },
     /// Other reason
     Other(String),
 }
@@ -248,12 +287,20 @@ impl PolicyFinancials {
 /// - Quoted -> PendingUnderwriting (via submit_for_underwriting)
 /// - Quoted -> Cancelled (via cancel)
 /// - PendingUnderwriting -> InForce (via approve)
-/// - PendingUnderwriting -> Quoted (via decline)
+/// - PendingUnderwriting -> Declined (via decline)
 /// - InForce -> Lapsed (via lapse)
 /// - InForce -> Terminated (via terminate)
 /// - InForce -> Expired (on expiry date)
-/// - Lapsed -> InForce (via reinstate)
+/// - InForce / Reinstated -> InForce (via renew)
+/// - InForce -> Cancelled (via cancel)
+/// - Lapsed -> Reinstated (via reinstate)
 /// - Lapsed -> Terminated (via terminate)
+/// - Reinstated -> Lapsed (via lapse)
+/// - Reinstated -> Cancelled (via cancel)
+/// - InForce / Reinstated / Lapsed -> PaidUp (via apply_nonforfeiture)
+/// - PaidUp -> Expired (via expire, for extended term insurance)
+/// - PaidUp -> Terminated (via terminate)
+/// - InForce / Reinstated -> Terminated (via convert)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Policy {
     /// Unique policy identifier
@@ -262,6 +309,9 @@ pub struct Policy {
     policy_number: String,
     /// Product code this policy is based on
     product_code: String,
+    /// Catalog version of the product the policy was sold under
+    #[serde(default)]
+    product_version: Option<String>,
     /// Current lifecycle state
     state: PolicyState,
     /// Policyholder party ID
@@ -280,6 +330,33 @@ pub struct Policy {
     term_years: Option<u32>,
     /// Date when policy expires
     expiry_date: Option<NaiveDate>,
+    /// Whether the product allows the term to be renewed
+    #[serde(default)]
+    renewable: bool,
+    /// Date cover first started (set on issue)
+    #[serde(default)]
+    inception_date: Option<NaiveDate>,
+    /// Underwriting risk class
+    #[serde(default)]
+    risk_class: Option<RiskClass>,
+    /// Underwriting details of the life insured
+    #[serde(default)]
+    insured: Option<InsuredLife>,
+    /// Policy this policy was converted from
+    #[serde(default)]
+    converted_from: Option<PolicyId>,
+    /// Policy this policy was converted to
+    #[serde(default)]
+    converted_to: Option<PolicyId>,
+    /// Riders attached to base coverages
+    #[serde(default)]
+    riders: Vec<Rider>,
+    /// Waiver of premium periods
+    #[serde(default)]
+    waiver_periods: Vec<WaiverPeriod>,
+    /// Benefit illustration given with the quote
+    #[serde(default)]
+    illustration: Option<Illustration>,
     /// Applied endorsements
     endorsements: Vec<Endorsement>,
     /// Domain events to be published
@@ -309,6 +386,11 @@ impl Policy {
         &self.product_code
     }
 
+    /// Returns the catalog version of the product the policy is pinned to
+    pub fn product_version(&self) -> Option<&str> {
+        self.product_version.as_deref()
+    }
+
     /// Returns the current state
     pub fn state(&self) -> &PolicyState {
         &self.state
@@ -339,6 +421,102 @@ impl Policy {
         self.currency
     }
 
+    /// Returns the underwriting risk class, if recorded
+    pub fn risk_class(&self) -> Option<RiskClass> {
+        self.risk_class
+    }
+
+    /// Returns the underwriting details of the life insured, if recorded
+    pub fn insured(&self) -> Option<&InsuredLife> {
+        self.insured.as_ref()
+    }
+
+    /// Returns the policy this policy was converted from
+    pub fn converted_from(&self) -> Option<PolicyId> {
+        self.converted_from
+    }
+
+    /// Returns the policy this policy was converted to
+    pub fn converted_to(&self) -> Option<PolicyId> {
+        self.converted_to
+    }
+
+    /// Returns the policy term in years (`None` for whole life)
+    pub fn term_years(&self) -> Option<u32> {
+        self.term_years
+    }
+
+    /// Returns the date the current term ends (`None` for whole life)
+    pub fn expiry_date(&self) -> Option<NaiveDate> {
+        self.expiry_date
+    }
+
+    /// Whether the term can be renewed at expiry
+    pub fn is_renewable(&self) -> bool {
+        self.renewable
+    }
+
+    /// Returns the next renewal date while the policy is in force
+    pub fn renewal_date(&self) -> Option<NaiveDate> {
+        match &self.state {
+            PolicyState::InForce { renewal_date, .. } => Some(*renewal_date),
+            PolicyState::Reinstated { .. } if self.renewable => self.expiry_date,
+            _ => None,
+        }
+    }
+
+    /// Returns the riders attached to the policy, including ended riders
+    pub fn riders(&self) -> &[Rider] {
+        &self.riders
+    }
+
+    /// Returns the benefit illustration given with the quote, if any
+    pub fn illustration(&self) -> Option<&Illustration> {
+        self.illustration.as_ref()
+    }
+
+    /// Returns the waiver of premium periods, including ended periods
+    pub fn waiver_periods(&self) -> &[WaiverPeriod] {
+        &self.waiver_periods
+    }
+
+    /// Returns the open waiver of premium period, if any
+    pub fn active_waiver(&self) -> Option<&WaiverPeriod> {
+        self.waiver_periods.iter().find(|w| w.is_open())
+    }
+
+    /// Whether a premium due on `due_date` is waived
+    pub fn is_premium_waived(&self, due_date: NaiveDate) -> bool {
+        self.waiver_periods.iter().any(|w| w.covers(due_date))
+    }
+
+    /// Returns the premium schedule from inception, with waived
+    /// instalments marked `Waived`
+    ///
+    /// # Arguments
+    ///
+    /// * `years` - Number of years to schedule
+    pub fn premium_schedule(&self, years: u32) -> PremiumSchedule {
+        let start_date = self.inception_date.unwrap_or_else(|| self.created_at.date_naive());
+        let mut entries = self.premium.generate_schedule(start_date, years);
+        for entry in &mut entries {
+            if self.is_premium_waived(entry.due_date) {
+                entry.status = PaymentStatus::Waived;
+            }
+        }
+        PremiumSchedule { entries }
+    }
+
+    /// Returns the insured risks
+    pub fn insured_risks(&self) -> &[RiskObject] {
+        &self.insured_risks
+    }
+
+    /// Returns the date cover first started, once issued
+    pub fn inception_date(&self) -> Option<NaiveDate> {
+        self.inception_date
+    }
+
     /// Returns accumulated domain events and clears them
     pub fn take_events(&mut self) -> Vec<PolicyEvent> {
         std::mem::take(&mut self.events)
@@ -381,7 +559,13 @@ impl Policy {
     ) -> Result<(), PolicyError> {
         match &self.state {
             PolicyState::Quoted { .. } | PolicyState::PendingUnderwriting { .. } => {
-                let renewal_date = self.calculate_renewal_date(effective_date);
+                self.expiry_date = self
+                    .term_years
+                    .and_then(|years| effective_date.checked_add_months(Months::new(12 * years)));
+                let renewal_date = self
+                    .expiry_date
+                    .filter(|_| self.renewable)
+                    .unwrap_or_else(|| self.calculate_renewal_date(effective_date));
                 let now = Utc::now();
 
                 self.state = PolicyState::InForce {
@@ -391,6 +575,7 @@ impl Policy {
                 };
 
                 self.financial_state.next_due_date = Some(effective_date);
+                self.inception_date = Some(effective_date);
                 self.updated_at = now;
 
                 self.events.push(PolicyEvent::PolicyIssued {
@@ -409,8 +594,181 @@ impl Policy {
         }
     }
 
+    /// Submits a quote for underwriting review
+    ///
+    /// # Arguments
+    ///
+    /// * `required_documents` - Evidence the underwriter needs
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not in Quoted state
+    pub fn submit_for_underwriting(&mut self, required_documents: Vec<String>) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::Quoted { .. }) {
+            return Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "PendingUnderwriting".to_string(),
+            });
+        }
+
+        let now = Utc::now();
+        self.state = PolicyState::PendingUnderwriting {
+            submission_date: now,
+            required_documents,
+        };
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::SubmittedForUnderwriting {
+            policy_id: self.id,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Approves a policy pending underwriting and issues it
+    ///
+    /// # Arguments
+    ///
+    /// * `effective_date` - When the policy becomes effective
+    /// * `risk_class` - Risk class assigned by the underwriter
+    /// * `underwriter` - ID of the approving underwriter
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not pending underwriting or the risk
+    /// class is `Declined`
+    pub fn approve(
+        &mut self,
+        effective_date: NaiveDate,
+        risk_class: RiskClass,
+        underwriter: &str,
+    ) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::PendingUnderwriting { .. }) {
+            return Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "InForce".to_string(),
+            });
+        }
+        if risk_class == RiskClass::Declined {
+            return Err(PolicyError::Underwriting(
+                "Cannot approve with a declined risk class".to_string(),
+            ));
+        }
+
+        self.risk_class = Some(risk_class);
+        self.events.push(PolicyEvent::UnderwritingDecision {
+            policy_id: self.id,
+            decision: if risk_class.rate_multiplier() > Decimal::ONE {
+                UnderwritingDecisionType::ApprovedWithRating
+            } else {
+                UnderwritingDecisionType::Approved
+            },
+            underwriter: underwriter.to_string(),
+            notes: None,
+            timestamp: Utc::now(),
+        });
+
+        self.issue(effective_date, underwriter)
+    }
+
+    /// Declines a policy pending underwriting
+    ///
+    /// A declined policy cannot be issued; the applicant needs a new quote.
+    ///
+    /// # Arguments
+    ///
+    /// * `underwriter` - ID of the declining underwriter
+    /// * `reason` - Reason for the decline
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not pending underwriting
+    pub fn decline(&mut self, underwriter: &str, reason: &str) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::PendingUnderwriting { .. }) {
+            return Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "Declined".to_string(),
+            });
+        }
+
+        let now = Utc::now();
+        self.state = PolicyState::Declined {
+            reason: reason.to_string(),
+            declined_date: now,
+        };
+        self.risk_class = Some(RiskClass::Declined);
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::UnderwritingDecision {
+            policy_id: self.id,
+            decision: UnderwritingDecisionType::Declined,
+            underwriter: underwriter.to_string(),
+            notes: Some(reason.to_string()),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Records that a premium has gone overdue and the grace period has started
+    ///
+    /// The policy stays in force during the grace period; this only raises
+    /// the `PremiumOverdue` and `GracePeriodStarted` events and adds the
+    /// overdue premium to any premium already outstanding.
+    ///
+    /// # Arguments
+    ///
+    /// * `outstanding` - The overdue premium amount
+    /// * `days_overdue` - Days since the premium fell due
+    /// * `grace_end_date` - Last day of the grace period
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not in force or currencies differ
+    pub fn start_grace_period(
+        &mut self,
+        outstanding: Money,
+        days_overdue: u32,
+        grace_end_date: NaiveDate,
+    ) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::InForce { .. } | PolicyState::Reinstated { .. }) {
+            return Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "GracePeriod".to_string(),
+            });
+        }
+        if outstanding.currency() != self.currency {
+            return Err(PolicyError::CurrencyMismatch {
+                expected: self.currency.to_string(),
+                actual: outstanding.currency().to_string(),
+            });
+        }
+
+        let now = Utc::now();
+        self.financial_state.premium_outstanding = self.financial_state.premium_outstanding + outstanding;
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::PremiumOverdue {
+            policy_id: self.id,
+            amount: outstanding.amount(),
+            currency: outstanding.currency().to_string(),
+            days_overdue,
+            timestamp: now,
+        });
+        self.events.push(PolicyEvent::GracePeriodStarted {
+            policy_id: self.id,
+            grace_end_date,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
     /// Lapses the policy due to non-payment
     ///
+    /// A policy that was previously reinstated can lapse again.
+    ///
     /// # Arguments
     ///
     /// * `reason` - The reason for lapse
@@ -423,17 +781,49 @@ impl Policy {
         &mut self,
         reason: LapseReason,
         reinstatement_period_days: Option<u32>,
+    ) -> Result<(), PolicyError> {
+        self.lapse_at(reason, reinstatement_period_days, Utc::now())
+    }
+
+    /// Lapses the policy as of a business date
+    ///
+    /// The lapse, and the reinstatement deadline, run from the start of
+    /// `as_of` rather than the time of processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - Reason for lapse
+    /// * `reinstatement_period_days` - Days allowed for reinstatement
+    /// * `as_of` - Date the policy lapses
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not in force
+    pub fn lapse_on(
+        &mut self,
+        reason: LapseReason,
+        reinstatement_period_days: Option<u32>,
+        as_of: NaiveDate,
+    ) -> Result<(), PolicyError> {
+        self.lapse_at(reason, reinstatement_period_days, utc_start_of_day(as_of))
+    }
+
+    fn lapse_at(
+        &mut self,
+        reason: LapseReason,
+        reinstatement_period_days: Option<u32>,
+        effective_date: DateTime<Utc>,
     ) -> Result<(), PolicyError> {
         match &self.state {
-            PolicyState::InForce { .. } => {
+            PolicyState::InForce { .. } | PolicyState::Reinstated { .. } => {
                 let now = Utc::now();
                 let reinstatement_deadline = reinstatement_period_days.map(|days| {
-                    now + chrono::Duration::days(days as i64)
+                    effective_date + chrono::Duration::days(days as i64)
                 });
 
                 self.state = PolicyState::Lapsed {
                     reason: reason.clone(),
-                    effective_date: now,
+                    effective_date,
                     reinstatement_deadline,
                 };
 
@@ -460,6 +850,27 @@ impl Policy {
     ///
     /// Returns error if policy is not lapsed or reinstatement period has passed
     pub fn reinstate(&mut self) -> Result<(), PolicyError> {
+        self.reinstate_at(Utc::now())
+    }
+
+    /// Reinstates a lapsed policy as of a business date
+    ///
+    /// The reinstatement deadline is checked against, and the policy
+    /// reinstated from, the start of `as_of` rather than the time of
+    /// processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `as_of` - Date the policy is reinstated
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not lapsed or reinstatement period has passed
+    pub fn reinstate_on(&mut self, as_of: NaiveDate) -> Result<(), PolicyError> {
+        self.reinstate_at(utc_start_of_day(as_of))
+    }
+
+    fn reinstate_at(&mut self, reinstatement_date: DateTime<Utc>) -> Result<(), PolicyError> {
         match &self.state {
             PolicyState::Lapsed {
                 effective_date,
@@ -470,13 +881,13 @@ impl Policy {
 
                 // Check if reinstatement period has passed
                 if let Some(deadline) = reinstatement_deadline {
-                    if now > *deadline {
+                    if reinstatement_date > *deadline {
                         return Err(PolicyError::ReinstatementPeriodExpired);
                     }
                 }
 
                 self.state = PolicyState::Reinstated {
-                    reinstatement_date: now,
+                    reinstatement_date,
                     original_lapse_date: *effective_date,
                 };
 
@@ -507,7 +918,7 @@ impl Policy {
     /// Returns error if policy cannot be terminated from current state
     pub fn terminate(&mut self, reason: TerminationReason) -> Result<(), PolicyError> {
         match &self.state {
-            PolicyState::InForce { .. } | PolicyState::Lapsed { .. } => {
+            PolicyState::InForce { .. } | PolicyState::Lapsed { .. } | PolicyState::PaidUp { .. } => {
                 let now = Utc::now();
 
                 self.state = PolicyState::Terminated {
@@ -532,80 +943,650 @@ impl Policy {
         }
     }
 
-    /// Applies an endorsement to modify the policy
+    /// Applies a nonforfeiture option, converting the policy to paid-up cover
+    ///
+    /// The primary death benefit is set to `sum_assured`, and for extended
+    /// term insurance ends on `expiry_date`; other coverages end. Premiums
+    /// stop and the remaining cash value is recorded. Any policy loan must
+    /// already have been offset against the cash value by `LoanEngine`.
     ///
     /// # Arguments
     ///
-    /// * `endorsement` - The endorsement to apply
+    /// * `option` - The nonforfeiture option
+    /// * `effective_date` - Date the option takes effect
+    /// * `sum_assured` - Paid-up sum assured
+    /// * `net_cash_value` - Cash value remaining after settling any loan
+    /// * `expiry_date` - End of extended term cover
     ///
     /// # Errors
     ///
-    /// Returns error if policy is not in a modifiable state
-    pub fn apply_endorsement(&mut self, endorsement: Endorsement) -> Result<(), PolicyError> {
-        if !self.is_modifiable() {
-            return Err(PolicyError::NotModifiable);
+    /// Returns error if the policy is not in force, reinstated or lapsed,
+    /// has a loan outstanding, has no death benefit, or currencies differ
+    pub fn apply_nonforfeiture(
+        &mut self,
+        option: NonforfeitureOption,
+        effective_date: NaiveDate,
+        sum_assured: Money,
+        net_cash_value: Money,
+        expiry_date: Option<NaiveDate>,
+    ) -> Result<(), PolicyError> {
+        if !matches!(
+            self.state,
+            PolicyState::InForce { .. } | PolicyState::Reinstated { .. } | PolicyState::Lapsed { .. }
+        ) {
+            return Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "PaidUp".to_string(),
+            });
+        }
+        self.check_currency(&sum_assured)?;
+        self.check_currency(&net_cash_value)?;
+        if self.financial_state.loan_outstanding.is_some_and(|loan| loan.is_positive()) {
+            return Err(PolicyError::Nonforfeiture(format!(
+                "Policy {} has a loan outstanding",
+                self.policy_number
+            )));
         }
 
-        // Apply the endorsement effects
-        match &endorsement.endorsement_type {
-            EndorsementType::CoverageChange { add, remove, modify } => {
-                // Remove coverages
-                for coverage_id in remove {
-                    self.coverages.retain(|c| c.id != *coverage_id);
-                }
-
-                // Add new coverages
-                self.coverages.extend(add.clone());
-
-                // Modify existing coverages
-                for modification in modify {
-                    if let Some(coverage) = self.coverages.iter_mut().find(|c| c.id == modification.coverage_id) {
-                        coverage.apply_modification(modification)?;
-                    }
-                }
-            }
-            EndorsementType::BeneficiaryChange { beneficiaries } => {
-                // Handle beneficiary changes
-            }
-            EndorsementType::PremiumChange { new_premium } => {
-                self.premium = new_premium.clone();
+        let primary = self
+            .coverages
+            .iter()
+            .position(|c| c.is_active && c.coverage_type == CoverageType::DeathBenefit)
+            .ok_or_else(|| PolicyError::coverage_not_found("DeathBenefit"))?;
+        for (index, coverage) in self.coverages.iter_mut().enumerate() {
+            if index == primary {
+                coverage.sum_assured = sum_assured;
+                coverage.expiry_date = expiry_date;
+            } else {
+                coverage.is_active = false;
             }
-            EndorsementType::NameChange { .. } => {
-                // Handle through party service
-            }
-            EndorsementType::AddressChange { .. } => {
-                // Handle through party service
-            }
-            // Other endorsement types are handled by their respective domain services
-            _ => {}
+        }
+        for rider in self.riders.iter_mut().filter(|r| r.is_active()) {
+            rider.terminated_on = Some(effective_date);
         }
 
         let now = Utc::now();
-        self.endorsements.push(endorsement.clone());
+        self.premium = Premium::new(Money::zero(self.currency), self.premium.frequency);
+        self.financial_state.premium_outstanding = Money::zero(self.currency);
+        self.financial_state.surrender_value = Some(net_cash_value);
+        self.state = PolicyState::PaidUp {
+            option,
+            effective_date,
+            expiry_date,
+        };
         self.updated_at = now;
 
-        self.events.push(PolicyEvent::EndorsementApplied {
+        self.events.push(PolicyEvent::NonforfeitureApplied {
             policy_id: self.id,
-            endorsement_id: endorsement.id,
-            endorsement_type: format!("{:?}", endorsement.endorsement_type),
-            effective_date: endorsement.effective_date,
+            option: format!("{:?}", option),
+            sum_assured: sum_assured.amount(),
+            currency: self.currency.to_string(),
+            expiry_date,
             timestamp: now,
         });
 
         Ok(())
     }
 
-    /// Records a premium payment
+    /// Expires the policy at the end of its cover
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in force or paid-up
+    pub fn expire(&mut self, expiry_date: NaiveDate) -> Result<(), PolicyError> {
+        match &self.state {
+            PolicyState::InForce { .. } | PolicyState::Reinstated { .. } | PolicyState::PaidUp { .. } => {
+                let now = Utc::now();
+                self.state = PolicyState::Expired { expiry_date };
+                self.updated_at = now;
+
+                self.events.push(PolicyEvent::PolicyExpired {
+                    policy_id: self.id,
+                    expiry_date,
+                    timestamp: now,
+                });
+
+                Ok(())
+            }
+            _ => Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "Expired".to_string(),
+            }),
+        }
+    }
+
+    /// Renews the policy for a further term
+    ///
+    /// The new term starts on the current expiry date. The premium is
+    /// replaced by the re-rated renewal premium.
     ///
     /// # Arguments
     ///
-    /// * `amount` - The payment amount
+    /// * `term_years` - Length of the renewal term
+    /// * `premium` - Premium for the renewal term
     ///
     /// # Errors
     ///
-    /// Returns error if currency mismatch or policy not active
-    pub fn record_payment(&mut self, amount: Money) -> Result<(), PolicyError> {
-        if amount.currency() != self.currency {
+    /// Returns error if the policy is not in force or reinstated, has no
+    /// expiry date, or the premium currency differs from the policy
+    pub fn renew(&mut self, term_years: u32, premium: Premium) -> Result<NaiveDate, PolicyError> {
+        let issue_date = match &self.state {
+            PolicyState::InForce { issue_date, .. } => *issue_date,
+            PolicyState::Reinstated { .. } => self
+                .inception_date
+                .map(utc_start_of_day)
+                .unwrap_or(self.created_at),
+            _ => {
+                return Err(PolicyError::InvalidStateTransition {
+                    from: format!("{:?}", self.state),
+                    to: "InForce".to_string(),
+                })
+            }
+        };
+        let new_effective_date = self
+            .expiry_date
+            .ok_or_else(|| PolicyError::validation("Policy has no expiry date to renew from"))?;
+        let new_expiry_date = new_effective_date
+            .checked_add_months(Months::new(12 * term_years))
+            .ok_or_else(|| PolicyError::validation("Renewal term out of range"))?;
+        self.check_currency(&premium.base_amount)?;
+
+        let now = Utc::now();
+        self.state = PolicyState::InForce {
+            effective_date: new_effective_date,
+            renewal_date: new_expiry_date,
+            issue_date,
+        };
+        self.expiry_date = Some(new_expiry_date);
+        self.premium = premium;
+        self.financial_state.next_due_date = Some(new_effective_date);
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::PolicyRenewed {
+            policy_id: self.id,
+            new_effective_date,
+            new_expiry_date,
+            timestamp: now,
+        });
+
+        Ok(new_expiry_date)
+    }
+
+    /// Terminates the policy on conversion to another policy
+    ///
+    /// # Arguments
+    ///
+    /// * `new_policy_id` - The policy issued on conversion
+    /// * `conversion_date` - Date the conversion takes effect
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in force or reinstated
+    pub fn convert(&mut self, new_policy_id: PolicyId, conversion_date: NaiveDate) -> Result<(), PolicyError> {
+        match &self.state {
+            PolicyState::InForce { .. } | PolicyState::Reinstated { .. } => {
+                let now = Utc::now();
+
+                self.state = PolicyState::Terminated {
+                    reason: TerminationReason::Conversion,
+                    effective_date: utc_start_of_day(conversion_date),
+                };
+                self.converted_to = Some(new_policy_id);
+                self.updated_at = now;
+
+                self.events.push(PolicyEvent::PolicyTerminated {
+                    policy_id: self.id,
+                    reason: format!("{:?}", TerminationReason::Conversion),
+                    timestamp: now,
+                });
+                self.events.push(PolicyEvent::PolicyConverted {
+                    policy_id: self.id,
+                    new_policy_id,
+                    conversion_date,
+                    timestamp: now,
+                });
+
+                Ok(())
+            }
+            _ => Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "Terminated".to_string(),
+            }),
+        }
+    }
+
+    /// Cancels the policy
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - The reason for cancellation
+    /// * `cancellation_date` - Effective date of cancellation
+    /// * `refund` - Premium refunded to the policyholder, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not quoted, pending underwriting, in force
+    /// or reinstated
+    pub fn cancel(
+        &mut self,
+        reason: &str,
+        cancellation_date: DateTime<Utc>,
+        refund: Option<Money>,
+    ) -> Result<(), PolicyError> {
+        match &self.state {
+            PolicyState::Quoted { .. }
+            | PolicyState::PendingUnderwriting { .. }
+            | PolicyState::InForce { .. }
+            | PolicyState::Reinstated { .. } => {
+                let now = Utc::now();
+                let refund = refund.filter(|amount| amount.is_positive());
+
+                self.state = PolicyState::Cancelled {
+                    reason: reason.to_string(),
+                    cancellation_date,
+                    premium_refunded: refund.is_some(),
+                };
+
+                self.updated_at = now;
+
+                self.events.push(PolicyEvent::PolicyCancelled {
+                    policy_id: self.id,
+                    reason: reason.to_string(),
+                    refund_amount: refund.map(|amount| amount.amount()),
+                    timestamp: now,
+                });
+
+                Ok(())
+            }
+            _ => Err(PolicyError::InvalidStateTransition {
+                from: format!("{:?}", self.state),
+                to: "Cancelled".to_string(),
+            }),
+        }
+    }
+
+    /// Applies an endorsement to modify the policy
+    ///
+    /// Coverage changes that would leave a rider above its limit on the
+    /// base sum assured are rejected. Riders are added through
+    /// `RiderEngine::endorse`, which checks the catalog limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `endorsement` - The endorsement to apply
+    ///
+    /// # Errors
+    ///
+    /// Returns error if policy is not in a modifiable state, the
+    /// endorsement adds a rider, or a rider limit would be breached
+    pub fn apply_endorsement(&mut self, endorsement: Endorsement) -> Result<(), PolicyError> {
+        if matches!(endorsement.endorsement_type, EndorsementType::RiderAddition { .. }) {
+            return Err(PolicyError::Rider(
+                "Riders must be added through RiderEngine".to_string(),
+            ));
+        }
+        self.apply_validated_endorsement(endorsement)
+    }
+
+    /// Applies an endorsement already validated by the engine that owns it
+    pub(crate) fn apply_validated_endorsement(&mut self, endorsement: Endorsement) -> Result<(), PolicyError> {
+        if !self.is_modifiable() {
+            return Err(PolicyError::NotModifiable);
+        }
+
+        // Apply the endorsement effects
+        match &endorsement.endorsement_type {
+            EndorsementType::CoverageChange { add, remove, modify } => {
+                let mut coverages = self.coverages.clone();
+
+                // Remove coverages
+                for coverage_id in remove {
+                    coverages.retain(|c| c.id != *coverage_id);
+                }
+
+                // Add new coverages
+                coverages.extend(add.clone());
+
+                // Modify existing coverages
+                for modification in modify {
+                    if let Some(coverage) = coverages.iter_mut().find(|c| c.id == modification.coverage_id) {
+                        coverage.apply_modification(modification)?;
+                    }
+                }
+
+                self.check_rider_limits(&coverages)?;
+                self.coverages = coverages;
+            }
+            EndorsementType::BeneficiaryChange { beneficiaries } => {
+                // Handle beneficiary changes
+            }
+            EndorsementType::PremiumChange { new_premium } => {
+                self.premium = new_premium.clone();
+            }
+            EndorsementType::PolicyLoan { .. } => {
+                // Loan limits, the product minimum and the ledger posting
+                // live in the loan engine
+                return Err(PolicyError::PolicyLoan(
+                    "Policy loans must be advanced through LoanEngine".to_string(),
+                ));
+            }
+            EndorsementType::RiderAddition { rider, coverage, premium } => {
+                self.attach_rider((**rider).clone(), coverage.clone(), premium.clone())?;
+            }
+            EndorsementType::RiderRemoval { rider_code } => {
+                self.terminate_rider(rider_code, endorsement.effective_date, "Removed by endorsement")?;
+            }
+            EndorsementType::NameChange { .. } => {
+                // Handle through party service
+            }
+            EndorsementType::AddressChange { .. } => {
+                // Handle through party service
+            }
+            // Other endorsement types are handled by their respective domain services
+            _ => {}
+        }
+
+        let now = Utc::now();
+        self.endorsements.push(endorsement.clone());
+        self.clear_quote_illustration();
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::EndorsementApplied {
+            policy_id: self.id,
+            endorsement_id: endorsement.id,
+            endorsement_type: format!("{:?}", endorsement.endorsement_type),
+            effective_date: endorsement.effective_date,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Attaches a benefit illustration to the quote
+    ///
+    /// Replaces any earlier illustration. Endorsing the quote or changing
+    /// its riders removes the illustration, so the quote only ever carries
+    /// one prepared for its current terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `illustration` - Illustration prepared for this quote
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in Quoted state or the
+    /// illustration was prepared for another policy
+    pub fn attach_illustration(&mut self, illustration: Illustration) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::Quoted { .. }) {
+            return Err(PolicyError::NotModifiable);
+        }
+        if illustration.policy_id != self.id {
+            return Err(PolicyError::Illustration(format!(
+                "Illustration was prepared for another policy than {}",
+                self.policy_number
+            )));
+        }
+
+        let now = Utc::now();
+        self.events.push(PolicyEvent::IllustrationAttached {
+            policy_id: self.id,
+            illustration_id: illustration.id,
+            timestamp: now,
+        });
+        self.illustration = Some(illustration);
+        self.updated_at = now;
+
+        Ok(())
+    }
+
+    /// Drops the illustration once a quote's terms change
+    ///
+    /// The illustration was prepared for the earlier terms and must be
+    /// prepared again before the quote is presented.
+    fn clear_quote_illustration(&mut self) {
+        if matches!(self.state, PolicyState::Quoted { .. }) {
+            self.illustration = None;
+        }
+    }
+
+    /// Attaches a rider to a base coverage
+    ///
+    /// The rider's coverage is added to the policy coverages and its
+    /// premium to the rider premiums. Catalog limits are checked by
+    /// `RiderEngine` before this is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `rider` - The rider attachment
+    /// * `coverage` - Coverage provided by the rider
+    /// * `premium` - Premium for the rider, per payment
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy cannot be modified, the base coverage
+    /// is not active, or the rider is already attached
+    pub fn attach_rider(&mut self, rider: Rider, coverage: Coverage, premium: RiderPremium) -> Result<(), PolicyError> {
+        if !self.is_modifiable() {
+            return Err(PolicyError::NotModifiable);
+        }
+        if !self.coverages.iter().any(|c| c.id == rider.base_coverage_id && c.is_active) {
+            return Err(PolicyError::coverage_not_found(rider.base_coverage_id));
+        }
+        if self.riders.iter().any(|r| r.code == rider.code && r.is_active()) {
+            return Err(PolicyError::Rider(format!("Rider {} is already attached", rider.code)));
+        }
+        self.check_currency(&coverage.sum_assured)?;
+        self.check_currency(&premium.amount)?;
+
+        let now = Utc::now();
+        self.events.push(PolicyEvent::RiderAttached {
+            policy_id: self.id,
+            rider_code: rider.code.clone(),
+            coverage_id: coverage.id,
+            base_coverage_id: rider.base_coverage_id,
+            sum_assured: coverage.sum_assured.amount(),
+            currency: self.currency.to_string(),
+            timestamp: now,
+        });
+
+        self.coverages.push(coverage);
+        self.premium.add_rider_premium(premium);
+        self.riders.push(rider);
+        self.clear_quote_illustration();
+        self.updated_at = now;
+
+        Ok(())
+    }
+
+    /// Checks the active riders against their limits on the base coverage
+    fn check_rider_limits(&self, coverages: &[Coverage]) -> Result<(), PolicyError> {
+        for rider in self.riders.iter().filter(|r| r.is_active()) {
+            let Some(base) = coverages.iter().find(|c| c.id == rider.base_coverage_id) else {
+                return Err(PolicyError::Rider(format!(
+                    "Rider {} must be removed before its base coverage",
+                    rider.code
+                )));
+            };
+            let Some(coverage) = coverages.iter().find(|c| c.id == rider.coverage_id) else {
+                continue;
+            };
+            if let Some(max) = rider.max_sum_assured(base.sum_assured) {
+                if coverage.sum_assured.amount() > max.amount() {
+                    return Err(PolicyError::Rider(format!(
+                        "Rider {} sum assured {} exceeds limit of {}",
+                        rider.code, coverage.sum_assured, max
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends a rider
+    ///
+    /// The rider's coverage is deactivated and its premium stops.
+    ///
+    /// # Arguments
+    ///
+    /// * `rider_code` - Code of the rider to end
+    /// * `effective_date` - Date the rider ends
+    /// * `reason` - Why the rider ended
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no active rider has the code
+    pub fn terminate_rider(&mut self, rider_code: &str, effective_date: NaiveDate, reason: &str) -> Result<(), PolicyError> {
+        let rider = self
+            .riders
+            .iter_mut()
+            .find(|r| r.code == rider_code && r.is_active())
+            .ok_or_else(|| PolicyError::Rider(format!("No active rider {}", rider_code)))?;
+        rider.terminated_on = Some(effective_date);
+        let coverage_id = rider.coverage_id;
+
+        if let Some(coverage) = self.coverages.iter_mut().find(|c| c.id == coverage_id) {
+            coverage.is_active = false;
+            coverage.expiry_date = Some(effective_date);
+        }
+        self.premium.rider_premiums.retain(|p| p.rider_code != rider_code);
+        self.clear_quote_illustration();
+
+        let now = Utc::now();
+        self.updated_at = now;
+        self.events.push(PolicyEvent::RiderTerminated {
+            policy_id: self.id,
+            rider_code: rider_code.to_string(),
+            effective_date,
+            reason: reason.to_string(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Starts waiving premiums following a disability claim
+    ///
+    /// # Arguments
+    ///
+    /// * `claim_id` - The approved disability claim
+    /// * `start_date` - First day premiums are waived
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in force, has no active waiver
+    /// of premium cover, or a waiver is already open
+    pub fn start_premium_waiver(&mut self, claim_id: ClaimId, start_date: NaiveDate) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::InForce { .. } | PolicyState::Reinstated { .. }) {
+            return Err(PolicyError::Waiver(format!("Policy is not in force ({:?})", self.state)));
+        }
+        if !self
+            .coverages
+            .iter()
+            .any(|c| c.is_active && c.coverage_type == CoverageType::WaiverOfPremium)
+        {
+            return Err(PolicyError::Waiver("Policy has no waiver of premium cover".to_string()));
+        }
+        if let Some(open) = self.active_waiver() {
+            return Err(PolicyError::Waiver(format!(
+                "Premiums are already waived from {}",
+                open.start_date
+            )));
+        }
+
+        let now = Utc::now();
+        self.waiver_periods.push(WaiverPeriod {
+            claim_id,
+            start_date,
+            end_date: None,
+            end_reason: None,
+            premiums_waived: Money::zero(self.currency),
+        });
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::PremiumWaiverStarted {
+            policy_id: self.id,
+            claim_id,
+            start_date,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Ends the open waiver of premium period
+    ///
+    /// # Arguments
+    ///
+    /// * `end_date` - First day premiums are payable again
+    /// * `reason` - Why the waiver ended
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no waiver is open or `end_date` precedes its start
+    pub fn end_premium_waiver(&mut self, end_date: NaiveDate, reason: WaiverEndReason) -> Result<(), PolicyError> {
+        let waiver = self
+            .waiver_periods
+            .iter_mut()
+            .find(|w| w.is_open())
+            .ok_or_else(|| PolicyError::Waiver("No premium waiver is open".to_string()))?;
+        if end_date < waiver.start_date {
+            return Err(PolicyError::Waiver(format!(
+                "Waiver cannot end on {} before it started on {}",
+                end_date, waiver.start_date
+            )));
+        }
+        waiver.end_date = Some(end_date);
+        waiver.end_reason = Some(reason);
+        let claim_id = waiver.claim_id;
+
+        let now = Utc::now();
+        self.updated_at = now;
+        self.events.push(PolicyEvent::PremiumWaiverEnded {
+            policy_id: self.id,
+            claim_id,
+            end_date,
+            reason: format!("{:?}", reason),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Records an instalment settled by the waiver of premium benefit
+    ///
+    /// The instalment counts as paid.
+    ///
+    /// # Errors
+    ///
+    /// Returns error on currency mismatch or if `due_date` is not in a
+    /// waiver period
+    pub fn record_waived_premium(&mut self, due_date: NaiveDate, amount: Money) -> Result<(), PolicyError> {
+        self.check_currency(&amount)?;
+        let waiver = self
+            .waiver_periods
+            .iter_mut()
+            .find(|w| w.covers(due_date))
+            .ok_or_else(|| PolicyError::Waiver(format!("Premium due {} is not waived", due_date)))?;
+        waiver.premiums_waived = waiver.premiums_waived + amount;
+
+        let now = Utc::now();
+        self.financial_state.record_payment(amount, now)?;
+        self.updated_at = now;
+
+        Ok(())
+    }
+
+    /// Records a premium payment
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The payment amount
+    ///
+    /// # Errors
+    ///
+    /// Returns error if currency mismatch or policy not active
+    pub fn record_payment(&mut self, amount: Money) -> Result<(), PolicyError> {
+        if amount.currency() != self.currency {
             return Err(PolicyError::CurrencyMismatch {
                 expected: self.currency.to_string(),
                 actual: amount.currency().to_string(),
@@ -626,6 +1607,122 @@ impl Policy {
         Ok(())
     }
 
+    /// Records the current cash (surrender) value
+    ///
+    /// # Arguments
+    ///
+    /// * `cash_value` - The cash value
+    ///
+    /// # Errors
+    ///
+    /// Returns error on currency mismatch
+    pub fn record_cash_value(&mut self, cash_value: Money) -> Result<(), PolicyError> {
+        self.check_currency(&cash_value)?;
+        self.financial_state.surrender_value = Some(cash_value);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Records a loan advanced against the policy
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount advanced
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in force, the amount is not
+    /// positive or currencies differ
+    pub fn take_loan(&mut self, amount: Money) -> Result<(), PolicyError> {
+        if !matches!(self.state, PolicyState::InForce { .. } | PolicyState::Reinstated { .. }) {
+            return Err(PolicyError::PolicyLoan(format!(
+                "Policy {} is not in force",
+                self.policy_number
+            )));
+        }
+        self.check_currency(&amount)?;
+        if !amount.is_positive() {
+            return Err(PolicyError::PolicyLoan("Loan amount must be positive".to_string()));
+        }
+
+        let now = Utc::now();
+        self.add_to_loan(amount);
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::PolicyLoanTaken {
+            policy_id: self.id,
+            amount: amount.amount(),
+            currency: self.currency.to_string(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Adds capitalised loan interest to the loan outstanding
+    ///
+    /// # Errors
+    ///
+    /// Returns error on currency mismatch
+    pub fn capitalise_loan_interest(&mut self, interest: Money) -> Result<(), PolicyError> {
+        self.check_currency(&interest)?;
+        self.add_to_loan(interest);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Records a repayment of loan principal
+    ///
+    /// The loan outstanding is cleared once it is repaid in full.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The principal repaid
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the amount exceeds the loan outstanding or
+    /// currencies differ
+    pub fn repay_loan(&mut self, amount: Money) -> Result<(), PolicyError> {
+        self.check_currency(&amount)?;
+        let outstanding = self.financial_state.loan_outstanding.unwrap_or(Money::zero(self.currency));
+        if amount.amount() > outstanding.amount() {
+            return Err(PolicyError::PolicyLoan(format!(
+                "Repayment {} exceeds loan outstanding {}",
+                amount, outstanding
+            )));
+        }
+
+        let now = Utc::now();
+        let remaining = outstanding - amount;
+        self.financial_state.loan_outstanding = (!remaining.is_zero()).then_some(remaining);
+        self.updated_at = now;
+
+        self.events.push(PolicyEvent::PolicyLoanRepaid {
+            policy_id: self.id,
+            amount: amount.amount(),
+            currency: self.currency.to_string(),
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    fn add_to_loan(&mut self, amount: Money) {
+        let outstanding = self.financial_state.loan_outstanding.unwrap_or(Money::zero(self.currency));
+        self.financial_state.loan_outstanding = Some(outstanding + amount);
+    }
+
+    fn check_currency(&self, amount: &Money) -> Result<(), PolicyError> {
+        if amount.currency() != self.currency {
+            return Err(PolicyError::CurrencyMismatch {
+                expected: self.currency.to_string(),
+                actual: amount.currency().to_string(),
+            });
+        }
+        Ok(())
+    }
+
     /// Calculates the next renewal date based on effective date
     fn calculate_renewal_date(&self, effective_date: NaiveDate) -> NaiveDate {
         match self.premium.frequency {
@@ -656,12 +1753,17 @@ impl Policy {
 /// ```
 pub struct PolicyBuilder {
     product_code: Option<String>,
+    product_version: Option<String>,
     policyholder_id: Option<PartyId>,
     currency: Currency,
     coverages: Vec<Coverage>,
     insured_risks: Vec<RiskObject>,
     premium: Option<Premium>,
     term_years: Option<u32>,
+    renewable: bool,
+    risk_class: Option<RiskClass>,
+    insured: Option<InsuredLife>,
+    converted_from: Option<PolicyId>,
     quote_validity_days: u32,
 }
 
@@ -670,12 +1772,17 @@ impl PolicyBuilder {
     pub fn new() -> Self {
         Self {
             product_code: None,
+            product_version: None,
             policyholder_id: None,
             currency: Currency::USD,
             coverages: Vec::new(),
             insured_risks: Vec::new(),
             premium: None,
             term_years: None,
+            renewable: false,
+            risk_class: None,
+            insured: None,
+            converted_from: None,
             quote_validity_days: 30,
         }
     }
@@ -728,6 +1835,36 @@ impl PolicyBuilder {
         self
     }
 
+    /// Sets the underwriting risk class
+    pub fn risk_class(mut self, risk_class: RiskClass) -> Self {
+        self.risk_class = Some(risk_class);
+        self
+    }
+
+    /// Records the underwriting details of the life insured
+    pub fn insured(mut self, insured: InsuredLife) -> Self {
+        self.insured = Some(insured);
+        self
+    }
+
+    /// Marks the term as renewable at expiry
+    pub fn renewable(mut self, renewable: bool) -> Self {
+        self.renewable = renewable;
+        self
+    }
+
+    /// Pins the policy to a catalog version of its product
+    pub fn product_version(mut self, version: impl Into<String>) -> Self {
+        self.product_version = Some(version.into());
+        self
+    }
+
+    /// Links the policy to the policy it was converted from
+    pub fn converted_from(mut self, policy_id: PolicyId) -> Self {
+        self.converted_from = Some(policy_id);
+        self
+    }
+
     /// Sets quote validity period
     pub fn quote_validity_days(mut self, days: u32) -> Self {
         self.quote_validity_days = days;
@@ -761,6 +1898,7 @@ impl PolicyBuilder {
             id: policy_id,
             policy_number,
             product_code,
+            product_version: self.product_version,
             state: PolicyState::Quoted {
                 quote_date: now,
                 quote_expiry,
@@ -773,6 +1911,15 @@ impl PolicyBuilder {
             currency: self.currency,
             term_years: self.term_years,
             expiry_date: None,
+            renewable: self.renewable,
+            inception_date: None,
+            risk_class: self.risk_class,
+            insured: self.insured,
+            converted_from: self.converted_from,
+            converted_to: None,
+            riders: Vec::new(),
+            waiver_periods: Vec::new(),
+            illustration: None,
             endorsements: Vec::new(),
             events: vec![PolicyEvent::PolicyQuoted {
                 policy_id,
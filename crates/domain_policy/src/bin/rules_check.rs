@@ -0,0 +1,63 @@
+//! Golden-case runner for product rules
+//!
+//! Runs the golden cases stored next to product rules files and reports
+//! every field that differs from the expected outputs. Exits with status 1
+//! if any case fails, so it can gate rule changes before deployment.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Run every *.cases.json file in the products directory
+//! cargo run -p domain_policy --bin rules-check
+//!
+//! # Run a directory or specific case files
+//! cargo run -p domain_policy --bin rules-check -- products/term_life.cases.json
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use domain_policy::golden::{GoldenRunner, SuiteReport};
+use domain_policy::rules_engine::RulesError;
+
+fn main() -> ExitCode {
+    let mut targets: Vec<PathBuf> = std::env::args().skip(1).map(PathBuf::from).collect();
+    if targets.is_empty() {
+        targets.push(PathBuf::from("products"));
+    }
+
+    let runner = GoldenRunner::new();
+    let mut all_passed = true;
+    for target in &targets {
+        match run(&runner, target) {
+            Ok(reports) => {
+                if reports.is_empty() {
+                    println!("{}: no golden cases found", target.display());
+                }
+                for report in reports {
+                    print!("{}", report);
+                    all_passed &= report.passed();
+                }
+            }
+            Err(e) => {
+                eprintln!("{}: {}", target.display(), e);
+                all_passed = false;
+            }
+        }
+    }
+
+    if all_passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs a directory of case files or a single case file
+fn run(runner: &GoldenRunner, target: &Path) -> Result<Vec<SuiteReport>, RulesError> {
+    if target.is_dir() {
+        runner.run_dir(target)
+    } else {
+        runner.run_file(target).map(|report| vec![report])
+    }
+}
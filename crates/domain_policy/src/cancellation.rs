@@ -0,0 +1,482 @@
+//! Policy cancellation and premium refunds
+//!
+//! This module calculates the premium returned when a policy is cancelled,
+//! cancels the policy, raises the refund payment and posts the ledger
+//! entries reversing the unearned premium and the commission paid on it.
+//!
+//! # Refund Basis
+//!
+//! - **Free look**: cancelled within the product's free-look window; all
+//!   premium paid is returned, less medical examination costs and, for
+//!   unit-linked products, plus or minus the movement in unit prices
+//! - **Pro rata**: the unearned part of the premium paid is returned
+//! - **Short rate**: as pro rata, less a penalty on the unearned premium
+//!
+//! Rules are configured per product in the `refund_rules` block of
+//! `catalog.json`:
+//!
+//! ```json
+//! "refund_rules": {
+//!   "free_look_days": 15,
+//!   "deduct_medical_costs": true,
+//!   "nav_adjustment": false,
+//!   "mid_term_method": "short_rate",
+//!   "short_rate_penalty": 0.10
+//! }
+//! ```
+
+use chrono::{Duration, Months, NaiveDate};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{AccountId, JournalEntryId, Money, PolicyId, utc_start_of_day};
+use domain_billing::transaction::Transaction;
+use domain_billing::{Ledger, Payment, PaymentMethod};
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::commission::{CommissionEngine, CommissionEntry};
+use crate::error::PolicyError;
+use crate::premium::PremiumFrequency;
+
+/// How premium is refunded on a mid-term cancellation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundMethod {
+    /// Unearned premium in proportion to the unexpired period
+    ProRata,
+    /// Pro-rata refund less a penalty
+    ShortRate,
+    /// No premium refund after the free-look period
+    None,
+}
+
+/// Product-level refund rules
+///
+/// Loaded from the `refund_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RefundRules {
+    /// Days after inception during which the policyholder may cancel for a full refund
+    pub free_look_days: u32,
+    /// Whether medical examination costs are deducted from a free-look refund
+    pub deduct_medical_costs: bool,
+    /// Whether unit price movement is passed on in a free-look refund
+    pub nav_adjustment: bool,
+    /// Refund method after the free-look period
+    pub mid_term_method: RefundMethod,
+    /// Fraction of the unearned premium retained on a short-rate refund
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub short_rate_penalty: Decimal,
+}
+
+impl Default for RefundRules {
+    fn default() -> Self {
+        Self {
+            free_look_days: 15,
+            deduct_medical_costs: true,
+            nav_adjustment: false,
+            mid_term_method: RefundMethod::ProRata,
+            short_rate_penalty: Decimal::ZERO,
+        }
+    }
+}
+
+impl RefundRules {
+    /// Reads refund rules from a catalog product entry
+    ///
+    /// Products without a `refund_rules` block use the defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - A product object from `catalog.json`
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the `refund_rules` block is malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Self, PolicyError> {
+        match product.get("refund_rules") {
+            Some(rules) => serde_json::from_value(rules.clone())
+                .map_err(|e| PolicyError::validation(format!("Invalid refund_rules: {}", e))),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+/// Basis on which a refund was calculated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RefundBasis {
+    /// Cancelled within the free-look period
+    FreeLook,
+    /// Pro-rata refund of unearned premium
+    ProRata,
+    /// Short-rate refund of unearned premium
+    ShortRate,
+    /// No premium is refundable
+    NoRefund,
+}
+
+/// A request to cancel a policy
+#[derive(Debug, Clone)]
+pub struct CancellationRequest {
+    /// Effective date of cancellation
+    pub cancellation_date: NaiveDate,
+    /// Reason for cancellation
+    pub reason: String,
+    /// Medical examination costs incurred on the application
+    pub medical_costs: Option<Money>,
+    /// Change in value of allocated units since allocation (unit-linked products)
+    pub nav_movement: Option<Money>,
+}
+
+impl CancellationRequest {
+    /// Creates a cancellation request
+    ///
+    /// # Arguments
+    ///
+    /// * `cancellation_date` - Effective date of cancellation
+    /// * `reason` - Reason for cancellation
+    pub fn new(cancellation_date: NaiveDate, reason: impl Into<String>) -> Self {
+        Self {
+            cancellation_date,
+            reason: reason.into(),
+            medical_costs: None,
+            nav_movement: None,
+        }
+    }
+
+    /// Sets the medical examination costs
+    pub fn with_medical_costs(mut self, costs: Money) -> Self {
+        self.medical_costs = Some(costs);
+        self
+    }
+
+    /// Sets the unit price movement (negative when unit prices fell)
+    pub fn with_nav_movement(mut self, movement: Money) -> Self {
+        self.nav_movement = Some(movement);
+        self
+    }
+}
+
+/// Breakdown of the premium refunded on cancellation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RefundQuote {
+    /// Policy being cancelled
+    pub policy_id: PolicyId,
+    /// Basis of the refund
+    pub basis: RefundBasis,
+    /// Effective date of cancellation
+    pub cancellation_date: NaiveDate,
+    /// Total premium paid to date
+    pub premium_paid: Money,
+    /// Premium not yet earned at the cancellation date
+    pub unearned_premium: Money,
+    /// Short-rate penalty retained
+    pub short_rate_charge: Money,
+    /// Medical costs deducted
+    pub medical_costs: Money,
+    /// Unit price movement passed on to the policyholder
+    pub nav_adjustment: Money,
+    /// Amount refunded to the policyholder
+    pub refund_amount: Money,
+}
+
+impl RefundQuote {
+    /// Returns the premium income reversed by the refund
+    pub fn premium_reversal(&self) -> Money {
+        self.unearned_premium - self.short_rate_charge - self.medical_costs
+    }
+}
+
+/// Ledger accounts used for cancellation refunds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CancellationAccounts {
+    /// Premium income account reversed by the refund
+    pub premium_income: AccountId,
+    /// Liability account holding refunds due to policyholders
+    pub refunds_payable: AccountId,
+    /// Investment gains and losses account absorbing unit price movement
+    pub investment_gains: AccountId,
+}
+
+/// Result of cancelling a policy
+#[derive(Debug, Clone)]
+pub struct CancellationOutcome {
+    /// Refund calculation
+    pub quote: RefundQuote,
+    /// Refund disbursement due to the policyholder, paid out of refunds payable
+    pub refund_payment: Option<Payment>,
+    /// Journal entry reversing the refunded premium
+    pub journal_entry_id: Option<JournalEntryId>,
+    /// Commission reversed on cancellation
+    pub commission_reversals: Vec<CommissionEntry>,
+}
+
+/// Engine calculating and processing cancellation refunds
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = CancellationEngine::from_catalog(&catalog, accounts)?;
+///
+/// let request = CancellationRequest::new(today, "Cancelled in free look")
+///     .with_medical_costs(medical_costs);
+/// let quote = engine.quote(&policy, &request)?;
+///
+/// let outcome = engine.cancel(&mut ledger, &mut policy, &request, &mut commissions)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CancellationEngine {
+    product_rules: VersionedRules<RefundRules>,
+    default_rules: RefundRules,
+    accounts: CancellationAccounts,
+}
+
+impl CancellationEngine {
+    /// Creates an engine using the default refund rules for every product
+    pub fn new(accounts: CancellationAccounts) -> Self {
+        Self {
+            product_rules: VersionedRules::default(),
+            default_rules: RefundRules::default(),
+            accounts,
+        }
+    }
+
+    /// Creates an engine with per-product rules read from the catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The parsed `catalog.json` document
+    /// * `accounts` - Ledger accounts for refund postings
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `refund_rules`
+    pub fn from_catalog(catalog: &Value, accounts: CancellationAccounts) -> Result<Self, PolicyError> {
+        let mut engine = Self::new(accounts);
+        engine.product_rules = VersionedRules::from_catalog(catalog, |product| RefundRules::from_catalog_product(product).map(Some))?;
+        Ok(engine)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: RefundRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Returns the rules that apply to a product's latest version
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> &RefundRules {
+        self.product_rules.latest(product_code).unwrap_or(&self.default_rules)
+    }
+
+    /// Returns the rules that apply to the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> &RefundRules {
+        self.product_rules.for_policy(policy).unwrap_or(&self.default_rules)
+    }
+
+    /// Calculates the refund due if a policy is cancelled
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The in-force or reinstated policy
+    /// * `request` - The cancellation request
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy has not been issued or is no longer in
+    /// force, or the request is in a different currency
+    pub fn quote(&self, policy: &Policy, request: &CancellationRequest) -> Result<RefundQuote, PolicyError> {
+        if !matches!(policy.state(), PolicyState::InForce { .. } | PolicyState::Reinstated { .. }) {
+            return Err(PolicyError::Cancellation(format!(
+                "Policy {} is not in force ({:?})",
+                policy.policy_number(),
+                policy.state()
+            )));
+        }
+        let inception_date = policy.inception_date().ok_or_else(|| {
+            PolicyError::Cancellation(format!("Policy {} has no inception date", policy.policy_number()))
+        })?;
+        for amount in [request.medical_costs, request.nav_movement].into_iter().flatten() {
+            if amount.currency() != policy.currency() {
+                return Err(PolicyError::CurrencyMismatch {
+                    expected: policy.currency().to_string(),
+                    actual: amount.currency().to_string(),
+                });
+            }
+        }
+
+        let rules = self.rules_for_policy(policy);
+        let zero = Money::zero(policy.currency());
+        let premium_paid = policy.financial_state().total_premium_paid;
+        let free_look_end = inception_date + Duration::days(rules.free_look_days as i64);
+
+        let mut quote = RefundQuote {
+            policy_id: policy.id(),
+            basis: RefundBasis::NoRefund,
+            cancellation_date: request.cancellation_date,
+            premium_paid,
+            unearned_premium: zero,
+            short_rate_charge: zero,
+            medical_costs: zero,
+            nav_adjustment: zero,
+            refund_amount: zero,
+        };
+
+        if request.cancellation_date <= free_look_end {
+            quote.basis = RefundBasis::FreeLook;
+            quote.unearned_premium = premium_paid;
+            if rules.deduct_medical_costs {
+                let costs = request.medical_costs.unwrap_or(zero);
+                quote.medical_costs = if costs.amount() > premium_paid.amount() { premium_paid } else { costs };
+            }
+            if rules.nav_adjustment {
+                let movement = request.nav_movement.unwrap_or(zero);
+                let floor = -quote.premium_reversal();
+                // A fall in unit prices cannot take the refund below zero
+                quote.nav_adjustment = if movement.amount() < floor.amount() { floor } else { movement };
+            }
+        } else {
+            let unearned = unearned_premium(policy, inception_date, request.cancellation_date);
+            match rules.mid_term_method {
+                _ if !unearned.is_positive() => {}
+                RefundMethod::None => {}
+                RefundMethod::ProRata => {
+                    quote.basis = RefundBasis::ProRata;
+                    quote.unearned_premium = unearned;
+                }
+                RefundMethod::ShortRate => {
+                    quote.basis = RefundBasis::ShortRate;
+                    quote.unearned_premium = unearned;
+                    quote.short_rate_charge = unearned.multiply(rules.short_rate_penalty).round_to_currency();
+                }
+            }
+        }
+
+        quote.refund_amount = quote.premium_reversal() + quote.nav_adjustment;
+        Ok(quote)
+    }
+
+    /// Cancels a policy and processes the refund
+    ///
+    /// This method:
+    /// 1. Calculates the refund
+    /// 2. Posts the premium reversal to the refunds payable account
+    /// 3. Cancels the policy
+    /// 4. Raises a pending refund payment to the policyholder
+    /// 5. Reverses commission: in full for a free-look cancellation,
+    ///    otherwise by the product's clawback rules
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `policy` - The policy to cancel
+    /// * `request` - The cancellation request
+    /// * `commissions` - Commission engine holding commission paid on the policy
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the refund cannot be quoted or a ledger posting fails
+    pub fn cancel(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        request: &CancellationRequest,
+        commissions: &mut CommissionEngine,
+    ) -> Result<CancellationOutcome, PolicyError> {
+        let quote = self.quote(policy, request)?;
+        let cancellation_time = utc_start_of_day(request.cancellation_date);
+
+        let journal_entry_id = if quote.refund_amount.is_positive() {
+            let mut transaction = Transaction::new("Premium refund on cancellation")
+                .with_reference("policy", *policy.id().as_uuid())
+                .dated(cancellation_time);
+            let premium_reversal = quote.premium_reversal();
+            if premium_reversal.is_positive() {
+                transaction = transaction.debit(self.accounts.premium_income, premium_reversal);
+            }
+            if quote.nav_adjustment.is_positive() {
+                transaction = transaction.debit(self.accounts.investment_gains, quote.nav_adjustment);
+            } else if quote.nav_adjustment.is_negative() {
+                transaction = transaction.credit(self.accounts.investment_gains, -quote.nav_adjustment);
+            }
+            transaction = transaction.credit(self.accounts.refunds_payable, quote.refund_amount);
+            Some(
+                ledger
+                    .post(transaction)
+                    .map_err(|e| PolicyError::Financial(e.to_string()))?,
+            )
+        } else {
+            None
+        };
+
+        policy.cancel(&request.reason, cancellation_time, Some(quote.refund_amount))?;
+
+        let refund_payment = quote.refund_amount.is_positive().then(|| {
+            let mut payment =
+                Payment::disbursement(policy.policyholder_id(), quote.refund_amount, PaymentMethod::BankTransfer)
+                    .with_reference(policy.policy_number());
+            payment.payment_date = cancellation_time;
+            payment.notes = Some(format!("Cancellation refund: {}", request.reason));
+            payment
+        });
+
+        let commission_reversals = if quote.basis == RefundBasis::FreeLook {
+            commissions.reverse_all(ledger, policy.id(), request.cancellation_date)?
+        } else {
+            commissions.clawback(ledger, policy)?
+        };
+
+        Ok(CancellationOutcome {
+            quote,
+            refund_payment,
+            journal_entry_id,
+            commission_reversals,
+        })
+    }
+}
+
+/// Calculates the premium paid for cover beyond the cancellation date
+///
+/// Premium is assumed to have been paid for whole modal periods from
+/// inception. The current period is refunded in proportion to the days
+/// remaining; later prepaid periods are refunded in full. Single premiums
+/// are not refundable mid-term.
+fn unearned_premium(policy: &Policy, inception_date: NaiveDate, cancellation_date: NaiveDate) -> Money {
+    let currency = policy.currency();
+    let premium = policy.premium();
+    let per_payment = premium.total_per_payment();
+    if premium.frequency == PremiumFrequency::Single || !per_payment.is_positive() {
+        return Money::zero(currency);
+    }
+
+    let periods_paid = (policy.financial_state().total_premium_paid.amount() / per_payment.amount())
+        .floor()
+        .to_u32()
+        .unwrap_or(0);
+    let months = 12 / premium.frequency.payments_per_year();
+    let period_start = |n: u32| inception_date.checked_add_months(Months::new(months * n));
+    let Some(paid_to) = period_start(periods_paid) else {
+        return Money::zero(currency);
+    };
+    let cancellation_date = cancellation_date.max(inception_date);
+    if cancellation_date >= paid_to {
+        return Money::zero(currency);
+    }
+
+    let mut current = 0;
+    while period_start(current + 1).is_some_and(|end| end <= cancellation_date) {
+        current += 1;
+    }
+    let (Some(start), Some(end)) = (period_start(current), period_start(current + 1)) else {
+        return Money::zero(currency);
+    };
+    let unexpired = Decimal::from((end - cancellation_date).num_days()) / Decimal::from((end - start).num_days());
+    let future_periods = Decimal::from(periods_paid - current - 1);
+
+    per_payment.multiply(unexpired + future_periods).round_to_currency()
+}
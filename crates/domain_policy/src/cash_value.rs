@@ -0,0 +1,885 @@
+//! Whole life cash value, dividends and paid-up additions
+//!
+//! This module runs the annual anniversary processing for participating
+//! whole life policies: it credits premium to the guaranteed cash value,
+//! grows existing values, declares the policy dividend and applies it
+//! under the policyholder's dividend option.
+//!
+//! # Rates
+//!
+//! The guaranteed cash value rate and the current dividend rate come from
+//! the `cash_value_factors` decision table in `whole_life.json`, keyed by
+//! issue age and payment mode, and are fixed when the account is opened.
+//! The share of each year's premium credited to the cash value, the
+//! interest on dividends left on deposit and the single premium for
+//! paid-up additions are configured in the `cash_value_rules` block of
+//! `catalog.json`:
+//!
+//! ```json
+//! "cash_value_rules": {
+//!   "premium_allocation": [0.0, 0.40, 0.60, 0.75, 0.85],
+//!   "ultimate_allocation": 0.90,
+//!   "accumulation_rate": 0.03,
+//!   "pua_rates": [
+//!     { "from_age": 18, "to_age": 39, "single_premium_per_thousand": 220 }
+//!   ]
+//! }
+//! ```
+//!
+//! # Dividend Options
+//!
+//! - **Cash**: paid to the policyholder
+//! - **Premium reduction**: held as a credit against the next premium
+//! - **Accumulate at interest**: left on deposit with the insurer
+//! - **Paid-up additions**: buys single-premium paid-up cover; requires the
+//!   product to offer the `PUA` rider
+
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{AccountId, JournalEntryId, Money, PolicyId, utc_start_of_day};
+use domain_billing::transaction::Transaction;
+use domain_billing::{Invoice, InvoiceStatus, Ledger, Payment, PaymentMethod};
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::coverage::CoverageType;
+use crate::error::PolicyError;
+use crate::rules_engine::EvaluationResult;
+
+/// Single premium for paid-up additions by attained age band
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PuaRate {
+    /// First attained age in the band
+    pub from_age: u32,
+    /// Last attained age in the band; open-ended if absent
+    pub to_age: Option<u32>,
+    /// Single premium per 1,000 of paid-up cover
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub single_premium_per_thousand: Decimal,
+}
+
+/// Product-level cash value rules
+///
+/// Loaded from the `cash_value_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CashValueRules {
+    /// Fraction of premium credited to the cash value, by policy year
+    #[serde(deserialize_with = "catalog_serde::rates")]
+    pub premium_allocation: Vec<Decimal>,
+    /// Fraction credited once the allocation schedule is exhausted
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub ultimate_allocation: Decimal,
+    /// Annual interest credited on dividends left on deposit
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub accumulation_rate: Decimal,
+    /// Paid-up addition single premiums by attained age
+    #[serde(default)]
+    pub pua_rates: Vec<PuaRate>,
+    /// Whether the product offers the `PUA` rider
+    #[serde(skip)]
+    pub paid_up_additions_available: bool,
+}
+
+impl CashValueRules {
+    /// Reads the cash value rules from a catalog product entry
+    ///
+    /// Returns `None` when the product has no `cash_value_rules` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the block is present but malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        let Some(rules) = product.get("cash_value_rules") else {
+            return Ok(None);
+        };
+        let mut rules: Self = serde_json::from_value(rules.clone())
+            .map_err(|e| PolicyError::validation(format!("Invalid cash_value_rules: {}", e)))?;
+        rules.paid_up_additions_available = product
+            .get("available_riders")
+            .and_then(|r| r.as_array())
+            .is_some_and(|riders| {
+                riders
+                    .iter()
+                    .any(|rider| rider.get("code").and_then(|c| c.as_str()) == Some("PUA"))
+            });
+        rules.validate()?;
+        Ok(Some(rules))
+    }
+
+    /// Checks that every paid-up addition rate is positive
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a single premium is zero or negative
+    pub fn validate(&self) -> Result<(), PolicyError> {
+        for rate in &self.pua_rates {
+            if rate.single_premium_per_thousand <= Decimal::ZERO {
+                return Err(PolicyError::validation(format!(
+                    "Invalid cash_value_rules: pua_rates from age {} must be positive",
+                    rate.from_age
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the fraction of premium credited in a policy year
+    pub fn allocation(&self, policy_year: u32) -> Decimal {
+        policy_year
+            .checked_sub(1)
+            .and_then(|index| self.premium_allocation.get(index as usize))
+            .copied()
+            .unwrap_or(self.ultimate_allocation)
+    }
+
+    /// Returns the single premium per 1,000 of paid-up cover at an age
+    pub fn pua_single_premium(&self, attained_age: u32) -> Option<Decimal> {
+        self.pua_rates
+            .iter()
+            .find(|rate| {
+                attained_age >= rate.from_age && rate.to_age.is_none_or(|to| attained_age <= to)
+            })
+            .map(|rate| rate.single_premium_per_thousand)
+    }
+}
+
+/// Cash value and dividend rates for a policy
+///
+/// Rates are percentages, as output by the `cash_value_factors` decision
+/// table.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CashValueFactors {
+    /// Guaranteed annual cash value growth rate, in percent
+    pub cash_value_rate: Decimal,
+    /// Current annual dividend rate on the cash value, in percent
+    pub dividend_rate: Decimal,
+}
+
+impl CashValueFactors {
+    /// Reads the factors from a whole life rules evaluation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the evaluation did not produce both rates
+    pub fn from_evaluation(result: &EvaluationResult) -> Result<Self, PolicyError> {
+        let rate = |key: &str| {
+            result
+                .additional_decimal(key)
+                .ok_or_else(|| PolicyError::CashValue(format!("Rules evaluation did not produce {}", key)))
+        };
+        Ok(Self {
+            cash_value_rate: rate("cash_value_rate")?,
+            dividend_rate: rate("dividend_rate")?,
+        })
+    }
+}
+
+/// How policy dividends are applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DividendOption {
+    /// Paid to the policyholder
+    Cash,
+    /// Credited against the next premium
+    PremiumReduction,
+    /// Left on deposit to earn interest
+    AccumulateAtInterest,
+    /// Used to buy paid-up additional cover
+    PaidUpAdditions,
+}
+
+/// Values credited at a policy anniversary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnniversaryValues {
+    /// Policy year just completed
+    pub policy_year: u32,
+    /// Anniversary date
+    pub anniversary_date: NaiveDate,
+    /// Premium credited to the cash value for the year
+    pub premium_credited: Money,
+    /// Guaranteed cash value at the anniversary
+    pub guaranteed_cash_value: Money,
+    /// Interest credited on dividend accumulations
+    pub accumulation_interest: Money,
+    /// Dividend declared
+    pub dividend: Money,
+    /// Option the dividend was applied under
+    pub dividend_option: DividendOption,
+    /// Paid-up cover bought with the dividend
+    pub pua_face_purchased: Money,
+    /// Total cash value after the anniversary
+    pub total_cash_value: Money,
+    /// Total death benefit after the anniversary
+    pub total_death_benefit: Money,
+}
+
+/// Cash value account held for a whole life policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashValueAccount {
+    /// Policy the account belongs to
+    pub policy_id: PolicyId,
+    /// Age of the insured at issue
+    pub issue_age: u32,
+    /// Cash value and dividend rates fixed at issue
+    pub factors: CashValueFactors,
+    /// Current dividend option
+    pub dividend_option: DividendOption,
+    /// Policy years completed
+    pub policy_year: u32,
+    /// Premium paid that has been credited to the cash value
+    pub premiums_credited: Money,
+    /// Guaranteed cash value of the base policy
+    pub guaranteed_cash_value: Money,
+    /// Face amount of paid-up additions
+    pub pua_face_amount: Money,
+    /// Cash value of paid-up additions
+    pub pua_cash_value: Money,
+    /// Dividends left on deposit, with interest
+    pub dividend_accumulations: Money,
+    /// Dividends held to reduce the next premium
+    pub premium_credit: Money,
+    /// Values credited at each anniversary
+    pub history: Vec<AnniversaryValues>,
+}
+
+impl CashValueAccount {
+    /// Returns the total cash value including additions and accumulations
+    pub fn total_cash_value(&self) -> Money {
+        self.values().total_cash_value()
+    }
+
+    /// Returns the attained age at the end of a policy year
+    pub fn attained_age(&self, policy_year: u32) -> u32 {
+        self.issue_age + policy_year
+    }
+
+    fn values(&self) -> Values {
+        Values {
+            guaranteed_cash_value: self.guaranteed_cash_value,
+            pua_face_amount: self.pua_face_amount,
+            pua_cash_value: self.pua_cash_value,
+            dividend_accumulations: self.dividend_accumulations,
+        }
+    }
+}
+
+/// One policy year in a guaranteed vs non-guaranteed values table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyValuesRow {
+    /// Policy year
+    pub policy_year: u32,
+    /// Attained age at the end of the year
+    pub attained_age: u32,
+    /// Premium paid to the end of the year
+    pub cumulative_premium: Money,
+    /// Guaranteed cash value
+    pub guaranteed_cash_value: Money,
+    /// Guaranteed death benefit
+    pub guaranteed_death_benefit: Money,
+    /// Dividend at the current dividend scale
+    pub projected_dividend: Money,
+    /// Cash value with dividends at the current scale
+    pub non_guaranteed_cash_value: Money,
+    /// Death benefit with dividends at the current scale
+    pub non_guaranteed_death_benefit: Money,
+}
+
+/// Ledger accounts used for dividend postings
+#[derive(Debug, Clone, Copy)]
+pub struct DividendAccounts {
+    /// Expense account for dividends and interest on accumulations
+    pub dividend_expense: AccountId,
+    /// Liability for dividends payable in cash or against premium
+    pub dividends_payable: AccountId,
+    /// Liability for dividends left on deposit
+    pub dividend_accumulations: AccountId,
+    /// Reserve for paid-up additions
+    pub paid_up_additions: AccountId,
+    /// Premium receivable settled by premium reduction credits
+    pub premium_receivable: AccountId,
+}
+
+/// Result of processing a policy anniversary
+#[derive(Debug, Clone)]
+pub struct AnniversaryOutcome {
+    /// Values credited at the anniversary
+    pub values: AnniversaryValues,
+    /// Dividend disbursed to the policyholder under the cash option, paid out of dividends payable
+    pub dividend_payment: Option<Payment>,
+    /// Journal entry for the dividend and accumulation interest
+    pub journal_entry_id: Option<JournalEntryId>,
+}
+
+/// Engine accumulating cash value and applying dividends
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = CashValueEngine::from_catalog(&catalog, accounts)?;
+///
+/// let evaluation = rules_engine.evaluate(&whole_life_rules, context)?;
+/// let factors = CashValueFactors::from_evaluation(&evaluation)?;
+/// let mut account = engine.open(&policy, 35, factors, DividendOption::PaidUpAdditions)?;
+///
+/// let outcome = engine.process_anniversary(&mut ledger, &mut policy, &mut account, anniversary)?;
+/// let table = engine.values_table(&policy, &account, 20)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct CashValueEngine {
+    product_rules: VersionedRules<CashValueRules>,
+    accounts: DividendAccounts,
+}
+
+impl CashValueEngine {
+    /// Creates an engine with no products accumulating cash value
+    pub fn new(accounts: DividendAccounts) -> Self {
+        Self {
+            product_rules: VersionedRules::default(),
+            accounts,
+        }
+    }
+
+    /// Creates an engine with per-product rules read from the catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The parsed `catalog.json` document
+    /// * `accounts` - Ledger accounts for dividend postings
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `cash_value_rules`
+    pub fn from_catalog(catalog: &Value, accounts: DividendAccounts) -> Result<Self, PolicyError> {
+        let mut engine = Self::new(accounts);
+        engine.product_rules = VersionedRules::from_catalog(catalog, CashValueRules::from_catalog_product)?;
+        Ok(engine)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: CashValueRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Returns the rules of a product's latest version, if it accumulates cash value
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> Option<&CashValueRules> {
+        self.product_rules.latest(product_code)
+    }
+
+    /// Returns the rules of the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> Option<&CashValueRules> {
+        self.product_rules.for_policy(policy)
+    }
+
+    /// Opens a cash value account for an issued policy
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The issued policy
+    /// * `issue_age` - Age of the insured at issue
+    /// * `factors` - Rates from the product's rules evaluation
+    /// * `dividend_option` - Initial dividend option
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product has no cash value, the policy has not
+    /// been issued or the dividend option is not offered
+    pub fn open(
+        &self,
+        policy: &Policy,
+        issue_age: u32,
+        factors: CashValueFactors,
+        dividend_option: DividendOption,
+    ) -> Result<CashValueAccount, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        check_option(rules, dividend_option)?;
+        if policy.inception_date().is_none() {
+            return Err(PolicyError::CashValue(format!(
+                "Policy {} has not been issued",
+                policy.policy_number()
+            )));
+        }
+
+        Ok(new_account(policy, issue_age, factors, dividend_option))
+    }
+
+    /// Projects values for a policy that has not been issued
+    ///
+    /// Used for sales illustrations: values are projected from a new
+    /// account as if the policy were issued today.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The quoted policy
+    /// * `issue_age` - Age of the insured at issue
+    /// * `factors` - Rates from the product's rules evaluation
+    /// * `dividend_option` - Dividend option to illustrate
+    /// * `years` - Number of policy years to project
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product has no cash value, the dividend option
+    /// is not offered or a paid-up addition rate is missing
+    pub fn projected_values(
+        &self,
+        policy: &Policy,
+        issue_age: u32,
+        factors: CashValueFactors,
+        dividend_option: DividendOption,
+        years: u32,
+    ) -> Result<Vec<PolicyValuesRow>, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        check_option(rules, dividend_option)?;
+        self.values_table(policy, &new_account(policy, issue_age, factors, dividend_option), years)
+    }
+
+    /// Changes the option applied to future dividends
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the option is not offered by the product
+    pub fn change_dividend_option(
+        &self,
+        policy: &Policy,
+        account: &mut CashValueAccount,
+        dividend_option: DividendOption,
+    ) -> Result<(), PolicyError> {
+        check_account(policy, account)?;
+        check_option(self.require_rules(policy)?, dividend_option)?;
+        account.dividend_option = dividend_option;
+        Ok(())
+    }
+
+    /// Processes the next policy anniversary
+    ///
+    /// Premium paid since the last anniversary is credited to the
+    /// guaranteed cash value, existing values are grown, and the dividend
+    /// is declared and applied. The policy's cash value is updated to the
+    /// new total.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger receiving the dividend postings
+    /// * `policy` - The in-force policy
+    /// * `account` - The policy's cash value account
+    /// * `anniversary_date` - Processing date, on or after the anniversary
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in force, the anniversary has not
+    /// been reached or a ledger posting fails
+    pub fn process_anniversary(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        account: &mut CashValueAccount,
+        anniversary_date: NaiveDate,
+    ) -> Result<AnniversaryOutcome, PolicyError> {
+        check_account(policy, account)?;
+        let rules = self.require_rules(policy)?;
+        if !matches!(policy.state(), PolicyState::InForce { .. } | PolicyState::Reinstated { .. }) {
+            return Err(PolicyError::CashValue(format!(
+                "Policy {} is not in force",
+                policy.policy_number()
+            )));
+        }
+
+        let policy_year = account.policy_year + 1;
+        let due = anniversary(policy, policy_year)?;
+        if anniversary_date < due {
+            return Err(PolicyError::CashValue(format!(
+                "Anniversary {} of policy {} falls on {}",
+                policy_year,
+                policy.policy_number(),
+                due
+            )));
+        }
+
+        let paid = policy.financial_state().total_premium_paid;
+        let premium = paid - account.premiums_credited;
+        let premium = if premium.is_positive() { premium } else { Money::zero(policy.currency()) };
+
+        let year_end = roll_forward(
+            rules,
+            &account.factors,
+            Some(account.dividend_option),
+            account.attained_age(policy_year),
+            policy_year,
+            account.values(),
+            premium,
+        )?;
+        let dividend = year_end.dividend;
+        let posting_time = utc_start_of_day(anniversary_date);
+
+        let journal_entry_id = if dividend.is_positive() || year_end.accumulation_interest.is_positive() {
+            let credit_account = match account.dividend_option {
+                DividendOption::Cash | DividendOption::PremiumReduction => self.accounts.dividends_payable,
+                DividendOption::AccumulateAtInterest => self.accounts.dividend_accumulations,
+                DividendOption::PaidUpAdditions => self.accounts.paid_up_additions,
+            };
+            let mut transaction = Transaction::new(format!("Policy dividend for policy year {}", policy_year))
+                .with_reference("policy", *policy.id().as_uuid())
+                .dated(posting_time)
+                .debit(self.accounts.dividend_expense, dividend + year_end.accumulation_interest);
+            if dividend.is_positive() {
+                transaction = transaction.credit(credit_account, dividend);
+            }
+            if year_end.accumulation_interest.is_positive() {
+                transaction = transaction.credit(self.accounts.dividend_accumulations, year_end.accumulation_interest);
+            }
+            Some(
+                ledger
+                    .post(transaction)
+                    .map_err(|e| PolicyError::Financial(e.to_string()))?,
+            )
+        } else {
+            None
+        };
+
+        let dividend_payment = (account.dividend_option == DividendOption::Cash && dividend.is_positive())
+            .then(|| {
+                let mut payment =
+                    Payment::disbursement(policy.policyholder_id(), dividend, PaymentMethod::BankTransfer)
+                        .with_reference(policy.policy_number());
+                payment.payment_date = posting_time;
+                payment.notes = Some(format!("Policy dividend for policy year {}", policy_year));
+                payment
+            });
+        if account.dividend_option == DividendOption::PremiumReduction {
+            account.premium_credit = account.premium_credit + dividend;
+        }
+
+        account.policy_year = policy_year;
+        account.premiums_credited = paid;
+        account.guaranteed_cash_value = year_end.values.guaranteed_cash_value;
+        account.pua_face_amount = year_end.values.pua_face_amount;
+        account.pua_cash_value = year_end.values.pua_cash_value;
+        account.dividend_accumulations = year_end.values.dividend_accumulations;
+        policy.record_cash_value(account.total_cash_value())?;
+
+        let values = AnniversaryValues {
+            policy_year,
+            anniversary_date,
+            premium_credited: premium,
+            guaranteed_cash_value: account.guaranteed_cash_value,
+            accumulation_interest: year_end.accumulation_interest,
+            dividend,
+            dividend_option: account.dividend_option,
+            pua_face_purchased: year_end.pua_face_purchased,
+            total_cash_value: account.total_cash_value(),
+            total_death_benefit: base_death_benefit(policy) + year_end.values.death_benefit_additions(),
+        };
+        account.history.push(values.clone());
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            policy_year,
+            dividend = %dividend,
+            cash_value = %values.total_cash_value,
+            "Policy anniversary processed"
+        );
+
+        Ok(AnniversaryOutcome {
+            values,
+            dividend_payment,
+            journal_entry_id,
+        })
+    }
+
+    /// Applies held premium reduction dividends to a premium invoice
+    ///
+    /// Returns the amount applied, or `None` if there is no credit or the
+    /// invoice is settled. The posting is dated on `as_of`, the date the
+    /// credit is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the invoice belongs to another policy or the
+    /// ledger posting fails
+    pub fn apply_premium_credit(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        account: &mut CashValueAccount,
+        invoice: &mut Invoice,
+        as_of: NaiveDate,
+    ) -> Result<Option<Money>, PolicyError> {
+        check_account(policy, account)?;
+        if invoice.policy_id != policy.id() {
+            return Err(PolicyError::validation(format!(
+                "Invoice {} does not belong to policy {}",
+                invoice.invoice_number,
+                policy.policy_number()
+            )));
+        }
+
+        let balance = invoice.balance_due();
+        if !account.premium_credit.is_positive()
+            || !balance.is_positive()
+            || matches!(invoice.status, InvoiceStatus::Paid | InvoiceStatus::Cancelled)
+        {
+            return Ok(None);
+        }
+
+        let applied = if account.premium_credit.amount() < balance.amount() {
+            account.premium_credit
+        } else {
+            balance
+        };
+        ledger
+            .post(
+                Transaction::new(format!("Dividend applied to invoice {}", invoice.invoice_number))
+                    .with_reference("policy", *policy.id().as_uuid())
+                    .dated(utc_start_of_day(as_of))
+                    .debit(self.accounts.dividends_payable, applied)
+                    .credit(self.accounts.premium_receivable, applied),
+            )
+            .map_err(|e| PolicyError::Financial(e.to_string()))?;
+        invoice.record_payment(applied);
+        policy.record_payment(applied)?;
+        account.premium_credit = account.premium_credit - applied;
+
+        Ok(Some(applied))
+    }
+
+    /// Projects guaranteed and non-guaranteed values by policy year
+    ///
+    /// Projections start from the account's current values and assume the
+    /// current annualised premium is paid each year. Guaranteed values
+    /// assume no further dividends; non-guaranteed values apply dividends
+    /// at the current scale under the current dividend option.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy
+    /// * `account` - The policy's cash value account
+    /// * `years` - Number of policy years to project
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product has no cash value rules or a
+    /// paid-up addition rate is missing for an attained age
+    pub fn values_table(
+        &self,
+        policy: &Policy,
+        account: &CashValueAccount,
+        years: u32,
+    ) -> Result<Vec<PolicyValuesRow>, PolicyError> {
+        check_account(policy, account)?;
+        let rules = self.require_rules(policy)?;
+        let annual_premium = policy.premium().annualized();
+        let base_death_benefit = base_death_benefit(policy);
+
+        let mut guaranteed = account.values();
+        let mut projected = account.values();
+        let mut cumulative_premium = policy.financial_state().total_premium_paid;
+        let mut rows = Vec::with_capacity(years as usize);
+
+        for policy_year in account.policy_year + 1..=account.policy_year + years {
+            let attained_age = account.attained_age(policy_year);
+            guaranteed = roll_forward(
+                rules,
+                &account.factors,
+                None,
+                attained_age,
+                policy_year,
+                guaranteed,
+                annual_premium,
+            )?
+            .values;
+            let year_end = roll_forward(
+                rules,
+                &account.factors,
+                Some(account.dividend_option),
+                attained_age,
+                policy_year,
+                projected,
+                annual_premium,
+            )?;
+            projected = year_end.values;
+            cumulative_premium = cumulative_premium + annual_premium;
+
+            rows.push(PolicyValuesRow {
+                policy_year,
+                attained_age,
+                cumulative_premium,
+                guaranteed_cash_value: guaranteed.total_cash_value(),
+                guaranteed_death_benefit: base_death_benefit + guaranteed.death_benefit_additions(),
+                projected_dividend: year_end.dividend,
+                non_guaranteed_cash_value: projected.total_cash_value(),
+                non_guaranteed_death_benefit: base_death_benefit + projected.death_benefit_additions(),
+            });
+        }
+
+        Ok(rows)
+    }
+
+    fn require_rules(&self, policy: &Policy) -> Result<&CashValueRules, PolicyError> {
+        self.product_rules.for_policy(policy).ok_or_else(|| {
+            PolicyError::CashValue(format!(
+                "Product {} does not accumulate cash value",
+                policy.product_code()
+            ))
+        })
+    }
+}
+
+/// Values carried from one anniversary to the next
+#[derive(Debug, Clone, Copy)]
+struct Values {
+    guaranteed_cash_value: Money,
+    pua_face_amount: Money,
+    pua_cash_value: Money,
+    dividend_accumulations: Money,
+}
+
+impl Values {
+    fn total_cash_value(&self) -> Money {
+        self.guaranteed_cash_value + self.pua_cash_value + self.dividend_accumulations
+    }
+
+    fn death_benefit_additions(&self) -> Money {
+        self.pua_face_amount + self.dividend_accumulations
+    }
+}
+
+struct YearEnd {
+    values: Values,
+    accumulation_interest: Money,
+    dividend: Money,
+    pua_face_purchased: Money,
+}
+
+/// Rolls values forward one policy year
+///
+/// With no dividend option the dividend is not declared and dividends on
+/// deposit earn no interest, giving the guaranteed values.
+fn roll_forward(
+    rules: &CashValueRules,
+    factors: &CashValueFactors,
+    dividend_option: Option<DividendOption>,
+    attained_age: u32,
+    policy_year: u32,
+    start: Values,
+    premium: Money,
+) -> Result<YearEnd, PolicyError> {
+    let currency = premium.currency();
+    let growth = Decimal::ONE + factors.cash_value_rate / Decimal::ONE_HUNDRED;
+
+    let mut values = start;
+    values.guaranteed_cash_value = (start.guaranteed_cash_value + premium.multiply(rules.allocation(policy_year)))
+        .multiply(growth)
+        .round_to_currency();
+    values.pua_cash_value = start.pua_cash_value.multiply(growth).round_to_currency();
+
+    let Some(dividend_option) = dividend_option else {
+        return Ok(YearEnd {
+            values,
+            accumulation_interest: Money::zero(currency),
+            dividend: Money::zero(currency),
+            pua_face_purchased: Money::zero(currency),
+        });
+    };
+
+    let accumulation_interest = start
+        .dividend_accumulations
+        .multiply(rules.accumulation_rate)
+        .round_to_currency();
+    values.dividend_accumulations = start.dividend_accumulations + accumulation_interest;
+
+    let dividend = (values.guaranteed_cash_value + values.pua_cash_value)
+        .multiply(factors.dividend_rate / Decimal::ONE_HUNDRED)
+        .round_to_currency();
+
+    let mut pua_face_purchased = Money::zero(currency);
+    match dividend_option {
+        DividendOption::AccumulateAtInterest => {
+            values.dividend_accumulations = values.dividend_accumulations + dividend;
+        }
+        DividendOption::PaidUpAdditions if dividend.is_positive() => {
+            let single_premium = rules.pua_single_premium(attained_age).ok_or_else(|| {
+                PolicyError::CashValue(format!("No paid-up addition rate for age {}", attained_age))
+            })?;
+            let face_per_unit = Decimal::ONE_THOUSAND.checked_div(single_premium).ok_or_else(|| {
+                PolicyError::CashValue(format!("Invalid paid-up addition rate for age {}", attained_age))
+            })?;
+            pua_face_purchased = dividend.multiply(face_per_unit).round_to_currency();
+            values.pua_face_amount = values.pua_face_amount + pua_face_purchased;
+            values.pua_cash_value = values.pua_cash_value + dividend;
+        }
+        _ => {}
+    }
+
+    Ok(YearEnd {
+        values,
+        accumulation_interest,
+        dividend,
+        pua_face_purchased,
+    })
+}
+
+fn check_option(rules: &CashValueRules, dividend_option: DividendOption) -> Result<(), PolicyError> {
+    if dividend_option == DividendOption::PaidUpAdditions && !rules.paid_up_additions_available {
+        return Err(PolicyError::CashValue(
+            "Paid-up additions are not offered by this product".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn new_account(
+    policy: &Policy,
+    issue_age: u32,
+    factors: CashValueFactors,
+    dividend_option: DividendOption,
+) -> CashValueAccount {
+    let zero = Money::zero(policy.currency());
+    CashValueAccount {
+        policy_id: policy.id(),
+        issue_age,
+        factors,
+        dividend_option,
+        policy_year: 0,
+        premiums_credited: zero,
+        guaranteed_cash_value: zero,
+        pua_face_amount: zero,
+        pua_cash_value: zero,
+        dividend_accumulations: zero,
+        premium_credit: zero,
+        history: Vec::new(),
+    }
+}
+
+fn check_account(policy: &Policy, account: &CashValueAccount) -> Result<(), PolicyError> {
+    if account.policy_id != policy.id() {
+        return Err(PolicyError::CashValue(format!(
+            "Cash value account does not belong to policy {}",
+            policy.policy_number()
+        )));
+    }
+    Ok(())
+}
+
+fn anniversary(policy: &Policy, policy_year: u32) -> Result<NaiveDate, PolicyError> {
+    policy
+        .inception_date()
+        .and_then(|inception| inception.checked_add_months(Months::new(12 * policy_year)))
+        .ok_or_else(|| {
+            PolicyError::CashValue(format!("Policy {} has not been issued", policy.policy_number()))
+        })
+}
+
+fn base_death_benefit(policy: &Policy) -> Money {
+    policy
+        .coverages()
+        .iter()
+        .filter(|c| c.is_active && c.coverage_type == CoverageType::DeathBenefit)
+        .fold(Money::zero(policy.currency()), |total, c| total + c.effective_sum_assured())
+}
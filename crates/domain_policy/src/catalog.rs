@@ -0,0 +1,704 @@
+//! Product catalog
+//!
+//! The product catalog (`products/catalog.json`) is the source of truth for
+//! what can be sold: product codes, limits, riders, payment modes and
+//! currencies, and the rules file holding each product's underwriting and
+//! rating decision model.
+//!
+//! A product can have several versions, each with its own effective date.
+//! Quotes use the version in force on the application date, while policies
+//! stay pinned to the version they were sold under, so a new version never
+//! changes the terms of business already written.
+//!
+//! Version and effective date default to the catalog's own; a new version
+//! is added as a further entry with the same code:
+//!
+//! ```json
+//! {
+//!   "code": "TERM_LIFE_01",
+//!   "version": "2.0.0",
+//!   "effective_date": "2026-07-01",
+//!   "rules_file": "term_life.json",
+//!   ...
+//! }
+//! ```
+//!
+//! A version runs until the day before the next version takes effect,
+//! unless it sets an earlier `effective_to`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{Currency, Money};
+
+use crate::aggregate::{Policy, PolicyBuilder, PolicyState};
+use crate::catalog_serde;
+use crate::error::PolicyError;
+use crate::premium::PremiumFrequency;
+use crate::rider::RiderDefinition;
+use crate::rules_engine::{ProductRules, RulesEngine};
+
+/// Name of the catalog file within a products directory
+pub const CATALOG_FILE: &str = "catalog.json";
+
+/// Issue limits for a product version
+///
+/// Loaded from the `limits` block of a product in `catalog.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProductLimits {
+    /// Minimum age at entry
+    #[serde(default)]
+    pub min_entry_age: Option<u32>,
+    /// Maximum age at entry
+    #[serde(default)]
+    pub max_entry_age: Option<u32>,
+    /// Maximum age at the end of the term
+    #[serde(default)]
+    pub max_maturity_age: Option<u32>,
+    /// Age at which cover ends
+    #[serde(default)]
+    pub max_coverage_age: Option<u32>,
+    /// Minimum term in years
+    #[serde(default)]
+    pub min_term_years: Option<u32>,
+    /// Maximum term in years
+    #[serde(default)]
+    pub max_term_years: Option<u32>,
+    /// Minimum sum assured
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub min_sum_assured: Option<Decimal>,
+    /// Maximum sum assured
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub max_sum_assured: Option<Decimal>,
+    /// Minimum annual premium
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub min_premium: Option<Decimal>,
+}
+
+impl ProductLimits {
+    fn validate(&self, code: &str) -> Result<(), PolicyError> {
+        let ranges = [
+            ("entry age", self.min_entry_age.map(Decimal::from), self.max_entry_age.map(Decimal::from)),
+            ("term", self.min_term_years.map(Decimal::from), self.max_term_years.map(Decimal::from)),
+            ("sum assured", self.min_sum_assured, self.max_sum_assured),
+        ];
+        for (name, min, max) in ranges {
+            if let (Some(min), Some(max)) = (min, max) {
+                if min > max {
+                    return Err(PolicyError::Catalog(format!(
+                        "Product {} has minimum {} {} above maximum {}",
+                        code, name, min, max
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A version of a product definition
+#[derive(Debug, Clone)]
+pub struct ProductVersion {
+    /// Product code
+    pub code: String,
+    /// Product name
+    pub name: String,
+    /// Product description
+    pub description: String,
+    /// Product category (life, health, ...)
+    pub category: String,
+    /// Version identifier
+    pub version: String,
+    /// First day the version can be quoted
+    pub effective_date: NaiveDate,
+    /// Last day the version can be quoted; `None` while it is current
+    pub effective_to: Option<NaiveDate>,
+    /// Whether the product is open for new business
+    pub active: bool,
+    /// Rules file, relative to the catalog directory
+    pub rules_file: Option<String>,
+    /// Issue limits
+    pub limits: ProductLimits,
+    /// Riders offered with the product
+    pub riders: Vec<RiderDefinition>,
+    /// Premium payment modes offered
+    pub payment_modes: Vec<PremiumFrequency>,
+    /// Currencies the product is sold in
+    pub currencies: Vec<Currency>,
+    /// Decision model loaded from the rules file
+    pub rules: Option<Arc<ProductRules>>,
+    /// Catalog entry the version was read from
+    pub definition: Value,
+}
+
+impl ProductVersion {
+    /// Whether the version is the one to quote on `date`
+    pub fn is_effective_on(&self, date: NaiveDate) -> bool {
+        date >= self.effective_date && self.effective_to.is_none_or(|to| date <= to)
+    }
+
+    /// Rules to evaluate the version with
+    ///
+    /// Rules reloaded into the engine for the same rules version replace
+    /// the rules loaded with the catalog, so a revised rules file takes
+    /// effect without a restart. Rules the engine holds for another rules
+    /// version leave this version on the rules it was loaded with.
+    /// Versions without a rules file use the engine's rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - The engine holding the reloaded rules
+    pub fn current_rules(&self, engine: &RulesEngine) -> Option<Arc<ProductRules>> {
+        let reloaded = engine.get_product(&self.code);
+        match (&self.rules, reloaded) {
+            (Some(loaded), Some(reloaded)) if reloaded.metadata.version == loaded.metadata.version => Some(reloaded),
+            (Some(loaded), _) => Some(loaded.clone()),
+            (None, reloaded) => reloaded,
+        }
+    }
+
+    /// Whether premiums can be paid at the given frequency
+    pub fn offers_payment_mode(&self, frequency: PremiumFrequency) -> bool {
+        self.payment_modes.contains(&frequency)
+    }
+
+    /// Whether the product is sold in the given currency
+    pub fn offers_currency(&self, currency: Currency) -> bool {
+        self.currencies.contains(&currency)
+    }
+
+    /// Checks an application against the version's limits
+    ///
+    /// # Arguments
+    ///
+    /// * `sum_assured` - Base sum assured applied for
+    /// * `age` - Age of the insured at entry
+    /// * `term_years` - Policy term, for term products
+    /// * `frequency` - Premium payment frequency
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProductRuleViolation` describing the first limit breached
+    pub fn check_application(
+        &self,
+        sum_assured: Money,
+        age: u32,
+        term_years: Option<u32>,
+        frequency: PremiumFrequency,
+    ) -> Result<(), PolicyError> {
+        let violation = |message: String| Err(PolicyError::ProductRuleViolation(message));
+        let limits = &self.limits;
+
+        if !self.offers_currency(sum_assured.currency()) {
+            return violation(format!("{} is not sold in {}", self.code, sum_assured.currency()));
+        }
+        if !self.offers_payment_mode(frequency) {
+            return violation(format!("{} does not offer {:?} premiums", self.code, frequency));
+        }
+        if limits.min_entry_age.is_some_and(|min| age < min) || limits.max_entry_age.is_some_and(|max| age > max) {
+            return violation(format!("Entry age {} is outside the limits for {}", age, self.code));
+        }
+        if let Some(term) = term_years {
+            if limits.min_term_years.is_some_and(|min| term < min)
+                || limits.max_term_years.is_some_and(|max| term > max)
+            {
+                return violation(format!("Term of {} years is outside the limits for {}", term, self.code));
+            }
+            if limits.max_maturity_age.is_some_and(|max| age + term > max) {
+                return violation(format!(
+                    "Age at maturity {} exceeds the maximum for {}",
+                    age + term,
+                    self.code
+                ));
+            }
+        }
+        let amount = sum_assured.amount();
+        if limits.min_sum_assured.is_some_and(|min| amount < min)
+            || limits.max_sum_assured.is_some_and(|max| amount > max)
+        {
+            return violation(format!("Sum assured {} is outside the limits for {}", sum_assured, self.code));
+        }
+        Ok(())
+    }
+}
+
+/// Catalog entry as written in `catalog.json`
+#[derive(Deserialize)]
+struct CatalogEntry {
+    code: String,
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    category: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    effective_date: Option<NaiveDate>,
+    #[serde(default)]
+    effective_to: Option<NaiveDate>,
+    active: bool,
+    #[serde(default)]
+    rules_file: Option<String>,
+    #[serde(default)]
+    limits: ProductLimits,
+    #[serde(default)]
+    available_riders: Vec<RiderDefinition>,
+    #[serde(default)]
+    payment_modes: Vec<String>,
+    #[serde(default)]
+    currencies: Vec<String>,
+}
+
+/// Versioned product catalog
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let catalog = ProductCatalog::load(Path::new("products"))?;
+///
+/// // New business uses the version in force on the application date
+/// let product = catalog.quote_version("TERM_LIFE_01", application_date)?;
+/// product.check_application(sum_assured, age, Some(20), PremiumFrequency::Monthly)?;
+/// let policy = catalog.start_quote("TERM_LIFE_01", application_date)?
+///     .policyholder(party_id)
+///     .add_coverage(Coverage::death_benefit(sum_assured))
+///     .premium(premium)
+///     .build()?;
+///
+/// // Existing business stays on the version it was sold under
+/// let product = catalog.version_for(&policy)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProductCatalog {
+    /// Catalog name
+    pub name: String,
+    /// Catalog release
+    pub version: String,
+    /// Default effective date for product versions
+    pub effective_date: NaiveDate,
+    products: BTreeMap<String, Vec<ProductVersion>>,
+}
+
+impl ProductCatalog {
+    /// Loads and validates the catalog and its rules files
+    ///
+    /// Reads `catalog.json` from the products directory and loads each
+    /// version's rules file from the same directory. Rules files are
+    /// required for products open for new business.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the catalog is invalid, or a rules file is missing,
+    /// malformed or written for another product
+    pub fn load(dir: &Path) -> Result<Self, PolicyError> {
+        let path = dir.join(CATALOG_FILE);
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| PolicyError::Catalog(format!("Cannot read {}: {}", path.display(), e)))?;
+        let catalog: Value = serde_json::from_str(&content)
+            .map_err(|e| PolicyError::Catalog(format!("Invalid {}: {}", path.display(), e)))?;
+
+        let mut catalog = Self::from_json(&catalog)?;
+        catalog.load_rules(dir)?;
+
+        tracing::info!(
+            catalog_version = %catalog.version,
+            products = catalog.products.len(),
+            "Product catalog loaded"
+        );
+
+        Ok(catalog)
+    }
+
+    /// Parses and validates a catalog without loading its rules files
+    ///
+    /// # Errors
+    ///
+    /// Returns error if an entry is malformed, a version is duplicated,
+    /// effective periods overlap, limits are inconsistent, or payment
+    /// modes or currencies are missing or unknown
+    pub fn from_json(catalog: &Value) -> Result<Self, PolicyError> {
+        let text = |field: &str| {
+            catalog
+                .get(field)
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| PolicyError::Catalog(format!("Catalog is missing {}", field)))
+        };
+        let name = text("name")?.to_string();
+        let version = text("version")?.to_string();
+        let effective_date = text("effective_date")?
+            .parse::<NaiveDate>()
+            .map_err(|e| PolicyError::Catalog(format!("Invalid catalog effective_date: {}", e)))?;
+
+        let entries = catalog
+            .get("products")
+            .and_then(|p| p.as_array())
+            .ok_or_else(|| PolicyError::Catalog("Catalog is missing products".to_string()))?;
+
+        let mut products: BTreeMap<String, Vec<ProductVersion>> = BTreeMap::new();
+        for entry in entries {
+            let product = parse_entry(entry, &version, effective_date)?;
+            let versions = products.entry(product.code.clone()).or_default();
+            if versions.iter().any(|v| v.version == product.version) {
+                return Err(PolicyError::Catalog(format!(
+                    "Product {} version {} is defined more than once",
+                    product.code, product.version
+                )));
+            }
+            versions.push(product);
+        }
+
+        for versions in products.values_mut() {
+            versions.sort_by_key(|v| v.effective_date);
+            for i in 0..versions.len() {
+                let next_start = versions.get(i + 1).map(|v| v.effective_date);
+                let current = &mut versions[i];
+                if next_start == Some(current.effective_date) {
+                    return Err(PolicyError::Catalog(format!(
+                        "Product {} has two versions effective {}",
+                        current.code, current.effective_date
+                    )));
+                }
+                if let Some(next_start) = next_start {
+                    if current.effective_to.is_some_and(|to| to >= next_start) {
+                        return Err(PolicyError::Catalog(format!(
+                            "Product {} version {} overlaps the version effective {}",
+                            current.code, current.version, next_start
+                        )));
+                    }
+                    current.effective_to = current.effective_to.or(next_start.pred_opt());
+                }
+            }
+        }
+
+        Ok(Self {
+            name,
+            version,
+            effective_date,
+            products,
+        })
+    }
+
+    /// Returns the codes of all products in the catalog
+    pub fn product_codes(&self) -> impl Iterator<Item = &str> {
+        self.products.keys().map(String::as_str)
+    }
+
+    /// Returns all versions of a product, oldest first
+    pub fn versions(&self, code: &str) -> &[ProductVersion] {
+        self.products.get(code).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns the version of a product in force on a date
+    pub fn version_on(&self, code: &str, date: NaiveDate) -> Option<&ProductVersion> {
+        self.versions(code).iter().find(|v| v.is_effective_on(date))
+    }
+
+    /// Returns the versions of all products in force on a date
+    pub fn in_force_on(&self, date: NaiveDate) -> Vec<&ProductVersion> {
+        self.products
+            .keys()
+            .filter_map(|code| self.version_on(code, date))
+            .collect()
+    }
+
+    /// Returns a specific version of a product
+    pub fn pinned(&self, code: &str, version: &str) -> Option<&ProductVersion> {
+        self.versions(code).iter().find(|v| v.version == version)
+    }
+
+    /// Returns the version to quote for an application
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product has no version in force on the
+    /// application date or is closed to new business
+    pub fn quote_version(&self, code: &str, application_date: NaiveDate) -> Result<&ProductVersion, PolicyError> {
+        let product = self.version_on(code, application_date).ok_or_else(|| {
+            PolicyError::Catalog(format!("Product {} is not available on {}", code, application_date))
+        })?;
+        if !product.active {
+            return Err(PolicyError::Catalog(format!("Product {} is closed to new business", code)));
+        }
+        Ok(product)
+    }
+
+    /// Starts a quote pinned to the version in force on the application date
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product cannot be quoted on the application date
+    pub fn start_quote(&self, code: &str, application_date: NaiveDate) -> Result<PolicyBuilder, PolicyError> {
+        let product = self.quote_version(code, application_date)?;
+        let renewable = product
+            .definition
+            .pointer("/features/renewable")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        Ok(PolicyBuilder::new()
+            .product_code(product.code.clone())
+            .product_version(product.version.clone())
+            .renewable(renewable))
+    }
+
+    /// Returns the version a policy was sold under
+    ///
+    /// Policies written before version pinning use the version in force
+    /// on their inception date, or quote date if not yet issued.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the version is not in the catalog
+    pub fn version_for(&self, policy: &Policy) -> Result<&ProductVersion, PolicyError> {
+        let code = policy.product_code();
+        if let Some(version) = policy.product_version() {
+            return self.pinned(code, version).ok_or_else(|| {
+                PolicyError::Catalog(format!("Product {} version {} is not in the catalog", code, version))
+            });
+        }
+        let sold_on = sold_on(policy).ok_or_else(|| {
+            PolicyError::Catalog(format!(
+                "Policy {} has no product version or inception date",
+                policy.policy_number()
+            ))
+        })?;
+        self.version_on(code, sold_on).ok_or_else(|| {
+            PolicyError::Catalog(format!("Product {} is not available on {}", code, sold_on))
+        })
+    }
+
+    fn load_rules(&mut self, dir: &Path) -> Result<(), PolicyError> {
+        let engine = RulesEngine::new();
+        for product in self.products.values_mut().flatten() {
+            let Some(file) = &product.rules_file else {
+                continue;
+            };
+            let path = dir.join(file);
+            if !path.exists() {
+                if product.active {
+                    return Err(PolicyError::Catalog(format!(
+                        "Rules file {} for product {} not found",
+                        file, product.code
+                    )));
+                }
+                continue;
+            }
+            let rules = engine
+                .load_rules_from_file(&path)
+                .map_err(|e| PolicyError::Catalog(format!("Rules file {} is invalid: {}", file, e)))?;
+            if rules.metadata.product_code != product.code {
+                return Err(PolicyError::Catalog(format!(
+                    "Rules file {} is for product {}, not {}",
+                    file, rules.metadata.product_code, product.code
+                )));
+            }
+            product.rules = Some(Arc::new(rules));
+        }
+        Ok(())
+    }
+}
+
+/// Date a policy was sold: its inception date, or quote date if not issued
+fn sold_on(policy: &Policy) -> Option<NaiveDate> {
+    match (policy.inception_date(), policy.state()) {
+        (Some(inception), _) => Some(inception),
+        (None, PolicyState::Quoted { quote_date, .. }) => Some(quote_date.date_naive()),
+        (None, _) => None,
+    }
+}
+
+/// Per-product engine rules, kept for each version of a product
+///
+/// Engines servicing policies read their catalog block from every version
+/// of a product and look rules up by the version a policy was sold under,
+/// chosen as by [`ProductCatalog::version_for`], so a new version never
+/// changes the terms of business already written.
+#[derive(Debug, Clone)]
+pub(crate) struct VersionedRules<T> {
+    products: HashMap<String, Vec<RulesVersion<T>>>,
+}
+
+#[derive(Debug, Clone)]
+struct RulesVersion<T> {
+    /// Version identifier; `None` for rules set for every version
+    version: Option<String>,
+    effective_date: NaiveDate,
+    /// Rules read from the version; `None` if the version has none
+    rules: Option<T>,
+}
+
+impl<T> Default for VersionedRules<T> {
+    fn default() -> Self {
+        Self { products: HashMap::new() }
+    }
+}
+
+impl<T> VersionedRules<T> {
+    /// Reads each product version's rules from a catalog document
+    ///
+    /// Version and effective date default to the catalog's own.
+    pub(crate) fn from_catalog(
+        catalog: &Value,
+        read: impl Fn(&Value) -> Result<Option<T>, PolicyError>,
+    ) -> Result<Self, PolicyError> {
+        let text = |value: &Value, field: &str| value.get(field).and_then(|v| v.as_str()).map(str::to_string);
+        let date = |value: &Value| text(value, "effective_date").and_then(|d| d.parse::<NaiveDate>().ok());
+        let catalog_version = text(catalog, "version");
+        let catalog_effective_date = date(catalog).unwrap_or(NaiveDate::MIN);
+
+        let mut rules = Self::default();
+        if let Some(products) = catalog.get("products").and_then(|p| p.as_array()) {
+            for product in products {
+                if let Some(code) = product.get("code").and_then(|c| c.as_str()) {
+                    rules.products.entry(code.to_string()).or_default().push(RulesVersion {
+                        version: text(product, "version").or_else(|| catalog_version.clone()),
+                        effective_date: date(product).unwrap_or(catalog_effective_date),
+                        rules: read(product)?,
+                    });
+                }
+            }
+        }
+        rules.products.retain(|_, versions| versions.iter().any(|v| v.rules.is_some()));
+        for versions in rules.products.values_mut() {
+            versions.sort_by_key(|v| v.effective_date);
+        }
+        Ok(rules)
+    }
+
+    /// Sets the rules for every version of a product
+    pub(crate) fn insert(&mut self, product_code: impl Into<String>, rules: T) {
+        self.products.insert(
+            product_code.into(),
+            vec![RulesVersion {
+                version: None,
+                effective_date: NaiveDate::MIN,
+                rules: Some(rules),
+            }],
+        );
+    }
+
+    /// Rules of a product's latest version
+    pub(crate) fn latest(&self, product_code: &str) -> Option<&T> {
+        self.products.get(product_code)?.last()?.rules.as_ref()
+    }
+
+    /// Rules of the product version a policy was sold under
+    ///
+    /// Pinned policies use their version; others the version in force on
+    /// their inception or quote date.
+    pub(crate) fn for_policy(&self, policy: &Policy) -> Option<&T> {
+        self.for_version(policy.product_code(), policy.product_version(), sold_on(policy))
+    }
+
+    /// Rules of a product version, or of the version in force on the date
+    /// sold if the version is not known
+    pub(crate) fn for_version(&self, product_code: &str, version: Option<&str>, sold_on: Option<NaiveDate>) -> Option<&T> {
+        let versions = self.products.get(product_code)?;
+        let version = match version {
+            Some(pinned) => versions
+                .iter()
+                .find(|v| v.version.as_deref() == Some(pinned))
+                .or_else(|| versions.iter().find(|v| v.version.is_none())),
+            None => match sold_on {
+                Some(date) => versions
+                    .iter()
+                    .rev()
+                    .find(|v| v.effective_date <= date)
+                    .or(versions.first()),
+                None => versions.last(),
+            },
+        };
+        version?.rules.as_ref()
+    }
+}
+
+fn parse_entry(
+    entry: &Value,
+    catalog_version: &str,
+    catalog_effective_date: NaiveDate,
+) -> Result<ProductVersion, PolicyError> {
+    let raw: CatalogEntry = serde_json::from_value(entry.clone())
+        .map_err(|e| PolicyError::Catalog(format!("Invalid product entry: {}", e)))?;
+    let code = raw.code;
+    let effective_date = raw.effective_date.unwrap_or(catalog_effective_date);
+
+    if raw.effective_to.is_some_and(|to| to < effective_date) {
+        return Err(PolicyError::Catalog(format!(
+            "Product {} ends before it takes effect",
+            code
+        )));
+    }
+    raw.limits.validate(&code)?;
+
+    if raw.payment_modes.is_empty() {
+        return Err(PolicyError::Catalog(format!("Product {} has no payment modes", code)));
+    }
+    let payment_modes = raw
+        .payment_modes
+        .iter()
+        .map(|mode| {
+            parse_payment_mode(mode).ok_or_else(|| {
+                PolicyError::Catalog(format!("Product {} has unknown payment mode {}", code, mode))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if raw.currencies.is_empty() {
+        return Err(PolicyError::Catalog(format!("Product {} has no currencies", code)));
+    }
+    let currencies = raw
+        .currencies
+        .iter()
+        .map(|currency| {
+            currency.parse::<Currency>().map_err(|_| {
+                PolicyError::Catalog(format!("Product {} has unknown currency {}", code, currency))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ProductVersion {
+        code,
+        name: raw.name,
+        description: raw.description,
+        category: raw.category,
+        version: raw.version.unwrap_or_else(|| catalog_version.to_string()),
+        effective_date,
+        effective_to: raw.effective_to,
+        active: raw.active,
+        rules_file: raw.rules_file,
+        limits: raw.limits,
+        riders: raw.available_riders,
+        payment_modes,
+        currencies,
+        rules: None,
+        definition: entry.clone(),
+    })
+}
+
+/// Returns the catalog name of a payment mode, as used in rules contexts
+pub fn payment_mode_name(frequency: PremiumFrequency) -> &'static str {
+    match frequency {
+        PremiumFrequency::Single => "single",
+        PremiumFrequency::Annual => "annual",
+        PremiumFrequency::SemiAnnual => "semi_annual",
+        PremiumFrequency::Quarterly => "quarterly",
+        PremiumFrequency::Monthly => "monthly",
+    }
+}
+
+fn parse_payment_mode(mode: &str) -> Option<PremiumFrequency> {
+    match mode {
+        "single" => Some(PremiumFrequency::Single),
+        "annual" => Some(PremiumFrequency::Annual),
+        "semi_annual" => Some(PremiumFrequency::SemiAnnual),
+        "quarterly" => Some(PremiumFrequency::Quarterly),
+        "monthly" => Some(PremiumFrequency::Monthly),
+        _ => None,
+    }
+}
@@ -0,0 +1,40 @@
+//! Serde helpers for product catalog values
+//!
+//! Catalog rates are read through `f64`, as in the rules engine, so they
+//! parse regardless of how serde_json represents numbers. Monetary amounts
+//! are read from their decimal representation so they are exact.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use serde_json::Number;
+
+/// Reads a catalog rate as a decimal
+pub(crate) fn rate<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    let value = f64::deserialize(deserializer)?;
+    Decimal::try_from(value).map_err(serde::de::Error::custom)
+}
+
+/// Reads an optional catalog rate as a decimal
+pub(crate) fn optional_rate<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Decimal>, D::Error> {
+    Option::<f64>::deserialize(deserializer)?
+        .map(|value| Decimal::try_from(value).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Reads a list of catalog rates as decimals
+pub(crate) fn rates<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Decimal>, D::Error> {
+    Vec::<f64>::deserialize(deserializer)?
+        .into_iter()
+        .map(|value| Decimal::try_from(value).map_err(serde::de::Error::custom))
+        .collect()
+}
+
+/// Reads a catalog monetary amount as an exact decimal
+pub(crate) fn amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    let value = Number::deserialize(deserializer)?.to_string();
+    Decimal::from_str(&value)
+        .or_else(|_| Decimal::from_scientific(&value))
+        .map_err(serde::de::Error::custom)
+}
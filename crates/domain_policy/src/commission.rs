@@ -0,0 +1,635 @@
+//! Agent commission
+//!
+//! This module calculates the commission earned by agents when premium is
+//! received, rolls override commission up the agent hierarchy, claws back
+//! unearned first-year commission when a policy lapses or is cancelled early,
+//! and produces periodic agent statements.
+//!
+//! # Commission Schedules
+//!
+//! Schedules are configured per product in the `commission` block of
+//! `catalog.json`:
+//!
+//! ```json
+//! "commission": {
+//!   "first_year_rate": 0.50,
+//!   "renewal_rates": [
+//!     { "from_year": 2, "to_year": 10, "rate": 0.05 },
+//!     { "from_year": 11, "to_year": null, "rate": 0.02 }
+//!   ],
+//!   "override_rates": [0.10, 0.05],
+//!   "clawback_months": 12
+//! }
+//! ```
+//!
+//! `override_rates[n]` is paid to the agent `n + 1` levels up the
+//! `manager_id` chain from the writing agent. Products without a schedule
+//! fall back to the writing agent's `default_commission_rate`.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use core_kernel::{AccountId, AgentId, Currency, JournalEntryId, Money, PolicyId, utc_start_of_day};
+use domain_billing::transaction::{InsuranceTransactions, Transaction};
+use domain_billing::Ledger;
+use domain_party::agent::{Agent, AgentStatus};
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::error::PolicyError;
+
+/// Renewal commission rate for a band of policy years
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenewalRate {
+    /// First policy year of the band
+    pub from_year: u32,
+    /// Last policy year of the band (None for all later years)
+    pub to_year: Option<u32>,
+    /// Commission rate as a fraction of premium
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub rate: Decimal,
+}
+
+/// Product-level commission schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommissionSchedule {
+    /// Rate on premium for the first policy year
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub first_year_rate: Decimal,
+    /// Rates on renewal premium by policy year
+    #[serde(default)]
+    pub renewal_rates: Vec<RenewalRate>,
+    /// Override rates by hierarchy level above the writing agent
+    #[serde(default, deserialize_with = "catalog_serde::rates")]
+    pub override_rates: Vec<Decimal>,
+    /// Months from inception within which first-year commission is clawed back
+    #[serde(default)]
+    pub clawback_months: u32,
+}
+
+impl CommissionSchedule {
+    /// Creates a flat schedule paying the same rate every year
+    ///
+    /// Used for products without a configured schedule. Flat schedules pay
+    /// no overrides and have no clawback period.
+    pub fn flat(rate: Decimal) -> Self {
+        Self {
+            first_year_rate: rate,
+            renewal_rates: vec![RenewalRate {
+                from_year: 2,
+                to_year: None,
+                rate,
+            }],
+            override_rates: Vec::new(),
+            clawback_months: 0,
+        }
+    }
+
+    /// Reads the schedule from a catalog product entry
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - A product object from `catalog.json`
+    ///
+    /// # Returns
+    ///
+    /// `None` if the product has no `commission` block
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the `commission` block is malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        product
+            .get("commission")
+            .map(|schedule| {
+                serde_json::from_value(schedule.clone())
+                    .map_err(|e| PolicyError::validation(format!("Invalid commission schedule: {}", e)))
+            })
+            .transpose()
+    }
+
+    /// Returns the writing agent's rate for a policy year
+    pub fn rate_for_year(&self, policy_year: u32) -> Decimal {
+        if policy_year <= 1 {
+            return self.first_year_rate;
+        }
+        self.renewal_rates
+            .iter()
+            .find(|band| policy_year >= band.from_year && band.to_year.is_none_or(|to| policy_year <= to))
+            .map(|band| band.rate)
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+/// Whether premium falls in the first policy year or a renewal year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PremiumType {
+    /// Premium for the first policy year
+    FirstYear,
+    /// Premium for the second and later policy years
+    Renewal,
+}
+
+/// Type of commission entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommissionKind {
+    /// Commission to the writing agent
+    Writing,
+    /// Override to a manager above the writing agent
+    Override {
+        /// Levels above the writing agent (1 = direct manager)
+        level: u32,
+    },
+    /// Recovery of unearned commission
+    Clawback,
+}
+
+/// A commission earned or clawed back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommissionEntry {
+    /// Unique identifier
+    pub id: Uuid,
+    /// Agent credited or debited
+    pub agent_id: AgentId,
+    /// Policy the premium belongs to
+    pub policy_id: PolicyId,
+    /// Type of entry
+    pub kind: CommissionKind,
+    /// First-year or renewal premium
+    pub premium_type: PremiumType,
+    /// Policy year of the premium
+    pub policy_year: u32,
+    /// Premium the commission is based on
+    pub premium: Money,
+    /// Rate applied (fraction clawed back for clawbacks)
+    pub rate: Decimal,
+    /// Commission amount (negative for clawbacks)
+    pub amount: Money,
+    /// Date earned or clawed back
+    pub earned_date: NaiveDate,
+    /// Entry reversed by a clawback
+    pub reverses: Option<Uuid>,
+    /// Ledger posting
+    pub journal_entry_id: Option<JournalEntryId>,
+}
+
+/// A premium receipt on which commission is earned
+#[derive(Debug, Clone)]
+pub struct PremiumReceipt {
+    /// Policy the premium was paid for
+    pub policy_id: PolicyId,
+    /// Product code of the policy
+    pub product_code: String,
+    /// Product version the policy was sold under (None if not pinned)
+    pub product_version: Option<String>,
+    /// Agent who wrote the policy
+    pub writing_agent_id: AgentId,
+    /// Policy inception date
+    pub inception_date: NaiveDate,
+    /// Due date of the premium (determines the policy year)
+    pub due_date: NaiveDate,
+    /// Date the premium was received
+    pub received_date: NaiveDate,
+    /// Premium received
+    pub amount: Money,
+}
+
+impl PremiumReceipt {
+    /// Returns the policy year the premium is due in (1 = first year)
+    pub fn policy_year(&self) -> u32 {
+        let mut years = self.due_date.year() - self.inception_date.year();
+        if (self.due_date.month(), self.due_date.day())
+            < (self.inception_date.month(), self.inception_date.day())
+        {
+            years -= 1;
+        }
+        years.max(0) as u32 + 1
+    }
+}
+
+/// Ledger accounts used for commission
+#[derive(Debug, Clone, Copy)]
+pub struct CommissionAccounts {
+    /// Commission expense
+    pub expense: AccountId,
+    /// Commission payable to agents
+    pub payable: AccountId,
+}
+
+/// Commission activity for one agent over a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentStatement {
+    /// Agent
+    pub agent_id: AgentId,
+    /// First day of the period
+    pub period_start: NaiveDate,
+    /// Last day of the period
+    pub period_end: NaiveDate,
+    /// Entries in the period
+    pub entries: Vec<CommissionEntry>,
+    /// Writing commission on first-year premium
+    pub first_year: Money,
+    /// Writing commission on renewal premium
+    pub renewal: Money,
+    /// Override commission
+    pub overrides: Money,
+    /// Clawbacks (negative)
+    pub clawbacks: Money,
+    /// Net commission for the period
+    pub net: Money,
+}
+
+/// Engine calculating, recording and posting agent commission
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut engine = CommissionEngine::from_catalog(&catalog, accounts)?;
+/// engine.earn(&mut ledger, &receipt, &agents)?;
+///
+/// // Policy lapsed within the clawback period
+/// engine.clawback(&mut ledger, &policy)?;
+///
+/// let statement = engine.statement(agent_id, month_start, month_end, Currency::USD);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommissionEngine {
+    /// Schedules by product code
+    product_schedules: VersionedRules<CommissionSchedule>,
+    /// Ledger accounts
+    accounts: CommissionAccounts,
+    /// Commission recorded so far
+    entries: Vec<CommissionEntry>,
+    /// Inception date and product of each commissioned policy
+    policies: HashMap<PolicyId, (NaiveDate, String)>,
+}
+
+impl CommissionEngine {
+    /// Creates an engine with no product schedules
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - Ledger accounts to post to
+    pub fn new(accounts: CommissionAccounts) -> Self {
+        Self {
+            product_schedules: VersionedRules::default(),
+            accounts,
+            entries: Vec::new(),
+            policies: HashMap::new(),
+        }
+    }
+
+    /// Builds an engine from a product catalog document
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The parsed `catalog.json` document
+    /// * `accounts` - Ledger accounts to post to
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has a malformed commission schedule
+    pub fn from_catalog(catalog: &Value, accounts: CommissionAccounts) -> Result<Self, PolicyError> {
+        let mut engine = Self::new(accounts);
+        engine.product_schedules = VersionedRules::from_catalog(catalog, CommissionSchedule::from_catalog_product)?;
+        Ok(engine)
+    }
+
+    /// Sets the schedule for a product
+    pub fn with_product_schedule(mut self, product_code: impl Into<String>, schedule: CommissionSchedule) -> Self {
+        self.product_schedules.insert(product_code.into(), schedule);
+        self
+    }
+
+    /// Returns the schedule configured for a product
+    pub fn schedule_for(&self, product_code: &str) -> Option<&CommissionSchedule> {
+        self.product_schedules.latest(product_code)
+    }
+
+    /// Returns all commission recorded
+    pub fn entries(&self) -> &[CommissionEntry] {
+        &self.entries
+    }
+
+    /// Earns commission on a premium receipt
+    ///
+    /// This method:
+    /// 1. Determines the policy year and premium type from the due date
+    /// 2. Pays the writing agent at the schedule rate for that year
+    /// 3. Pays overrides to active managers up the `manager_id` chain
+    /// 4. Posts each amount as a commission accrual
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `receipt` - The premium receipt
+    /// * `agents` - Agent directory containing the writing agent and managers
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the writing agent is unknown, no schedule or default
+    /// rate applies, or a ledger posting fails
+    pub fn earn(
+        &mut self,
+        ledger: &mut Ledger,
+        receipt: &PremiumReceipt,
+        agents: &[Agent],
+    ) -> Result<Vec<CommissionEntry>, PolicyError> {
+        let writing_agent = find_agent(agents, receipt.writing_agent_id).ok_or_else(|| {
+            PolicyError::Commission(format!("Unknown writing agent {}", receipt.writing_agent_id))
+        })?;
+        let schedule = match self.product_schedules.for_version(
+            &receipt.product_code,
+            receipt.product_version.as_deref(),
+            Some(receipt.inception_date),
+        ) {
+            Some(schedule) => schedule.clone(),
+            None => CommissionSchedule::flat(writing_agent.default_commission_rate.ok_or_else(|| {
+                PolicyError::Commission(format!(
+                    "No commission schedule for product {} and no default rate for agent {}",
+                    receipt.product_code, writing_agent.agent_code
+                ))
+            })?),
+        };
+
+        let policy_year = receipt.policy_year();
+        let premium_type = if policy_year == 1 {
+            PremiumType::FirstYear
+        } else {
+            PremiumType::Renewal
+        };
+
+        let mut payees = vec![(writing_agent.id, CommissionKind::Writing, schedule.rate_for_year(policy_year))];
+        for (index, manager) in managers(agents, writing_agent).into_iter().enumerate() {
+            let Some(rate) = schedule.override_rates.get(index) else { break };
+            if manager.status == AgentStatus::Active {
+                payees.push((manager.id, CommissionKind::Override { level: index as u32 + 1 }, *rate));
+            }
+        }
+
+        let mut earned = Vec::new();
+        for (agent_id, kind, rate) in payees {
+            let amount = receipt.amount.multiply(rate).round_to_currency();
+            if !amount.is_positive() {
+                continue;
+            }
+            let transaction = InsuranceTransactions::commission_accrual(
+                self.accounts.expense,
+                self.accounts.payable,
+                amount,
+                *receipt.policy_id.as_uuid(),
+            )
+            .dated(utc_start_of_day(receipt.received_date));
+            let journal_entry_id = ledger
+                .post(transaction)
+                .map_err(|e| PolicyError::Financial(e.to_string()))?;
+
+            earned.push(CommissionEntry {
+                id: Uuid::new_v4(),
+                agent_id,
+                policy_id: receipt.policy_id,
+                kind,
+                premium_type,
+                policy_year,
+                premium: receipt.amount,
+                rate,
+                amount,
+                earned_date: receipt.received_date,
+                reverses: None,
+                journal_entry_id: Some(journal_entry_id),
+            });
+        }
+
+        self.policies
+            .entry(receipt.policy_id)
+            .or_insert_with(|| (receipt.inception_date, receipt.product_code.clone()));
+        self.entries.extend(earned.iter().cloned());
+        Ok(earned)
+    }
+
+    /// Claws back unearned first-year commission on a lapsed or cancelled policy
+    ///
+    /// Commission on first-year premium (writing and overrides) is
+    /// recovered in proportion to the part of the clawback period remaining
+    /// at the lapse or cancellation date. Entries already clawed back are
+    /// skipped, so calling this twice has no further effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `policy` - The lapsed or cancelled policy
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is neither lapsed nor cancelled, or a
+    /// ledger posting fails
+    pub fn clawback(&mut self, ledger: &mut Ledger, policy: &Policy) -> Result<Vec<CommissionEntry>, PolicyError> {
+        let termination_date = match policy.state() {
+            PolicyState::Lapsed { effective_date, .. } => effective_date.date_naive(),
+            PolicyState::Cancelled { cancellation_date, .. } => cancellation_date.date_naive(),
+            other => {
+                return Err(PolicyError::Commission(format!(
+                    "Policy {} is not lapsed or cancelled ({:?})",
+                    policy.policy_number(),
+                    other
+                )))
+            }
+        };
+
+        let Some((inception_date, product_code)) = self.policies.get(&policy.id()).cloned() else {
+            return Ok(Vec::new());
+        };
+        let clawback_months = self
+            .product_schedules
+            .for_version(&product_code, policy.product_version(), Some(inception_date))
+            .map(|s| s.clawback_months)
+            .unwrap_or(0);
+        let Some(clawback_end) = inception_date.checked_add_months(Months::new(clawback_months)) else {
+            return Ok(Vec::new());
+        };
+        if termination_date >= clawback_end {
+            return Ok(Vec::new());
+        }
+
+        let period_days = Decimal::from((clawback_end - inception_date).num_days());
+        let remaining_days = Decimal::from((clawback_end - termination_date.max(inception_date)).num_days());
+        self.recover(ledger, policy.id(), termination_date, remaining_days / period_days, true)
+    }
+
+    /// Reverses all commission paid on a policy
+    ///
+    /// Used when a policy is cancelled within its free-look period and the
+    /// whole premium is returned, so no commission is earned on it. Entries
+    /// already clawed back are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `policy_id` - The cancelled policy
+    /// * `reversal_date` - Date of the reversal
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a ledger posting fails
+    pub fn reverse_all(
+        &mut self,
+        ledger: &mut Ledger,
+        policy_id: PolicyId,
+        reversal_date: NaiveDate,
+    ) -> Result<Vec<CommissionEntry>, PolicyError> {
+        self.recover(ledger, policy_id, reversal_date, Decimal::ONE, false)
+    }
+
+    /// Recovers a fraction of the commission not yet clawed back on a policy
+    fn recover(
+        &mut self,
+        ledger: &mut Ledger,
+        policy_id: PolicyId,
+        recovery_date: NaiveDate,
+        fraction: Decimal,
+        first_year_only: bool,
+    ) -> Result<Vec<CommissionEntry>, PolicyError> {
+        let already_reversed: HashSet<Uuid> = self.entries.iter().filter_map(|e| e.reverses).collect();
+        let recoverable: Vec<CommissionEntry> = self
+            .entries
+            .iter()
+            .filter(|e| {
+                e.policy_id == policy_id
+                    && (!first_year_only || e.premium_type == PremiumType::FirstYear)
+                    && e.kind != CommissionKind::Clawback
+                    && !already_reversed.contains(&e.id)
+            })
+            .cloned()
+            .collect();
+
+        let mut clawbacks = Vec::new();
+        for original in recoverable {
+            let amount = original.amount.multiply(fraction).round_to_currency();
+            if !amount.is_positive() {
+                continue;
+            }
+            let transaction = Transaction::new("Commission clawback")
+                .with_reference("policy", *policy_id.as_uuid())
+                .dated(utc_start_of_day(recovery_date))
+                .debit(self.accounts.payable, amount)
+                .credit(self.accounts.expense, amount);
+            let journal_entry_id = ledger
+                .post(transaction)
+                .map_err(|e| PolicyError::Financial(e.to_string()))?;
+
+            clawbacks.push(CommissionEntry {
+                id: Uuid::new_v4(),
+                agent_id: original.agent_id,
+                policy_id: original.policy_id,
+                kind: CommissionKind::Clawback,
+                premium_type: original.premium_type,
+                policy_year: original.policy_year,
+                premium: original.premium,
+                rate: fraction,
+                amount: -amount,
+                earned_date: recovery_date,
+                reverses: Some(original.id),
+                journal_entry_id: Some(journal_entry_id),
+            });
+        }
+
+        self.entries.extend(clawbacks.iter().cloned());
+        Ok(clawbacks)
+    }
+
+    /// Produces an agent's statement for a period
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_id` - The agent
+    /// * `period_start` - First day of the period
+    /// * `period_end` - Last day of the period
+    /// * `currency` - Statement currency; entries in other currencies are excluded
+    pub fn statement(
+        &self,
+        agent_id: AgentId,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        currency: Currency,
+    ) -> AgentStatement {
+        let entries: Vec<CommissionEntry> = self
+            .entries
+            .iter()
+            .filter(|e| {
+                e.agent_id == agent_id
+                    && e.earned_date >= period_start
+                    && e.earned_date <= period_end
+                    && e.amount.currency() == currency
+            })
+            .cloned()
+            .collect();
+
+        let total = |predicate: &dyn Fn(&CommissionEntry) -> bool| {
+            entries
+                .iter()
+                .filter(|e| predicate(e))
+                .fold(Money::zero(currency), |acc, e| acc + e.amount)
+        };
+        let first_year = total(&|e| e.kind == CommissionKind::Writing && e.premium_type == PremiumType::FirstYear);
+        let renewal = total(&|e| e.kind == CommissionKind::Writing && e.premium_type == PremiumType::Renewal);
+        let overrides = total(&|e| matches!(e.kind, CommissionKind::Override { .. }));
+        let clawbacks = total(&|e| e.kind == CommissionKind::Clawback);
+
+        AgentStatement {
+            agent_id,
+            period_start,
+            period_end,
+            entries,
+            first_year,
+            renewal,
+            overrides,
+            clawbacks,
+            net: first_year + renewal + overrides + clawbacks,
+        }
+    }
+
+    /// Produces statements for every agent with activity in a period
+    pub fn statements(&self, period_start: NaiveDate, period_end: NaiveDate, currency: Currency) -> Vec<AgentStatement> {
+        let mut agent_ids: Vec<AgentId> = Vec::new();
+        for entry in &self.entries {
+            if entry.earned_date >= period_start
+                && entry.earned_date <= period_end
+                && !agent_ids.contains(&entry.agent_id)
+            {
+                agent_ids.push(entry.agent_id);
+            }
+        }
+        agent_ids
+            .into_iter()
+            .map(|agent_id| self.statement(agent_id, period_start, period_end, currency))
+            .collect()
+    }
+}
+
+/// Finds an agent by ID
+fn find_agent(agents: &[Agent], id: AgentId) -> Option<&Agent> {
+    agents.iter().find(|a| a.id == id)
+}
+
+/// Returns the managers above an agent, nearest first
+///
+/// Stops at the top of the hierarchy, at an unknown manager, or on a cycle.
+fn managers<'a>(agents: &'a [Agent], agent: &Agent) -> Vec<&'a Agent> {
+    let mut chain = Vec::new();
+    let mut seen = HashSet::from([agent.id]);
+    let mut next = agent.manager_id;
+    while let Some(manager) = next.and_then(|id| find_agent(agents, id)) {
+        if !seen.insert(manager.id) {
+            break;
+        }
+        chain.push(manager);
+        next = manager.manager_id;
+    }
+    chain
+}
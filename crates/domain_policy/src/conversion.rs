@@ -0,0 +1,446 @@
+//! Term conversion
+//!
+//! Convertible term policies may be exchanged for a permanent policy
+//! without evidence of insurability. The term policy is terminated with
+//! `TerminationReason::Conversion` and a new policy is issued at the
+//! insured's attained age, rated in the risk class of the original policy.
+//! The two policies are linked in both directions for audit.
+//!
+//! Conversion rules are configured per term product in the
+//! `conversion_rules` block of `catalog.json`:
+//!
+//! ```json
+//! "conversion_rules": {
+//!   "target_products": ["WHOLE_LIFE_01"],
+//!   "conversion_period_years": 10,
+//!   "max_conversion_age": 65,
+//!   "max_sum_assured_percentage": 1.00
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{Money, PolicyId};
+
+use crate::aggregate::{Policy, PolicyBuilder, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::coverage::{Coverage, CoverageType};
+use crate::error::PolicyError;
+use crate::premium::{Premium, PremiumFrequency};
+use crate::services::RatingService;
+use crate::underwriting::RiskClass;
+
+/// Underwriter recorded on policies issued by conversion
+pub const CONVERSION_UNDERWRITER: &str = "CONVERSION";
+
+/// Product-level conversion rules
+///
+/// Loaded from the `conversion_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversionRules {
+    /// Products the policy may be converted to
+    pub target_products: Vec<String>,
+    /// Years from inception during which conversion is allowed
+    pub conversion_period_years: u32,
+    /// Oldest attained age at which conversion is allowed
+    pub max_conversion_age: u32,
+    /// Maximum converted sum assured as a fraction of the term sum assured
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub max_sum_assured_percentage: Decimal,
+}
+
+impl ConversionRules {
+    /// Reads the conversion rules from a catalog product entry
+    ///
+    /// Returns `None` when the product has no `conversion_rules` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the block is present but malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        product
+            .get("conversion_rules")
+            .map(|rules| {
+                serde_json::from_value(rules.clone())
+                    .map_err(|e| PolicyError::validation(format!("Invalid conversion_rules: {}", e)))
+            })
+            .transpose()
+    }
+}
+
+/// A request to convert a term policy
+#[derive(Debug, Clone)]
+pub struct ConversionRequest {
+    /// Permanent product to convert to
+    pub target_product: String,
+    /// Date the conversion takes effect
+    pub conversion_date: NaiveDate,
+    /// Age of the insured on the conversion date, used when the term
+    /// policy records no insured life
+    pub attained_age: u32,
+    /// Sum assured to convert; the full term sum assured if `None`
+    pub sum_assured: Option<Money>,
+    /// Premium frequency for the new policy; the term frequency if `None`
+    pub frequency: Option<PremiumFrequency>,
+    /// Whether the insured smokes, used when the term policy records no
+    /// insured life
+    pub is_smoker: bool,
+}
+
+impl ConversionRequest {
+    /// Creates a request converting the full sum assured
+    pub fn new(target_product: impl Into<String>, conversion_date: NaiveDate, attained_age: u32) -> Self {
+        Self {
+            target_product: target_product.into(),
+            conversion_date,
+            attained_age,
+            sum_assured: None,
+            frequency: None,
+            is_smoker: false,
+        }
+    }
+
+    /// Converts part of the sum assured
+    pub fn with_sum_assured(mut self, sum_assured: Money) -> Self {
+        self.sum_assured = Some(sum_assured);
+        self
+    }
+
+    /// Sets the premium frequency for the new policy
+    pub fn with_frequency(mut self, frequency: PremiumFrequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Marks the insured as a smoker
+    pub fn smoker(mut self, is_smoker: bool) -> Self {
+        self.is_smoker = is_smoker;
+        self
+    }
+}
+
+/// Audit record linking a converted term policy to its replacement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversionRecord {
+    /// Term policy converted
+    pub original_policy_id: PolicyId,
+    /// Term policy number
+    pub original_policy_number: String,
+    /// Term product code
+    pub original_product: String,
+    /// Policy issued on conversion
+    pub new_policy_id: PolicyId,
+    /// New policy number
+    pub new_policy_number: String,
+    /// Permanent product issued
+    pub new_product: String,
+    /// Date the conversion took effect
+    pub conversion_date: NaiveDate,
+    /// Age of the insured at conversion
+    pub attained_age: u32,
+    /// Risk class carried over from the term policy
+    pub risk_class: RiskClass,
+    /// Sum assured converted
+    pub sum_assured: Money,
+    /// Annual premium of the new policy
+    pub annual_premium: Money,
+}
+
+/// Result of converting a term policy
+#[derive(Debug, Clone)]
+pub struct ConversionOutcome {
+    /// The new, in-force permanent policy
+    pub policy: Policy,
+    /// Audit record of the conversion
+    pub record: ConversionRecord,
+}
+
+/// Service converting term policies to permanent products
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let service = ConversionService::from_catalog(&catalog)?;
+///
+/// let request = ConversionRequest::new("WHOLE_LIFE_01", today, 47);
+/// let outcome = service.convert(&mut term_policy, &request)?;
+/// repository.save(&outcome.policy)?;
+/// ```
+#[derive(Default)]
+pub struct ConversionService {
+    product_rules: VersionedRules<ConversionRules>,
+    min_sum_assured: HashMap<String, Decimal>,
+    rating: RatingService,
+}
+
+impl ConversionService {
+    /// Creates a service with no convertible products
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a service with per-product rules read from the catalog
+    ///
+    /// Products whose `features.convertible` flag is false are not
+    /// convertible even if they have conversion rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `conversion_rules`
+    pub fn from_catalog(catalog: &Value) -> Result<Self, PolicyError> {
+        let mut service = Self::new();
+        if let Some(products) = catalog.get("products").and_then(|p| p.as_array()) {
+            for product in products {
+                let Some(code) = product.get("code").and_then(|c| c.as_str()) else {
+                    continue;
+                };
+                if let Some(min) = product
+                    .pointer("/limits/min_sum_assured")
+                    .and_then(|v| v.as_f64())
+                    .and_then(|f| Decimal::try_from(f).ok())
+                {
+                    service.min_sum_assured.insert(code.to_string(), min);
+                }
+            }
+        }
+        service.product_rules = VersionedRules::from_catalog(catalog, |product| {
+            let convertible = product
+                .pointer("/features/convertible")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            Ok(ConversionRules::from_catalog_product(product)?.filter(|_| convertible))
+        })?;
+        Ok(service)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: ConversionRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Sets the rating service used to price converted policies
+    pub fn with_rating(mut self, rating: RatingService) -> Self {
+        self.rating = rating;
+        self
+    }
+
+    /// Returns the rules of a product's latest version, if it is convertible
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> Option<&ConversionRules> {
+        self.product_rules.latest(product_code)
+    }
+
+    /// Returns the rules of the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> Option<&ConversionRules> {
+        self.product_rules.for_policy(policy)
+    }
+
+    /// Returns the last day on which a policy may be converted
+    ///
+    /// The window closes at the end of the conversion period or of the
+    /// term, whichever is earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product is not convertible or the policy has
+    /// not been issued
+    pub fn conversion_deadline(&self, policy: &Policy) -> Result<NaiveDate, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        let inception_date = policy.inception_date().ok_or_else(|| {
+            PolicyError::Conversion(format!("Policy {} has not been issued", policy.policy_number()))
+        })?;
+        let period_end = inception_date
+            .checked_add_months(Months::new(12 * rules.conversion_period_years))
+            .ok_or_else(|| PolicyError::Conversion("Conversion deadline out of range".to_string()))?;
+        let window_end = policy.expiry_date().map_or(period_end, |expiry| expiry.min(period_end));
+        window_end
+            .pred_opt()
+            .ok_or_else(|| PolicyError::Conversion("Conversion deadline out of range".to_string()))
+    }
+
+    /// Checks that a policy may be converted as requested
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product is not convertible, the policy is not
+    /// in force, the target product, date, age or sum assured fall outside
+    /// the conversion rules, or the policy has no recorded risk class
+    pub fn check_eligibility(&self, policy: &Policy, request: &ConversionRequest) -> Result<(), PolicyError> {
+        let rules = self.require_rules(policy)?;
+        if !matches!(policy.state(), PolicyState::InForce { .. } | PolicyState::Reinstated { .. }) {
+            return Err(PolicyError::Conversion(format!(
+                "Policy {} is not in force ({:?})",
+                policy.policy_number(),
+                policy.state()
+            )));
+        }
+        if !rules.target_products.contains(&request.target_product) {
+            return Err(PolicyError::Conversion(format!(
+                "Product {} cannot be converted to {}",
+                policy.product_code(),
+                request.target_product
+            )));
+        }
+
+        let deadline = self.conversion_deadline(policy)?;
+        if request.conversion_date > deadline {
+            return Err(PolicyError::Conversion(format!(
+                "Conversion window for policy {} closed on {}",
+                policy.policy_number(),
+                deadline
+            )));
+        }
+        let (attained_age, _) = insured_on(policy, request);
+        if attained_age > rules.max_conversion_age {
+            return Err(PolicyError::Conversion(format!(
+                "Attained age {} exceeds maximum conversion age {}",
+                attained_age, rules.max_conversion_age
+            )));
+        }
+
+        let term_sum_assured = term_sum_assured(policy)?;
+        let sum_assured = request.sum_assured.unwrap_or(term_sum_assured);
+        if sum_assured.currency() != policy.currency() {
+            return Err(PolicyError::CurrencyMismatch {
+                expected: policy.currency().to_string(),
+                actual: sum_assured.currency().to_string(),
+            });
+        }
+        let max_sum_assured = term_sum_assured.multiply(rules.max_sum_assured_percentage);
+        if sum_assured.amount() > max_sum_assured.amount() {
+            return Err(PolicyError::Conversion(format!(
+                "Sum assured {} exceeds convertible amount {}",
+                sum_assured, max_sum_assured
+            )));
+        }
+        if let Some(min) = self.min_sum_assured.get(&request.target_product) {
+            if sum_assured.amount() < *min {
+                return Err(PolicyError::Conversion(format!(
+                    "Sum assured {} is below the {} minimum of {}",
+                    sum_assured, request.target_product, min
+                )));
+            }
+        }
+
+        if policy.risk_class().is_none() {
+            return Err(PolicyError::Conversion(format!(
+                "Policy {} has no recorded risk class",
+                policy.policy_number()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Converts a term policy to a permanent policy
+    ///
+    /// The new policy is issued on the conversion date without
+    /// underwriting, priced at the attained age in the term policy's risk
+    /// class, and the term policy is terminated.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not eligible for conversion or the
+    /// new policy cannot be rated or issued
+    pub fn convert(&self, policy: &mut Policy, request: &ConversionRequest) -> Result<ConversionOutcome, PolicyError> {
+        self.check_eligibility(policy, request)?;
+        let risk_class = policy.risk_class().unwrap_or(RiskClass::Standard);
+        let (attained_age, is_smoker) = insured_on(policy, request);
+        let sum_assured = request.sum_assured.unwrap_or(term_sum_assured(policy)?);
+        let frequency = request.frequency.unwrap_or(policy.premium().frequency);
+
+        let coverage = Coverage::death_benefit(sum_assured);
+        let annual_premium = self
+            .rating
+            .calculate_premium(
+                std::slice::from_ref(&coverage),
+                attained_age,
+                is_smoker,
+                risk_class,
+                policy.currency(),
+            )?
+            .base_amount
+            .round_to_currency();
+        let modal_premium = annual_premium.multiply(frequency.modal_factor()).round_to_currency();
+
+        let mut builder = PolicyBuilder::new()
+            .product_code(request.target_product.clone())
+            .policyholder(policy.policyholder_id())
+            .currency(policy.currency())
+            .add_coverage(coverage)
+            .premium(Premium::new(modal_premium, frequency))
+            .risk_class(risk_class)
+            .converted_from(policy.id());
+        if let Some(insured) = policy.insured() {
+            builder = builder.insured(*insured);
+        }
+        for risk in policy.insured_risks() {
+            builder = builder.add_risk(risk.clone());
+        }
+        let mut new_policy = builder.build()?;
+        new_policy.issue(request.conversion_date, CONVERSION_UNDERWRITER)?;
+
+        policy.convert(new_policy.id(), request.conversion_date)?;
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            new_policy_number = %new_policy.policy_number(),
+            product = %request.target_product,
+            "Term policy converted"
+        );
+
+        let record = ConversionRecord {
+            original_policy_id: policy.id(),
+            original_policy_number: policy.policy_number().to_string(),
+            original_product: policy.product_code().to_string(),
+            new_policy_id: new_policy.id(),
+            new_policy_number: new_policy.policy_number().to_string(),
+            new_product: request.target_product.clone(),
+            conversion_date: request.conversion_date,
+            attained_age,
+            risk_class,
+            sum_assured,
+            annual_premium,
+        };
+
+        Ok(ConversionOutcome {
+            policy: new_policy,
+            record,
+        })
+    }
+
+    fn require_rules(&self, policy: &Policy) -> Result<&ConversionRules, PolicyError> {
+        self.product_rules.for_policy(policy).ok_or_else(|| {
+            PolicyError::Conversion(format!("Product {} is not convertible", policy.product_code()))
+        })
+    }
+}
+
+/// Age and smoker status of the insured on the conversion date
+///
+/// Taken from the insured life recorded on the term policy, falling back
+/// to the request for policies issued without one.
+fn insured_on(policy: &Policy, request: &ConversionRequest) -> (u32, bool) {
+    match policy.insured() {
+        Some(insured) => (insured.age_on(request.conversion_date), insured.is_smoker),
+        None => (request.attained_age, request.is_smoker),
+    }
+}
+
+fn term_sum_assured(policy: &Policy) -> Result<Money, PolicyError> {
+    policy
+        .coverages()
+        .iter()
+        .find(|c| c.is_active && c.coverage_type == CoverageType::DeathBenefit)
+        .map(|c| c.sum_assured)
+        .ok_or_else(|| PolicyError::coverage_not_found("DeathBenefit"))
+}
@@ -138,6 +138,9 @@ pub struct Coverage {
     pub expiry_date: Option<NaiveDate>,
     /// Additional loading percentage (if any)
     pub loading_percent: Option<Decimal>,
+    /// Flat extra premium per 1,000 sum assured (if any)
+    #[serde(default)]
+    pub flat_extra_per_mille: Option<Decimal>,
 }
 
 impl Coverage {
@@ -172,6 +175,7 @@ impl Coverage {
             effective_date: None,
             expiry_date: None,
             loading_percent: None,
+            flat_extra_per_mille: None,
         }
     }
 
@@ -228,6 +232,16 @@ impl Coverage {
         self
     }
 
+    /// Adds a flat extra premium to this coverage
+    ///
+    /// # Arguments
+    ///
+    /// * `per_mille` - Annual extra premium per 1,000 sum assured
+    pub fn with_flat_extra(mut self, per_mille: Decimal) -> Self {
+        self.flat_extra_per_mille = Some(per_mille);
+        self
+    }
+
     /// Checks if a claim type is covered
     ///
     /// # Arguments
@@ -0,0 +1,582 @@
+//! Premium delinquency processing
+//!
+//! This module drives the non-payment lifecycle of a policy. Overdue premium
+//! invoices start a product-configured grace period, the policy lapses
+//! automatically once the grace period expires, and a lapsed policy can be
+//! reinstated once arrears, interest and a reinstatement fee are settled.
+//! Products with nonforfeiture options convert a lapsing policy with a cash
+//! value to paid-up cover instead.
+//!
+//! # Lifecycle
+//!
+//! ```text
+//! Invoice due -> Overdue (grace period) -> Policy Lapsed -> Reinstated
+//! ```
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{InvoiceId, Money, PolicyId};
+use domain_billing::{Invoice, InvoiceItem, InvoiceStatus, Ledger};
+use domain_billing::invoice::InvoiceItemType;
+
+use crate::aggregate::{LapseReason, Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::error::PolicyError;
+use crate::loan::PolicyLoan;
+use crate::nonforfeiture::{NonforfeitureEngine, NonforfeitureQuote};
+use crate::underwriting::{RiskClass, UnderwritingDecision};
+
+/// Product-level lapse and reinstatement rules
+///
+/// Loaded from the `lapse_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LapseRules {
+    /// Days after the due date before an unpaid policy lapses
+    pub grace_period_days: u32,
+    /// Days after lapse during which the policy may be reinstated
+    pub reinstatement_period_days: u32,
+    /// Annual simple interest rate charged on arrears at reinstatement
+    pub reinstatement_interest_rate: Decimal,
+    /// Flat reinstatement fee
+    pub reinstatement_fee: Decimal,
+    /// Days after lapse beyond which fresh evidence of insurability is required
+    pub evidence_required_after_days: Option<u32>,
+}
+
+impl Default for LapseRules {
+    fn default() -> Self {
+        Self {
+            grace_period_days: 31,
+            reinstatement_period_days: 730,
+            reinstatement_interest_rate: dec!(0.06),
+            reinstatement_fee: Decimal::ZERO,
+            evidence_required_after_days: Some(90),
+        }
+    }
+}
+
+impl LapseRules {
+    /// Reads lapse rules from a catalog product entry
+    ///
+    /// Products without a `lapse_rules` block use the defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - A product object from `catalog.json`
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the `lapse_rules` block is malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Self, PolicyError> {
+        match product.get("lapse_rules") {
+            Some(rules) => serde_json::from_value(rules.clone())
+                .map_err(|e| PolicyError::validation(format!("Invalid lapse_rules: {}", e))),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+/// Outcome of running delinquency processing for one invoice
+#[derive(Debug, Clone, PartialEq)]
+pub enum DelinquencyOutcome {
+    /// Invoice is settled or the policy is not in a billable state
+    NoAction,
+    /// Invoice is not yet past due
+    Current,
+    /// Invoice became overdue and the grace period started
+    GracePeriodStarted {
+        /// Last day of the grace period
+        grace_end_date: NaiveDate,
+    },
+    /// Invoice is overdue but still within the grace period
+    InGracePeriod {
+        /// Last day of the grace period
+        grace_end_date: NaiveDate,
+        /// Days since the due date
+        days_overdue: u32,
+    },
+    /// Grace period expired and the policy lapsed
+    Lapsed {
+        /// Days elapsed since the due date
+        grace_days_elapsed: u32,
+        /// Amount outstanding at lapse
+        outstanding: Money,
+    },
+    /// Grace period expired and the cash value bought paid-up cover under
+    /// the product's default nonforfeiture option
+    PaidUp {
+        /// Days elapsed since the due date
+        grace_days_elapsed: u32,
+        /// Amount outstanding at lapse
+        outstanding: Money,
+        /// Paid-up cover applied
+        nonforfeiture: NonforfeitureQuote,
+    },
+}
+
+/// Amounts required to reinstate a lapsed policy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReinstatementQuote {
+    /// Policy being reinstated
+    pub policy_id: PolicyId,
+    /// Date the quote was calculated for
+    pub as_of: NaiveDate,
+    /// Unpaid premium arrears
+    pub arrears: Money,
+    /// Interest on arrears
+    pub interest: Money,
+    /// Reinstatement fee
+    pub fee: Money,
+    /// Whether fresh evidence of insurability is required
+    pub evidence_required: bool,
+    /// Invoices making up the arrears
+    pub invoice_ids: Vec<InvoiceId>,
+}
+
+impl ReinstatementQuote {
+    /// Returns the total amount payable to reinstate
+    pub fn total(&self) -> Money {
+        self.arrears + self.interest + self.fee
+    }
+}
+
+/// Service driving grace periods, automatic lapse and reinstatement
+///
+/// Rules are held per product code; products without explicit rules fall
+/// back to the default rules.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = DelinquencyEngine::from_catalog(&catalog)?;
+/// match engine.process_invoice(&mut policy, &mut invoice, today)? {
+///     DelinquencyOutcome::Lapsed { .. } => notify_lapse(&policy),
+///     _ => {}
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DelinquencyEngine {
+    /// Lapse rules keyed by product code
+    product_rules: VersionedRules<LapseRules>,
+    /// Rules used when a product has none configured
+    default_rules: LapseRules,
+    /// Nonforfeiture options applied when a policy with a cash value lapses
+    nonforfeiture: Option<NonforfeitureEngine>,
+}
+
+impl DelinquencyEngine {
+    /// Creates an engine with default rules only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds an engine from a product catalog document
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The parsed `catalog.json` document
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed lapse rules
+    pub fn from_catalog(catalog: &Value) -> Result<Self, PolicyError> {
+        let mut engine = Self::new();
+        engine.product_rules = VersionedRules::from_catalog(catalog, |product| LapseRules::from_catalog_product(product).map(Some))?;
+        Ok(engine)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: LapseRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Sets the fallback rules
+    pub fn with_default_rules(mut self, rules: LapseRules) -> Self {
+        self.default_rules = rules;
+        self
+    }
+
+    /// Applies the product's default nonforfeiture option on lapse
+    ///
+    /// Lapsing policies whose product has nonforfeiture rules, whose
+    /// insured is recorded and that have a cash value are converted to
+    /// paid-up cover instead of being left lapsed.
+    pub fn with_nonforfeiture(mut self, nonforfeiture: NonforfeitureEngine) -> Self {
+        self.nonforfeiture = Some(nonforfeiture);
+        self
+    }
+
+    /// Returns the rules that apply to a product's latest version
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> &LapseRules {
+        self.product_rules.latest(product_code).unwrap_or(&self.default_rules)
+    }
+
+    /// Returns the rules that apply to the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> &LapseRules {
+        self.product_rules.for_policy(policy).unwrap_or(&self.default_rules)
+    }
+
+    /// Returns the last day of a policy's grace period for an invoice due on `due_date`
+    pub fn grace_end_date(&self, policy: &Policy, due_date: NaiveDate) -> NaiveDate {
+        due_date + chrono::Duration::days(self.rules_for_policy(policy).grace_period_days as i64)
+    }
+
+    /// Processes a premium invoice for delinquency
+    ///
+    /// This method:
+    /// 1. Ignores settled invoices, policies that are not in force and
+    ///    premiums falling due in a waiver of premium period
+    /// 2. Marks past-due invoices as `Overdue` and starts the grace period
+    /// 3. Lapses the policy with `LapseReason::NonPayment` once grace expires
+    /// 4. Applies the default nonforfeiture option to a lapsing policy with
+    ///    a cash value, if configured with [`Self::with_nonforfeiture`]
+    ///
+    /// Policies with a loan are left lapsed; process them with
+    /// [`Self::process_invoice_with_loan`] so the loan is offset against
+    /// the cash value.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy the invoice belongs to
+    /// * `invoice` - The premium invoice
+    /// * `as_of` - Processing date
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the invoice belongs to another policy or a state
+    /// transition fails
+    pub fn process_invoice(
+        &self,
+        policy: &mut Policy,
+        invoice: &mut Invoice,
+        as_of: NaiveDate,
+    ) -> Result<DelinquencyOutcome, PolicyError> {
+        self.process(policy, invoice, as_of, None)
+    }
+
+    /// Processes a premium invoice for a policy with a loan account
+    ///
+    /// As [`Self::process_invoice`]; if the policy lapses into a
+    /// nonforfeiture option, the loan is first offset against the cash
+    /// value through the nonforfeiture engine's `LoanEngine`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the invoice belongs to another policy, a state
+    /// transition fails or the loan offset cannot be posted
+    pub fn process_invoice_with_loan(
+        &self,
+        ledger: &mut Ledger,
+        loan: &mut PolicyLoan,
+        policy: &mut Policy,
+        invoice: &mut Invoice,
+        as_of: NaiveDate,
+    ) -> Result<DelinquencyOutcome, PolicyError> {
+        self.process(policy, invoice, as_of, Some((ledger, loan)))
+    }
+
+    fn process(
+        &self,
+        policy: &mut Policy,
+        invoice: &mut Invoice,
+        as_of: NaiveDate,
+        loan: Option<(&mut Ledger, &mut PolicyLoan)>,
+    ) -> Result<DelinquencyOutcome, PolicyError> {
+        if invoice.policy_id != policy.id() {
+            return Err(PolicyError::validation(format!(
+                "Invoice {} does not belong to policy {}",
+                invoice.invoice_number,
+                policy.policy_number()
+            )));
+        }
+
+        if !invoice.is_open()
+            || !matches!(policy.state(), PolicyState::InForce { .. } | PolicyState::Reinstated { .. })
+            || policy.is_premium_waived(invoice.due_date)
+        {
+            return Ok(DelinquencyOutcome::NoAction);
+        }
+
+        let days_overdue = invoice.days_overdue(as_of);
+        if days_overdue == 0 {
+            return Ok(DelinquencyOutcome::Current);
+        }
+
+        let rules = self.rules_for_policy(policy);
+        let grace_end_date = self.grace_end_date(policy, invoice.due_date);
+        let outstanding = invoice.balance_due();
+        let newly_overdue = invoice.mark_overdue();
+
+        if as_of > grace_end_date {
+            policy.lapse_on(
+                LapseReason::NonPayment {
+                    grace_days_elapsed: days_overdue,
+                    outstanding_amount: outstanding.amount(),
+                },
+                Some(rules.reinstatement_period_days),
+                as_of,
+            )?;
+
+            tracing::info!(
+                policy_number = %policy.policy_number(),
+                days_overdue,
+                "Policy lapsed for non-payment"
+            );
+
+            if let Some(nonforfeiture) = self.apply_nonforfeiture(policy, as_of, loan)? {
+                return Ok(DelinquencyOutcome::PaidUp {
+                    grace_days_elapsed: days_overdue,
+                    outstanding,
+                    nonforfeiture,
+                });
+            }
+
+            return Ok(DelinquencyOutcome::Lapsed {
+                grace_days_elapsed: days_overdue,
+                outstanding,
+            });
+        }
+
+        if newly_overdue {
+            policy.start_grace_period(outstanding, days_overdue, grace_end_date)?;
+            return Ok(DelinquencyOutcome::GracePeriodStarted { grace_end_date });
+        }
+
+        Ok(DelinquencyOutcome::InGracePeriod {
+            grace_end_date,
+            days_overdue,
+        })
+    }
+
+    /// Applies the default nonforfeiture option to a lapsed policy
+    ///
+    /// Returns `None` if no option applies: nonforfeiture is not
+    /// configured or not offered by the product, the insured is not
+    /// recorded, there is no net cash value, or a loan is outstanding and
+    /// no loan account was given.
+    fn apply_nonforfeiture(
+        &self,
+        policy: &mut Policy,
+        as_of: NaiveDate,
+        loan: Option<(&mut Ledger, &mut PolicyLoan)>,
+    ) -> Result<Option<NonforfeitureQuote>, PolicyError> {
+        let Some(engine) = &self.nonforfeiture else {
+            return Ok(None);
+        };
+        if engine.rules_for_policy(policy).is_none() {
+            return Ok(None);
+        }
+        let Some(attained_age) = policy.insured().map(|insured| insured.age_on(as_of)) else {
+            tracing::warn!(
+                policy_number = %policy.policy_number(),
+                "Nonforfeiture not applied: insured not recorded"
+            );
+            return Ok(None);
+        };
+
+        let financials = policy.financial_state();
+        let currency = policy.currency();
+        let cash_value = financials.surrender_value.unwrap_or(Money::zero(currency));
+        let loan_outstanding = financials.loan_outstanding.unwrap_or(Money::zero(currency));
+        if !(cash_value - loan_outstanding).is_positive() {
+            return Ok(None);
+        }
+
+        let quote = match loan {
+            Some((ledger, loan)) => engine.apply_with_loan(ledger, policy, loan, None, attained_age, as_of)?,
+            None if loan_outstanding.is_positive() => {
+                tracing::warn!(
+                    policy_number = %policy.policy_number(),
+                    "Nonforfeiture not applied: policy loan must be offset"
+                );
+                return Ok(None);
+            }
+            None => engine.apply(policy, None, attained_age, as_of)?,
+        };
+        Ok(Some(quote))
+    }
+
+    /// Calculates the amounts required to reinstate a lapsed policy
+    ///
+    /// Interest accrues at the product's simple annual rate on each unpaid
+    /// invoice from its due date to `as_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The lapsed policy
+    /// * `arrears` - Invoices for the policy; settled invoices are ignored
+    /// * `as_of` - Reinstatement date
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not lapsed or the reinstatement
+    /// period has expired
+    pub fn quote_reinstatement(
+        &self,
+        policy: &Policy,
+        arrears: &[Invoice],
+        as_of: NaiveDate,
+    ) -> Result<ReinstatementQuote, PolicyError> {
+        let (lapse_date, deadline) = match policy.state() {
+            PolicyState::Lapsed {
+                effective_date,
+                reinstatement_deadline,
+                ..
+            } => (effective_date.date_naive(), reinstatement_deadline.map(|d| d.date_naive())),
+            other => {
+                return Err(PolicyError::InvalidStateTransition {
+                    from: format!("{:?}", other),
+                    to: "Reinstated".to_string(),
+                })
+            }
+        };
+
+        if deadline.is_some_and(|d| as_of > d) {
+            return Err(PolicyError::ReinstatementPeriodExpired);
+        }
+
+        let rules = self.rules_for_policy(policy);
+        let currency = policy.currency();
+        let mut total_arrears = Money::zero(currency);
+        let mut interest = Money::zero(currency);
+        let mut invoice_ids = Vec::new();
+
+        for invoice in arrears
+            .iter()
+            .filter(|i| i.policy_id == policy.id() && i.is_open())
+        {
+            let balance = invoice.balance_due();
+            let days = Decimal::from(invoice.days_overdue(as_of));
+            total_arrears = total_arrears + balance;
+            interest = interest + balance * (rules.reinstatement_interest_rate * days / dec!(365));
+            invoice_ids.push(invoice.id);
+        }
+
+        let days_lapsed = (as_of - lapse_date).num_days().max(0) as u32;
+        let evidence_required = rules
+            .evidence_required_after_days
+            .is_some_and(|limit| days_lapsed > limit);
+
+        Ok(ReinstatementQuote {
+            policy_id: policy.id(),
+            as_of,
+            arrears: total_arrears,
+            interest: interest.round_to_currency(),
+            fee: Money::new(rules.reinstatement_fee, currency),
+            evidence_required,
+            invoice_ids,
+        })
+    }
+
+    /// Builds the invoice collecting a reinstatement quote
+    ///
+    /// The invoice carries separate lines for premium arrears, arrears
+    /// interest and the `ReinstatementFee`.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The lapsed policy
+    /// * `quote` - The reinstatement quote
+    /// * `due_date` - Payment due date
+    pub fn reinstatement_invoice(
+        &self,
+        policy: &Policy,
+        quote: &ReinstatementQuote,
+        due_date: NaiveDate,
+    ) -> Invoice {
+        let mut invoice = Invoice::new(policy.id(), policy.policyholder_id(), due_date, policy.currency());
+
+        invoice.add_item(InvoiceItem::new("Premium arrears", InvoiceItemType::Premium, quote.arrears));
+        if quote.interest.is_positive() {
+            invoice.add_item(InvoiceItem::new("Interest on arrears", InvoiceItemType::Interest, quote.interest));
+        }
+        if quote.fee.is_positive() {
+            invoice.add_item(InvoiceItem::new("Reinstatement fee", InvoiceItemType::ReinstatementFee, quote.fee));
+        }
+
+        invoice.notes = Some(format!("Reinstatement of policy {}", policy.policy_number()));
+        invoice.issue();
+        invoice
+    }
+
+    /// Reinstates a lapsed policy once the reinstatement invoice is paid
+    ///
+    /// On success the underlying arrears invoices are settled and the
+    /// premium is recorded against the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The lapsed policy
+    /// * `quote` - The reinstatement quote
+    /// * `reinstatement_invoice` - The paid reinstatement invoice
+    /// * `arrears` - The invoices making up the arrears
+    /// * `evidence` - Underwriting decision on fresh evidence of insurability
+    /// * `as_of` - Reinstatement date
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the invoice is unpaid, evidence is missing or
+    /// declined, or the policy cannot be reinstated on `as_of`
+    pub fn reinstate(
+        &self,
+        policy: &mut Policy,
+        quote: &ReinstatementQuote,
+        reinstatement_invoice: &Invoice,
+        arrears: &mut [Invoice],
+        evidence: Option<&UnderwritingDecision>,
+        as_of: NaiveDate,
+    ) -> Result<(), PolicyError> {
+        if quote.policy_id != policy.id() || reinstatement_invoice.policy_id != policy.id() {
+            return Err(PolicyError::validation("Reinstatement documents do not match the policy"));
+        }
+
+        if reinstatement_invoice.status != InvoiceStatus::Paid
+            || reinstatement_invoice.amount_paid.amount() < quote.total().amount()
+        {
+            return Err(PolicyError::validation(format!(
+                "Reinstatement invoice {} has not been settled",
+                reinstatement_invoice.invoice_number
+            )));
+        }
+
+        if quote.evidence_required {
+            match evidence {
+                None => {
+                    return Err(PolicyError::Underwriting(
+                        "Evidence of insurability is required for reinstatement".to_string(),
+                    ))
+                }
+                Some(decision) if decision.risk_class == RiskClass::Declined => {
+                    return Err(PolicyError::Underwriting(
+                        "Evidence of insurability was declined".to_string(),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        policy.reinstate_on(as_of)?;
+
+        for invoice in arrears
+            .iter_mut()
+            .filter(|i| quote.invoice_ids.contains(&i.id) && i.is_open())
+        {
+            let balance = invoice.balance_due();
+            invoice.record_payment(balance);
+        }
+
+        if quote.arrears.is_positive() {
+            policy.record_payment(quote.arrears)?;
+        }
+
+        Ok(())
+    }
+}
@@ -9,7 +9,8 @@ use uuid::Uuid;
 
 use core_kernel::{EndorsementId, PartyId};
 use crate::coverage::{Coverage, CoverageModification};
-use crate::premium::Premium;
+use crate::premium::{Premium, RiderPremium};
+use crate::rider::Rider;
 
 /// Types of endorsements that can be applied to a policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -90,6 +91,22 @@ pub enum EndorsementType {
         allocations: Vec<FundAllocation>,
     },
 
+    /// Rider attached to a base coverage
+    RiderAddition {
+        /// Rider attachment
+        rider: Box<Rider>,
+        /// Coverage provided by the rider
+        coverage: Coverage,
+        /// Premium for the rider
+        premium: RiderPremium,
+    },
+
+    /// Rider removed at the policyholder's request
+    RiderRemoval {
+        /// Rider code
+        rider_code: String,
+    },
+
     /// Free-form endorsement
     Custom {
         /// Endorsement code
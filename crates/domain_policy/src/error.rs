@@ -74,6 +74,58 @@ pub enum PolicyError {
     #[error("Policy loan error: {0}")]
     PolicyLoan(String),
 
+    /// Commission calculation error
+    #[error("Commission error: {0}")]
+    Commission(String),
+
+    /// Cancellation refund error
+    #[error("Cancellation error: {0}")]
+    Cancellation(String),
+
+    /// Cash value or dividend processing error
+    #[error("Cash value error: {0}")]
+    CashValue(String),
+
+    /// Nonforfeiture option error
+    #[error("Nonforfeiture error: {0}")]
+    Nonforfeiture(String),
+
+    /// Term conversion error
+    #[error("Conversion error: {0}")]
+    Conversion(String),
+
+    /// Renewal processing error
+    #[error("Renewal error: {0}")]
+    Renewal(String),
+
+    /// Rider error
+    #[error("Rider error: {0}")]
+    Rider(String),
+
+    /// Waiver of premium error
+    #[error("Waiver error: {0}")]
+    Waiver(String),
+
+    /// Product catalog error
+    #[error("Product catalog error: {0}")]
+    Catalog(String),
+
+    /// Rule-change impact simulation error
+    #[error("Simulation error: {0}")]
+    Simulation(String),
+
+    /// Impairment rating manual error
+    #[error("Impairment rating error: {0}")]
+    Impairment(String),
+
+    /// Benefit illustration error
+    #[error("Illustration error: {0}")]
+    Illustration(String),
+
+    /// Quoting error
+    #[error("Quoting error: {0}")]
+    Quoting(String),
+
     /// Validation error
     #[error("Validation error: {0}")]
     Validation(String),
@@ -10,7 +10,9 @@ use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use core_kernel::{EndorsementId, PolicyId};
+use uuid::Uuid;
+
+use core_kernel::{ClaimId, EndorsementId, PolicyId};
 
 /// Domain events emitted by the Policy aggregate
 ///
@@ -155,6 +157,68 @@ pub enum PolicyEvent {
         currency: String,
         timestamp: DateTime<Utc>,
     },
+
+    /// Policy converted to a new policy
+    PolicyConverted {
+        policy_id: PolicyId,
+        new_policy_id: PolicyId,
+        conversion_date: NaiveDate,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// Cash value applied under a nonforfeiture option
+    NonforfeitureApplied {
+        policy_id: PolicyId,
+        option: String,
+        sum_assured: Decimal,
+        currency: String,
+        expiry_date: Option<NaiveDate>,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// A rider has been attached to a base coverage
+    RiderAttached {
+        policy_id: PolicyId,
+        rider_code: String,
+        coverage_id: Uuid,
+        base_coverage_id: Uuid,
+        sum_assured: Decimal,
+        currency: String,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// A rider has ended
+    RiderTerminated {
+        policy_id: PolicyId,
+        rider_code: String,
+        effective_date: NaiveDate,
+        reason: String,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// A benefit illustration has been attached to a quote
+    IllustrationAttached {
+        policy_id: PolicyId,
+        illustration_id: Uuid,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// Premiums are waived following a disability claim
+    PremiumWaiverStarted {
+        policy_id: PolicyId,
+        claim_id: ClaimId,
+        start_date: NaiveDate,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// Premiums are payable again
+    PremiumWaiverEnded {
+        policy_id: PolicyId,
+        claim_id: ClaimId,
+        end_date: NaiveDate,
+        reason: String,
+        timestamp: DateTime<Utc>,
+    },
 }
 
 /// Types of underwriting decisions
@@ -196,6 +260,13 @@ impl PolicyEvent {
             PolicyEvent::BeneficiaryChanged { policy_id, .. } => *policy_id,
             PolicyEvent::PolicyLoanTaken { policy_id, .. } => *policy_id,
             PolicyEvent::PolicyLoanRepaid { policy_id, .. } => *policy_id,
+            PolicyEvent::NonforfeitureApplied { policy_id, .. } => *policy_id,
+            PolicyEvent::PolicyConverted { policy_id, .. } => *policy_id,
+            PolicyEvent::RiderAttached { policy_id, .. } => *policy_id,
+            PolicyEvent::RiderTerminated { policy_id, .. } => *policy_id,
+            PolicyEvent::PremiumWaiverStarted { policy_id, .. } => *policy_id,
+            PolicyEvent::PremiumWaiverEnded { policy_id, .. } => *policy_id,
+            PolicyEvent::IllustrationAttached { policy_id, .. } => *policy_id,
         }
     }
 
@@ -220,6 +291,13 @@ impl PolicyEvent {
             PolicyEvent::BeneficiaryChanged { timestamp, .. } => *timestamp,
             PolicyEvent::PolicyLoanTaken { timestamp, .. } => *timestamp,
             PolicyEvent::PolicyLoanRepaid { timestamp, .. } => *timestamp,
+            PolicyEvent::NonforfeitureApplied { timestamp, .. } => *timestamp,
+            PolicyEvent::PolicyConverted { timestamp, .. } => *timestamp,
+            PolicyEvent::RiderAttached { timestamp, .. } => *timestamp,
+            PolicyEvent::RiderTerminated { timestamp, .. } => *timestamp,
+            PolicyEvent::PremiumWaiverStarted { timestamp, .. } => *timestamp,
+            PolicyEvent::PremiumWaiverEnded { timestamp, .. } => *timestamp,
+            PolicyEvent::IllustrationAttached { timestamp, .. } => *timestamp,
         }
     }
 
@@ -244,6 +322,13 @@ impl PolicyEvent {
             PolicyEvent::BeneficiaryChanged { .. } => "BeneficiaryChanged",
             PolicyEvent::PolicyLoanTaken { .. } => "PolicyLoanTaken",
             PolicyEvent::PolicyLoanRepaid { .. } => "PolicyLoanRepaid",
+            PolicyEvent::NonforfeitureApplied { .. } => "NonforfeitureApplied",
+            PolicyEvent::PolicyConverted { .. } => "PolicyConverted",
+            PolicyEvent::RiderAttached { .. } => "RiderAttached",
+            PolicyEvent::RiderTerminated { .. } => "RiderTerminated",
+            PolicyEvent::PremiumWaiverStarted { .. } => "PremiumWaiverStarted",
+            PolicyEvent::PremiumWaiverEnded { .. } => "PremiumWaiverEnded",
+            PolicyEvent::IllustrationAttached { .. } => "IllustrationAttached",
         }
     }
 }
@@ -0,0 +1,376 @@
+//! Aggregate exposure per life
+//!
+//! The sum assured an applicant declares as existing cover is not enough to
+//! underwrite large cases: the company must know its own total sum at risk
+//! on the life. This module totals the in-force and pending policies on a
+//! life, including those held under duplicate party records, and checks
+//! the total with the new application against:
+//!
+//! - the per-life retention, above which the excess must be placed with a
+//!   reinsurer facultatively
+//! - the financial underwriting limit, a multiple of annual income set by
+//!   age band, above which the case is referred for manual review
+//!
+//! The income multiple is applied to the larger of the declared existing
+//! cover and the in-house total, since declared cover includes other
+//! insurers and in-house cover may have gone undisclosed.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! // Policies on every party record matching the applicant
+//! let mut existing = Vec::new();
+//! for party in parties.iter().filter(|p| LifeExposure::is_same_life(&applicant, p)) {
+//!     for row in repo.find_by_party(*party.id.as_uuid()).await? {
+//!         existing.push(ExistingPolicy::new(row.policy_number, party.id, status, sum_at_risk));
+//!     }
+//! }
+//!
+//! let exposure = LifeExposure::new(existing);
+//! let decision = service.evaluate_with_exposure(&application, &exposure, today)?;
+//!
+//! // Cases over a limit cannot be issued straight through
+//! if let Some(case) = case_engine.refer(&mut policy, &decision, today)? {
+//!     cases.push(case);
+//! }
+//! ```
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use core_kernel::{Currency, Money, PartyId, PolicyId};
+use domain_party::Party;
+
+use crate::aggregate::{Policy, PolicyState, RiskType};
+use crate::coverage::CoverageType;
+use crate::underwriting::{RuleImpact, RuleResult, UnderwritingApplication};
+use crate::underwriting_case::RequirementType;
+
+/// Whether a policy is in force or still being underwritten
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExposureStatus {
+    /// Policy in force
+    InForce,
+    /// Application pending underwriting
+    Pending,
+}
+
+/// A policy on the life held with the company
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExistingPolicy {
+    /// Policy number
+    pub policy_number: String,
+    /// Party record the policy is held under
+    pub party_id: PartyId,
+    /// In force or pending
+    pub status: ExposureStatus,
+    /// Death benefit payable on the life
+    pub sum_at_risk: Money,
+}
+
+impl ExistingPolicy {
+    /// Creates an existing policy entry
+    pub fn new(
+        policy_number: impl Into<String>,
+        party_id: PartyId,
+        status: ExposureStatus,
+        sum_at_risk: Money,
+    ) -> Self {
+        Self {
+            policy_number: policy_number.into(),
+            party_id,
+            status,
+            sum_at_risk,
+        }
+    }
+
+    /// Creates an entry from a policy
+    ///
+    /// The sum at risk is the total of the policy's active death cover.
+    /// The life is the primary insured, or the policyholder if no insured
+    /// person is recorded. Returns `None` for policies neither in force
+    /// nor pending underwriting.
+    pub fn from_policy(policy: &Policy) -> Option<Self> {
+        let status = match policy.state() {
+            PolicyState::InForce { .. } | PolicyState::Reinstated { .. } | PolicyState::PaidUp { .. } => {
+                ExposureStatus::InForce
+            }
+            PolicyState::PendingUnderwriting { .. } => ExposureStatus::Pending,
+            _ => return None,
+        };
+        let party_id = policy
+            .insured_risks()
+            .iter()
+            .find_map(|risk| match &risk.risk_type {
+                RiskType::Person { party_id, .. } => Some(*party_id),
+                _ => None,
+            })
+            .unwrap_or_else(|| policy.policyholder_id());
+        let sum_at_risk = policy
+            .coverages()
+            .iter()
+            .filter(|c| c.is_active && is_death_cover(&c.coverage_type))
+            .fold(Money::zero(policy.currency()), |total, c| total + c.sum_assured);
+
+        Some(Self::new(policy.policy_number(), party_id, status, sum_at_risk))
+    }
+}
+
+/// Whether a coverage pays a sum assured on death
+fn is_death_cover(coverage_type: &CoverageType) -> bool {
+    matches!(
+        coverage_type,
+        CoverageType::DeathBenefit | CoverageType::TermRider | CoverageType::WholeLifeRider
+    )
+}
+
+/// The company's existing exposure on one life
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LifeExposure {
+    /// In-force and pending policies on the life
+    pub policies: Vec<ExistingPolicy>,
+}
+
+impl LifeExposure {
+    /// Creates an exposure from existing policies
+    pub fn new(policies: Vec<ExistingPolicy>) -> Self {
+        Self { policies }
+    }
+
+    /// Collects the exposure on a life from policies held under any party
+    /// record matching it
+    ///
+    /// The policy being underwritten is left out, since its cover is
+    /// counted as the sum requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `life` - The applicant's party record
+    /// * `parties` - Party records to match against the applicant
+    /// * `policies` - Policies held under those records
+    /// * `underwriting` - The policy being underwritten
+    pub fn for_life(life: &Party, parties: &[Party], policies: &[Policy], underwriting: PolicyId) -> Self {
+        let mut party_ids = vec![life.id];
+        party_ids.extend(
+            parties
+                .iter()
+                .filter(|p| Self::is_same_life(life, p))
+                .map(|p| p.id),
+        );
+        Self::new(
+            policies
+                .iter()
+                .filter(|p| p.id() != underwriting)
+                .filter_map(ExistingPolicy::from_policy)
+                .filter(|p| party_ids.contains(&p.party_id))
+                .collect(),
+        )
+    }
+
+    /// Whether two party records describe the same person
+    pub fn is_same_life(a: &Party, b: &Party) -> bool {
+        a.id == b.id
+            || matches!((&a.individual, &b.individual), (Some(a), Some(b)) if a.is_same_person(b))
+    }
+
+    /// Total sum at risk in a currency
+    pub fn total(&self, currency: Currency) -> Money {
+        self.policies
+            .iter()
+            .filter(|p| p.sum_at_risk.currency() == currency)
+            .fold(Money::zero(currency), |total, p| total + p.sum_at_risk)
+    }
+
+    /// Total sum at risk on policies with a status
+    pub fn total_with_status(&self, status: ExposureStatus, currency: Currency) -> Money {
+        self.policies
+            .iter()
+            .filter(|p| p.status == status && p.sum_at_risk.currency() == currency)
+            .fold(Money::zero(currency), |total, p| total + p.sum_at_risk)
+    }
+}
+
+/// Maximum total cover as a multiple of income for an age band
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncomeMultiple {
+    /// Lowest age in the band
+    pub min_age: u32,
+    /// Highest age in the band (None for no limit)
+    pub max_age: Option<u32>,
+    /// Multiple of annual income
+    pub multiple: Decimal,
+}
+
+/// Limits on the total cover on one life
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExposureRules {
+    /// Most the company keeps on one life
+    pub per_life_retention: Decimal,
+    /// Financial underwriting limits by age band
+    pub income_multiples: Vec<IncomeMultiple>,
+}
+
+impl Default for ExposureRules {
+    fn default() -> Self {
+        let band = |min_age, max_age, multiple| IncomeMultiple { min_age, max_age, multiple };
+        Self {
+            per_life_retention: dec!(1000000),
+            income_multiples: vec![
+                band(0, Some(39), dec!(30)),
+                band(40, Some(49), dec!(20)),
+                band(50, Some(59), dec!(15)),
+                band(60, Some(64), dec!(10)),
+                band(65, None, dec!(5)),
+            ],
+        }
+    }
+}
+
+impl ExposureRules {
+    /// Returns the income multiple for an age
+    pub fn income_multiple_for(&self, age: u32) -> Option<Decimal> {
+        self.income_multiples
+            .iter()
+            .find(|band| age >= band.min_age && band.max_age.is_none_or(|max| age <= max))
+            .map(|band| band.multiple)
+    }
+
+    /// Checks an application against the limits
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The new application
+    /// * `exposure` - The company's existing exposure on the life
+    pub fn check(&self, application: &UnderwritingApplication, exposure: &LifeExposure) -> ExposureCheck {
+        let currency = application
+            .coverages
+            .first()
+            .map(|c| c.sum_assured.currency())
+            .unwrap_or(Currency::USD);
+        let requested = application
+            .coverages
+            .iter()
+            .filter(|c| is_death_cover(&c.coverage_type) && c.sum_assured.currency() == currency)
+            .fold(Money::zero(currency), |total, c| total + c.sum_assured);
+        let in_force = exposure.total_with_status(ExposureStatus::InForce, currency);
+        let pending = exposure.total_with_status(ExposureStatus::Pending, currency);
+        let total = in_force + pending + requested;
+
+        let mut reasons = Vec::new();
+        let existing = in_force + pending;
+        let declared = application.financial.existing_coverage;
+        if existing.amount() > declared {
+            reasons.push(format!(
+                "In-house cover of {} exceeds the {} declared",
+                existing, declared
+            ));
+        }
+
+        let age = application.applicant.age();
+        let financial_limit = self
+            .income_multiple_for(age)
+            .map(|multiple| Money::new(application.financial.annual_income * multiple, currency));
+        let financially_justified = match financial_limit {
+            Some(limit) => declared.max(existing.amount()) + requested.amount() <= limit.amount(),
+            None => false,
+        };
+        if !financially_justified {
+            reasons.push(format!(
+                "Total cover of {} is not justified by income at age {}",
+                Money::new(declared.max(existing.amount()) + requested.amount(), currency),
+                age
+            ));
+        }
+
+        let retention_excess = total.amount() - self.per_life_retention;
+        let routing = if !financially_justified {
+            ExposureRouting::ManualReview
+        } else if retention_excess > Decimal::ZERO {
+            reasons.push(format!(
+                "Total cover of {} exceeds the per-life retention of {}",
+                total, self.per_life_retention
+            ));
+            ExposureRouting::FacultativeReinsurance {
+                excess: Money::new(retention_excess, currency),
+            }
+        } else {
+            ExposureRouting::Automatic
+        };
+
+        ExposureCheck {
+            in_force,
+            pending,
+            requested,
+            total,
+            financial_limit,
+            routing,
+            reasons,
+        }
+    }
+}
+
+/// Where a case goes after the exposure check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExposureRouting {
+    /// Within retention and financial limits
+    Automatic,
+    /// Over the per-life retention; the excess needs facultative reinsurance
+    FacultativeReinsurance {
+        /// Total cover above the retention
+        excess: Money,
+    },
+    /// Over the financial underwriting limit; refer to an underwriter
+    ManualReview,
+}
+
+impl ExposureRouting {
+    /// Requirement an underwriter must clear before the case is accepted
+    ///
+    /// Returns `None` for automatic cases.
+    pub fn requirement(&self) -> Option<RequirementType> {
+        match self {
+            ExposureRouting::Automatic => None,
+            ExposureRouting::FacultativeReinsurance { .. } => Some(RequirementType::FacultativePlacement),
+            ExposureRouting::ManualReview => Some(RequirementType::FinancialReview),
+        }
+    }
+}
+
+/// Result of checking an application against the exposure on the life
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExposureCheck {
+    /// Sum at risk on in-force policies
+    pub in_force: Money,
+    /// Sum at risk on pending applications
+    pub pending: Money,
+    /// Death cover applied for
+    pub requested: Money,
+    /// In-house total including the application
+    pub total: Money,
+    /// Most cover the applicant's income justifies (None if no band applies)
+    pub financial_limit: Option<Money>,
+    /// Where the case goes
+    pub routing: ExposureRouting,
+    /// Findings behind the routing
+    pub reasons: Vec<String>,
+}
+
+impl ExposureCheck {
+    /// Rule result for the decision trace
+    pub fn rule_result(&self) -> RuleResult {
+        RuleResult {
+            rule_name: "aggregate_exposure".to_string(),
+            passed: self.routing == ExposureRouting::Automatic,
+            message: if self.reasons.is_empty() {
+                format!("Total cover of {} is within limits", self.total)
+            } else {
+                self.reasons.join("; ")
+            },
+            impact: match self.routing {
+                ExposureRouting::Automatic => RuleImpact::None,
+                _ => RuleImpact::Referral,
+            },
+        }
+    }
+}
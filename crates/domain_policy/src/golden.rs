@@ -0,0 +1,298 @@
+//! Golden-case regression harness for product rules
+//!
+//! Each rules file can have a case file next to it, named after the rules
+//! file with a `.cases.json` suffix (`term_life.json` is covered by
+//! `term_life.cases.json`). A case gives an input context and the
+//! `EvaluationResult` fields it must produce; fields not listed are not
+//! checked. Output values outside the standard result fields are looked up
+//! in `additional`.
+//!
+//! ```json
+//! {
+//!   "rules_file": "term_life.json",
+//!   "cases": [
+//!     {
+//!       "name": "non_smoker_35_standard_rate",
+//!       "context": {
+//!         "applicant": { "age": 35, "gender": "male" },
+//!         "medical": { "bmi": 24.0, "is_smoker": false }
+//!       },
+//!       "expected": {
+//!         "eligible": true,
+//!         "base_rate_per_thousand": 0.85,
+//!         "smoker_class": "non_smoker"
+//!       }
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! The runner evaluates every case through `RulesEngine::evaluate` and
+//! reports the fields that differ. It is used from tests and by the
+//! `rules-check` binary, which exits non-zero when a case fails so rule
+//! changes can be gated before deployment:
+//!
+//! ```bash
+//! cargo run -p domain_policy --bin rules-check -- products
+//! ```
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::rules_engine::{EvaluationResult, RulesEngine, RulesError};
+
+/// File name suffix of golden-case files
+pub const CASES_SUFFIX: &str = ".cases.json";
+
+/// A golden case: an input context and the outputs it must produce
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenCase {
+    /// Case name, unique within the suite
+    pub name: String,
+    /// What the case checks
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Evaluation context
+    pub context: Value,
+    /// Expected result fields
+    pub expected: Map<String, Value>,
+}
+
+/// The golden cases for one rules file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenSuite {
+    /// Rules file the cases run against, relative to the case file
+    pub rules_file: String,
+    /// Cases in the suite
+    pub cases: Vec<GoldenCase>,
+}
+
+impl GoldenSuite {
+    /// Reads a suite from a case file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or parsed
+    pub fn from_file(path: &Path) -> Result<Self, RulesError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|_| RulesError::FileNotFound(path.display().to_string()))?;
+        serde_json::from_str(&content)
+            .map_err(|e| RulesError::ParseError(format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// A result field that did not match
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDiff {
+    /// Result field
+    pub field: String,
+    /// Expected value
+    pub expected: Value,
+    /// Value produced; `None` if the field was not produced
+    pub actual: Option<Value>,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.actual {
+            Some(actual) => write!(f, "{}: expected {}, got {}", self.field, self.expected, actual),
+            None => write!(f, "{}: expected {}, not produced", self.field, self.expected),
+        }
+    }
+}
+
+/// Outcome of running one case
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseOutcome {
+    /// Case name
+    pub name: String,
+    /// Fields that did not match
+    pub diffs: Vec<FieldDiff>,
+    /// Evaluation error, if the rules could not be evaluated
+    pub error: Option<String>,
+}
+
+impl CaseOutcome {
+    /// Whether the case produced every expected field
+    pub fn passed(&self) -> bool {
+        self.diffs.is_empty() && self.error.is_none()
+    }
+}
+
+/// Outcome of running a suite
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuiteReport {
+    /// Case file the suite was read from
+    pub cases_file: PathBuf,
+    /// Product whose rules were run
+    pub product_code: String,
+    /// Rules version run
+    pub rules_version: String,
+    /// Case outcomes in suite order
+    pub outcomes: Vec<CaseOutcome>,
+}
+
+impl SuiteReport {
+    /// Whether every case passed
+    pub fn passed(&self) -> bool {
+        self.outcomes.iter().all(CaseOutcome::passed)
+    }
+
+    /// Cases that failed
+    pub fn failures(&self) -> impl Iterator<Item = &CaseOutcome> {
+        self.outcomes.iter().filter(|o| !o.passed())
+    }
+}
+
+impl fmt::Display for SuiteReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed = self.failures().count();
+        writeln!(
+            f,
+            "{} ({} v{}): {} passed, {} failed",
+            self.cases_file.display(),
+            self.product_code,
+            self.rules_version,
+            self.outcomes.len() - failed,
+            failed
+        )?;
+        for outcome in self.failures() {
+            writeln!(f, "  FAIL {}", outcome.name)?;
+            if let Some(error) = &outcome.error {
+                writeln!(f, "    {}", error)?;
+            }
+            for diff in &outcome.diffs {
+                writeln!(f, "    {}", diff)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs golden cases through the rules engine
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let runner = GoldenRunner::new();
+/// let reports = runner.run_dir(Path::new("products"))?;
+/// assert!(reports.iter().all(SuiteReport::passed));
+/// ```
+#[derive(Default)]
+pub struct GoldenRunner {
+    engine: RulesEngine,
+}
+
+impl GoldenRunner {
+    /// Creates a runner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs every case file in a directory
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the directory, a case file or a rules file cannot
+    /// be read
+    pub fn run_dir(&self, dir: &Path) -> Result<Vec<SuiteReport>, RulesError> {
+        let entries = std::fs::read_dir(dir)
+            .map_err(|_| RulesError::FileNotFound(dir.display().to_string()))?;
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.ends_with(CASES_SUFFIX))
+            })
+            .collect();
+        files.sort();
+
+        files.iter().map(|path| self.run_file(path)).collect()
+    }
+
+    /// Runs the cases in a case file against its rules file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the case file or rules file cannot be read
+    pub fn run_file(&self, cases_file: &Path) -> Result<SuiteReport, RulesError> {
+        let suite = GoldenSuite::from_file(cases_file)?;
+        let rules_path = cases_file
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(&suite.rules_file);
+        let rules = self.engine.load_rules_from_file(&rules_path)?;
+
+        let outcomes = suite
+            .cases
+            .iter()
+            .map(|case| match self.engine.evaluate(&rules, case.context.clone()) {
+                Ok(result) => CaseOutcome {
+                    name: case.name.clone(),
+                    diffs: compare(&case.expected, &result),
+                    error: None,
+                },
+                Err(e) => CaseOutcome {
+                    name: case.name.clone(),
+                    diffs: Vec::new(),
+                    error: Some(e.to_string()),
+                },
+            })
+            .collect();
+
+        Ok(SuiteReport {
+            cases_file: cases_file.to_path_buf(),
+            product_code: rules.metadata.product_code.clone(),
+            rules_version: rules.metadata.version.clone(),
+            outcomes,
+        })
+    }
+}
+
+/// Compares expected fields with an evaluation result
+pub fn compare(expected: &Map<String, Value>, result: &EvaluationResult) -> Vec<FieldDiff> {
+    let actual = serde_json::to_value(result).unwrap_or_default();
+    expected
+        .iter()
+        .filter_map(|(field, expected)| {
+            let value = actual
+                .get(field)
+                .filter(|v| !v.is_null())
+                .or_else(|| result.additional.get(field));
+            if value.is_some_and(|v| values_match(expected, v)) {
+                return None;
+            }
+            Some(FieldDiff {
+                field: field.clone(),
+                expected: expected.clone(),
+                actual: value.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// Compares values, treating numbers by value and ignoring the quotes the
+/// evaluator can leave around strings
+fn values_match(expected: &Value, actual: &Value) -> bool {
+    match (decimal(expected), decimal(actual)) {
+        (Some(e), Some(a)) if expected.is_number() => e == a,
+        _ => match (expected, actual) {
+            (Value::String(e), Value::String(a)) => e == a.trim_matches('"'),
+            _ => expected == actual,
+        },
+    }
+}
+
+fn decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::Number(n) => n.as_f64().and_then(|f| Decimal::try_from(f).ok()).map(|d| d.normalize()),
+        Value::String(s) => s.trim_matches('"').parse::<Decimal>().ok().map(|d| d.normalize()),
+        _ => None,
+    }
+}
@@ -0,0 +1,461 @@
+//! Benefit illustrations for quotes
+//!
+//! Regulators require a benefit illustration at the point of sale showing
+//! how a policy is expected to perform over its term. This module projects
+//! a quoted policy year by year and produces a table that is attached to
+//! the quote.
+//!
+//! # Unit-Linked Plans
+//!
+//! Unit-linked plans are projected at each prescribed growth rate (4% and
+//! 8% by default). Each year the premium is paid at the start of the year,
+//! the allocation, policy administration and mortality charges are
+//! deducted, the fund grows at the illustration rate and the fund
+//! management charge is taken from the grown fund. The mortality charge is
+//! levied on the sum at risk, the sum assured less the fund value. Charges
+//! are configured in the `ulip_charges` block of `catalog.json`:
+//!
+//! ```json
+//! "ulip_charges": {
+//!   "premium_allocation_charges": [0.06, 0.04, 0.04],
+//!   "ultimate_allocation_charge": 0.02,
+//!   "policy_admin_charge": 60,
+//!   "fund_management_charge": 0.0135,
+//!   "mortality_rates": [
+//!     { "from_age": 18, "to_age": 39, "rate_per_thousand": 1.2 }
+//!   ]
+//! }
+//! ```
+//!
+//! # Whole Life
+//!
+//! Whole life plans are projected with the `CashValueEngine`, showing
+//! guaranteed and non-guaranteed cash values and death benefits under the
+//! chosen dividend option.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let engine = IllustrationEngine::from_catalog(&catalog)?;
+//! let illustration = engine.illustrate_unit_linked(&quote, 35)?;
+//! quote.attach_illustration(illustration)?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use core_kernel::{Money, PolicyId};
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::cash_value::{CashValueEngine, CashValueFactors, DividendOption, PolicyValuesRow};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::coverage::CoverageType;
+use crate::error::PolicyError;
+
+/// Whole life illustrations do not run beyond this attained age
+const MAX_ILLUSTRATION_AGE: u32 = 100;
+
+/// Mortality charge per 1,000 of sum at risk by attained age band
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MortalityRate {
+    /// First attained age in the band
+    pub from_age: u32,
+    /// Last attained age in the band; open-ended if absent
+    pub to_age: Option<u32>,
+    /// Annual charge per 1,000 of sum at risk
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub rate_per_thousand: Decimal,
+}
+
+/// Product-level unit-linked charges
+///
+/// Loaded from the `ulip_charges` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UlipCharges {
+    /// Fraction of premium deducted before allocation, by policy year
+    #[serde(deserialize_with = "catalog_serde::rates")]
+    pub premium_allocation_charges: Vec<Decimal>,
+    /// Fraction deducted once the allocation schedule is exhausted
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub ultimate_allocation_charge: Decimal,
+    /// Annual policy administration charge, in the policy currency
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub policy_admin_charge: Decimal,
+    /// Annual fund management charge on the fund value
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub fund_management_charge: Decimal,
+    /// Mortality charges by attained age
+    pub mortality_rates: Vec<MortalityRate>,
+}
+
+impl UlipCharges {
+    /// Reads the charges from a catalog product entry
+    ///
+    /// Returns `None` when the product has no `ulip_charges` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the block is present but malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        product
+            .get("ulip_charges")
+            .map(|charges| {
+                serde_json::from_value(charges.clone())
+                    .map_err(|e| PolicyError::validation(format!("Invalid ulip_charges: {}", e)))
+            })
+            .transpose()
+    }
+
+    /// Returns the premium allocation charge in a policy year
+    pub fn allocation_charge(&self, policy_year: u32) -> Decimal {
+        policy_year
+            .checked_sub(1)
+            .and_then(|index| self.premium_allocation_charges.get(index as usize))
+            .copied()
+            .unwrap_or(self.ultimate_allocation_charge)
+    }
+
+    /// Returns the mortality charge per 1,000 of sum at risk at an age
+    pub fn mortality_rate(&self, attained_age: u32) -> Option<Decimal> {
+        self.mortality_rates
+            .iter()
+            .find(|rate| {
+                attained_age >= rate.from_age && rate.to_age.is_none_or(|to| attained_age <= to)
+            })
+            .map(|rate| rate.rate_per_thousand)
+    }
+}
+
+/// One policy year of a unit-linked illustration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UlipIllustrationRow {
+    /// Policy year
+    pub policy_year: u32,
+    /// Attained age during the year
+    pub attained_age: u32,
+    /// Premium paid in the year
+    pub premium: Money,
+    /// Premium paid to the end of the year
+    pub cumulative_premium: Money,
+    /// Premium allocation charge
+    pub allocation_charge: Money,
+    /// Policy administration charge
+    pub admin_charge: Money,
+    /// Mortality charge on the sum at risk
+    pub mortality_charge: Money,
+    /// Fund management charge
+    pub fund_management_charge: Money,
+    /// Fund value at the end of the year
+    pub fund_value: Money,
+    /// Death benefit at the end of the year
+    pub death_benefit: Money,
+}
+
+/// Unit-linked projection at one growth rate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UlipScenario {
+    /// Assumed annual fund growth before charges
+    pub growth_rate: Decimal,
+    /// Values by policy year
+    pub rows: Vec<UlipIllustrationRow>,
+}
+
+/// Projected values shown in an illustration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IllustrationTable {
+    /// Fund values at each prescribed growth rate
+    UnitLinked {
+        /// One projection per growth rate
+        scenarios: Vec<UlipScenario>,
+    },
+    /// Guaranteed and non-guaranteed cash values and death benefits
+    WholeLife {
+        /// Dividend option the non-guaranteed values assume
+        dividend_option: DividendOption,
+        /// Values by policy year
+        rows: Vec<PolicyValuesRow>,
+    },
+}
+
+/// Benefit illustration for a quote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Illustration {
+    /// Unique identifier
+    pub id: Uuid,
+    /// Quoted policy
+    pub policy_id: PolicyId,
+    /// Policy number of the quote
+    pub policy_number: String,
+    /// Product illustrated
+    pub product_code: String,
+    /// Age of the insured at issue
+    pub issue_age: u32,
+    /// When the illustration was prepared
+    pub prepared_at: DateTime<Utc>,
+    /// Projected values
+    pub table: IllustrationTable,
+}
+
+impl Illustration {
+    fn new(policy: &Policy, issue_age: u32, table: IllustrationTable) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            policy_id: policy.id(),
+            policy_number: policy.policy_number().to_string(),
+            product_code: policy.product_code().to_string(),
+            issue_age,
+            prepared_at: Utc::now(),
+            table,
+        }
+    }
+
+    /// Number of policy years illustrated
+    pub fn years(&self) -> usize {
+        match &self.table {
+            IllustrationTable::UnitLinked { scenarios } => {
+                scenarios.first().map_or(0, |s| s.rows.len())
+            }
+            IllustrationTable::WholeLife { rows, .. } => rows.len(),
+        }
+    }
+}
+
+/// Engine producing benefit illustrations for quotes
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = IllustrationEngine::from_catalog(&catalog)?;
+/// let illustration = engine.illustrate_whole_life(
+///     &cash_value_engine,
+///     &quote,
+///     35,
+///     factors,
+///     DividendOption::PaidUpAdditions,
+/// )?;
+/// quote.attach_illustration(illustration)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct IllustrationEngine {
+    product_charges: VersionedRules<UlipCharges>,
+    growth_rates: Vec<Decimal>,
+}
+
+impl Default for IllustrationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IllustrationEngine {
+    /// Creates an engine with no unit-linked products and the default
+    /// growth rates of 4% and 8%
+    pub fn new() -> Self {
+        Self {
+            product_charges: VersionedRules::default(),
+            growth_rates: vec![dec!(0.04), dec!(0.08)],
+        }
+    }
+
+    /// Creates an engine with per-product charges read from the catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The parsed `catalog.json` document
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `ulip_charges`
+    pub fn from_catalog(catalog: &Value) -> Result<Self, PolicyError> {
+        let mut engine = Self::new();
+        engine.product_charges = VersionedRules::from_catalog(catalog, UlipCharges::from_catalog_product)?;
+        Ok(engine)
+    }
+
+    /// Sets the charges for a product
+    pub fn with_product_charges(mut self, product_code: impl Into<String>, charges: UlipCharges) -> Self {
+        self.product_charges.insert(product_code.into(), charges);
+        self
+    }
+
+    /// Sets the growth rates unit-linked plans are illustrated at
+    pub fn with_growth_rates(mut self, growth_rates: Vec<Decimal>) -> Self {
+        self.growth_rates = growth_rates;
+        self
+    }
+
+    /// Returns the charges for a product, if it is unit-linked
+    pub fn charges_for(&self, product_code: &str) -> Option<&UlipCharges> {
+        self.product_charges.latest(product_code)
+    }
+
+    /// Returns the growth rates unit-linked plans are illustrated at
+    pub fn growth_rates(&self) -> &[Decimal] {
+        &self.growth_rates
+    }
+
+    /// Illustrates a unit-linked quote over its term
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The quoted policy
+    /// * `issue_age` - Age of the insured at issue
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not a quote, the product has no
+    /// unit-linked charges, the policy has no term or a mortality rate is
+    /// missing for an attained age
+    pub fn illustrate_unit_linked(&self, policy: &Policy, issue_age: u32) -> Result<Illustration, PolicyError> {
+        check_quoted(policy)?;
+        let charges = self.product_charges.for_policy(policy).ok_or_else(|| {
+            PolicyError::Illustration(format!("Product {} is not unit-linked", policy.product_code()))
+        })?;
+        let term = policy.term_years().ok_or_else(|| {
+            PolicyError::Illustration(format!("Policy {} has no term", policy.policy_number()))
+        })?;
+
+        let scenarios = self
+            .growth_rates
+            .iter()
+            .map(|growth_rate| {
+                Ok(UlipScenario {
+                    growth_rate: *growth_rate,
+                    rows: project_fund(policy, charges, issue_age, term, *growth_rate)?,
+                })
+            })
+            .collect::<Result<Vec<_>, PolicyError>>()?;
+
+        tracing::info!(
+            policy_number = policy.policy_number(),
+            years = term,
+            "Unit-linked illustration prepared"
+        );
+        Ok(Illustration::new(policy, issue_age, IllustrationTable::UnitLinked { scenarios }))
+    }
+
+    /// Illustrates a whole life quote
+    ///
+    /// Values are projected over the policy term, to attained age 100 at
+    /// most.
+    ///
+    /// # Arguments
+    ///
+    /// * `cash_value` - Engine holding the product's cash value rules
+    /// * `policy` - The quoted policy
+    /// * `issue_age` - Age of the insured at issue
+    /// * `factors` - Rates from the product's rules evaluation
+    /// * `dividend_option` - Dividend option to illustrate
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not a quote, the product has no cash
+    /// value or the dividend option is not offered
+    pub fn illustrate_whole_life(
+        &self,
+        cash_value: &CashValueEngine,
+        policy: &Policy,
+        issue_age: u32,
+        factors: CashValueFactors,
+        dividend_option: DividendOption,
+    ) -> Result<Illustration, PolicyError> {
+        check_quoted(policy)?;
+        let to_max_age = MAX_ILLUSTRATION_AGE.saturating_sub(issue_age);
+        let years = policy.term_years().map_or(to_max_age, |term| term.min(to_max_age));
+        let rows = cash_value.projected_values(policy, issue_age, factors, dividend_option, years)?;
+
+        tracing::info!(
+            policy_number = policy.policy_number(),
+            years,
+            "Whole life illustration prepared"
+        );
+        Ok(Illustration::new(
+            policy,
+            issue_age,
+            IllustrationTable::WholeLife { dividend_option, rows },
+        ))
+    }
+}
+
+/// Projects the fund year by year at one growth rate
+fn project_fund(
+    policy: &Policy,
+    charges: &UlipCharges,
+    issue_age: u32,
+    term: u32,
+    growth_rate: Decimal,
+) -> Result<Vec<UlipIllustrationRow>, PolicyError> {
+    let currency = policy.currency();
+    let zero = Money::zero(currency);
+    let premium = policy.premium().annualized();
+    let sum_assured = policy
+        .coverages()
+        .iter()
+        .filter(|c| c.is_active && c.coverage_type == CoverageType::DeathBenefit)
+        .fold(zero, |total, c| total + c.effective_sum_assured());
+
+    let mut fund = zero;
+    let mut cumulative_premium = zero;
+    let mut rows = Vec::with_capacity(term as usize);
+
+    for policy_year in 1..=term {
+        let attained_age = issue_age + policy_year - 1;
+        let mortality_rate = charges.mortality_rate(attained_age).ok_or_else(|| {
+            PolicyError::Illustration(format!("No mortality rate for attained age {}", attained_age))
+        })?;
+
+        let allocation_charge = premium.multiply(charges.allocation_charge(policy_year)).round_to_currency();
+        let admin_charge = Money::new(charges.policy_admin_charge, currency);
+        fund = floor_at_zero(fund + premium - allocation_charge - admin_charge);
+
+        let sum_at_risk = floor_at_zero(sum_assured - fund);
+        let mortality_charge = sum_at_risk
+            .multiply(mortality_rate / dec!(1000))
+            .round_to_currency();
+        fund = floor_at_zero(fund - mortality_charge);
+
+        let grown = fund.multiply(Decimal::ONE + growth_rate).round_to_currency();
+        let fund_management_charge = grown.multiply(charges.fund_management_charge).round_to_currency();
+        fund = grown - fund_management_charge;
+        cumulative_premium = cumulative_premium + premium;
+
+        rows.push(UlipIllustrationRow {
+            policy_year,
+            attained_age,
+            premium,
+            cumulative_premium,
+            allocation_charge,
+            admin_charge,
+            mortality_charge,
+            fund_management_charge,
+            fund_value: fund,
+            death_benefit: if fund.amount() > sum_assured.amount() { fund } else { sum_assured },
+        });
+    }
+
+    Ok(rows)
+}
+
+fn floor_at_zero(amount: Money) -> Money {
+    if amount.is_negative() {
+        Money::zero(amount.currency())
+    } else {
+        amount
+    }
+}
+
+fn check_quoted(policy: &Policy) -> Result<(), PolicyError> {
+    if !matches!(policy.state(), PolicyState::Quoted { .. }) {
+        return Err(PolicyError::Illustration(format!(
+            "Policy {} is not a quote",
+            policy.policy_number()
+        )));
+    }
+    Ok(())
+}
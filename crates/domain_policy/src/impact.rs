@@ -0,0 +1,338 @@
+//! Rule-change impact simulation
+//!
+//! Before a change to underwriting loadings or base rates is deployed,
+//! pricing can measure its impact on the book. The simulator evaluates
+//! stored quotes and policies under the rules currently in force and
+//! under a candidate version, and reports premium deltas, eligibility
+//! flips and the shift in risk-class distribution.
+//!
+//! Simulation is read-only: cases carry the evaluation context captured at
+//! underwriting and policies are only borrowed, so nothing is changed.
+//!
+//! Premiums follow the rules' `calculate_premium` node: the rules' annual
+//! premium when produced, otherwise
+//! `sum_assured / 1000 x base_rate_per_thousand x (1 + total_loading / 100)`.
+//! Risk classes are derived from the total loading as in underwriting.
+
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::Money;
+
+use crate::aggregate::Policy;
+use crate::coverage::CoverageType;
+use crate::error::PolicyError;
+use crate::rules_engine::{EvaluationResult, ProductRules, RulesEngine};
+use crate::underwriting::{risk_class_for_loading, RiskClass};
+
+/// A stored quote or policy to re-evaluate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactCase {
+    /// Policy or quote number
+    pub reference: String,
+    /// Evaluation context captured at underwriting
+    pub context: Value,
+    /// Base sum assured
+    pub sum_assured: Money,
+}
+
+impl ImpactCase {
+    /// Creates a case from an evaluation context
+    pub fn new(reference: impl Into<String>, context: Value, sum_assured: Money) -> Self {
+        Self {
+            reference: reference.into(),
+            context,
+            sum_assured,
+        }
+    }
+
+    /// Creates a case for a quote or policy
+    ///
+    /// The sum assured is the policy's base coverage: the death benefit,
+    /// or the critical illness cover of a standalone critical illness
+    /// product. The context holds the underwriting data the policy was
+    /// evaluated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy has no base coverage
+    pub fn from_policy(policy: &Policy, context: Value) -> Result<Self, PolicyError> {
+        let active = |coverage_type: CoverageType| {
+            policy
+                .coverages()
+                .iter()
+                .find(move |c| c.is_active && c.coverage_type == coverage_type)
+        };
+        let base = active(CoverageType::DeathBenefit)
+            .or_else(|| active(CoverageType::CriticalIllness))
+            .ok_or_else(|| {
+                PolicyError::Simulation(format!("Policy {} has no base coverage", policy.policy_number()))
+            })?;
+        Ok(Self::new(policy.policy_number(), context, base.sum_assured))
+    }
+}
+
+/// Which cases to simulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulationScope {
+    /// Every case
+    Full,
+    /// A systematic sample of at most this many cases, spread evenly
+    /// across the book
+    Sample(usize),
+}
+
+/// Outcome of evaluating a case under one rules version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RulesOutcome {
+    /// Whether the case is eligible
+    pub eligible: bool,
+    /// Annual premium; `None` if the rules produce no rate
+    pub annual_premium: Option<Money>,
+    /// Risk class
+    pub risk_class: RiskClass,
+}
+
+/// Impact of the rule change on one case
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseImpact {
+    /// Policy or quote number
+    pub reference: String,
+    /// Outcome under the current rules
+    pub current: RulesOutcome,
+    /// Outcome under the candidate rules
+    pub candidate: RulesOutcome,
+}
+
+impl CaseImpact {
+    /// Change in annual premium, if both versions produce one
+    pub fn premium_delta(&self) -> Option<Money> {
+        match (self.current.annual_premium, self.candidate.annual_premium) {
+            (Some(current), Some(candidate)) => Some(candidate - current),
+            _ => None,
+        }
+    }
+
+    /// Whether the case changes eligibility
+    pub fn eligibility_flipped(&self) -> bool {
+        self.current.eligible != self.candidate.eligible
+    }
+}
+
+/// A case that could not be evaluated
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationError {
+    /// Policy or quote number
+    pub reference: String,
+    /// Evaluation error
+    pub error: String,
+}
+
+/// Result of an impact simulation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactReport {
+    /// Product simulated
+    pub product_code: String,
+    /// Version of the current rules
+    pub current_version: String,
+    /// Revision of the current rules
+    pub current_revision: String,
+    /// Version of the candidate rules
+    pub candidate_version: String,
+    /// Revision of the candidate rules
+    pub candidate_revision: String,
+    /// Impact per case
+    pub cases: Vec<CaseImpact>,
+    /// Cases that could not be evaluated
+    pub errors: Vec<SimulationError>,
+}
+
+impl ImpactReport {
+    /// Cases eligible today that the candidate rules would decline
+    pub fn newly_ineligible(&self) -> impl Iterator<Item = &CaseImpact> {
+        self.cases.iter().filter(|c| c.current.eligible && !c.candidate.eligible)
+    }
+
+    /// Cases declined today that the candidate rules would accept
+    pub fn newly_eligible(&self) -> impl Iterator<Item = &CaseImpact> {
+        self.cases.iter().filter(|c| !c.current.eligible && c.candidate.eligible)
+    }
+
+    /// Total annual premium of cases eligible under both versions, under
+    /// the current and candidate rules
+    pub fn premium_totals(&self) -> Option<(Money, Money)> {
+        let mut totals: Option<(Money, Money)> = None;
+        for case in self.cases.iter().filter(|c| c.current.eligible && c.candidate.eligible) {
+            if let (Some(current), Some(candidate)) = (case.current.annual_premium, case.candidate.annual_premium) {
+                totals = Some(match totals {
+                    Some((c, n)) => (c + current, n + candidate),
+                    None => (current, candidate),
+                });
+            }
+        }
+        totals
+    }
+
+    /// Change in total annual premium as a percentage
+    pub fn premium_change_percent(&self) -> Option<Decimal> {
+        let (current, candidate) = self.premium_totals()?;
+        if current.amount().is_zero() {
+            return None;
+        }
+        Some(((candidate.amount() - current.amount()) / current.amount() * Decimal::ONE_HUNDRED).round_dp(2))
+    }
+
+    /// Number of cases in each risk class under the current rules
+    pub fn current_distribution(&self) -> BTreeMap<String, usize> {
+        distribution(self.cases.iter().map(|c| c.current.risk_class))
+    }
+
+    /// Number of cases in each risk class under the candidate rules
+    pub fn candidate_distribution(&self) -> BTreeMap<String, usize> {
+        distribution(self.cases.iter().map(|c| c.candidate.risk_class))
+    }
+}
+
+/// Simulates the impact of candidate rules on stored business
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let current = engine.get_product("TERM_LIFE_01").unwrap();
+/// let candidate = engine.load_rules_from_file(Path::new("term_life_v2.json"))?;
+///
+/// let cases = policies
+///     .iter()
+///     .map(|(policy, context)| ImpactCase::from_policy(policy, context.clone()))
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let report = ImpactSimulator::new().simulate(&current, &candidate, &cases, SimulationScope::Sample(500))?;
+/// println!("{} newly declined", report.newly_ineligible().count());
+/// ```
+#[derive(Default)]
+pub struct ImpactSimulator {
+    engine: RulesEngine,
+}
+
+impl ImpactSimulator {
+    /// Creates a simulator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluates cases under the current and candidate rules
+    ///
+    /// Cases that fail to evaluate under either version are reported in
+    /// `errors` and left out of the comparison.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the candidate rules are for another product
+    pub fn simulate(
+        &self,
+        current: &ProductRules,
+        candidate: &ProductRules,
+        cases: &[ImpactCase],
+        scope: SimulationScope,
+    ) -> Result<ImpactReport, PolicyError> {
+        if current.metadata.product_code != candidate.metadata.product_code {
+            return Err(PolicyError::Simulation(format!(
+                "Candidate rules are for {}, not {}",
+                candidate.metadata.product_code, current.metadata.product_code
+            )));
+        }
+
+        let mut report = ImpactReport {
+            product_code: current.metadata.product_code.clone(),
+            current_version: current.metadata.version.clone(),
+            current_revision: current.revision.clone(),
+            candidate_version: candidate.metadata.version.clone(),
+            candidate_revision: candidate.revision.clone(),
+            cases: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for case in select(cases, scope) {
+            let outcomes = self
+                .outcome(current, case)
+                .and_then(|before| Ok((before, self.outcome(candidate, case)?)));
+            match outcomes {
+                Ok((current, candidate)) => report.cases.push(CaseImpact {
+                    reference: case.reference.clone(),
+                    current,
+                    candidate,
+                }),
+                Err(e) => report.errors.push(SimulationError {
+                    reference: case.reference.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        tracing::info!(
+            product_code = %report.product_code,
+            current_version = %report.current_version,
+            candidate_version = %report.candidate_version,
+            cases = report.cases.len(),
+            newly_ineligible = report.newly_ineligible().count(),
+            newly_eligible = report.newly_eligible().count(),
+            "Rule-change impact simulated"
+        );
+
+        Ok(report)
+    }
+
+    fn outcome(&self, rules: &ProductRules, case: &ImpactCase) -> Result<RulesOutcome, PolicyError> {
+        let result = self
+            .engine
+            .evaluate(rules, case.context.clone())
+            .map_err(|e| PolicyError::Simulation(e.to_string()))?;
+        let loading = result.total_loading_percent.unwrap_or_default();
+        Ok(RulesOutcome {
+            eligible: result.eligible,
+            annual_premium: annual_premium(&result, case.sum_assured),
+            risk_class: if result.eligible {
+                risk_class_for_loading(loading)
+            } else {
+                RiskClass::Declined
+            },
+        })
+    }
+}
+
+/// Annual premium produced by the rules for a sum assured
+fn annual_premium(result: &EvaluationResult, sum_assured: Money) -> Option<Money> {
+    let amount = match result.annual_premium {
+        Some(premium) => premium,
+        None => {
+            let rate = result.base_rate_per_thousand?;
+            let loading = result.total_loading_percent.unwrap_or_default();
+            sum_assured.amount() / Decimal::ONE_THOUSAND * rate * (Decimal::ONE + loading / Decimal::ONE_HUNDRED)
+        }
+    };
+    Some(Money::new(amount, sum_assured.currency()).round_to_currency())
+}
+
+/// Cases within the simulation scope
+fn select(cases: &[ImpactCase], scope: SimulationScope) -> Vec<&ImpactCase> {
+    match scope {
+        SimulationScope::Sample(size) if size < cases.len() => {
+            if size == 0 {
+                return Vec::new();
+            }
+            (0..size).map(|i| &cases[i * cases.len() / size]).collect()
+        }
+        _ => cases.iter().collect(),
+    }
+}
+
+fn distribution(classes: impl Iterator<Item = RiskClass>) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for class in classes {
+        *counts.entry(format!("{:?}", class)).or_insert(0) += 1;
+    }
+    counts
+}
@@ -0,0 +1,302 @@
+//! Medical impairment rating manual
+//!
+//! Disclosed medical conditions are rated with a numerical rating manual.
+//! Each entry matches a condition by ICD-10 code, severity, status and time
+//! since diagnosis, and assigns debits (extra mortality, in percent) or
+//! credits, a flat extra premium, an exclusion, or a decline. The first
+//! entry matching a condition applies, so specific entries go before
+//! general ones.
+//!
+//! Debits and credits from all conditions are netted and converted to a
+//! table rating: each table is `debits_per_table` debits, rounded to the
+//! nearest table, so with the usual 25 debits per table +50 is Table 2 and
+//! +100 is Table 4. Ratings beyond `max_table` are declined. The table is
+//! priced through `RiskClass::TableRated`, whose rate multiplier carries
+//! the extra mortality.
+//!
+//! # Example manual
+//!
+//! ```json
+//! {
+//!   "name": "Standard Impairment Manual",
+//!   "version": "2024.1",
+//!   "ratings": [
+//!     { "code": "E11", "name": "Type 2 diabetes, controlled", "statuses": ["Controlled"], "debits": 50 },
+//!     { "code": "E11", "name": "Type 2 diabetes, uncontrolled", "decline": true },
+//!     { "code": "C50", "name": "Breast cancer, over 5 years", "min_years_since_diagnosis": 5,
+//!       "flat_extra_per_mille": 5, "exclusion": "Recurrence of breast cancer" }
+//!   ]
+//! }
+//! ```
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::catalog_serde;
+use crate::coverage::{Coverage, Exclusion, ExclusionType};
+use crate::error::PolicyError;
+use crate::underwriting::{ConditionStatus, MedicalCondition, RiskClass, RuleImpact, RuleResult, Severity};
+
+fn default_debits_per_table() -> u32 {
+    25
+}
+
+fn default_max_table() -> u8 {
+    8
+}
+
+/// An entry in the rating manual
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpairmentRating {
+    /// ICD-10 code or code prefix (`E11` matches `E11.9`)
+    pub code: String,
+    /// Entry name
+    pub name: String,
+    /// Severity matched; any severity if not set
+    #[serde(default)]
+    pub severity: Option<Severity>,
+    /// Statuses matched; any status if empty
+    #[serde(default)]
+    pub statuses: Vec<ConditionStatus>,
+    /// Minimum completed years since diagnosis
+    #[serde(default)]
+    pub min_years_since_diagnosis: Option<u32>,
+    /// Years since diagnosis from which the entry no longer applies
+    #[serde(default)]
+    pub max_years_since_diagnosis: Option<u32>,
+    /// Debits; negative for credits
+    #[serde(default)]
+    pub debits: i32,
+    /// Flat extra premium per 1,000 sum assured
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub flat_extra_per_mille: Option<Decimal>,
+    /// Exclusion applied to the coverage
+    #[serde(default)]
+    pub exclusion: Option<String>,
+    /// Whether the condition is uninsurable
+    #[serde(default)]
+    pub decline: bool,
+}
+
+impl ImpairmentRating {
+    /// Whether the entry applies to a condition
+    ///
+    /// A condition without a diagnosis date is treated as newly diagnosed.
+    pub fn matches(&self, condition: &MedicalCondition, as_of: NaiveDate) -> bool {
+        let years = condition.years_since_diagnosis(as_of).unwrap_or(0);
+        condition.code.starts_with(&self.code)
+            && self.severity.is_none_or(|severity| condition.severity == Some(severity))
+            && (self.statuses.is_empty() || self.statuses.contains(&condition.status))
+            && self.min_years_since_diagnosis.is_none_or(|min| years >= min)
+            && self.max_years_since_diagnosis.is_none_or(|max| years < max)
+    }
+}
+
+/// A numerical rating manual
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpairmentManual {
+    /// Manual name
+    pub name: String,
+    /// Manual version
+    pub version: String,
+    /// Debits per table
+    #[serde(default = "default_debits_per_table")]
+    pub debits_per_table: u32,
+    /// Highest table offered; worse ratings are declined
+    #[serde(default = "default_max_table")]
+    pub max_table: u8,
+    /// Entries, most specific first
+    pub ratings: Vec<ImpairmentRating>,
+}
+
+impl ImpairmentManual {
+    /// Parses a manual from JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the JSON is invalid or the manual has no debits per
+    /// table
+    pub fn from_json(json: &str) -> Result<Self, PolicyError> {
+        let manual: Self = serde_json::from_str(json).map_err(|e| PolicyError::Impairment(e.to_string()))?;
+        if manual.debits_per_table == 0 {
+            return Err(PolicyError::Impairment(format!(
+                "Manual {} must have at least one debit per table",
+                manual.name
+            )));
+        }
+        Ok(manual)
+    }
+
+    /// Loads a manual from a JSON file
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or is invalid
+    pub fn from_file(path: &Path) -> Result<Self, PolicyError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| PolicyError::Impairment(format!("{}: {}", path.display(), e)))?;
+        Self::from_json(&json)
+    }
+
+    /// Returns the entry rating a condition
+    pub fn rating_for(&self, condition: &MedicalCondition, as_of: NaiveDate) -> Option<&ImpairmentRating> {
+        self.ratings.iter().find(|r| r.matches(condition, as_of))
+    }
+
+    /// Rates disclosed conditions
+    ///
+    /// Conditions the manual does not cover are listed in `unrated` for the
+    /// underwriter to refer.
+    pub fn assess(&self, conditions: &[MedicalCondition], as_of: NaiveDate) -> ImpairmentAssessment {
+        let mut assessment = ImpairmentAssessment {
+            manual_version: self.version.clone(),
+            ..Default::default()
+        };
+
+        for condition in conditions {
+            match self.rating_for(condition, as_of) {
+                Some(rating) => assessment.findings.push(ImpairmentFinding {
+                    condition_code: condition.code.clone(),
+                    condition_name: condition.name.clone(),
+                    rating: rating.name.clone(),
+                    debits: rating.debits,
+                    flat_extra_per_mille: rating.flat_extra_per_mille,
+                    exclusion: rating.exclusion.clone(),
+                    decline: rating.decline,
+                }),
+                None => assessment.unrated.push(condition.code.clone()),
+            }
+        }
+
+        let net: i32 = assessment.findings.iter().map(|f| f.debits).sum();
+        assessment.total_debits = net.max(0) as u32;
+        let table = (assessment.total_debits + self.debits_per_table / 2) / self.debits_per_table;
+        assessment.declined =
+            assessment.findings.iter().any(|f| f.decline) || table > u32::from(self.max_table);
+        if !assessment.declined && table > 0 {
+            assessment.table = Some(table as u8);
+            assessment.loading_percent = Decimal::from(table * self.debits_per_table);
+        }
+        assessment.flat_extra_per_mille = assessment
+            .findings
+            .iter()
+            .filter_map(|f| f.flat_extra_per_mille)
+            .reduce(|a, b| a + b);
+
+        assessment
+    }
+}
+
+/// How one condition was rated
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImpairmentFinding {
+    /// Condition code
+    pub condition_code: String,
+    /// Condition name
+    pub condition_name: String,
+    /// Manual entry applied
+    pub rating: String,
+    /// Debits; negative for credits
+    pub debits: i32,
+    /// Flat extra premium per 1,000 sum assured
+    pub flat_extra_per_mille: Option<Decimal>,
+    /// Exclusion applied
+    pub exclusion: Option<String>,
+    /// Whether the condition is uninsurable
+    pub decline: bool,
+}
+
+/// Result of rating disclosed conditions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImpairmentAssessment {
+    /// Version of the manual used
+    pub manual_version: String,
+    /// Rating of each condition the manual covers
+    pub findings: Vec<ImpairmentFinding>,
+    /// Codes of conditions the manual does not cover
+    pub unrated: Vec<String>,
+    /// Net debits, never below zero
+    pub total_debits: u32,
+    /// Table rating; `None` at standard
+    pub table: Option<u8>,
+    /// Extra mortality of the table rating; priced through the
+    /// `TableRated` risk class, not as a coverage loading
+    pub loading_percent: Decimal,
+    /// Total flat extra premium per 1,000 sum assured
+    pub flat_extra_per_mille: Option<Decimal>,
+    /// Whether the application is declined
+    pub declined: bool,
+}
+
+impl ImpairmentAssessment {
+    /// Risk class for the rating
+    pub fn risk_class(&self) -> RiskClass {
+        match (self.declined, self.table) {
+            (true, _) => RiskClass::Declined,
+            (false, Some(table)) => RiskClass::TableRated(table),
+            (false, None) => RiskClass::Standard,
+        }
+    }
+
+    /// Underwriting exclusions for the rated conditions
+    pub fn exclusions(&self) -> Vec<Exclusion> {
+        self.findings
+            .iter()
+            .filter_map(|f| {
+                f.exclusion.as_ref().map(|description| Exclusion {
+                    code: f.condition_code.clone(),
+                    description: description.clone(),
+                    exclusion_type: ExclusionType::Underwriting,
+                    effective_date: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Applies the flat extra and exclusions to a coverage
+    ///
+    /// The table rating is not applied here; it is priced by rating the
+    /// coverage in the class returned by [`Self::risk_class`].
+    pub fn apply_to(&self, coverage: Coverage) -> Coverage {
+        let mut coverage = coverage;
+        if let Some(per_mille) = self.flat_extra_per_mille {
+            coverage = coverage.with_flat_extra(per_mille);
+        }
+        for exclusion in self.exclusions() {
+            coverage.add_exclusion(exclusion);
+        }
+        coverage
+    }
+
+    /// Rule results for the decision trace
+    pub fn rule_results(&self) -> Vec<RuleResult> {
+        let mut results: Vec<RuleResult> = self
+            .findings
+            .iter()
+            .map(|f| RuleResult {
+                rule_name: format!("impairment_{}", f.condition_code),
+                passed: !f.decline,
+                message: format!("{}: {} ({:+} debits)", f.condition_name, f.rating, f.debits),
+                impact: if f.decline {
+                    RuleImpact::Decline
+                } else if f.exclusion.is_some() {
+                    RuleImpact::Exclusion
+                } else if f.debits > 0 {
+                    RuleImpact::Loading(Decimal::from(f.debits))
+                } else {
+                    RuleImpact::None
+                },
+            })
+            .collect();
+        results.extend(self.unrated.iter().map(|code| RuleResult {
+            rule_name: format!("impairment_{}", code),
+            passed: false,
+            message: format!("Condition {} is not in the rating manual", code),
+            impact: RuleImpact::Referral,
+        }));
+        results
+    }
+}
@@ -41,16 +41,56 @@ pub mod coverage;
 pub mod premium;
 pub mod endorsement;
 pub mod underwriting;
+pub mod underwriting_case;
+pub mod impairment;
+pub mod exposure;
 pub mod events;
 pub mod error;
 pub mod services;
+pub mod quoting;
 pub mod rules_engine;
+pub mod rules_reload;
+pub mod golden;
+pub mod impact;
+pub mod delinquency;
+pub mod commission;
+pub mod cancellation;
+pub mod loan;
+pub mod cash_value;
+pub mod illustration;
+pub mod nonforfeiture;
+pub mod conversion;
+pub mod renewal;
+pub mod rider;
+pub mod waiver;
+pub mod catalog;
 
-pub use aggregate::{Policy, PolicyState, PolicyBuilder};
+mod catalog_serde;
+
+pub use aggregate::{Policy, PolicyState, PolicyBuilder, NonforfeitureOption};
 pub use coverage::{Coverage, CoverageType, Benefit};
 pub use premium::{Premium, PremiumFrequency, PremiumSchedule};
 pub use endorsement::{Endorsement, EndorsementType};
 pub use events::PolicyEvent;
 pub use error::PolicyError;
 pub use services::{UnderwritingService, RatingService};
-pub use rules_engine::{RulesEngine, ProductRules, EvaluationResult, ProductMetadata, RulesError};
+pub use quoting::{QuotingService, QuoteRequest, QuoteScenario, QuoteComparison, QuoteOption};
+pub use exposure::{LifeExposure, ExistingPolicy, ExposureRules, ExposureCheck, ExposureRouting};
+pub use impairment::{ImpairmentManual, ImpairmentRating, ImpairmentAssessment};
+pub use underwriting_case::{UnderwritingCaseEngine, UnderwritingCase, CaseRules, CaseDecision, RequirementType, UnderwriterAuthority};
+pub use rules_engine::{RulesEngine, ProductRules, EvaluationResult, ProductMetadata, RulesError, TableTrace};
+pub use rules_reload::{RulesRepository, DirectoryRulesRepository, RulesDocument, RulesWatcher, ReloadReport};
+pub use golden::{GoldenRunner, GoldenSuite, GoldenCase, SuiteReport};
+pub use impact::{ImpactSimulator, ImpactCase, ImpactReport, SimulationScope};
+pub use delinquency::{DelinquencyEngine, DelinquencyOutcome, LapseRules, ReinstatementQuote};
+pub use commission::{CommissionEngine, CommissionEntry, CommissionSchedule, AgentStatement, PremiumReceipt};
+pub use cancellation::{CancellationEngine, CancellationRequest, RefundQuote, RefundRules, RefundBasis};
+pub use loan::{LoanEngine, LoanRules, PolicyLoan, InterestAccrual};
+pub use cash_value::{CashValueEngine, CashValueAccount, CashValueFactors, DividendOption, PolicyValuesRow};
+pub use illustration::{IllustrationEngine, Illustration, IllustrationTable, UlipCharges, UlipScenario};
+pub use nonforfeiture::{NonforfeitureEngine, NonforfeitureQuote, NonforfeitureRules};
+pub use conversion::{ConversionService, ConversionRequest, ConversionRecord, ConversionRules};
+pub use renewal::{RenewalEngine, RenewalDecision, RenewalOffer, RenewalRules};
+pub use rider::{RiderEngine, Rider, RiderDefinition, RiderQuote, RiderRequest};
+pub use waiver::{WaiverEngine, WaiverAccounts, WaiverEndReason, WaiverPeriod};
+pub use catalog::{ProductCatalog, ProductLimits, ProductVersion};
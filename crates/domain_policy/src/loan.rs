@@ -0,0 +1,735 @@
+//! Policy loans
+//!
+//! This module lends against the cash value of a permanent life policy.
+//! Loans are limited to a product-configured share of the cash value,
+//! accrue simple interest daily and capitalise unpaid interest into the
+//! loan on each policy anniversary. Repayments settle accrued interest
+//! before principal.
+//!
+//! # Automatic Premium Loans
+//!
+//! Where the product allows it, a premium still unpaid when the grace
+//! period expires is paid by a loan against the policy instead of the
+//! policy lapsing. Callers run [`LoanEngine::automatic_premium_loan`]
+//! before handing the invoice to delinquency processing.
+//!
+//! # Loan Lapse
+//!
+//! A policy lapses with `LapseReason::LoanExceedsCashValue` once the loan
+//! balance, including accrued interest, exceeds its cash value.
+//!
+//! Rules are configured per product in the `loan_rules` block of
+//! `catalog.json`; products without the block do not allow loans:
+//!
+//! ```json
+//! "loan_rules": {
+//!   "max_loan_percentage": 0.90,
+//!   "interest_rate": 0.06,
+//!   "minimum_loan": 500,
+//!   "automatic_premium_loan": true
+//! }
+//! ```
+
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{AccountId, JournalEntryId, Money, PolicyId, utc_start_of_day};
+use domain_billing::transaction::Transaction;
+use domain_billing::{Invoice, InvoiceStatus, Ledger};
+
+use crate::aggregate::{LapseReason, Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::error::PolicyError;
+
+/// Product-level loan rules
+///
+/// Loaded from the `loan_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoanRules {
+    /// Maximum loan balance as a fraction of the cash value
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub max_loan_percentage: Decimal,
+    /// Simple annual interest rate charged on the loan
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub interest_rate: Decimal,
+    /// Smallest loan that may be requested
+    #[serde(deserialize_with = "catalog_serde::amount")]
+    pub minimum_loan: Decimal,
+    /// Whether unpaid premiums are paid by loan at the end of the grace period
+    #[serde(default)]
+    pub automatic_premium_loan: bool,
+}
+
+impl LoanRules {
+    /// Reads the loan rules from a catalog product entry
+    ///
+    /// Returns `None` when the product has no `loan_rules` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the block is present but malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        product
+            .get("loan_rules")
+            .map(|rules| {
+                serde_json::from_value(rules.clone())
+                    .map_err(|e| PolicyError::validation(format!("Invalid loan_rules: {}", e)))
+            })
+            .transpose()
+    }
+}
+
+/// Kind of movement on a policy loan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoanTransactionType {
+    /// Cash advanced to the policyholder
+    Advance,
+    /// Unpaid premium paid by loan
+    AutomaticPremiumLoan,
+    /// Interest accrued on the loan
+    InterestAccrued,
+    /// Accrued interest added to the loan at the policy anniversary
+    InterestCapitalised,
+    /// Interest repaid
+    InterestRepayment,
+    /// Principal repaid
+    PrincipalRepayment,
+    /// Loan and accrued interest settled from the cash value
+    CashValueOffset,
+}
+
+/// A movement on a policy loan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanTransaction {
+    /// Date of the movement
+    pub date: NaiveDate,
+    /// Kind of movement
+    pub transaction_type: LoanTransactionType,
+    /// Amount of the movement
+    pub amount: Money,
+    /// Journal entry posted for the movement
+    pub journal_entry_id: JournalEntryId,
+}
+
+/// Loan account held against a policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyLoan {
+    /// Policy the loan is secured on
+    pub policy_id: PolicyId,
+    /// Principal, including capitalised interest
+    pub principal: Money,
+    /// Interest accrued since the last capitalisation
+    pub accrued_interest: Money,
+    /// Simple annual interest rate
+    pub interest_rate: Decimal,
+    /// Policy inception date; interest capitalises on its anniversaries
+    pub inception_date: NaiveDate,
+    /// Date interest has been accrued to
+    pub accrued_to: NaiveDate,
+    /// Movements on the loan
+    pub transactions: Vec<LoanTransaction>,
+}
+
+impl PolicyLoan {
+    /// Returns principal plus accrued interest
+    pub fn balance(&self) -> Money {
+        self.principal + self.accrued_interest
+    }
+
+    /// Returns the first policy anniversary after `date`
+    pub fn next_anniversary(&self, date: NaiveDate) -> NaiveDate {
+        let mut years = 1;
+        loop {
+            match self.inception_date.checked_add_months(Months::new(12 * years)) {
+                Some(anniversary) if anniversary > date => return anniversary,
+                Some(_) => years += 1,
+                None => return NaiveDate::MAX,
+            }
+        }
+    }
+
+    fn record(
+        &mut self,
+        date: NaiveDate,
+        transaction_type: LoanTransactionType,
+        amount: Money,
+        journal_entry_id: JournalEntryId,
+    ) {
+        self.transactions.push(LoanTransaction {
+            date,
+            transaction_type,
+            amount,
+            journal_entry_id,
+        });
+    }
+}
+
+/// Ledger accounts used for loan postings
+#[derive(Debug, Clone, Copy)]
+pub struct LoanAccounts {
+    /// Cash account paying advances and receiving repayments
+    pub cash: AccountId,
+    /// Asset account holding loan principal
+    pub loan_receivable: AccountId,
+    /// Asset account holding accrued, uncapitalised interest
+    pub interest_receivable: AccountId,
+    /// Revenue account for loan interest
+    pub interest_income: AccountId,
+    /// Premium receivable settled by automatic premium loans
+    pub premium_receivable: AccountId,
+    /// Cash value liability a loan is offset against
+    pub cash_value: AccountId,
+}
+
+/// Result of accruing interest on a loan
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterestAccrual {
+    /// Interest accrued in the period
+    pub interest: Money,
+    /// Interest capitalised into the principal in the period
+    pub capitalised: Money,
+    /// Whether the policy lapsed because the loan exceeded the cash value
+    pub lapsed: bool,
+}
+
+/// Engine advancing, accruing and settling policy loans
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = LoanEngine::from_catalog(&catalog, accounts)?;
+///
+/// let mut loan = engine.open(&policy, today)?;
+/// engine.advance(&mut ledger, &mut policy, &mut loan, amount, today)?;
+///
+/// // Month end
+/// let accrual = engine.accrue(&mut ledger, &mut policy, &mut loan, month_end)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoanEngine {
+    product_rules: VersionedRules<LoanRules>,
+    accounts: LoanAccounts,
+}
+
+impl LoanEngine {
+    /// Creates an engine with no products allowing loans
+    pub fn new(accounts: LoanAccounts) -> Self {
+        Self {
+            product_rules: VersionedRules::default(),
+            accounts,
+        }
+    }
+
+    /// Creates an engine with per-product rules read from the catalog
+    ///
+    /// # Arguments
+    ///
+    /// * `catalog` - The parsed `catalog.json` document
+    /// * `accounts` - Ledger accounts for loan postings
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `loan_rules`
+    pub fn from_catalog(catalog: &Value, accounts: LoanAccounts) -> Result<Self, PolicyError> {
+        let mut engine = Self::new(accounts);
+        engine.product_rules = VersionedRules::from_catalog(catalog, LoanRules::from_catalog_product)?;
+        Ok(engine)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: LoanRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Returns the rules of a product's latest version, if it allows loans
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> Option<&LoanRules> {
+        self.product_rules.latest(product_code)
+    }
+
+    /// Returns the rules of the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> Option<&LoanRules> {
+        self.product_rules.for_policy(policy)
+    }
+
+    /// Opens a loan account for a policy
+    ///
+    /// Any loan already recorded on the policy is carried over as
+    /// principal, with interest accruing from `as_of`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product does not allow loans or the policy
+    /// has not been issued
+    pub fn open(&self, policy: &Policy, as_of: NaiveDate) -> Result<PolicyLoan, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        let inception_date = policy.inception_date().ok_or_else(|| {
+            PolicyError::PolicyLoan(format!("Policy {} has not been issued", policy.policy_number()))
+        })?;
+        let currency = policy.currency();
+
+        Ok(PolicyLoan {
+            policy_id: policy.id(),
+            principal: policy
+                .financial_state()
+                .loan_outstanding
+                .unwrap_or(Money::zero(currency)),
+            accrued_interest: Money::zero(currency),
+            interest_rate: rules.interest_rate,
+            inception_date,
+            accrued_to: as_of,
+            transactions: Vec::new(),
+        })
+    }
+
+    /// Returns the maximum loan balance the policy supports
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product does not allow loans or no cash value
+    /// has been recorded
+    pub fn max_loan(&self, policy: &Policy) -> Result<Money, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        Ok(cash_value(policy)?
+            .multiply(rules.max_loan_percentage)
+            .round_to_currency())
+    }
+
+    /// Returns the amount that may still be borrowed
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product does not allow loans or no cash value
+    /// has been recorded
+    pub fn available(&self, policy: &Policy, loan: &PolicyLoan) -> Result<Money, PolicyError> {
+        let headroom = self.max_loan(policy)? - loan.balance();
+        Ok(if headroom.is_positive() {
+            headroom
+        } else {
+            Money::zero(policy.currency())
+        })
+    }
+
+    /// Advances a loan to the policyholder
+    ///
+    /// Interest is accrued to `date` before the limit is checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger receiving the advance
+    /// * `policy` - The in-force policy
+    /// * `loan` - The policy's loan account
+    /// * `amount` - Amount requested
+    /// * `date` - Date of the advance
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the amount is below the product minimum or above
+    /// the available loan value, or a ledger posting fails
+    pub fn advance(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        amount: Money,
+        date: NaiveDate,
+    ) -> Result<JournalEntryId, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        check_loan(policy, loan)?;
+        if amount.amount() < rules.minimum_loan {
+            return Err(PolicyError::PolicyLoan(format!(
+                "Loan {} is below the minimum of {}",
+                amount, rules.minimum_loan
+            )));
+        }
+
+        self.accrue_interest(ledger, policy, loan, date)?;
+        let available = self.available(policy, loan)?;
+        if amount.amount() > available.amount() {
+            return Err(PolicyError::PolicyLoan(format!(
+                "Loan {} exceeds available loan value {}",
+                amount, available
+            )));
+        }
+
+        policy.take_loan(amount)?;
+        let entry_id = post(
+            ledger,
+            Transaction::new("Policy loan advance")
+                .with_reference("policy", *policy.id().as_uuid())
+                .dated(utc_start_of_day(date))
+                .debit(self.accounts.loan_receivable, amount)
+                .credit(self.accounts.cash, amount),
+        )?;
+        loan.principal = loan.principal + amount;
+        loan.record(date, LoanTransactionType::Advance, amount, entry_id);
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            amount = %amount,
+            "Policy loan advanced"
+        );
+
+        Ok(entry_id)
+    }
+
+    /// Applies a repayment to the loan
+    ///
+    /// Interest is accrued to `date`; the repayment settles accrued
+    /// interest first and then principal.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repayment exceeds the loan balance or a ledger
+    /// posting fails
+    pub fn repay(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        amount: Money,
+        date: NaiveDate,
+    ) -> Result<JournalEntryId, PolicyError> {
+        check_loan(policy, loan)?;
+        if !amount.is_positive() {
+            return Err(PolicyError::PolicyLoan("Repayment must be positive".to_string()));
+        }
+
+        self.accrue_interest(ledger, policy, loan, date)?;
+        if amount.amount() > loan.balance().amount() {
+            return Err(PolicyError::PolicyLoan(format!(
+                "Repayment {} exceeds loan balance {}",
+                amount,
+                loan.balance()
+            )));
+        }
+
+        let interest_paid = if amount.amount() > loan.accrued_interest.amount() {
+            loan.accrued_interest
+        } else {
+            amount
+        };
+        let principal_paid = amount - interest_paid;
+
+        if principal_paid.is_positive() {
+            policy.repay_loan(principal_paid)?;
+        }
+
+        let mut transaction = Transaction::new("Policy loan repayment")
+            .with_reference("policy", *policy.id().as_uuid())
+            .dated(utc_start_of_day(date))
+            .debit(self.accounts.cash, amount);
+        if interest_paid.is_positive() {
+            transaction = transaction.credit(self.accounts.interest_receivable, interest_paid);
+        }
+        if principal_paid.is_positive() {
+            transaction = transaction.credit(self.accounts.loan_receivable, principal_paid);
+        }
+        let entry_id = post(ledger, transaction)?;
+
+        if interest_paid.is_positive() {
+            loan.accrued_interest = loan.accrued_interest - interest_paid;
+            loan.record(date, LoanTransactionType::InterestRepayment, interest_paid, entry_id);
+        }
+        if principal_paid.is_positive() {
+            loan.principal = loan.principal - principal_paid;
+            loan.record(date, LoanTransactionType::PrincipalRepayment, principal_paid, entry_id);
+        }
+
+        Ok(entry_id)
+    }
+
+    /// Accrues interest to `as_of` and lapses the policy if the loan
+    /// balance exceeds the cash value
+    ///
+    /// Interest accrued up to each policy anniversary passed is
+    /// capitalised into the principal on that anniversary.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a ledger posting or the lapse fails
+    pub fn accrue(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        as_of: NaiveDate,
+    ) -> Result<InterestAccrual, PolicyError> {
+        check_loan(policy, loan)?;
+        let (interest, capitalised) = self.accrue_interest(ledger, policy, loan, as_of)?;
+
+        let mut lapsed = false;
+        if let Some(cash_value) = policy.financial_state().surrender_value {
+            let balance = loan.balance();
+            if balance.amount() > cash_value.amount()
+                && matches!(policy.state(), PolicyState::InForce { .. } | PolicyState::Reinstated { .. })
+            {
+                policy.lapse_on(
+                    LapseReason::LoanExceedsCashValue {
+                        loan_balance: balance.amount(),
+                        cash_value: cash_value.amount(),
+                    },
+                    None,
+                    as_of,
+                )?;
+                lapsed = true;
+
+                tracing::info!(
+                    policy_number = %policy.policy_number(),
+                    loan_balance = %balance,
+                    cash_value = %cash_value,
+                    "Policy lapsed: loan exceeds cash value"
+                );
+            }
+        }
+
+        Ok(InterestAccrual {
+            interest,
+            capitalised,
+            lapsed,
+        })
+    }
+
+    /// Pays an unpaid premium invoice by loan at the end of the grace period
+    ///
+    /// Returns `None`, leaving the invoice for delinquency processing, if
+    /// the product does not offer automatic premium loans, the invoice is
+    /// settled or waived, the grace period has not yet expired or the
+    /// available loan value does not cover the premium.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger receiving the loan
+    /// * `policy` - The policy the invoice belongs to
+    /// * `loan` - The policy's loan account
+    /// * `invoice` - The unpaid premium invoice
+    /// * `grace_end_date` - Last day of the invoice's grace period
+    /// * `as_of` - Processing date
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the invoice belongs to another policy or a ledger
+    /// posting fails
+    pub fn automatic_premium_loan(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        invoice: &mut Invoice,
+        grace_end_date: NaiveDate,
+        as_of: NaiveDate,
+    ) -> Result<Option<JournalEntryId>, PolicyError> {
+        if invoice.policy_id != policy.id() {
+            return Err(PolicyError::validation(format!(
+                "Invoice {} does not belong to policy {}",
+                invoice.invoice_number,
+                policy.policy_number()
+            )));
+        }
+        check_loan(policy, loan)?;
+
+        let enabled = self
+            .product_rules
+            .for_policy(policy)
+            .is_some_and(|rules| rules.automatic_premium_loan);
+        let premium = invoice.balance_due();
+        if !enabled
+            || as_of <= grace_end_date
+            || !premium.is_positive()
+            || matches!(invoice.status, InvoiceStatus::Paid | InvoiceStatus::Cancelled)
+            || !matches!(policy.state(), PolicyState::InForce { .. } | PolicyState::Reinstated { .. })
+            || policy.financial_state().surrender_value.is_none()
+            || policy.is_premium_waived(invoice.due_date)
+        {
+            return Ok(None);
+        }
+
+        self.accrue_interest(ledger, policy, loan, as_of)?;
+        if premium.amount() > self.available(policy, loan)?.amount() {
+            return Ok(None);
+        }
+
+        policy.take_loan(premium)?;
+        let entry_id = post(
+            ledger,
+            Transaction::new(format!("Automatic premium loan for invoice {}", invoice.invoice_number))
+                .with_reference("policy", *policy.id().as_uuid())
+                .dated(utc_start_of_day(as_of))
+                .debit(self.accounts.loan_receivable, premium)
+                .credit(self.accounts.premium_receivable, premium),
+        )?;
+        invoice.record_payment(premium);
+        policy.record_payment(premium)?;
+        loan.principal = loan.principal + premium;
+        loan.record(as_of, LoanTransactionType::AutomaticPremiumLoan, premium, entry_id);
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            invoice_number = %invoice.invoice_number,
+            amount = %premium,
+            "Premium paid by automatic premium loan"
+        );
+
+        Ok(Some(entry_id))
+    }
+
+    /// Settles the loan from the cash value
+    ///
+    /// Interest is accrued to `date`, then the loan balance is offset
+    /// against the cash value, as when the policy is made paid-up. The
+    /// policy's cash value is reduced by the balance settled.
+    ///
+    /// Returns the balance settled.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the balance exceeds the cash value or a ledger
+    /// posting fails
+    pub fn offset_against_cash_value(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        date: NaiveDate,
+    ) -> Result<Money, PolicyError> {
+        check_loan(policy, loan)?;
+        self.accrue_interest(ledger, policy, loan, date)?;
+        let balance = loan.balance();
+        if !balance.is_positive() {
+            return Ok(balance);
+        }
+        let cash_value = cash_value(policy)?;
+        if balance.amount() > cash_value.amount() {
+            return Err(PolicyError::PolicyLoan(format!(
+                "Loan balance {} exceeds cash value {}",
+                balance, cash_value
+            )));
+        }
+
+        let mut transaction = Transaction::new("Policy loan offset against cash value")
+            .with_reference("policy", *policy.id().as_uuid())
+            .dated(utc_start_of_day(date))
+            .debit(self.accounts.cash_value, balance);
+        if loan.principal.is_positive() {
+            transaction = transaction.credit(self.accounts.loan_receivable, loan.principal);
+        }
+        if loan.accrued_interest.is_positive() {
+            transaction = transaction.credit(self.accounts.interest_receivable, loan.accrued_interest);
+        }
+        let entry_id = post(ledger, transaction)?;
+
+        if loan.principal.is_positive() {
+            policy.repay_loan(loan.principal)?;
+        }
+        policy.record_cash_value(cash_value - balance)?;
+        loan.principal = Money::zero(policy.currency());
+        loan.accrued_interest = Money::zero(policy.currency());
+        loan.record(date, LoanTransactionType::CashValueOffset, balance, entry_id);
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            amount = %balance,
+            "Policy loan offset against cash value"
+        );
+
+        Ok(balance)
+    }
+
+    fn require_rules(&self, policy: &Policy) -> Result<&LoanRules, PolicyError> {
+        self.product_rules.for_policy(policy).ok_or_else(|| {
+            PolicyError::PolicyLoan(format!(
+                "Product {} does not allow policy loans",
+                policy.product_code()
+            ))
+        })
+    }
+
+    /// Accrues daily simple interest to `as_of`, capitalising at each
+    /// anniversary passed. Returns the interest accrued and capitalised.
+    fn accrue_interest(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        as_of: NaiveDate,
+    ) -> Result<(Money, Money), PolicyError> {
+        let currency = policy.currency();
+        let mut interest_total = Money::zero(currency);
+        let mut capitalised_total = Money::zero(currency);
+
+        while loan.accrued_to < as_of {
+            let anniversary = loan.next_anniversary(loan.accrued_to);
+            let period_end = anniversary.min(as_of);
+            let days = Decimal::from((period_end - loan.accrued_to).num_days());
+            let interest = loan
+                .principal
+                .multiply(loan.interest_rate * days / Decimal::from(365))
+                .round_to_currency();
+
+            if interest.is_positive() {
+                let entry_id = post(
+                    ledger,
+                    Transaction::new("Policy loan interest")
+                        .with_reference("policy", *policy.id().as_uuid())
+                        .dated(utc_start_of_day(period_end))
+                        .debit(self.accounts.interest_receivable, interest)
+                        .credit(self.accounts.interest_income, interest),
+                )?;
+                loan.accrued_interest = loan.accrued_interest + interest;
+                loan.record(period_end, LoanTransactionType::InterestAccrued, interest, entry_id);
+                interest_total = interest_total + interest;
+            }
+            loan.accrued_to = period_end;
+
+            if period_end == anniversary && loan.accrued_interest.is_positive() {
+                let capitalised = loan.accrued_interest;
+                let entry_id = post(
+                    ledger,
+                    Transaction::new("Policy loan interest capitalised")
+                        .with_reference("policy", *policy.id().as_uuid())
+                        .dated(utc_start_of_day(period_end))
+                        .debit(self.accounts.loan_receivable, capitalised)
+                        .credit(self.accounts.interest_receivable, capitalised),
+                )?;
+                policy.capitalise_loan_interest(capitalised)?;
+                loan.principal = loan.principal + capitalised;
+                loan.accrued_interest = Money::zero(currency);
+                loan.record(period_end, LoanTransactionType::InterestCapitalised, capitalised, entry_id);
+                capitalised_total = capitalised_total + capitalised;
+            }
+        }
+
+        Ok((interest_total, capitalised_total))
+    }
+}
+
+fn check_loan(policy: &Policy, loan: &PolicyLoan) -> Result<(), PolicyError> {
+    if loan.policy_id != policy.id() {
+        return Err(PolicyError::PolicyLoan(format!(
+            "Loan does not belong to policy {}",
+            policy.policy_number()
+        )));
+    }
+    Ok(())
+}
+
+fn cash_value(policy: &Policy) -> Result<Money, PolicyError> {
+    policy.financial_state().surrender_value.ok_or_else(|| {
+        PolicyError::PolicyLoan(format!(
+            "No cash value recorded for policy {}",
+            policy.policy_number()
+        ))
+    })
+}
+
+fn post(ledger: &mut Ledger, transaction: Transaction) -> Result<JournalEntryId, PolicyError> {
+    ledger
+        .post(transaction)
+        .map_err(|e| PolicyError::Financial(e.to_string()))
+}
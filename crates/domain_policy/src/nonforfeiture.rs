@@ -0,0 +1,485 @@
+//! Nonforfeiture options
+//!
+//! When premiums stop on a whole life policy the cash value is not
+//! forfeited; it is applied, net of any policy loan, to buy paid-up cover:
+//!
+//! - **Reduced paid-up**: a smaller sum assured for life, equal to the net
+//!   cash value divided by the whole life net single premium at the
+//!   insured's attained age
+//! - **Extended term**: the sum assured, less any loan, for as long as the
+//!   net cash value pays for term cover year by year, accumulating at the
+//!   valuation interest rate
+//!
+//! Options are applied on request, or with the product's default option
+//! when `DelinquencyEngine` lapses the policy. A policy loan is offset
+//! against the cash value through the `LoanEngine` first. Rates are
+//! configured per product in the `nonforfeiture_rules` block of
+//! `catalog.json`:
+//!
+//! ```json
+//! "nonforfeiture_rules": {
+//!   "default_option": "extended_term",
+//!   "interest_rate": 0.04,
+//!   "rates": [
+//!     { "from_age": 18, "to_age": 39, "whole_life_nsp_per_thousand": 220, "term_cost_per_thousand": 1.5 }
+//!   ]
+//! }
+//! ```
+
+use chrono::{Duration, Months, NaiveDate};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::Money;
+use domain_billing::Ledger;
+
+use crate::aggregate::{NonforfeitureOption, Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::coverage::CoverageType;
+use crate::error::PolicyError;
+use crate::loan::{LoanEngine, PolicyLoan};
+
+/// Extended term cover does not run beyond this attained age
+const MAX_TERM_AGE: u32 = 100;
+
+/// Nonforfeiture rates by attained age band
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NonforfeitureRate {
+    /// First attained age in the band
+    pub from_age: u32,
+    /// Last attained age in the band; open-ended if absent
+    pub to_age: Option<u32>,
+    /// Net single premium per 1,000 of paid-up whole life cover
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub whole_life_nsp_per_thousand: Decimal,
+    /// Cost per 1,000 of one year of term cover
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub term_cost_per_thousand: Decimal,
+}
+
+/// Product-level nonforfeiture rules
+///
+/// Loaded from the `nonforfeiture_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NonforfeitureRules {
+    /// Option applied when a lapsed policy is processed
+    pub default_option: NonforfeitureOption,
+    /// Valuation interest rate
+    #[serde(deserialize_with = "catalog_serde::rate")]
+    pub interest_rate: Decimal,
+    /// Rates by attained age
+    pub rates: Vec<NonforfeitureRate>,
+}
+
+impl NonforfeitureRules {
+    /// Reads the nonforfeiture rules from a catalog product entry
+    ///
+    /// Returns `None` when the product has no `nonforfeiture_rules` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the block is present but malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        product
+            .get("nonforfeiture_rules")
+            .map(|rules| {
+                let rules: Self = serde_json::from_value(rules.clone())
+                    .map_err(|e| PolicyError::validation(format!("Invalid nonforfeiture_rules: {}", e)))?;
+                rules.validate()?;
+                Ok(rules)
+            })
+            .transpose()
+    }
+
+    /// Checks that every rate is positive
+    ///
+    /// # Errors
+    ///
+    /// Returns error naming the first age band with a zero or negative rate
+    pub fn validate(&self) -> Result<(), PolicyError> {
+        for rate in &self.rates {
+            if rate.whole_life_nsp_per_thousand <= Decimal::ZERO || rate.term_cost_per_thousand <= Decimal::ZERO {
+                return Err(PolicyError::validation(format!(
+                    "Invalid nonforfeiture_rules: rates from age {} must be positive",
+                    rate.from_age
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the rates for an attained age
+    pub fn rate_for(&self, attained_age: u32) -> Option<&NonforfeitureRate> {
+        self.rates
+            .iter()
+            .find(|rate| attained_age >= rate.from_age && rate.to_age.is_none_or(|to| attained_age <= to))
+    }
+}
+
+/// Paid-up cover the cash value buys under a nonforfeiture option
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NonforfeitureQuote {
+    /// Option quoted
+    pub option: NonforfeitureOption,
+    /// Date the option takes effect
+    pub effective_date: NaiveDate,
+    /// Cash value before settling any loan
+    pub cash_value: Money,
+    /// Policy loan settled from the cash value
+    pub loan_settled: Money,
+    /// Cash value applied to paid-up cover
+    pub net_cash_value: Money,
+    /// Paid-up sum assured
+    pub sum_assured: Money,
+    /// End of extended term cover
+    pub expiry_date: Option<NaiveDate>,
+}
+
+/// Engine quoting and applying nonforfeiture options
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = NonforfeitureEngine::from_catalog(&catalog)?;
+///
+/// // Policyholder stops paying and asks for reduced paid-up cover
+/// let quote = engine.apply(&mut policy, Some(NonforfeitureOption::ReducedPaidUp), 52, today)?;
+///
+/// // Policies with a loan settle it from the cash value
+/// let engine = engine.with_loans(loans);
+/// engine.apply_with_loan(&mut ledger, &mut policy, &mut loan, None, 47, today)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NonforfeitureEngine {
+    product_rules: VersionedRules<NonforfeitureRules>,
+    /// Engine offsetting policy loans against the cash value
+    loans: Option<LoanEngine>,
+}
+
+impl NonforfeitureEngine {
+    /// Creates an engine with no products offering nonforfeiture options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an engine with per-product rules read from the catalog
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `nonforfeiture_rules`
+    pub fn from_catalog(catalog: &Value) -> Result<Self, PolicyError> {
+        let mut engine = Self::new();
+        engine.product_rules = VersionedRules::from_catalog(catalog, NonforfeitureRules::from_catalog_product)?;
+        Ok(engine)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: NonforfeitureRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Sets the engine used to offset policy loans against the cash value
+    pub fn with_loans(mut self, loans: LoanEngine) -> Self {
+        self.loans = Some(loans);
+        self
+    }
+
+    /// Returns the rules of a product's latest version, if it offers nonforfeiture options
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> Option<&NonforfeitureRules> {
+        self.product_rules.latest(product_code)
+    }
+
+    /// Returns the rules of the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> Option<&NonforfeitureRules> {
+        self.product_rules.for_policy(policy)
+    }
+
+    /// Calculates the paid-up cover available under an option
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The in-force, reinstated or lapsed policy
+    /// * `option` - The option to quote
+    /// * `attained_age` - Age of the insured on `effective_date`
+    /// * `effective_date` - Date the option would take effect
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product has no nonforfeiture rules, the policy
+    /// is in another state, or there is no net cash value to apply
+    pub fn quote(
+        &self,
+        policy: &Policy,
+        option: NonforfeitureOption,
+        attained_age: u32,
+        effective_date: NaiveDate,
+    ) -> Result<NonforfeitureQuote, PolicyError> {
+        let currency = policy.currency();
+        let financials = policy.financial_state();
+        self.calculate(
+            policy,
+            option,
+            attained_age,
+            effective_date,
+            financials.surrender_value.unwrap_or(Money::zero(currency)),
+            financials.loan_outstanding.unwrap_or(Money::zero(currency)),
+        )
+    }
+
+    /// Calculates the paid-up cover a cash value buys after settling a loan
+    fn calculate(
+        &self,
+        policy: &Policy,
+        option: NonforfeitureOption,
+        attained_age: u32,
+        effective_date: NaiveDate,
+        cash_value: Money,
+        loan: Money,
+    ) -> Result<NonforfeitureQuote, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        if !matches!(
+            policy.state(),
+            PolicyState::InForce { .. } | PolicyState::Reinstated { .. } | PolicyState::Lapsed { .. }
+        ) {
+            return Err(PolicyError::Nonforfeiture(format!(
+                "Policy {} cannot be made paid-up ({:?})",
+                policy.policy_number(),
+                policy.state()
+            )));
+        }
+
+        let net_cash_value = cash_value - loan;
+        if !net_cash_value.is_positive() {
+            return Err(PolicyError::Nonforfeiture(format!(
+                "Policy {} has no net cash value",
+                policy.policy_number()
+            )));
+        }
+        let rate = rules.rate_for(attained_age).ok_or_else(|| {
+            PolicyError::Nonforfeiture(format!("No nonforfeiture rate for age {}", attained_age))
+        })?;
+
+        let (sum_assured, expiry_date) = match option {
+            NonforfeitureOption::ReducedPaidUp => {
+                let factor = Decimal::ONE_THOUSAND
+                    .checked_div(rate.whole_life_nsp_per_thousand)
+                    .ok_or_else(|| {
+                        PolicyError::Nonforfeiture(format!("No net single premium for age {}", attained_age))
+                    })?;
+                let sum_assured = net_cash_value.multiply(factor).round_to_currency();
+                // Reduced paid-up cover never exceeds the original sum assured
+                let original = death_benefit(policy);
+                let sum_assured = if sum_assured.amount() > original.amount() { original } else { sum_assured };
+                (sum_assured, None)
+            }
+            NonforfeitureOption::ExtendedTerm => {
+                let sum_assured = death_benefit(policy) - loan;
+                if !sum_assured.is_positive() {
+                    return Err(PolicyError::Nonforfeiture(format!(
+                        "Policy {} loan exceeds its death benefit; no extended term cover remains",
+                        policy.policy_number()
+                    )));
+                }
+                let expiry_date = extended_term_expiry(rules, net_cash_value, sum_assured, attained_age, effective_date)?;
+                (sum_assured, Some(expiry_date))
+            }
+        };
+
+        Ok(NonforfeitureQuote {
+            option,
+            effective_date,
+            cash_value,
+            loan_settled: loan,
+            net_cash_value,
+            sum_assured,
+            expiry_date,
+        })
+    }
+
+    /// Converts the policy to paid-up cover
+    ///
+    /// Policies with a loan are converted with [`Self::apply_with_loan`],
+    /// which offsets the loan against the cash value first.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The in-force, reinstated or lapsed policy
+    /// * `option` - The option elected; the product default if `None`
+    /// * `attained_age` - Age of the insured on `effective_date`
+    /// * `effective_date` - Date the option takes effect
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy has a loan, the option cannot be quoted
+    /// or the state transition fails
+    pub fn apply(
+        &self,
+        policy: &mut Policy,
+        option: Option<NonforfeitureOption>,
+        attained_age: u32,
+        effective_date: NaiveDate,
+    ) -> Result<NonforfeitureQuote, PolicyError> {
+        if policy.financial_state().loan_outstanding.is_some_and(|loan| loan.is_positive()) {
+            return Err(PolicyError::Nonforfeiture(format!(
+                "Policy {} has a loan to offset against the cash value first",
+                policy.policy_number()
+            )));
+        }
+        let option = self.option_or_default(policy, option)?;
+        let quote = self.quote(policy, option, attained_age, effective_date)?;
+        self.convert(policy, quote)
+    }
+
+    /// Offsets the policy loan against the cash value and converts the
+    /// policy to paid-up cover
+    ///
+    /// The loan, with interest accrued to `effective_date`, is settled
+    /// through the `LoanEngine` set with [`Self::with_loans`] before the
+    /// remaining cash value buys the paid-up cover.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger receiving the loan offset
+    /// * `policy` - The in-force, reinstated or lapsed policy
+    /// * `loan` - The policy's loan account
+    /// * `option` - The option elected; the product default if `None`
+    /// * `attained_age` - Age of the insured on `effective_date`
+    /// * `effective_date` - Date the option takes effect
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no `LoanEngine` is set, the option cannot be
+    /// quoted, the loan cannot be offset or the state transition fails
+    pub fn apply_with_loan(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        loan: &mut PolicyLoan,
+        option: Option<NonforfeitureOption>,
+        attained_age: u32,
+        effective_date: NaiveDate,
+    ) -> Result<NonforfeitureQuote, PolicyError> {
+        let loans = self.loans.as_ref().ok_or_else(|| {
+            PolicyError::Nonforfeiture("No loan engine to offset the policy loan".to_string())
+        })?;
+        let option = self.option_or_default(policy, option)?;
+        // Check the option is available before posting the offset
+        self.quote(policy, option, attained_age, effective_date)?;
+
+        let cash_value = policy
+            .financial_state()
+            .surrender_value
+            .unwrap_or(Money::zero(policy.currency()));
+        let settled = loans.offset_against_cash_value(ledger, policy, loan, effective_date)?;
+        let quote = self.calculate(policy, option, attained_age, effective_date, cash_value, settled)?;
+        self.convert(policy, quote)
+    }
+
+    fn option_or_default(
+        &self,
+        policy: &Policy,
+        option: Option<NonforfeitureOption>,
+    ) -> Result<NonforfeitureOption, PolicyError> {
+        match option {
+            Some(option) => Ok(option),
+            None => Ok(self.require_rules(policy)?.default_option),
+        }
+    }
+
+    fn convert(&self, policy: &mut Policy, quote: NonforfeitureQuote) -> Result<NonforfeitureQuote, PolicyError> {
+        policy.apply_nonforfeiture(
+            quote.option,
+            quote.effective_date,
+            quote.sum_assured,
+            quote.net_cash_value,
+            quote.expiry_date,
+        )?;
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            option = ?quote.option,
+            sum_assured = %quote.sum_assured,
+            "Nonforfeiture option applied"
+        );
+
+        Ok(quote)
+    }
+
+    /// Expires extended term cover whose term has ended
+    ///
+    /// Returns whether the policy expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the state transition fails
+    pub fn process_expiry(&self, policy: &mut Policy, as_of: NaiveDate) -> Result<bool, PolicyError> {
+        match policy.state() {
+            PolicyState::PaidUp { expiry_date: Some(expiry_date), .. } if as_of > *expiry_date => {
+                let expiry_date = *expiry_date;
+                policy.expire(expiry_date)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn require_rules(&self, policy: &Policy) -> Result<&NonforfeitureRules, PolicyError> {
+        self.product_rules.for_policy(policy).ok_or_else(|| {
+            PolicyError::Nonforfeiture(format!(
+                "Product {} has no nonforfeiture options",
+                policy.product_code()
+            ))
+        })
+    }
+}
+
+/// Runs the net cash value down year by year paying for term cover
+///
+/// Each year's cost is paid at the start of the year and the balance
+/// accumulates at the valuation interest rate; the final part year is
+/// covered in proportion to the funds remaining.
+fn extended_term_expiry(
+    rules: &NonforfeitureRules,
+    net_cash_value: Money,
+    sum_assured: Money,
+    attained_age: u32,
+    effective_date: NaiveDate,
+) -> Result<NaiveDate, PolicyError> {
+    let mut funds = net_cash_value.amount();
+    let mut years = 0;
+    let mut days = 0;
+
+    while attained_age + years < MAX_TERM_AGE {
+        let age = attained_age + years;
+        let rate = rules
+            .rate_for(age)
+            .ok_or_else(|| PolicyError::Nonforfeiture(format!("No nonforfeiture rate for age {}", age)))?;
+        let cost = sum_assured.amount() / Decimal::ONE_THOUSAND * rate.term_cost_per_thousand;
+        if funds < cost {
+            days = (funds / cost * Decimal::from(365)).floor().to_i64().unwrap_or(0);
+            break;
+        }
+        funds = (funds - cost) * (Decimal::ONE + rules.interest_rate);
+        years += 1;
+    }
+
+    effective_date
+        .checked_add_months(Months::new(12 * years))
+        .map(|date| date + Duration::days(days))
+        .ok_or_else(|| PolicyError::Nonforfeiture("Extended term expiry out of range".to_string()))
+}
+
+fn death_benefit(policy: &Policy) -> Money {
+    policy
+        .coverages()
+        .iter()
+        .filter(|c| c.is_active && c.coverage_type == CoverageType::DeathBenefit)
+        .fold(Money::zero(policy.currency()), |total, c| total + c.effective_sum_assured())
+}
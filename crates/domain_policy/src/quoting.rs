@@ -0,0 +1,579 @@
+//! Multi-product quoting
+//!
+//! Advisers price one application across every product open for new
+//! business, with several sum assured, term and payment frequency
+//! combinations at once. The `QuotingService` fans the application out to
+//! each candidate and returns a ranked comparison.
+//!
+//! For each product in force on the application date and each scenario:
+//!
+//! 1. The scenario is checked against the catalog limits
+//! 2. The `UnderwritingService` evaluates the application against the
+//!    product rules, which decide eligibility and the loadings, and rates
+//!    disclosed conditions with its impairment manual
+//! 3. The `RatingService` prices the base coverage, with any flat extra,
+//!    for the risk class underwritten
+//! 4. The annual premium is converted to the modal premium with
+//!    `PremiumFrequency::modal_factor`
+//!
+//! Eligible options are ranked by annualized modal premium per 1,000 of
+//! sum assured, cheapest first, and each is written as a quoted `Policy`
+//! pinned to the product version and carrying the quote expiry. Ineligible
+//! options follow, unranked, with the reasons they could not be offered.
+//!
+//! The term of a scenario applies only to products with term limits; other
+//! products are quoted once per sum assured and frequency.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use core_kernel::{Money, PartyId, PolicyId};
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::catalog::{payment_mode_name, ProductCatalog, ProductVersion};
+use crate::coverage::Coverage;
+use crate::error::PolicyError;
+use crate::premium::{Premium, PremiumFrequency};
+use crate::rules_engine::{EvaluationResult, ProductRules, RulesEngine};
+use crate::services::{RatingService, UnderwritingService};
+use crate::underwriting::{Gender, RiskClass, UnderwritingApplication};
+
+/// One combination of cover to quote
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuoteScenario {
+    /// Base sum assured
+    pub sum_assured: Money,
+    /// Policy term, for term products
+    pub term_years: Option<u32>,
+    /// Premium payment frequency
+    pub frequency: PremiumFrequency,
+}
+
+impl QuoteScenario {
+    /// Creates a scenario
+    pub fn new(sum_assured: Money, term_years: Option<u32>, frequency: PremiumFrequency) -> Self {
+        Self {
+            sum_assured,
+            term_years,
+            frequency,
+        }
+    }
+}
+
+/// An application to price across the catalog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteRequest {
+    /// Prospective policyholder
+    pub policyholder_id: PartyId,
+    /// Underwriting application for the life to be insured
+    pub application: UnderwritingApplication,
+    /// Date of application; selects the product versions quoted
+    pub application_date: NaiveDate,
+    /// Combinations of cover to quote
+    pub scenarios: Vec<QuoteScenario>,
+}
+
+/// Loadings applied by the product rules, in percent
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuoteLoadings {
+    /// Smoker loading
+    pub smoker: Decimal,
+    /// BMI loading
+    pub bmi: Decimal,
+    /// Occupation loading
+    pub occupation: Decimal,
+    /// Family history loading
+    pub family_history: Decimal,
+    /// Total loading
+    pub total: Decimal,
+}
+
+impl QuoteLoadings {
+    fn from_evaluation(result: &EvaluationResult) -> Self {
+        Self {
+            smoker: result.smoker_loading_percent.unwrap_or_default(),
+            bmi: result.bmi_loading_percent.unwrap_or_default(),
+            occupation: result.occupation_loading_percent.unwrap_or_default(),
+            family_history: result.family_history_loading_percent.unwrap_or_default(),
+            total: result.total_loading_percent.unwrap_or_default(),
+        }
+    }
+}
+
+/// One product and scenario in a comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteOption {
+    /// Product quoted
+    pub product_code: String,
+    /// Product name
+    pub product_name: String,
+    /// Product version quoted
+    pub product_version: String,
+    /// Cover quoted; the term is cleared for products without a term
+    pub scenario: QuoteScenario,
+    /// Position in the comparison, from 1; `None` if not eligible
+    pub rank: Option<u32>,
+    /// Whether the product can be offered
+    pub eligible: bool,
+    /// Why the product cannot be offered, or conditions on the offer
+    pub reasons: Vec<String>,
+    /// Loadings applied by the product rules
+    pub loadings: QuoteLoadings,
+    /// Risk class priced
+    pub risk_class: Option<RiskClass>,
+    /// Annual premium
+    pub annual_premium: Option<Money>,
+    /// Premium per payment at the scenario frequency
+    pub modal_premium: Option<Money>,
+    /// Modal premium times the number of payments a year
+    pub annualized_premium: Option<Money>,
+    /// Quoted policy for eligible options
+    pub quote: Option<Policy>,
+}
+
+impl QuoteOption {
+    fn ineligible(product: &ProductVersion, scenario: QuoteScenario, reason: impl Into<String>) -> Self {
+        Self {
+            product_code: product.code.clone(),
+            product_name: product.name.clone(),
+            product_version: product.version.clone(),
+            scenario,
+            rank: None,
+            eligible: false,
+            reasons: vec![reason.into()],
+            loadings: QuoteLoadings::default(),
+            risk_class: None,
+            annual_premium: None,
+            modal_premium: None,
+            annualized_premium: None,
+            quote: None,
+        }
+    }
+
+    /// ID of the quoted policy, for eligible options
+    pub fn quote_id(&self) -> Option<PolicyId> {
+        self.quote.as_ref().map(Policy::id)
+    }
+
+    /// When the quote expires, for eligible options
+    pub fn quote_expiry(&self) -> Option<DateTime<Utc>> {
+        match self.quote.as_ref().map(Policy::state) {
+            Some(PolicyState::Quoted { quote_expiry, .. }) => Some(*quote_expiry),
+            _ => None,
+        }
+    }
+
+    /// Annualized premium per 1,000 of sum assured
+    pub fn cost_per_thousand(&self) -> Option<Decimal> {
+        let sum_assured = self.scenario.sum_assured.amount();
+        match self.annualized_premium {
+            Some(premium) if !sum_assured.is_zero() => {
+                Some(premium.amount() * Decimal::ONE_THOUSAND / sum_assured)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Ranked comparison of the products an application can be offered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteComparison {
+    /// Unique identifier
+    pub id: Uuid,
+    /// Prospective policyholder
+    pub policyholder_id: PartyId,
+    /// Date of application
+    pub application_date: NaiveDate,
+    /// Age of the insured on the application date
+    pub applicant_age: u32,
+    /// When the comparison was prepared
+    pub prepared_at: DateTime<Utc>,
+    /// Ranked eligible options, then ineligible options
+    pub options: Vec<QuoteOption>,
+}
+
+impl QuoteComparison {
+    /// Eligible options, best first
+    pub fn eligible(&self) -> impl Iterator<Item = &QuoteOption> {
+        self.options.iter().filter(|o| o.eligible)
+    }
+
+    /// Options that cannot be offered
+    pub fn ineligible(&self) -> impl Iterator<Item = &QuoteOption> {
+        self.options.iter().filter(|o| !o.eligible)
+    }
+
+    /// The best ranked option
+    pub fn best(&self) -> Option<&QuoteOption> {
+        self.eligible().next()
+    }
+
+    /// Retrieves a quote that has not expired
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the comparison holds no such quote or it has
+    /// expired
+    pub fn quote(&self, quote_id: PolicyId, now: DateTime<Utc>) -> Result<&QuoteOption, PolicyError> {
+        let option = self
+            .options
+            .iter()
+            .find(|o| o.quote_id() == Some(quote_id))
+            .ok_or_else(|| PolicyError::Quoting(format!("Quote {} not found", quote_id)))?;
+        if option.quote_expiry().is_some_and(|expiry| expiry <= now) {
+            return Err(PolicyError::Quoting(format!("Quote {} has expired", quote_id)));
+        }
+        Ok(option)
+    }
+}
+
+/// Service pricing an application across the product catalog
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let service = QuotingService::new(ProductCatalog::load(Path::new("products"))?);
+/// let comparison = service.compare(&QuoteRequest {
+///     policyholder_id,
+///     application,
+///     application_date,
+///     scenarios: vec![
+///         QuoteScenario::new(usd(250_000), Some(20), PremiumFrequency::Monthly),
+///         QuoteScenario::new(usd(500_000), Some(20), PremiumFrequency::Annual),
+///     ],
+/// })?;
+///
+/// // Store the eligible options until they expire; `POST /api/v1/quotes`
+/// // stores them and `GET /api/v1/quotes/:id` reloads one
+/// let quotes = comparison
+///     .eligible()
+///     .map(|option| new_quote(&comparison, option))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// repo.insert_quotes(quotes).await?;
+/// ```
+pub struct QuotingService {
+    catalog: ProductCatalog,
+    rules_engine: Arc<RulesEngine>,
+    rating: RatingService,
+    underwriting: UnderwritingService,
+    quote_validity_days: Option<u32>,
+}
+
+impl QuotingService {
+    /// Creates a service quoting the products in a catalog
+    ///
+    /// Products are evaluated against the rules loaded with the catalog.
+    pub fn new(catalog: ProductCatalog) -> Self {
+        let rules_engine = Arc::new(RulesEngine::new());
+        Self {
+            catalog,
+            underwriting: UnderwritingService::new().with_rules_engine(rules_engine.clone()),
+            rules_engine,
+            rating: RatingService::new(),
+            quote_validity_days: None,
+        }
+    }
+
+    /// Evaluates with a shared rules engine
+    ///
+    /// Revisions of a product's rules reloaded into the engine replace the
+    /// rules loaded with the catalog, and products without rules in the
+    /// catalog use the engine's.
+    pub fn with_rules_engine(mut self, rules_engine: Arc<RulesEngine>) -> Self {
+        self.underwriting = self.underwriting.with_rules_engine(rules_engine.clone());
+        self.rules_engine = rules_engine;
+        self
+    }
+
+    /// Underwrites with an underwriting service
+    ///
+    /// Options are priced for the risk class the service decides,
+    /// including its impairment manual ratings. The service evaluates
+    /// product rules with this service's rules engine.
+    pub fn with_underwriting_service(mut self, underwriting: UnderwritingService) -> Self {
+        self.underwriting = underwriting.with_rules_engine(self.rules_engine.clone());
+        self
+    }
+
+    /// Prices with a rating service
+    pub fn with_rating_service(mut self, rating: RatingService) -> Self {
+        self.rating = rating;
+        self
+    }
+
+    /// Sets the number of days quotes remain valid
+    pub fn with_quote_validity_days(mut self, days: u32) -> Self {
+        self.quote_validity_days = Some(days);
+        self
+    }
+
+    /// Returns the catalog being quoted
+    pub fn catalog(&self) -> &ProductCatalog {
+        &self.catalog
+    }
+
+    /// Prices an application across every product and scenario
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The application and the scenarios to quote
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no scenarios are given or a quoted policy cannot
+    /// be built
+    pub fn compare(&self, request: &QuoteRequest) -> Result<QuoteComparison, PolicyError> {
+        if request.scenarios.is_empty() {
+            return Err(PolicyError::Quoting("At least one scenario is required".to_string()));
+        }
+        let age = request
+            .application_date
+            .years_since(request.application.applicant.date_of_birth)
+            .ok_or_else(|| PolicyError::Quoting("Applicant is born after the application date".to_string()))?;
+
+        let mut eligible = Vec::new();
+        let mut ineligible = Vec::new();
+        for product in self.catalog.in_force_on(request.application_date) {
+            if !product.active {
+                continue;
+            }
+            let mut quoted = HashSet::new();
+            for scenario in &request.scenarios {
+                let scenario = QuoteScenario {
+                    term_years: scenario.term_years.filter(|_| is_term_product(product)),
+                    ..scenario.clone()
+                };
+                let key = (
+                    scenario.sum_assured.amount(),
+                    scenario.sum_assured.currency(),
+                    scenario.term_years,
+                    payment_mode_name(scenario.frequency),
+                );
+                if !quoted.insert(key) {
+                    continue;
+                }
+                let option = self.quote_option(request, product, scenario, age)?;
+                if option.eligible {
+                    eligible.push(option);
+                } else {
+                    ineligible.push(option);
+                }
+            }
+        }
+
+        eligible.sort_by(|a, b| {
+            a.cost_per_thousand()
+                .cmp(&b.cost_per_thousand())
+                .then_with(|| {
+                    let premium = |o: &QuoteOption| o.annualized_premium.map(|p| p.amount());
+                    premium(a).cmp(&premium(b))
+                })
+        });
+        for (index, option) in eligible.iter_mut().enumerate() {
+            option.rank = Some(index as u32 + 1);
+        }
+
+        tracing::info!(
+            application_date = %request.application_date,
+            eligible = eligible.len(),
+            ineligible = ineligible.len(),
+            "Quote comparison prepared"
+        );
+
+        eligible.extend(ineligible);
+        Ok(QuoteComparison {
+            id: Uuid::new_v4(),
+            policyholder_id: request.policyholder_id,
+            application_date: request.application_date,
+            applicant_age: age,
+            prepared_at: Utc::now(),
+            options: eligible,
+        })
+    }
+
+    /// Prices one product and scenario
+    fn quote_option(
+        &self,
+        request: &QuoteRequest,
+        product: &ProductVersion,
+        scenario: QuoteScenario,
+        age: u32,
+    ) -> Result<QuoteOption, PolicyError> {
+        if is_term_product(product) && scenario.term_years.is_none() {
+            let reason = format!("{} requires a policy term", product.code);
+            return Ok(QuoteOption::ineligible(product, scenario, reason));
+        }
+        if let Err(e) = product.check_application(scenario.sum_assured, age, scenario.term_years, scenario.frequency) {
+            return Ok(QuoteOption::ineligible(product, scenario, e.to_string()));
+        }
+
+        let Some(rules) = self.rules_for(product) else {
+            let reason = format!("No rules are loaded for {}", product.code);
+            return Ok(QuoteOption::ineligible(product, scenario, reason));
+        };
+        let mut coverage = base_coverage(product, scenario.sum_assured);
+        let application = UnderwritingApplication {
+            coverages: vec![coverage.clone()],
+            ..request.application.clone()
+        };
+        let context = rules_context(&application, &scenario, age);
+        let underwritten =
+            self.underwriting
+                .evaluate_against_rules(&application, &rules, context, request.application_date);
+        let (decision, result) = match underwritten {
+            Ok(underwritten) => underwritten,
+            Err(e) => {
+                tracing::warn!(product_code = %product.code, error = %e, "Application could not be underwritten");
+                let reason = format!("Application could not be underwritten: {}", e);
+                return Ok(QuoteOption::ineligible(product, scenario, reason));
+            }
+        };
+        if !result.eligible || result.action.as_deref() == Some("decline") {
+            let reason = result
+                .eligibility_reason
+                .clone()
+                .unwrap_or_else(|| "Not eligible under product rules".to_string());
+            return Ok(QuoteOption::ineligible(product, scenario, reason));
+        }
+
+        let risk_class = decision.risk_class;
+        if risk_class == RiskClass::Declined {
+            let mut option = QuoteOption::ineligible(product, scenario, "Declined on underwriting");
+            option.reasons.extend(decision.reasons);
+            return Ok(option);
+        }
+        let mut reasons = Vec::new();
+        if result.action.as_deref() == Some("refer") {
+            reasons.push("Subject to referral to an underwriter".to_string());
+        }
+        reasons.extend(decision.exclusions.iter().map(|e| format!("Excludes {}", e.description)));
+        if let Some(per_mille) = decision.flat_extra_per_mille {
+            coverage = coverage.with_flat_extra(per_mille);
+        }
+
+        let loadings = QuoteLoadings::from_evaluation(&result);
+        let currency = scenario.sum_assured.currency();
+        let annual_premium = self
+            .rating
+            .calculate_premium(
+                std::slice::from_ref(&coverage),
+                age,
+                request.application.medical_history.is_smoker,
+                risk_class,
+                currency,
+            )?
+            .base_amount
+            .round_to_currency();
+        let modal_premium = annual_premium.multiply(scenario.frequency.modal_factor()).round_to_currency();
+        let annualized_premium = modal_premium.multiply(Decimal::from(scenario.frequency.payments_per_year()));
+
+        let mut builder = self
+            .catalog
+            .start_quote(&product.code, request.application_date)?
+            .policyholder(request.policyholder_id)
+            .currency(currency)
+            .add_coverage(coverage)
+            .premium(Premium::new(modal_premium, scenario.frequency))
+            .risk_class(risk_class);
+        if let Some(term) = scenario.term_years {
+            builder = builder.term_years(term);
+        }
+        if let Some(days) = self.quote_validity_days {
+            builder = builder.quote_validity_days(days);
+        }
+
+        Ok(QuoteOption {
+            product_code: product.code.clone(),
+            product_name: product.name.clone(),
+            product_version: product.version.clone(),
+            scenario,
+            rank: None,
+            eligible: true,
+            reasons,
+            loadings,
+            risk_class: Some(risk_class),
+            annual_premium: Some(annual_premium),
+            modal_premium: Some(modal_premium),
+            annualized_premium: Some(annualized_premium),
+            quote: Some(builder.build()?),
+        })
+    }
+
+    /// Rules for a product version, including reloaded revisions
+    fn rules_for(&self, product: &ProductVersion) -> Option<Arc<ProductRules>> {
+        product.current_rules(&self.rules_engine)
+    }
+}
+
+/// Whether a product is sold for a fixed term
+fn is_term_product(product: &ProductVersion) -> bool {
+    product.limits.min_term_years.is_some() || product.limits.max_term_years.is_some()
+}
+
+/// Base coverage for a product
+fn base_coverage(product: &ProductVersion, sum_assured: Money) -> Coverage {
+    let subcategory = product.definition.get("subcategory").and_then(|s| s.as_str());
+    if subcategory == Some("critical_illness") {
+        let waiting_period_days = product
+            .definition
+            .pointer("/limits/waiting_period_days")
+            .and_then(|d| d.as_u64())
+            .unwrap_or_default();
+        Coverage::critical_illness(sum_assured, waiting_period_days as u32)
+    } else {
+        Coverage::death_benefit(sum_assured)
+    }
+}
+
+/// Builds the rules context for an application and scenario
+///
+/// Critical illness history is any disclosed cancer, stroke or heart
+/// attack; family history counts relatives diagnosed before age 60.
+fn rules_context(application: &UnderwritingApplication, scenario: &QuoteScenario, age: u32) -> Value {
+    let medical = &application.medical_history;
+    let family_before_60 = |keyword: &str| {
+        medical.family_history.iter().any(|f| {
+            f.condition.to_lowercase().contains(keyword) && f.age_at_diagnosis.is_some_and(|age| age < 60)
+        })
+    };
+    let critical_illness_history = medical.conditions.iter().any(|c| {
+        let name = c.name.to_lowercase();
+        ["cancer", "stroke", "heart attack"].iter().any(|keyword| name.contains(keyword))
+    });
+    let gender = match application.applicant.gender {
+        Gender::Male => "male",
+        Gender::Female => "female",
+        Gender::Other => "other",
+    };
+
+    json!({
+        "applicant": {
+            "age": age,
+            "gender": gender,
+            "occupation_class": application.applicant.occupation_class,
+        },
+        "medical": {
+            "bmi": medical.bmi(),
+            "is_smoker": medical.is_smoker,
+            "is_former_smoker": medical.is_former_smoker,
+            "has_critical_illness_history": critical_illness_history,
+            "family_cancer_before_60": family_before_60("cancer"),
+            "family_heart_disease_before_60": family_before_60("heart"),
+            "family_stroke_before_60": family_before_60("stroke"),
+        },
+        "coverage": {
+            "sum_assured": scenario.sum_assured.amount().to_f64(),
+            "term_years": scenario.term_years,
+        },
+        "payment": {
+            "mode": payment_mode_name(scenario.frequency),
+        },
+    })
+}
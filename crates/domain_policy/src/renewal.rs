@@ -0,0 +1,416 @@
+//! Renewal processing
+//!
+//! Renewable term policies can be continued for a further term at the end
+//! of each term. Ahead of the renewal date the policy is re-rated at the
+//! insured's attained age in its recorded risk class and a renewal offer
+//! is sent. Paying the renewal premium renews the policy automatically;
+//! policies that are not renewed, or have reached the product's maximum
+//! maturity age, expire at the end of the term.
+//!
+//! Renewal rules are configured per product in the `renewal_rules` block
+//! of `catalog.json`. The maximum maturity age comes from the product
+//! `limits` unless set in the block:
+//!
+//! ```json
+//! "renewal_rules": {
+//!   "renewal_term_years": 5,
+//!   "notice_period_days": 45
+//! }
+//! ```
+
+use chrono::{Duration, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use core_kernel::{Money, PolicyId};
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::coverage::Coverage;
+use crate::catalog::VersionedRules;
+use crate::error::PolicyError;
+use crate::premium::{Premium, RiderPremium};
+use crate::services::RatingService;
+use crate::underwriting::RiskClass;
+
+/// Product-level renewal rules
+///
+/// Loaded from the `renewal_rules` block of a product in `catalog.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenewalRules {
+    /// Length of each renewal term
+    pub renewal_term_years: u32,
+    /// Days before the renewal date that the offer is issued
+    pub notice_period_days: u32,
+    /// Age at which cover must end
+    #[serde(default)]
+    pub max_maturity_age: Option<u32>,
+}
+
+impl RenewalRules {
+    /// Reads the renewal rules from a catalog product entry
+    ///
+    /// Returns `None` when the product has no `renewal_rules` block. The
+    /// maximum maturity age defaults to `limits.max_maturity_age`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the block is present but malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        let Some(block) = product.get("renewal_rules") else {
+            return Ok(None);
+        };
+        let mut rules: Self = serde_json::from_value(block.clone())
+            .map_err(|e| PolicyError::validation(format!("Invalid renewal_rules: {}", e)))?;
+        if rules.max_maturity_age.is_none() {
+            rules.max_maturity_age = product
+                .pointer("/limits/max_maturity_age")
+                .and_then(|v| v.as_u64())
+                .map(|age| age as u32);
+        }
+        Ok(Some(rules))
+    }
+
+    /// Reads the renewal rules of a product whose `features.renewable`
+    /// flag is set
+    ///
+    /// Returns `None` for products that are not renewable, even if they
+    /// have renewal rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the `renewal_rules` block is malformed
+    pub fn from_renewable_product(product: &Value) -> Result<Option<Self>, PolicyError> {
+        let renewable = product
+            .pointer("/features/renewable")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        Ok(Self::from_catalog_product(product)?.filter(|_| renewable))
+    }
+
+    /// Returns the renewal term available at an attained age
+    ///
+    /// The term is shortened so cover ends by the maximum maturity age;
+    /// zero means the policy cannot be renewed.
+    pub fn term_at_age(&self, attained_age: u32) -> u32 {
+        match self.max_maturity_age {
+            Some(max_age) => self.renewal_term_years.min(max_age.saturating_sub(attained_age)),
+            None => self.renewal_term_years,
+        }
+    }
+
+    /// Returns the range of expiry dates due an offer on a date
+    ///
+    /// Offers go to policies whose renewal date is after the first date
+    /// and on or before the second.
+    pub fn notice_window(&self, as_of: NaiveDate) -> (NaiveDate, NaiveDate) {
+        (as_of, as_of + Duration::days(i64::from(self.notice_period_days)))
+    }
+}
+
+/// A renewal offer for a policy approaching the end of its term
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenewalOffer {
+    /// Policy being renewed
+    pub policy_id: PolicyId,
+    /// Policy number
+    pub policy_number: String,
+    /// Date the renewal term starts (end of the current term)
+    pub renewal_date: NaiveDate,
+    /// Date the renewal term would end
+    pub new_expiry_date: NaiveDate,
+    /// Length of the renewal term
+    pub term_years: u32,
+    /// Age of the insured at renewal
+    pub attained_age: u32,
+    /// Risk class the policy was re-rated in
+    pub risk_class: RiskClass,
+    /// Annual premium for the base coverages in the renewal term
+    pub annual_premium: Money,
+    /// Premium per payment for the renewal term, with each continuing
+    /// rider re-rated separately
+    pub premium: Premium,
+    /// Date the offer was issued
+    pub offered_on: NaiveDate,
+}
+
+/// Outcome of preparing a policy for renewal
+#[derive(Debug, Clone)]
+pub enum RenewalDecision {
+    /// The policy may be renewed on the offered terms
+    Offer(RenewalOffer),
+    /// The policy cannot be renewed and will expire at the end of its term
+    Expire {
+        /// Date cover ends
+        expiry_date: NaiveDate,
+        /// Why the policy cannot be renewed
+        reason: String,
+    },
+}
+
+/// Engine for renewing term policies
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = RenewalEngine::from_catalog(&catalog)?;
+///
+/// for policy in engine.due_for_offer(&policies, today) {
+///     if let RenewalDecision::Offer(offer) = engine.prepare(policy, age, false, today)? {
+///         notifications.send_renewal_offer(&offer)?;
+///     }
+/// }
+///
+/// // When the renewal premium is received
+/// engine.renew_on_payment(&mut policy, &offer, payment)?;
+/// ```
+#[derive(Default)]
+pub struct RenewalEngine {
+    product_rules: VersionedRules<RenewalRules>,
+    rating: RatingService,
+}
+
+impl RenewalEngine {
+    /// Creates an engine with no renewable products
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an engine with per-product rules read from the catalog
+    ///
+    /// Products whose `features.renewable` flag is false are not renewable
+    /// even if they have renewal rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `renewal_rules`
+    pub fn from_catalog(catalog: &Value) -> Result<Self, PolicyError> {
+        let mut engine = Self::new();
+        engine.product_rules = VersionedRules::from_catalog(catalog, RenewalRules::from_renewable_product)?;
+        Ok(engine)
+    }
+
+    /// Sets the rules for a product
+    pub fn with_product_rules(mut self, product_code: impl Into<String>, rules: RenewalRules) -> Self {
+        self.product_rules.insert(product_code.into(), rules);
+        self
+    }
+
+    /// Sets the rating service used to re-rate renewals
+    pub fn with_rating(mut self, rating: RatingService) -> Self {
+        self.rating = rating;
+        self
+    }
+
+    /// Returns the rules of a product's latest version, if it is renewable
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::rules_for_policy`].
+    pub fn latest_rules_for(&self, product_code: &str) -> Option<&RenewalRules> {
+        self.product_rules.latest(product_code)
+    }
+
+    /// Returns the rules of the product version a policy was sold under
+    pub fn rules_for_policy(&self, policy: &Policy) -> Option<&RenewalRules> {
+        self.product_rules.for_policy(policy)
+    }
+
+    /// Selects the policies whose renewal date falls within the notice
+    /// period of the product version they were sold under
+    pub fn due_for_offer<'a>(&self, policies: &'a [Policy], as_of: NaiveDate) -> Vec<&'a Policy> {
+        policies
+            .iter()
+            .filter(|policy| {
+                match (self.rules_for_policy(policy).map(|rules| rules.notice_window(as_of)), policy.renewal_date()) {
+                    (Some((from, to)), Some(renewal_date)) => renewal_date > from && renewal_date <= to,
+                    _ => false,
+                }
+            })
+            .collect()
+    }
+
+    /// Re-rates a policy for renewal
+    ///
+    /// The active base coverages are rated at the attained age in the
+    /// policy's recorded risk class and the premium is spread over the
+    /// current payment frequency. Each rider continuing past the renewal
+    /// date is re-rated on its own and kept as a separate rider premium.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy approaching renewal
+    /// * `attained_age` - Age of the insured on the renewal date, used if
+    ///   the policy records no insured life
+    /// * `is_smoker` - Smoking status of the insured, used if the policy
+    ///   records no insured life
+    /// * `as_of` - Date the offer is prepared
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product is not renewable, the policy is not in
+    /// force, or rating fails
+    pub fn prepare(
+        &self,
+        policy: &Policy,
+        attained_age: u32,
+        is_smoker: bool,
+        as_of: NaiveDate,
+    ) -> Result<RenewalDecision, PolicyError> {
+        let rules = self.require_rules(policy)?;
+        let renewal_date = policy.renewal_date().ok_or_else(|| {
+            PolicyError::Renewal(format!(
+                "Policy {} is not in force ({:?})",
+                policy.policy_number(),
+                policy.state()
+            ))
+        })?;
+        let (attained_age, is_smoker) = match policy.insured() {
+            Some(insured) => (insured.age_on(renewal_date), insured.is_smoker),
+            None => (attained_age, is_smoker),
+        };
+
+        let term_years = rules.term_at_age(attained_age);
+        if term_years == 0 {
+            return Ok(RenewalDecision::Expire {
+                expiry_date: renewal_date,
+                reason: format!("Maximum maturity age reached at age {}", attained_age),
+            });
+        }
+        let new_expiry_date = renewal_date
+            .checked_add_months(Months::new(12 * term_years))
+            .ok_or_else(|| PolicyError::Renewal("Renewal term out of range".to_string()))?;
+
+        let risk_class = policy.risk_class().unwrap_or(RiskClass::Standard);
+        let riders: Vec<_> = policy
+            .riders()
+            .iter()
+            .filter(|r| r.is_active() && !matches!(r.expiry_date, Some(expiry) if expiry <= renewal_date))
+            .collect();
+        let coverages: Vec<_> = policy
+            .coverages()
+            .iter()
+            .filter(|c| c.is_active && !policy.riders().iter().any(|r| r.coverage_id == c.id))
+            .cloned()
+            .collect();
+        let rate = |coverages: &[Coverage]| -> Result<Money, PolicyError> {
+            Ok(self
+                .rating
+                .calculate_premium(coverages, attained_age, is_smoker, risk_class, policy.currency())?
+                .base_amount
+                .round_to_currency())
+        };
+        let annual_premium = rate(&coverages)?;
+
+        let current = policy.premium();
+        let modal = |annual: Money| annual.multiply(current.frequency.modal_factor()).round_to_currency();
+        let mut premium = Premium::new(modal(annual_premium), current.frequency);
+        premium.policy_fee = current.policy_fee;
+        for rider in riders {
+            let Some(coverage) = policy.coverages().iter().find(|c| c.id == rider.coverage_id && c.is_active) else {
+                continue;
+            };
+            premium.add_rider_premium(RiderPremium {
+                rider_code: rider.code.clone(),
+                rider_name: rider.name.clone(),
+                amount: modal(rate(std::slice::from_ref(coverage))?),
+            });
+        }
+
+        Ok(RenewalDecision::Offer(RenewalOffer {
+            policy_id: policy.id(),
+            policy_number: policy.policy_number().to_string(),
+            renewal_date,
+            new_expiry_date,
+            term_years,
+            attained_age,
+            risk_class,
+            annual_premium,
+            premium,
+            offered_on: as_of,
+        }))
+    }
+
+    /// Renews a policy when the renewal premium is paid
+    ///
+    /// Returns the new expiry date.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the offer is for another policy or term, or the
+    /// payment does not cover the first renewal premium
+    pub fn renew_on_payment(
+        &self,
+        policy: &mut Policy,
+        offer: &RenewalOffer,
+        payment: Money,
+    ) -> Result<NaiveDate, PolicyError> {
+        if offer.policy_id != policy.id() {
+            return Err(PolicyError::Renewal(format!(
+                "Offer for policy {} applied to policy {}",
+                offer.policy_number,
+                policy.policy_number()
+            )));
+        }
+        if policy.renewal_date() != Some(offer.renewal_date) {
+            return Err(PolicyError::Renewal(format!(
+                "Offer for renewal on {} no longer applies to policy {}",
+                offer.renewal_date,
+                policy.policy_number()
+            )));
+        }
+        let due = offer.premium.total_per_payment();
+        if payment.currency() != due.currency() {
+            return Err(PolicyError::CurrencyMismatch {
+                expected: due.currency().to_string(),
+                actual: payment.currency().to_string(),
+            });
+        }
+        if payment.amount() < due.amount() {
+            return Err(PolicyError::Renewal(format!(
+                "Payment {} does not cover the renewal premium {}",
+                payment, due
+            )));
+        }
+
+        let new_expiry_date = policy.renew(offer.term_years, offer.premium.clone())?;
+        policy.record_payment(payment)?;
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            new_expiry_date = %new_expiry_date,
+            premium = %due,
+            "Policy renewed"
+        );
+
+        Ok(new_expiry_date)
+    }
+
+    /// Expires a term policy whose term has ended without renewal
+    ///
+    /// Returns whether the policy expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the state transition fails
+    pub fn process_expiry(&self, policy: &mut Policy, as_of: NaiveDate) -> Result<bool, PolicyError> {
+        match (policy.state(), policy.expiry_date()) {
+            (PolicyState::InForce { .. } | PolicyState::Reinstated { .. }, Some(expiry_date))
+                if as_of > expiry_date =>
+            {
+                policy.expire(expiry_date)?;
+                tracing::info!(
+                    policy_number = %policy.policy_number(),
+                    expiry_date = %expiry_date,
+                    "Policy expired at end of term"
+                );
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn require_rules(&self, policy: &Policy) -> Result<&RenewalRules, PolicyError> {
+        self.product_rules.for_policy(policy).ok_or_else(|| {
+            PolicyError::Renewal(format!("Product {} is not renewable", policy.product_code()))
+        })
+    }
+}
@@ -0,0 +1,506 @@
+//! Riders
+//!
+//! Riders are optional benefits attached to a base coverage. Each product
+//! lists the riders it offers in the `available_riders` block of
+//! `catalog.json`, together with the limits that apply:
+//!
+//! - `max_multiple` - rider sum assured as a multiple of the base sum assured
+//! - `max_percentage` - rider sum assured as a percentage of the base sum assured
+//! - `payout_percentage` - fixed benefit as a percentage of the base sum assured
+//! - `max_age` - age at which the rider ends
+//!
+//! `RiderEngine` validates those limits when a rider is added to a quote
+//! or by endorsement, rates the rider separately from the base plan, and
+//! ends riders once the insured reaches their expiry age.
+
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use core_kernel::Money;
+
+use crate::aggregate::{Policy, PolicyState};
+use crate::catalog::VersionedRules;
+use crate::catalog_serde;
+use crate::coverage::{BenefitAmount, Coverage, CoverageType};
+use crate::endorsement::{Endorsement, EndorsementType};
+use crate::error::PolicyError;
+use crate::premium::RiderPremium;
+use crate::services::RatingService;
+use crate::underwriting::RiskClass;
+
+/// A rider offered by a product
+///
+/// Loaded from an entry in the product's `available_riders` block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiderDefinition {
+    /// Rider code
+    pub code: String,
+    /// Rider name
+    pub name: String,
+    /// Rider description
+    #[serde(default)]
+    pub description: String,
+    /// Maximum rider sum assured as a multiple of the base sum assured
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub max_multiple: Option<Decimal>,
+    /// Maximum rider sum assured as a percentage of the base sum assured
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub max_percentage: Option<Decimal>,
+    /// Rider benefit as a percentage of the base sum assured
+    #[serde(default, deserialize_with = "catalog_serde::optional_rate")]
+    pub payout_percentage: Option<Decimal>,
+    /// Age at which the rider ends
+    #[serde(default)]
+    pub max_age: Option<u32>,
+}
+
+impl RiderDefinition {
+    /// Reads the riders offered by a catalog product entry
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `available_riders` is malformed
+    pub fn from_catalog_product(product: &Value) -> Result<Vec<Self>, PolicyError> {
+        match product.get("available_riders") {
+            Some(riders) => serde_json::from_value(riders.clone())
+                .map_err(|e| PolicyError::validation(format!("Invalid available_riders: {}", e))),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the coverage type provided by the rider
+    pub fn coverage_type(&self) -> CoverageType {
+        match self.code.as_str() {
+            "AD" => CoverageType::AccidentalDeath,
+            "WOP" => CoverageType::WaiverOfPremium,
+            "CI_RIDER" => CoverageType::CriticalIllness,
+            "PUA" => CoverageType::WholeLifeRider,
+            code => CoverageType::Custom(code.to_string()),
+        }
+    }
+
+    /// Returns the highest rider sum assured as a multiple of the base sum
+    /// assured, if the rider is limited
+    pub fn max_factor(&self) -> Option<Decimal> {
+        self.max_multiple
+            .or_else(|| self.max_percentage.map(|p| p / Decimal::ONE_HUNDRED))
+            .or_else(|| self.payout_percentage.map(|p| p / Decimal::ONE_HUNDRED))
+    }
+
+    /// Returns the highest rider sum assured allowed on a base sum assured
+    pub fn max_sum_assured(&self, base_sum_assured: Money) -> Option<Money> {
+        self.max_factor().map(|factor| base_sum_assured.multiply(factor))
+    }
+}
+
+/// A rider attached to a policy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rider {
+    /// Rider code from the catalog
+    pub code: String,
+    /// Rider name
+    pub name: String,
+    /// Policy coverage provided by the rider
+    pub coverage_id: Uuid,
+    /// Base coverage the rider is attached to
+    pub base_coverage_id: Uuid,
+    /// Date the rider was attached
+    pub attached_on: NaiveDate,
+    /// Age at which the rider ends
+    pub expiry_age: Option<u32>,
+    /// Date the insured reaches the expiry age
+    pub expiry_date: Option<NaiveDate>,
+    /// Date the rider ended
+    pub terminated_on: Option<NaiveDate>,
+    /// Highest rider sum assured as a multiple of the base sum assured,
+    /// from the catalog when the rider was attached
+    #[serde(default)]
+    pub max_factor: Option<Decimal>,
+}
+
+impl Rider {
+    /// Whether the rider is still in effect
+    pub fn is_active(&self) -> bool {
+        self.terminated_on.is_none()
+    }
+
+    /// Returns the highest rider sum assured allowed on a base sum assured
+    pub fn max_sum_assured(&self, base_sum_assured: Money) -> Option<Money> {
+        self.max_factor.map(|factor| base_sum_assured.multiply(factor))
+    }
+}
+
+/// A request to add a rider to a policy
+#[derive(Debug, Clone)]
+pub struct RiderRequest {
+    /// Rider code from the catalog
+    pub code: String,
+    /// Rider sum assured
+    pub sum_assured: Money,
+    /// Insured's date of birth, used if the policy records no insured life
+    pub insured_birth_date: NaiveDate,
+    /// Whether the insured smokes, used if the policy records no insured life
+    pub is_smoker: bool,
+    /// Base coverage to attach to; the first active base coverage if `None`
+    pub base_coverage_id: Option<Uuid>,
+}
+
+impl RiderRequest {
+    /// Creates a rider request
+    pub fn new(code: impl Into<String>, sum_assured: Money, insured_birth_date: NaiveDate) -> Self {
+        Self {
+            code: code.into(),
+            sum_assured,
+            insured_birth_date,
+            is_smoker: false,
+            base_coverage_id: None,
+        }
+    }
+
+    /// Marks the insured as a smoker
+    pub fn smoker(mut self, is_smoker: bool) -> Self {
+        self.is_smoker = is_smoker;
+        self
+    }
+
+    /// Attaches the rider to a specific base coverage
+    pub fn on_coverage(mut self, coverage_id: Uuid) -> Self {
+        self.base_coverage_id = Some(coverage_id);
+        self
+    }
+}
+
+/// A validated and rated rider, ready to attach
+#[derive(Debug, Clone)]
+pub struct RiderQuote {
+    /// Rider attachment
+    pub rider: Rider,
+    /// Coverage provided by the rider
+    pub coverage: Coverage,
+    /// Rider premium per payment
+    pub premium: RiderPremium,
+    /// Annual rider premium
+    pub annual_premium: Money,
+}
+
+/// Engine for attaching, validating and ending riders
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = RiderEngine::from_catalog(&catalog)?;
+///
+/// // At quote
+/// engine.add_to_quote(&mut policy, &RiderRequest::new("AD", sum_assured, dob), today)?;
+///
+/// // After issue
+/// engine.endorse(&mut policy, &RiderRequest::new("WOP", annual_premium, dob), today)?;
+///
+/// // Daily batch
+/// engine.process_expiries(&mut policy, today)?;
+/// ```
+#[derive(Default)]
+pub struct RiderEngine {
+    product_riders: VersionedRules<Vec<RiderDefinition>>,
+    rating: RatingService,
+}
+
+impl RiderEngine {
+    /// Creates an engine with no riders
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an engine with the riders offered by each catalog product
+    /// version
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any product has malformed `available_riders`
+    pub fn from_catalog(catalog: &Value) -> Result<Self, PolicyError> {
+        let mut engine = Self::new();
+        engine.product_riders = VersionedRules::from_catalog(catalog, |product| {
+            let riders = RiderDefinition::from_catalog_product(product)?;
+            Ok(Some(riders).filter(|riders| !riders.is_empty()))
+        })?;
+        Ok(engine)
+    }
+
+    /// Sets the riders offered by a product
+    pub fn with_product_riders(mut self, product_code: impl Into<String>, riders: Vec<RiderDefinition>) -> Self {
+        self.product_riders.insert(product_code.into(), riders);
+        self
+    }
+
+    /// Sets the rating service used to price riders
+    pub fn with_rating(mut self, rating: RatingService) -> Self {
+        self.rating = rating;
+        self
+    }
+
+    /// Returns the riders offered by a product's latest version
+    ///
+    /// Policies are serviced under the version they were sold under; see
+    /// [`Self::riders_for_policy`].
+    pub fn latest_riders_for(&self, product_code: &str) -> &[RiderDefinition] {
+        self.product_riders.latest(product_code).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the riders offered by the product version a policy was sold under
+    pub fn riders_for_policy(&self, policy: &Policy) -> &[RiderDefinition] {
+        self.product_riders.for_policy(policy).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns a rider offered by the product version a policy was sold under
+    pub fn definition(&self, policy: &Policy, rider_code: &str) -> Option<&RiderDefinition> {
+        self.riders_for_policy(policy).iter().find(|r| r.code == rider_code)
+    }
+
+    /// Validates and rates a rider for a policy
+    ///
+    /// The rider is rated on its own at the insured's age on `as_of`, in
+    /// the policy's risk class, and spread over the policy's payment
+    /// frequency.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the product does not offer the rider, the base
+    /// coverage is missing, or a catalog limit is exceeded
+    pub fn quote(&self, policy: &Policy, request: &RiderRequest, as_of: NaiveDate) -> Result<RiderQuote, PolicyError> {
+        let definition = self.definition(policy, &request.code).ok_or_else(|| {
+            PolicyError::Rider(format!(
+                "Product {} does not offer rider {}",
+                policy.product_code(),
+                request.code
+            ))
+        })?;
+        if request.sum_assured.currency() != policy.currency() {
+            return Err(PolicyError::CurrencyMismatch {
+                expected: policy.currency().to_string(),
+                actual: request.sum_assured.currency().to_string(),
+            });
+        }
+        if !request.sum_assured.is_positive() {
+            return Err(PolicyError::Rider(format!("Rider {} sum assured must be positive", request.code)));
+        }
+        let base = base_coverage(policy, request.base_coverage_id)?;
+
+        if let Some(max) = definition.max_sum_assured(base.sum_assured) {
+            if request.sum_assured.amount() > max.amount() {
+                return Err(PolicyError::Rider(format!(
+                    "Rider {} sum assured {} exceeds limit of {}",
+                    request.code, request.sum_assured, max
+                )));
+            }
+        }
+
+        let (birth_date, is_smoker) = insured(policy, request);
+        let age = age_on(birth_date, as_of);
+        let expiry_date = match definition.max_age {
+            Some(max_age) => {
+                if age >= max_age {
+                    return Err(PolicyError::Rider(format!(
+                        "Rider {} is not available from age {}",
+                        request.code, max_age
+                    )));
+                }
+                birth_date.checked_add_months(Months::new(12 * max_age))
+            }
+            None => None,
+        };
+
+        let mut coverage = Coverage::new(definition.coverage_type(), request.sum_assured);
+        coverage.effective_date = Some(as_of);
+        coverage.expiry_date = expiry_date;
+        if let Some(percentage) = definition.payout_percentage {
+            coverage.benefits[0].amount = BenefitAmount::PercentageOfSumAssured(percentage);
+        }
+
+        let annual_premium = self
+            .rating
+            .calculate_premium(
+                std::slice::from_ref(&coverage),
+                age,
+                is_smoker,
+                policy.risk_class().unwrap_or(RiskClass::Standard),
+                policy.currency(),
+            )?
+            .base_amount
+            .round_to_currency();
+        let premium = RiderPremium {
+            rider_code: definition.code.clone(),
+            rider_name: definition.name.clone(),
+            amount: annual_premium
+                .multiply(policy.premium().frequency.modal_factor())
+                .round_to_currency(),
+        };
+
+        let rider = Rider {
+            code: definition.code.clone(),
+            name: definition.name.clone(),
+            coverage_id: coverage.id,
+            base_coverage_id: base.id,
+            attached_on: as_of,
+            expiry_age: definition.max_age,
+            expiry_date,
+            terminated_on: None,
+            max_factor: definition.max_factor(),
+        };
+
+        Ok(RiderQuote {
+            rider,
+            coverage,
+            premium,
+            annual_premium,
+        })
+    }
+
+    /// Adds a rider to a quoted policy
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not a quote or the rider fails
+    /// validation
+    pub fn add_to_quote(
+        &self,
+        policy: &mut Policy,
+        request: &RiderRequest,
+        as_of: NaiveDate,
+    ) -> Result<RiderQuote, PolicyError> {
+        if !matches!(policy.state(), PolicyState::Quoted { .. }) {
+            return Err(PolicyError::Rider(format!(
+                "Policy {} is not a quote; add riders by endorsement",
+                policy.policy_number()
+            )));
+        }
+        let quote = self.quote(policy, request, as_of)?;
+        policy.attach_rider(quote.rider.clone(), quote.coverage.clone(), quote.premium.clone())?;
+        Ok(quote)
+    }
+
+    /// Adds a rider to an in-force policy by endorsement
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the rider fails validation or the endorsement
+    /// cannot be applied
+    pub fn endorse(
+        &self,
+        policy: &mut Policy,
+        request: &RiderRequest,
+        effective_date: NaiveDate,
+    ) -> Result<RiderQuote, PolicyError> {
+        let quote = self.quote(policy, request, effective_date)?;
+        let endorsement = Endorsement::new(
+            EndorsementType::RiderAddition {
+                rider: Box::new(quote.rider.clone()),
+                coverage: quote.coverage.clone(),
+                premium: quote.premium.clone(),
+            },
+            effective_date,
+        )
+        .with_premium_adjustment(quote.premium.amount.amount());
+        policy.apply_validated_endorsement(endorsement)?;
+        Ok(quote)
+    }
+
+    /// Checks the active riders on a policy against the catalog limits
+    ///
+    /// Endorsements changing the base sum assured are checked against the
+    /// limits recorded on each rider; this re-checks against the current
+    /// catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns error naming the first rider that breaches its limit
+    pub fn check_limits(&self, policy: &Policy) -> Result<(), PolicyError> {
+        for rider in policy.riders().iter().filter(|r| r.is_active()) {
+            let definition = self.definition(policy, &rider.code).ok_or_else(|| {
+                PolicyError::Rider(format!(
+                    "Product {} does not offer rider {}",
+                    policy.product_code(),
+                    rider.code
+                ))
+            })?;
+            let (Some(base), Some(coverage)) = (
+                policy.coverages().iter().find(|c| c.id == rider.base_coverage_id),
+                policy.coverages().iter().find(|c| c.id == rider.coverage_id),
+            ) else {
+                return Err(PolicyError::coverage_not_found(rider.coverage_id));
+            };
+            if let Some(max) = definition.max_sum_assured(base.sum_assured) {
+                if coverage.sum_assured.amount() > max.amount() {
+                    return Err(PolicyError::Rider(format!(
+                        "Rider {} sum assured {} exceeds limit of {}",
+                        rider.code, coverage.sum_assured, max
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends riders whose expiry age has been reached
+    ///
+    /// Returns the codes of the riders ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a rider cannot be ended
+    pub fn process_expiries(&self, policy: &mut Policy, as_of: NaiveDate) -> Result<Vec<String>, PolicyError> {
+        let expired: Vec<(String, NaiveDate)> = policy
+            .riders()
+            .iter()
+            .filter(|r| r.is_active())
+            .filter_map(|r| r.expiry_date.filter(|d| *d <= as_of).map(|d| (r.code.clone(), d)))
+            .collect();
+
+        for (code, expiry_date) in &expired {
+            policy.terminate_rider(code, *expiry_date, "Expiry age reached")?;
+            tracing::info!(
+                policy_number = %policy.policy_number(),
+                rider = %code,
+                expiry_date = %expiry_date,
+                "Rider expired"
+            );
+        }
+
+        Ok(expired.into_iter().map(|(code, _)| code).collect())
+    }
+}
+
+/// Finds the base coverage a rider attaches to
+fn base_coverage(policy: &Policy, coverage_id: Option<Uuid>) -> Result<&Coverage, PolicyError> {
+    let rider_coverages: Vec<Uuid> = policy.riders().iter().map(|r| r.coverage_id).collect();
+    let mut candidates = policy
+        .coverages()
+        .iter()
+        .filter(|c| c.is_active && !rider_coverages.contains(&c.id));
+    match coverage_id {
+        Some(id) => candidates
+            .find(|c| c.id == id)
+            .ok_or_else(|| PolicyError::coverage_not_found(id)),
+        None => candidates
+            .next()
+            .ok_or_else(|| PolicyError::coverage_not_found("base")),
+    }
+}
+
+/// Date of birth and smoking status of the insured
+///
+/// Taken from the insured life recorded on the policy, falling back to
+/// the request for policies without one.
+fn insured(policy: &Policy, request: &RiderRequest) -> (NaiveDate, bool) {
+    match policy.insured() {
+        Some(insured) => (insured.date_of_birth, insured.is_smoker),
+        None => (request.insured_birth_date, request.is_smoker),
+    }
+}
+
+/// Age in whole years on a date
+fn age_on(birth_date: NaiveDate, date: NaiveDate) -> u32 {
+    date.years_since(birth_date).unwrap_or(0)
+}
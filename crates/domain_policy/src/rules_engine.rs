@@ -28,13 +28,27 @@
 //!
 //! let result = engine.evaluate(&rules, context).await?;
 //! ```
+//!
+//! # Hot Reload
+//!
+//! Registered rules can be replaced while the engine is serving requests.
+//! `get_product` hands out an `Arc<ProductRules>`, so an evaluation that
+//! is already running finishes on the rules it started with while new
+//! evaluations pick up the replacement. See [`crate::rules_reload`] for
+//! reloading from a rules repository.
+//!
+//! # Decision Trace
+//!
+//! Decision tables are evaluated in the order they appear in the JDM, first
+//! hit. `EvaluationResult::trace` records, for each table, the rule that
+//! matched, the input values it saw and the outputs it produced.
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use thiserror::Error;
 
 /// Errors that can occur during rules evaluation
@@ -99,6 +113,8 @@ pub struct ProductRules {
     pub jdm: Value,
     /// Extracted product metadata
     pub metadata: ProductMetadata,
+    /// Content digest of the JDM, identifying the exact rules evaluated
+    pub revision: String,
     /// Decision nodes extracted from JDM
     nodes: HashMap<String, Value>,
 }
@@ -113,6 +129,9 @@ impl ProductRules {
     /// # Returns
     ///
     /// ProductRules instance or error if invalid format
+    ///
+    /// Decision tables are checked when the rules are loaded, so rules
+    /// that would fail during evaluation are rejected up front.
     pub fn from_jdm(jdm: Value) -> Result<Self, RulesError> {
         // Extract metadata
         let metadata = jdm
@@ -135,11 +154,15 @@ impl ProductRules {
             }
         }
 
-        Ok(Self {
+        let revision = content_digest(&jdm.to_string());
+        let rules = Self {
             jdm,
             metadata,
+            revision,
             nodes,
-        })
+        };
+        rules.validate_decision_tables()?;
+        Ok(rules)
     }
 
     /// Gets a node by ID
@@ -147,10 +170,13 @@ impl ProductRules {
         self.nodes.get(id)
     }
 
-    /// Gets all decision table nodes
+    /// Gets all decision table nodes, in the order they appear in the JDM
     pub fn get_decision_tables(&self) -> Vec<&Value> {
-        self.nodes
-            .values()
+        self.jdm
+            .get("nodes")
+            .and_then(|n| n.as_array())
+            .into_iter()
+            .flatten()
             .filter(|n| {
                 n.get("type")
                     .and_then(|t| t.as_str())
@@ -159,6 +185,46 @@ impl ProductRules {
             })
             .collect()
     }
+
+    /// Checks every decision table has the structure the evaluator needs
+    fn validate_decision_tables(&self) -> Result<(), RulesError> {
+        for (id, node) in &self.nodes {
+            if node.get("type").and_then(|t| t.as_str()) != Some("decisionTableNode") {
+                continue;
+            }
+            let invalid = |message: &str| RulesError::InvalidFormat(format!("Decision table {}: {}", id, message));
+            let content = node.get("content").ok_or_else(|| invalid("missing content"))?;
+            let array = |field: &str| {
+                content
+                    .get(field)
+                    .and_then(|v| v.as_array())
+                    .ok_or_else(|| invalid(&format!("missing {} array", field)))
+            };
+            let inputs = array("inputs")?;
+            let outputs = array("outputs")?;
+            for rule in array("rules")? {
+                let rule_inputs = rule.get("inputs").and_then(|i| i.as_array());
+                let rule_outputs = rule.get("outputs").and_then(|o| o.as_array());
+                if rule_inputs.map(Vec::len) != Some(inputs.len()) || rule_outputs.map(Vec::len) != Some(outputs.len()) {
+                    return Err(invalid("rule does not match the table's inputs and outputs"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// FNV-1a digest of rules content, stable across builds and platforms
+fn content_digest(content: &str) -> String {
+    let hash = content.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Returns a string attribute of a JDM node, or an empty string
+fn node_text(node: &Value, key: &str) -> String {
+    node.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string()
 }
 
 /// Result of rules evaluation
@@ -194,6 +260,43 @@ pub struct EvaluationResult {
     pub underwriting_type: Option<String>,
     /// Additional output values
     pub additional: HashMap<String, Value>,
+    /// Product whose rules were evaluated
+    #[serde(default)]
+    pub product_code: Option<String>,
+    /// Version of the rules evaluated
+    #[serde(default)]
+    pub rules_version: Option<String>,
+    /// Revision of the rules evaluated
+    #[serde(default)]
+    pub rules_revision: Option<String>,
+    /// Decision tables evaluated, in order
+    #[serde(default)]
+    pub trace: Vec<TableTrace>,
+}
+
+/// Trace of one decision table evaluated
+///
+/// Records which rule row fired and the values it saw, so a loading or
+/// decline can be explained after the event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableTrace {
+    /// Decision table node ID
+    pub node_id: String,
+    /// Decision table name
+    pub node_name: String,
+    /// Index of the rule that matched; `None` if no rule matched
+    pub matched_rule: Option<usize>,
+    /// Input values by field; `null` if the field was missing
+    pub inputs: BTreeMap<String, Value>,
+    /// Outputs produced by the matched rule, by field
+    pub outputs: BTreeMap<String, Value>,
+}
+
+impl TableTrace {
+    /// Whether a rule in the table matched
+    pub fn matched(&self) -> bool {
+        self.matched_rule.is_some()
+    }
 }
 
 impl Default for EvaluationResult {
@@ -214,24 +317,39 @@ impl Default for EvaluationResult {
             medical_exam_required: None,
             underwriting_type: None,
             additional: HashMap::new(),
+            product_code: None,
+            rules_version: None,
+            rules_revision: None,
+            trace: Vec::new(),
         }
     }
 }
 
+impl EvaluationResult {
+    /// Returns an additional output value as a decimal
+    pub fn additional_decimal(&self, key: &str) -> Option<Decimal> {
+        self.additional
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .and_then(|f| Decimal::try_from(f).ok())
+    }
+}
+
 /// Rules engine for evaluating JDM decision models
 ///
 /// The RulesEngine provides methods for loading product rules from JSON
-/// and evaluating them against application contexts.
+/// and evaluating them against application contexts. Registered rules
+/// can be swapped while the engine is shared between threads.
 pub struct RulesEngine {
     /// Cached product rules by product code
-    products: HashMap<String, Arc<ProductRules>>,
+    products: RwLock<HashMap<String, Arc<ProductRules>>>,
 }
 
 impl RulesEngine {
     /// Creates a new rules engine
     pub fn new() -> Self {
         Self {
-            products: HashMap::new(),
+            products: RwLock::new(HashMap::new()),
         }
     }
 
@@ -276,12 +394,22 @@ impl RulesEngine {
 
     /// Registers product rules for caching
     ///
+    /// Replaces any rules registered for the product. Evaluations holding
+    /// the previous rules are unaffected.
+    ///
     /// # Arguments
     ///
     /// * `rules` - Product rules to register
-    pub fn register_product(&mut self, rules: ProductRules) {
+    ///
+    /// # Returns
+    ///
+    /// The rules previously registered for the product, if any
+    pub fn register_product(&self, rules: ProductRules) -> Option<Arc<ProductRules>> {
         let code = rules.metadata.product_code.clone();
-        self.products.insert(code, Arc::new(rules));
+        self.products
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(code, Arc::new(rules))
     }
 
     /// Gets cached product rules by product code
@@ -294,7 +422,11 @@ impl RulesEngine {
     ///
     /// Reference to cached ProductRules if found
     pub fn get_product(&self, product_code: &str) -> Option<Arc<ProductRules>> {
-        self.products.get(product_code).cloned()
+        self.products
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(product_code)
+            .cloned()
     }
 
     /// Evaluates product rules against an application context
@@ -339,7 +471,10 @@ impl RulesEngine {
         // Process decision tables in order
         for table in rules.get_decision_tables() {
             if let Some(content) = table.get("content") {
-                self.evaluate_decision_table(content, &context, &mut computed_values)?;
+                let mut trace = self.evaluate_decision_table(content, &context, &mut computed_values)?;
+                trace.node_id = node_text(table, "id");
+                trace.node_name = node_text(table, "name");
+                result.trace.push(trace);
             }
         }
 
@@ -402,6 +537,17 @@ impl RulesEngine {
 
         result.total_loading_percent = Some(smoker + bmi + occupation + family);
 
+        // Record the rules used, for audit
+        result.product_code = Some(rules.metadata.product_code.clone());
+        result.rules_version = Some(rules.metadata.version.clone());
+        result.rules_revision = Some(rules.revision.clone());
+        tracing::debug!(
+            product_code = %rules.metadata.product_code,
+            rules_version = %rules.metadata.version,
+            rules_revision = %rules.revision,
+            "Rules evaluated"
+        );
+
         // Store additional computed values
         for (key, value) in computed_values {
             if !matches!(
@@ -422,12 +568,15 @@ impl RulesEngine {
     }
 
     /// Evaluates a single decision table
+    ///
+    /// Returns a trace of the inputs seen and the rule that fired; the
+    /// caller fills in the node ID and name.
     fn evaluate_decision_table(
         &self,
         table: &Value,
         context: &Value,
         computed: &mut HashMap<String, Value>,
-    ) -> Result<(), RulesError> {
+    ) -> Result<TableTrace, RulesError> {
         let rules = table
             .get("rules")
             .and_then(|r| r.as_array())
@@ -443,8 +592,24 @@ impl RulesEngine {
             .and_then(|o| o.as_array())
             .ok_or_else(|| RulesError::InvalidFormat("Missing outputs array".to_string()))?;
 
+        // Capture inputs before any outputs of this table are applied
+        let mut trace = TableTrace {
+            node_id: String::new(),
+            node_name: String::new(),
+            matched_rule: None,
+            inputs: inputs
+                .iter()
+                .filter_map(|input| input.get("field").and_then(|f| f.as_str()))
+                .map(|field| {
+                    let value = self.get_field_value(field, context, computed);
+                    (field.to_string(), value.unwrap_or(Value::Null))
+                })
+                .collect(),
+            outputs: BTreeMap::new(),
+        };
+
         // Find first matching rule
-        for rule in rules {
+        for (index, rule) in rules.iter().enumerate() {
             if self.rule_matches(rule, inputs, context, computed)? {
                 // Apply outputs
                 if let Some(rule_outputs) = rule.get("outputs").and_then(|o| o.as_array()) {
@@ -455,15 +620,17 @@ impl RulesEngine {
                         ) {
                             // Parse the value
                             let parsed_value = self.parse_output_value(value);
+                            trace.outputs.insert(field.to_string(), parsed_value.clone());
                             computed.insert(field.to_string(), parsed_value);
                         }
                     }
                 }
+                trace.matched_rule = Some(index);
                 break; // First hit policy
             }
         }
 
-        Ok(())
+        Ok(trace)
     }
 
     /// Checks if a rule matches the given context
@@ -0,0 +1,262 @@
+//! Hot reload of product rules
+//!
+//! New products and rule changes are deployed by publishing JDM documents
+//! to a rules repository rather than by rebuilding the system. Reloading
+//! reads every document from the repository, validates and compiles the
+//! ones that are new or changed, and swaps them into the `RulesEngine`
+//! one product at a time. A document that fails validation is reported
+//! and the product keeps its current rules.
+//!
+//! Evaluations already running hold an `Arc` to the rules they started
+//! with and finish on that version; each `EvaluationResult` records the
+//! product, rules version and revision it was evaluated against.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let engine = Arc::new(RulesEngine::new());
+//! let repository = DirectoryRulesRepository::new("products");
+//!
+//! // Initial load
+//! engine.reload(&repository)?;
+//!
+//! // Pick up changes every 30 seconds
+//! let watcher = RulesWatcher::start(engine.clone(), repository, Duration::from_secs(30));
+//! ```
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::catalog::CATALOG_FILE;
+use crate::golden::CASES_SUFFIX;
+use crate::rules_engine::{RulesEngine, RulesError};
+
+/// A JDM document held in a rules repository
+#[derive(Debug, Clone)]
+pub struct RulesDocument {
+    /// Where the document came from (file name, row key, ...)
+    pub source: String,
+    /// JDM content
+    pub content: String,
+}
+
+/// Source of product rules documents
+///
+/// Implemented for a directory of JSON files by
+/// [`DirectoryRulesRepository`]; other stores, such as a database table,
+/// implement the same trait.
+pub trait RulesRepository: Send + Sync {
+    /// Returns every rules document currently published
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository cannot be read
+    fn documents(&self) -> Result<Vec<RulesDocument>, RulesError>;
+}
+
+/// Rules repository backed by a directory of JDM files
+///
+/// Every `.json` file in the directory other than the product catalog and
+/// golden-case files is treated as a rules document.
+#[derive(Debug, Clone)]
+pub struct DirectoryRulesRepository {
+    dir: PathBuf,
+}
+
+impl DirectoryRulesRepository {
+    /// Creates a repository reading from a directory
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl RulesRepository for DirectoryRulesRepository {
+    fn documents(&self) -> Result<Vec<RulesDocument>, RulesError> {
+        let entries = std::fs::read_dir(&self.dir)
+            .map_err(|_| RulesError::FileNotFound(self.dir.display().to_string()))?;
+
+        let mut documents = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if !name.ends_with(".json") || name == CATALOG_FILE || name.ends_with(CASES_SUFFIX) {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)
+                .map_err(|_| RulesError::FileNotFound(path.display().to_string()))?;
+            documents.push(RulesDocument {
+                source: name.to_string(),
+                content,
+            });
+        }
+        documents.sort_by(|a, b| a.source.cmp(&b.source));
+        Ok(documents)
+    }
+}
+
+/// Rules swapped in by a reload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadedRules {
+    /// Document the rules were read from
+    pub source: String,
+    /// Product the rules apply to
+    pub product_code: String,
+    /// Rules version
+    pub version: String,
+    /// Rules revision
+    pub revision: String,
+    /// Revision replaced, if the product already had rules
+    pub replaced_revision: Option<String>,
+}
+
+/// A document a reload could not use
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedRules {
+    /// Document that was rejected
+    pub source: String,
+    /// Why the document was rejected
+    pub reason: String,
+}
+
+/// Outcome of a reload
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReloadReport {
+    /// Rules swapped in
+    pub loaded: Vec<LoadedRules>,
+    /// Number of documents matching the rules already registered
+    pub unchanged: usize,
+    /// Documents rejected; their products keep their current rules
+    pub rejected: Vec<RejectedRules>,
+}
+
+impl ReloadReport {
+    /// Whether the reload changed any rules
+    pub fn has_changes(&self) -> bool {
+        !self.loaded.is_empty()
+    }
+}
+
+impl RulesEngine {
+    /// Reloads product rules from a repository
+    ///
+    /// New and changed documents are validated and swapped in; documents
+    /// whose content matches the registered rules are skipped. A document
+    /// that fails validation, or names a product already loaded from
+    /// another document, is rejected without affecting other products.
+    ///
+    /// # Errors
+    ///
+    /// Returns error only if the repository cannot be read
+    pub fn reload(&self, repository: &dyn RulesRepository) -> Result<ReloadReport, RulesError> {
+        let mut report = ReloadReport::default();
+        let mut seen: Vec<(String, String)> = Vec::new();
+
+        for document in repository.documents()? {
+            let rules = match self.load_rules_from_str(&document.content) {
+                Ok(rules) => rules,
+                Err(e) => {
+                    tracing::warn!(source = %document.source, error = %e, "Rules document rejected");
+                    report.rejected.push(RejectedRules {
+                        source: document.source,
+                        reason: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let code = rules.metadata.product_code.clone();
+            if let Some((_, other)) = seen.iter().find(|(product, _)| *product == code) {
+                report.rejected.push(RejectedRules {
+                    reason: format!("Product {} is already defined in {}", code, other),
+                    source: document.source,
+                });
+                continue;
+            }
+            seen.push((code.clone(), document.source.clone()));
+
+            let current = self.get_product(&code);
+            if current.as_ref().is_some_and(|c| c.revision == rules.revision) {
+                report.unchanged += 1;
+                continue;
+            }
+
+            let loaded = LoadedRules {
+                source: document.source,
+                product_code: code,
+                version: rules.metadata.version.clone(),
+                revision: rules.revision.clone(),
+                replaced_revision: current.map(|c| c.revision.clone()),
+            };
+            self.register_product(rules);
+
+            tracing::info!(
+                product_code = %loaded.product_code,
+                rules_version = %loaded.version,
+                rules_revision = %loaded.revision,
+                source = %loaded.source,
+                "Product rules loaded"
+            );
+            report.loaded.push(loaded);
+        }
+
+        Ok(report)
+    }
+}
+
+/// Background task polling a rules repository for changes
+///
+/// The watcher stops when [`RulesWatcher::stop`] is called or the watcher
+/// is dropped.
+pub struct RulesWatcher {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RulesWatcher {
+    /// Starts polling the repository at the given interval
+    pub fn start<R>(engine: Arc<RulesEngine>, repository: R, interval: Duration) -> Self
+    where
+        R: RulesRepository + 'static,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = stop.clone();
+        let handle = std::thread::spawn(move || {
+            while !stopped.load(Ordering::Relaxed) {
+                if let Err(e) = engine.reload(&repository) {
+                    tracing::warn!(error = %e, "Rules reload failed");
+                }
+                std::thread::park_timeout(interval);
+            }
+        });
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Stops polling and waits for the watcher to finish
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for RulesWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
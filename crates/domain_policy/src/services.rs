@@ -3,6 +3,9 @@
 //! This module contains domain services that orchestrate complex operations
 //! involving multiple aggregates or external systems.
 
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde_json::Value;
@@ -10,10 +13,13 @@ use serde_json::Value;
 use core_kernel::Money;
 use crate::coverage::Coverage;
 use crate::error::PolicyError;
+use crate::exposure::{ExposureRules, LifeExposure};
+use crate::impairment::ImpairmentManual;
 use crate::premium::Premium;
+use crate::rules_engine::{EvaluationResult, ProductRules, RulesEngine};
 use crate::underwriting::{
-    UnderwritingApplication, UnderwritingDecision, RiskClass,
-    evaluate_basic_rules, determine_risk_class, RuleImpact,
+    UnderwritingApplication, UnderwritingDecision, UnderwritingExclusion, UnderwritingTrace, RiskClass,
+    evaluate_basic_rules, determine_risk_class, risk_class_for_loading, RuleImpact,
 };
 
 /// Service for underwriting policy applications
@@ -23,6 +29,12 @@ use crate::underwriting::{
 pub struct UnderwritingService {
     /// Product rules (would be loaded from zen-engine in production)
     product_rules: Option<Value>,
+    /// Engine evaluating product rules
+    rules_engine: Arc<RulesEngine>,
+    /// Rating manual for disclosed medical conditions
+    impairment_manual: Option<ImpairmentManual>,
+    /// Limits on the total cover on one life
+    exposure_rules: ExposureRules,
 }
 
 impl UnderwritingService {
@@ -30,9 +42,32 @@ impl UnderwritingService {
     pub fn new() -> Self {
         Self {
             product_rules: None,
+            rules_engine: Arc::new(RulesEngine::new()),
+            impairment_manual: None,
+            exposure_rules: ExposureRules::default(),
         }
     }
 
+    /// Rates disclosed medical conditions with an impairment manual
+    ///
+    /// # Arguments
+    ///
+    /// * `manual` - The rating manual
+    pub fn with_impairment_manual(mut self, manual: ImpairmentManual) -> Self {
+        self.impairment_manual = Some(manual);
+        self
+    }
+
+    /// Sets the per-life retention and financial underwriting limits
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The exposure limits
+    pub fn with_exposure_rules(mut self, rules: ExposureRules) -> Self {
+        self.exposure_rules = rules;
+        self
+    }
+
     /// Loads product-specific rules
     ///
     /// # Arguments
@@ -43,12 +78,25 @@ impl UnderwritingService {
         self
     }
 
+    /// Evaluates product rules with a shared rules engine
+    ///
+    /// # Arguments
+    ///
+    /// * `rules_engine` - The engine shared with quoting and rules reloading
+    pub fn with_rules_engine(mut self, rules_engine: Arc<RulesEngine>) -> Self {
+        self.rules_engine = rules_engine;
+        self
+    }
+
     /// Evaluates an application and returns an underwriting decision
     ///
+    /// Disclosed conditions are rated as of today; use
+    /// [`Self::evaluate_on`] to rate them as of the application date.
+    ///
     /// This method:
     /// 1. Validates the application
     /// 2. Runs basic underwriting rules
-    /// 3. Applies product-specific rules (if loaded)
+    /// 3. Rates disclosed conditions with the impairment manual (if loaded)
     /// 4. Determines risk classification
     /// 5. Calculates any required loadings or exclusions
     ///
@@ -75,21 +123,31 @@ impl UnderwritingService {
     /// }
     /// ```
     pub fn evaluate(&self, application: &UnderwritingApplication) -> Result<UnderwritingDecision, PolicyError> {
+        self.evaluate_on(application, Utc::now().date_naive())
+    }
+
+    /// Evaluates an application as of the date it was made
+    ///
+    /// As [`Self::evaluate`], with the time since diagnosis of disclosed
+    /// conditions measured to `application_date`, so a decision made
+    /// later on the same application rates it the same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if application is invalid
+    pub fn evaluate_on(
+        &self,
+        application: &UnderwritingApplication,
+        application_date: NaiveDate,
+    ) -> Result<UnderwritingDecision, PolicyError> {
         // Validate application
         self.validate_application(application)?;
 
         // Run basic rules
-        let rule_results = evaluate_basic_rules(application);
+        let mut rule_results = evaluate_basic_rules(application);
 
         // Determine risk class
-        let risk_class = determine_risk_class(&rule_results);
-
-        // Collect reasons and impacts
-        let reasons: Vec<String> = rule_results
-            .iter()
-            .filter(|r| !r.passed || !matches!(r.impact, RuleImpact::None))
-            .map(|r| r.message.clone())
-            .collect();
+        let mut risk_class = determine_risk_class(&rule_results);
 
         // Calculate total loading
         let total_loading: Decimal = rule_results
@@ -103,24 +161,213 @@ impl UnderwritingService {
             })
             .sum();
 
+        // Rate disclosed conditions
+        let mut exclusions = Vec::new();
+        let mut flat_extra_per_mille = None;
+        if let Some(manual) = &self.impairment_manual {
+            let assessment = manual.assess(&application.medical_history.conditions, application_date);
+            // The table rating is priced through the risk class alone
+            if !assessment.findings.is_empty() {
+                risk_class = worse_risk_class(risk_class, assessment.risk_class());
+            }
+            flat_extra_per_mille = assessment.flat_extra_per_mille;
+            exclusions = assessment
+                .exclusions()
+                .into_iter()
+                .map(|e| UnderwritingExclusion {
+                    code: e.code,
+                    description: e.description,
+                    duration_years: None,
+                })
+                .collect();
+            rule_results.extend(assessment.rule_results());
+        }
+
+        // Collect reasons and impacts
+        let reasons: Vec<String> = rule_results
+            .iter()
+            .filter(|r| !r.passed || !matches!(r.impact, RuleImpact::None))
+            .map(|r| r.message.clone())
+            .collect();
+
         // Determine required documents based on risk
         let required_documents = self.determine_required_documents(&risk_class, application);
 
         Ok(UnderwritingDecision {
             risk_class,
             reasons,
-            exclusions: vec![],
+            exclusions,
             loading_percent: if total_loading.is_zero() {
                 None
             } else {
                 Some(total_loading)
             },
+            flat_extra_per_mille,
             coverage_modifications: vec![],
             required_documents,
             notes: None,
+            trace: Some(UnderwritingTrace::new(rule_results)),
+            exposure: None,
         })
     }
 
+    /// Evaluates an application against the basic rules and the loaded
+    /// product rules
+    ///
+    /// As [`Self::evaluate_with_rules`], with the rules loaded by
+    /// [`Self::with_rules`]. Without loaded rules only the basic rules are
+    /// evaluated.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the application is invalid or the product rules
+    /// cannot be evaluated
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let service = UnderwritingService::new().with_rules(jdm);
+    /// let decision = service.evaluate_with_context(&application, context)?;
+    /// for table in decision.trace.unwrap().matched_tables() {
+    ///     println!("{}: rule {:?} -> {:?}", table.node_name, table.matched_rule, table.outputs);
+    /// }
+    /// ```
+    pub fn evaluate_with_context(
+        &self,
+        application: &UnderwritingApplication,
+        context: Value,
+    ) -> Result<UnderwritingDecision, PolicyError> {
+        let Some(jdm) = &self.product_rules else {
+            return self.evaluate(application);
+        };
+        let rules = ProductRules::from_jdm(jdm.clone())
+            .map_err(|e| PolicyError::Underwriting(e.to_string()))?;
+        self.evaluate_with_rules(application, &rules, context, Utc::now().date_naive())
+    }
+
+    /// Evaluates an application against the basic rules and a product's rules
+    ///
+    /// The product rules are evaluated by the shared rules engine against
+    /// `context`, in the shape the rules expect. An application the product
+    /// rules find ineligible or decline is declined with the rules' reason.
+    /// The rules' total loading is added to the decision's loading and the
+    /// risk class worsened to match. The decision tables evaluated are
+    /// added to the decision trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The underwriting application to evaluate
+    /// * `rules` - The product's rules
+    /// * `context` - The application in the shape the rules expect
+    /// * `application_date` - Date disclosed conditions are rated as of
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the application is invalid or the product rules
+    /// cannot be evaluated
+    pub fn evaluate_with_rules(
+        &self,
+        application: &UnderwritingApplication,
+        rules: &ProductRules,
+        context: Value,
+        application_date: NaiveDate,
+    ) -> Result<UnderwritingDecision, PolicyError> {
+        self.evaluate_against_rules(application, rules, context, application_date)
+            .map(|(decision, _)| decision)
+    }
+
+    /// Evaluates an application as [`Self::evaluate_with_rules`], also
+    /// returning the product rules' evaluation for callers that report
+    /// its loadings
+    pub(crate) fn evaluate_against_rules(
+        &self,
+        application: &UnderwritingApplication,
+        rules: &ProductRules,
+        context: Value,
+        application_date: NaiveDate,
+    ) -> Result<(UnderwritingDecision, EvaluationResult), PolicyError> {
+        let mut decision = self.evaluate_on(application, application_date)?;
+        let result = self
+            .rules_engine
+            .evaluate(rules, context)
+            .map_err(|e| PolicyError::Underwriting(e.to_string()))?;
+
+        if !result.eligible || result.action.as_deref() == Some("decline") {
+            decision.risk_class = RiskClass::Declined;
+            decision.reasons.push(
+                result
+                    .eligibility_reason
+                    .clone()
+                    .unwrap_or_else(|| "Not eligible under product rules".to_string()),
+            );
+        } else if let Some(loading) = result.total_loading_percent.filter(|l| *l > Decimal::ZERO) {
+            decision.loading_percent = Some(decision.loading_percent.unwrap_or_default() + loading);
+            decision.risk_class = worse_risk_class(decision.risk_class, risk_class_for_loading(loading));
+            decision.reasons.push(format!("Product rules loading of {}%", loading));
+        }
+        decision.trace = decision.trace.map(|trace| trace.with_evaluation(&result));
+
+        tracing::info!(
+            product_code = %rules.metadata.product_code,
+            rules_version = %rules.metadata.version,
+            risk_class = ?decision.risk_class,
+            loading_percent = ?decision.loading_percent,
+            "Application underwritten against product rules"
+        );
+
+        Ok((decision, result))
+    }
+
+    /// Evaluates an application with the company's existing cover on the life
+    ///
+    /// The application is evaluated as by [`Self::evaluate`], then the
+    /// in-house total sum at risk, including the application, is checked
+    /// against the per-life retention and the income multiple for the
+    /// applicant's age. Cases over the retention are routed to facultative
+    /// reinsurance and cases the income does not justify to manual review;
+    /// the routing is recorded in the decision's `exposure`. Referred cases
+    /// must be opened with [`UnderwritingCaseEngine::refer`] rather than
+    /// issued straight through.
+    ///
+    /// [`UnderwritingCaseEngine::refer`]: crate::underwriting_case::UnderwritingCaseEngine::refer
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The underwriting application to evaluate
+    /// * `exposure` - In-force and pending policies on the life
+    /// * `application_date` - Date the application was made, as for
+    ///   [`Self::evaluate_on`]
+    ///
+    /// # Errors
+    ///
+    /// Returns error if application is invalid
+    pub fn evaluate_with_exposure(
+        &self,
+        application: &UnderwritingApplication,
+        exposure: &LifeExposure,
+        application_date: NaiveDate,
+    ) -> Result<UnderwritingDecision, PolicyError> {
+        let mut decision = self.evaluate_on(application, application_date)?;
+        let check = self.exposure_rules.check(application, exposure);
+
+        if let Some(requirement) = check.routing.requirement() {
+            decision.reasons.extend(check.reasons.iter().cloned());
+            decision.required_documents.push(requirement.description().to_string());
+        }
+        if let Some(trace) = decision.trace.as_mut() {
+            trace.rule_results.push(check.rule_result());
+        }
+
+        tracing::info!(
+            total = %check.total,
+            routing = ?check.routing,
+            "Aggregate exposure checked"
+        );
+
+        decision.exposure = Some(check);
+        Ok(decision)
+    }
+
     /// Validates an underwriting application
     fn validate_application(&self, application: &UnderwritingApplication) -> Result<(), PolicyError> {
         // Check age
@@ -193,6 +440,15 @@ impl Default for UnderwritingService {
     }
 }
 
+/// Returns the less favourable of two risk classes
+fn worse_risk_class(a: RiskClass, b: RiskClass) -> RiskClass {
+    match (a, b) {
+        (RiskClass::Declined, _) | (_, RiskClass::Declined) => RiskClass::Declined,
+        _ if b.rate_multiplier() > a.rate_multiplier() => b,
+        _ => a,
+    }
+}
+
 /// Service for rating (premium calculation)
 ///
 /// The RatingService calculates premiums based on product rules,
@@ -259,12 +515,18 @@ impl RatingService {
             let adjusted_premium = coverage_premium * risk_class.rate_multiplier();
 
             // Apply any coverage-specific loading
-            let final_premium = if let Some(loading) = coverage.loading_percent {
+            let loaded_premium = if let Some(loading) = coverage.loading_percent {
                 adjusted_premium * (dec!(1) + loading / dec!(100))
             } else {
                 adjusted_premium
             };
 
+            // Add any flat extra, which is not affected by risk class
+            let final_premium = match coverage.flat_extra_per_mille {
+                Some(per_mille) => loaded_premium + sum_assured * per_mille / dec!(1000),
+                None => loaded_premium,
+            };
+
             total_premium = total_premium + Money::new(final_premium, currency);
         }
 
@@ -312,7 +574,6 @@ impl Default for RatingService {
 mod tests {
     use super::*;
     use crate::underwriting::*;
-    use crate::coverage::CoverageType;
     use chrono::NaiveDate;
     use core_kernel::Currency;
 
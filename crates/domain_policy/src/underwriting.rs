@@ -10,6 +10,8 @@ use chrono::{NaiveDate, Utc, Datelike};
 
 use crate::coverage::Coverage;
 use crate::error::PolicyError;
+use crate::exposure::ExposureCheck;
+use crate::rules_engine::{EvaluationResult, TableTrace};
 
 /// Risk classification levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -55,12 +57,70 @@ pub struct UnderwritingDecision {
     pub exclusions: Vec<UnderwritingExclusion>,
     /// Loading percentage (if any)
     pub loading_percent: Option<Decimal>,
+    /// Flat extra premium per 1,000 sum assured (if any)
+    #[serde(default)]
+    pub flat_extra_per_mille: Option<Decimal>,
     /// Modified coverages (if any)
     pub coverage_modifications: Vec<CoverageModification>,
     /// Required documents
     pub required_documents: Vec<String>,
     /// Underwriter notes
     pub notes: Option<String>,
+    /// How the decision was reached
+    #[serde(default)]
+    pub trace: Option<UnderwritingTrace>,
+    /// Check of the total cover on the life (if run)
+    #[serde(default)]
+    pub exposure: Option<ExposureCheck>,
+}
+
+/// Trace of the rules behind an underwriting decision
+///
+/// Kept with the decision so underwriters and regulators can see which
+/// rules applied a loading, referral or decline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UnderwritingTrace {
+    /// Results of the basic underwriting rules
+    pub rule_results: Vec<RuleResult>,
+    /// Product decision tables evaluated, in order
+    pub decision_tables: Vec<TableTrace>,
+    /// Product whose rules were evaluated
+    pub product_code: Option<String>,
+    /// Version of the product rules
+    pub rules_version: Option<String>,
+    /// Revision of the product rules
+    pub rules_revision: Option<String>,
+}
+
+impl UnderwritingTrace {
+    /// Creates a trace from the basic rule results
+    pub fn new(rule_results: Vec<RuleResult>) -> Self {
+        Self {
+            rule_results,
+            ..Default::default()
+        }
+    }
+
+    /// Adds the decision tables of a product rules evaluation
+    pub fn with_evaluation(mut self, result: &EvaluationResult) -> Self {
+        self.decision_tables = result.trace.clone();
+        self.product_code = result.product_code.clone();
+        self.rules_version = result.rules_version.clone();
+        self.rules_revision = result.rules_revision.clone();
+        self
+    }
+
+    /// Basic rules that affected the decision
+    pub fn applied_rules(&self) -> impl Iterator<Item = &RuleResult> {
+        self.rule_results
+            .iter()
+            .filter(|r| !r.passed || r.impact != RuleImpact::None)
+    }
+
+    /// Decision tables in which a rule fired
+    pub fn matched_tables(&self) -> impl Iterator<Item = &TableTrace> {
+        self.decision_tables.iter().filter(|t| t.matched())
+    }
 }
 
 /// An exclusion applied during underwriting
@@ -129,6 +189,36 @@ impl ApplicantInfo {
     }
 }
 
+/// Underwriting details of the life insured, kept on the policy
+///
+/// Engines acting on the policy after issue, such as nonforfeiture and
+/// conversion, take the insured's age and smoker status from here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InsuredLife {
+    /// Date of birth
+    pub date_of_birth: NaiveDate,
+    /// Gender
+    pub gender: Gender,
+    /// Whether the insured smoked when underwritten
+    pub is_smoker: bool,
+}
+
+impl InsuredLife {
+    /// Takes the insured's details from an underwriting application
+    pub fn from_application(application: &UnderwritingApplication) -> Self {
+        Self {
+            date_of_birth: application.applicant.date_of_birth,
+            gender: application.applicant.gender,
+            is_smoker: application.medical_history.is_smoker,
+        }
+    }
+
+    /// Age in whole years on a date
+    pub fn age_on(&self, date: NaiveDate) -> u32 {
+        date.years_since(self.date_of_birth).unwrap_or(0)
+    }
+}
+
 /// Gender options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Gender {
@@ -179,10 +269,34 @@ pub struct MedicalCondition {
     pub diagnosed_date: Option<NaiveDate>,
     /// Current status
     pub status: ConditionStatus,
+    /// Severity, if assessed
+    #[serde(default)]
+    pub severity: Option<Severity>,
     /// Treatment details
     pub treatment: Option<String>,
 }
 
+impl MedicalCondition {
+    /// Completed years since diagnosis; `None` if the diagnosis date is
+    /// not known
+    pub fn years_since_diagnosis(&self, as_of: NaiveDate) -> Option<u32> {
+        self.diagnosed_date
+            .and_then(|diagnosed| as_of.years_since(diagnosed))
+    }
+}
+
+/// Severity of a medical condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Mild
+    Mild,
+    /// Moderate
+    Moderate,
+    /// Severe
+    Severe,
+}
+
 /// Status of a medical condition
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConditionStatus {
@@ -264,7 +378,7 @@ pub enum InsurancePurpose {
 }
 
 /// Rule result from underwriting evaluation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuleResult {
     pub rule_name: String,
     pub passed: bool,
@@ -273,7 +387,7 @@ pub struct RuleResult {
 }
 
 /// Impact of a rule on underwriting
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RuleImpact {
     /// No impact - informational only
     None,
@@ -435,7 +549,11 @@ pub fn determine_risk_class(results: &[RuleResult]) -> RiskClass {
         })
         .sum();
 
-    // Determine risk class based on loading
+    risk_class_for_loading(total_loading)
+}
+
+/// Determines the risk class for a total loading percentage
+pub fn risk_class_for_loading(total_loading: Decimal) -> RiskClass {
     if total_loading.is_zero() {
         RiskClass::PreferredPlus
     } else if total_loading <= dec!(10) {
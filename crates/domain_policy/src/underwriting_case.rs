@@ -0,0 +1,598 @@
+//! Underwriting case management
+//!
+//! An application that cannot be issued straight through is opened as an
+//! underwriting case. The case orders the evidence the product rules call
+//! for, tracks each requirement to a due date, and routes the case to a
+//! manual underwriter whose authority covers the sum assured and loading.
+//! Once every requirement is satisfied or waived, the underwriter records
+//! the final decision, which issues or declines the policy.
+//!
+//! # Requirements
+//!
+//! | Rule output / case               | Requirements ordered                          |
+//! |----------------------------------|-----------------------------------------------|
+//! | `medical_exam_required = true`   | Paramedical exam, lab panel                   |
+//! | `underwriting_type = "full"`     | Paramedical exam, lab panel, physician (APS)  |
+//! | Sum assured at or above limit    | Financial questionnaire                       |
+//! | Exposure over income multiple    | Financial underwriting review                 |
+//! | Exposure over per-life retention | Facultative reinsurance placement             |
+
+use chrono::{Days, NaiveDate};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{Money, PolicyId};
+
+use crate::aggregate::Policy;
+use crate::error::PolicyError;
+use crate::rules_engine::EvaluationResult;
+use crate::underwriting::{RiskClass, UnderwritingDecision};
+
+/// Evidence an underwriter can order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequirementType {
+    /// Paramedical examination
+    ParamedicalExam,
+    /// Attending physician statement
+    AttendingPhysicianStatement,
+    /// Blood and urine lab panel
+    LabPanel,
+    /// Financial questionnaire
+    FinancialQuestionnaire,
+    /// Review of total cover against the applicant's income
+    FinancialReview,
+    /// Placement of cover above the per-life retention with a reinsurer
+    FacultativePlacement,
+}
+
+impl RequirementType {
+    /// Returns the document name shown to the applicant
+    pub fn description(&self) -> &'static str {
+        match self {
+            RequirementType::ParamedicalExam => "Paramedical Examination",
+            RequirementType::AttendingPhysicianStatement => "Attending Physician Statement",
+            RequirementType::LabPanel => "Lab Panel",
+            RequirementType::FinancialQuestionnaire => "Financial Questionnaire",
+            RequirementType::FinancialReview => "Financial Underwriting Review",
+            RequirementType::FacultativePlacement => "Facultative Reinsurance Placement",
+        }
+    }
+}
+
+/// Status of a requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequirementStatus {
+    /// Ordered, awaiting evidence
+    Ordered,
+    /// Evidence received, awaiting review
+    Received,
+    /// Evidence reviewed and accepted
+    Satisfied,
+    /// No longer needed
+    Waived,
+}
+
+/// A requirement ordered for a case
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Requirement {
+    /// Requirement type
+    pub requirement_type: RequirementType,
+    /// Current status
+    pub status: RequirementStatus,
+    /// Date ordered
+    pub ordered_date: NaiveDate,
+    /// Date the evidence is due
+    pub due_date: NaiveDate,
+    /// Date the evidence was received
+    pub received_date: Option<NaiveDate>,
+    /// Reviewer note, such as why the requirement was waived
+    pub note: Option<String>,
+}
+
+impl Requirement {
+    /// Whether the requirement no longer blocks a decision
+    pub fn is_closed(&self) -> bool {
+        matches!(self.status, RequirementStatus::Satisfied | RequirementStatus::Waived)
+    }
+
+    /// Whether the evidence is late
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status == RequirementStatus::Ordered && today > self.due_date
+    }
+}
+
+/// Underwriting authority of a manual underwriter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnderwriterAuthority {
+    /// Underwriter ID
+    pub underwriter: String,
+    /// Largest sum assured the underwriter may accept, in the policy currency
+    pub max_sum_assured: Decimal,
+    /// Largest total loading the underwriter may apply
+    pub max_loading_percent: Decimal,
+}
+
+impl UnderwriterAuthority {
+    /// Whether the underwriter may decide a case
+    pub fn covers(&self, sum_assured: Decimal, loading_percent: Decimal) -> bool {
+        sum_assured <= self.max_sum_assured && loading_percent <= self.max_loading_percent
+    }
+}
+
+/// Where a case is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseStatus {
+    /// Waiting for requirements
+    AwaitingRequirements,
+    /// Every requirement closed; waiting for the underwriter
+    ReadyForDecision,
+    /// Approved and the policy issued
+    Approved,
+    /// Declined
+    Declined,
+}
+
+/// Final decision on a case
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CaseDecision {
+    /// Accept at a risk class
+    Approve {
+        /// Risk class assigned
+        risk_class: RiskClass,
+        /// Date the policy becomes effective
+        effective_date: NaiveDate,
+    },
+    /// Decline the application
+    Decline {
+        /// Reason for the decline
+        reason: String,
+    },
+}
+
+/// An underwriting case for one application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnderwritingCase {
+    /// Case ID
+    pub id: Uuid,
+    /// Policy being underwritten
+    pub policy_id: PolicyId,
+    /// Policy number
+    pub policy_number: String,
+    /// Total sum assured applied for
+    pub sum_assured: Money,
+    /// Total loading from the product rules
+    pub loading_percent: Decimal,
+    /// Requirements ordered
+    pub requirements: Vec<Requirement>,
+    /// Underwriter the case is routed to
+    pub assigned_to: String,
+    /// Case status
+    pub status: CaseStatus,
+    /// Date the case was opened
+    pub opened_date: NaiveDate,
+    /// Underwriter who made the final decision
+    pub decided_by: Option<String>,
+}
+
+impl UnderwritingCase {
+    /// Returns a requirement of the case
+    pub fn requirement(&self, requirement_type: RequirementType) -> Option<&Requirement> {
+        self.requirements.iter().find(|r| r.requirement_type == requirement_type)
+    }
+
+    /// Requirements still blocking a decision
+    pub fn outstanding(&self) -> impl Iterator<Item = &Requirement> {
+        self.requirements.iter().filter(|r| !r.is_closed())
+    }
+
+    /// Requirements whose evidence is late
+    pub fn overdue(&self, today: NaiveDate) -> impl Iterator<Item = &Requirement> {
+        self.requirements.iter().filter(move |r| r.is_overdue(today))
+    }
+
+    /// Records that evidence has arrived
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the requirement was not ordered or is not awaiting
+    /// evidence
+    pub fn receive(&mut self, requirement_type: RequirementType, received_date: NaiveDate) -> Result<(), PolicyError> {
+        let requirement = self.requirement_mut(requirement_type, RequirementStatus::Ordered)?;
+        requirement.status = RequirementStatus::Received;
+        requirement.received_date = Some(received_date);
+        Ok(())
+    }
+
+    /// Accepts received evidence
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the requirement was not ordered or has not been
+    /// received
+    pub fn satisfy(&mut self, requirement_type: RequirementType) -> Result<(), PolicyError> {
+        self.requirement_mut(requirement_type, RequirementStatus::Received)?.status = RequirementStatus::Satisfied;
+        self.refresh_status();
+        Ok(())
+    }
+
+    /// Waives a requirement that is no longer needed
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the requirement was not ordered or is already
+    /// closed
+    pub fn waive(&mut self, requirement_type: RequirementType, reason: &str) -> Result<(), PolicyError> {
+        let requirement = self.open_requirement_mut(requirement_type)?;
+        requirement.status = RequirementStatus::Waived;
+        requirement.note = Some(reason.to_string());
+        self.refresh_status();
+        Ok(())
+    }
+
+    fn requirement_mut(
+        &mut self,
+        requirement_type: RequirementType,
+        expected: RequirementStatus,
+    ) -> Result<&mut Requirement, PolicyError> {
+        let requirement = self.open_requirement_mut(requirement_type)?;
+        if requirement.status != expected {
+            return Err(PolicyError::Underwriting(format!(
+                "{} is {:?}, expected {:?}",
+                requirement_type.description(),
+                requirement.status,
+                expected
+            )));
+        }
+        Ok(requirement)
+    }
+
+    fn open_requirement_mut(&mut self, requirement_type: RequirementType) -> Result<&mut Requirement, PolicyError> {
+        if matches!(self.status, CaseStatus::Approved | CaseStatus::Declined) {
+            return Err(PolicyError::Underwriting(format!("Case {} is closed", self.policy_number)));
+        }
+        let policy_number = &self.policy_number;
+        let requirement = self
+            .requirements
+            .iter_mut()
+            .find(|r| r.requirement_type == requirement_type)
+            .ok_or_else(|| {
+                PolicyError::Underwriting(format!(
+                    "{} was not ordered for {}",
+                    requirement_type.description(),
+                    policy_number
+                ))
+            })?;
+        if requirement.is_closed() {
+            return Err(PolicyError::Underwriting(format!(
+                "{} is already {:?}",
+                requirement_type.description(),
+                requirement.status
+            )));
+        }
+        Ok(requirement)
+    }
+
+    fn refresh_status(&mut self) {
+        if self.status == CaseStatus::AwaitingRequirements && self.outstanding().next().is_none() {
+            self.status = CaseStatus::ReadyForDecision;
+        }
+    }
+}
+
+/// Requirement ordering and routing rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaseRules {
+    /// Days allowed for evidence to arrive
+    pub requirement_due_days: u64,
+    /// Sum assured from which a financial questionnaire is ordered
+    pub financial_questionnaire_from: Decimal,
+    /// Manual underwriters and their authority
+    pub authorities: Vec<UnderwriterAuthority>,
+}
+
+impl Default for CaseRules {
+    fn default() -> Self {
+        Self {
+            requirement_due_days: 30,
+            financial_questionnaire_from: dec!(500000),
+            authorities: Vec::new(),
+        }
+    }
+}
+
+/// Engine opening, routing and deciding underwriting cases
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = UnderwritingCaseEngine::new(rules);
+///
+/// let result = rules_engine.evaluate(&product_rules, context)?;
+/// let mut case = engine.open_case(&mut policy, &result, today)?;
+///
+/// case.receive(RequirementType::LabPanel, received)?;
+/// case.satisfy(RequirementType::LabPanel)?;
+///
+/// engine.decide(&mut case, &mut policy, "UW-SENIOR", CaseDecision::Approve {
+///     risk_class: RiskClass::Standard,
+///     effective_date,
+/// })?;
+/// ```
+pub struct UnderwritingCaseEngine {
+    rules: CaseRules,
+}
+
+impl UnderwritingCaseEngine {
+    /// Creates an engine with the given rules
+    pub fn new(rules: CaseRules) -> Self {
+        Self { rules }
+    }
+
+    /// Requirements called for by a rules evaluation
+    pub fn requirements_for(&self, result: &EvaluationResult, sum_assured: Decimal) -> Vec<RequirementType> {
+        let mut requirements = Vec::new();
+        let full = result.underwriting_type.as_deref() == Some("full");
+        if full || result.medical_exam_required == Some(true) {
+            requirements.push(RequirementType::ParamedicalExam);
+            requirements.push(RequirementType::LabPanel);
+        }
+        if full {
+            requirements.push(RequirementType::AttendingPhysicianStatement);
+        }
+        if sum_assured >= self.rules.financial_questionnaire_from {
+            requirements.push(RequirementType::FinancialQuestionnaire);
+        }
+        requirements
+    }
+
+    /// Underwriter with the least authority that still covers a case
+    pub fn route(&self, sum_assured: Decimal, loading_percent: Decimal) -> Option<&UnderwriterAuthority> {
+        self.rules
+            .authorities
+            .iter()
+            .filter(|a| a.covers(sum_assured, loading_percent))
+            .min_by(|a, b| {
+                a.max_sum_assured
+                    .cmp(&b.max_sum_assured)
+                    .then(a.max_loading_percent.cmp(&b.max_loading_percent))
+            })
+    }
+
+    /// Opens a case for a quote and submits the quote for underwriting
+    ///
+    /// Requirements are ordered from the rules evaluation and the case is
+    /// routed to an underwriter with enough authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the rules found the application ineligible, no
+    /// underwriter has enough authority, or the policy is not a quote
+    pub fn open_case(
+        &self,
+        policy: &mut Policy,
+        result: &EvaluationResult,
+        opened_date: NaiveDate,
+    ) -> Result<UnderwritingCase, PolicyError> {
+        if !result.eligible {
+            return Err(PolicyError::Underwriting(format!(
+                "Policy {} is not eligible: {}",
+                policy.policy_number(),
+                result.eligibility_reason.as_deref().unwrap_or("declined by product rules")
+            )));
+        }
+
+        let sum_assured = total_sum_assured(policy)?;
+        let requirements = self.requirements_for(result, sum_assured.amount());
+        self.open(
+            policy,
+            sum_assured,
+            result.total_loading_percent.unwrap_or_default(),
+            requirements,
+            opened_date,
+        )
+    }
+
+    /// Opens a case for an application the exposure check routed away from
+    /// straight-through issue
+    ///
+    /// Cases over the financial underwriting limit are opened with a
+    /// financial review, and cases over the per-life retention with a
+    /// facultative placement, which must be satisfied or waived before the
+    /// policy can be approved. Returns `None` if the decision was not
+    /// checked for exposure or the routing is automatic.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the application was declined, no underwriter has
+    /// enough authority, or the policy is not a quote
+    pub fn refer(
+        &self,
+        policy: &mut Policy,
+        decision: &UnderwritingDecision,
+        opened_date: NaiveDate,
+    ) -> Result<Option<UnderwritingCase>, PolicyError> {
+        let Some(requirement) = decision.exposure.as_ref().and_then(|check| check.routing.requirement()) else {
+            return Ok(None);
+        };
+        if decision.risk_class == RiskClass::Declined {
+            return Err(PolicyError::Underwriting(format!(
+                "Policy {} was declined",
+                policy.policy_number()
+            )));
+        }
+
+        let sum_assured = total_sum_assured(policy)?;
+        let case = self.open(
+            policy,
+            sum_assured,
+            decision.loading_percent.unwrap_or_default(),
+            vec![requirement],
+            opened_date,
+        )?;
+        Ok(Some(case))
+    }
+
+    fn open(
+        &self,
+        policy: &mut Policy,
+        sum_assured: Money,
+        loading_percent: Decimal,
+        requirements: Vec<RequirementType>,
+        opened_date: NaiveDate,
+    ) -> Result<UnderwritingCase, PolicyError> {
+        let assigned_to = self
+            .route(sum_assured.amount(), loading_percent)
+            .ok_or_else(|| {
+                PolicyError::Underwriting(format!(
+                    "No underwriter has authority for {} at {}% loading",
+                    sum_assured, loading_percent
+                ))
+            })?
+            .underwriter
+            .clone();
+
+        let due_date = opened_date
+            .checked_add_days(Days::new(self.rules.requirement_due_days))
+            .ok_or_else(|| PolicyError::validation("Requirement due date out of range"))?;
+        let requirements: Vec<Requirement> = requirements
+            .into_iter()
+            .map(|requirement_type| Requirement {
+                requirement_type,
+                status: RequirementStatus::Ordered,
+                ordered_date: opened_date,
+                due_date,
+                received_date: None,
+                note: None,
+            })
+            .collect();
+
+        policy.submit_for_underwriting(
+            requirements
+                .iter()
+                .map(|r| r.requirement_type.description().to_string())
+                .collect(),
+        )?;
+
+        let mut case = UnderwritingCase {
+            id: Uuid::new_v4(),
+            policy_id: policy.id(),
+            policy_number: policy.policy_number().to_string(),
+            sum_assured,
+            loading_percent,
+            requirements,
+            assigned_to,
+            status: CaseStatus::AwaitingRequirements,
+            opened_date,
+            decided_by: None,
+        };
+        case.refresh_status();
+
+        tracing::info!(
+            policy_number = %case.policy_number,
+            assigned_to = %case.assigned_to,
+            requirements = case.requirements.len(),
+            "Underwriting case opened"
+        );
+
+        Ok(case)
+    }
+
+    /// Cases assigned to an underwriter, those ready for decision first
+    pub fn worklist<'a>(&self, cases: &'a [UnderwritingCase], underwriter: &str) -> Vec<&'a UnderwritingCase> {
+        let mut worklist: Vec<_> = cases
+            .iter()
+            .filter(|c| c.assigned_to == underwriter)
+            .filter(|c| matches!(c.status, CaseStatus::AwaitingRequirements | CaseStatus::ReadyForDecision))
+            .collect();
+        worklist.sort_by_key(|c| (c.status != CaseStatus::ReadyForDecision, c.opened_date));
+        worklist
+    }
+
+    /// Records the final decision, issuing or declining the policy
+    ///
+    /// An open case can be declined at any time; approval waits until every
+    /// requirement is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the case is closed, requirements are outstanding
+    /// for an approval, the underwriter lacks authority for the case, or
+    /// the case is for another policy
+    pub fn decide(
+        &self,
+        case: &mut UnderwritingCase,
+        policy: &mut Policy,
+        underwriter: &str,
+        decision: CaseDecision,
+    ) -> Result<(), PolicyError> {
+        if case.policy_id != policy.id() {
+            return Err(PolicyError::Underwriting(format!(
+                "Case {} does not belong to policy {}",
+                case.policy_number,
+                policy.policy_number()
+            )));
+        }
+        let ready = match decision {
+            CaseDecision::Approve { .. } => case.status == CaseStatus::ReadyForDecision,
+            CaseDecision::Decline { .. } => {
+                matches!(case.status, CaseStatus::AwaitingRequirements | CaseStatus::ReadyForDecision)
+            }
+        };
+        if !ready {
+            return Err(PolicyError::Underwriting(format!(
+                "Case {} is not ready for decision ({:?}, {} requirements outstanding)",
+                case.policy_number,
+                case.status,
+                case.outstanding().count()
+            )));
+        }
+        let authorised = self
+            .rules
+            .authorities
+            .iter()
+            .any(|a| a.underwriter == underwriter && a.covers(case.sum_assured.amount(), case.loading_percent));
+        if !authorised {
+            return Err(PolicyError::Underwriting(format!(
+                "{} has no authority to decide case {}",
+                underwriter, case.policy_number
+            )));
+        }
+
+        case.status = match decision {
+            CaseDecision::Approve { risk_class, effective_date } => {
+                policy.approve(effective_date, risk_class, underwriter)?;
+                CaseStatus::Approved
+            }
+            CaseDecision::Decline { reason } => {
+                policy.decline(underwriter, &reason)?;
+                CaseStatus::Declined
+            }
+        };
+        case.decided_by = Some(underwriter.to_string());
+
+        tracing::info!(
+            policy_number = %case.policy_number,
+            underwriter = %underwriter,
+            status = ?case.status,
+            "Underwriting case decided"
+        );
+
+        Ok(())
+    }
+}
+
+/// Total sum assured of a policy's coverages
+fn total_sum_assured(policy: &Policy) -> Result<Money, PolicyError> {
+    policy
+        .coverages()
+        .iter()
+        .try_fold(Money::zero(policy.currency()), |total, coverage| {
+            total.checked_add(&coverage.sum_assured)
+        })
+        .map_err(|e| PolicyError::Underwriting(e.to_string()))
+}
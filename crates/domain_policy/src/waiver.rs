@@ -0,0 +1,221 @@
+//! Waiver of premium
+//!
+//! When the insured becomes disabled, a policy carrying waiver of premium
+//! cover stops billing premiums for as long as the disability lasts. An
+//! approved disability claim opens a waiver period from the date of
+//! disability; instalments falling due in the period are treated as paid,
+//! with the premium charged to the waiver of premium reserve instead of
+//! the policyholder. The waiver ends when the claim closes or the insured
+//! recovers.
+//!
+//! # Ledger postings
+//!
+//! | Event            | Debit                  | Credit             |
+//! |------------------|------------------------|--------------------|
+//! | Instalment waived| WOP reserve            | Premium receivable |
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use core_kernel::{AccountId, ClaimId, JournalEntryId, Money, utc_start_of_day};
+use domain_billing::transaction::Transaction;
+use domain_billing::{Invoice, InvoiceStatus, Ledger};
+use domain_claims::{Claim, ClaimStatus, LossType};
+
+use crate::aggregate::Policy;
+use crate::error::PolicyError;
+
+/// Why a waiver period ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaiverEndReason {
+    /// The disability claim was closed, withdrawn or denied
+    ClaimClosed,
+    /// The insured recovered and can resume paying premiums
+    Recovery,
+}
+
+/// A period during which premiums are waived
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaiverPeriod {
+    /// Disability claim that triggered the waiver
+    pub claim_id: ClaimId,
+    /// First day premiums are waived
+    pub start_date: NaiveDate,
+    /// First day premiums are payable again; `None` while the waiver is open
+    pub end_date: Option<NaiveDate>,
+    /// Why the waiver ended
+    pub end_reason: Option<WaiverEndReason>,
+    /// Premiums waived in the period
+    pub premiums_waived: Money,
+}
+
+impl WaiverPeriod {
+    /// Whether the waiver is still open
+    pub fn is_open(&self) -> bool {
+        self.end_date.is_none()
+    }
+
+    /// Whether a premium due on `due_date` falls within the waiver
+    pub fn covers(&self, due_date: NaiveDate) -> bool {
+        due_date >= self.start_date && self.end_date.is_none_or(|end| due_date < end)
+    }
+}
+
+/// Ledger accounts used for waived premiums
+#[derive(Debug, Clone, Copy)]
+pub struct WaiverAccounts {
+    /// Waiver of premium reserve charged with waived premiums
+    pub wop_reserve: AccountId,
+    /// Premium receivable settled by the waiver
+    pub premium_receivable: AccountId,
+}
+
+/// Engine applying waiver of premium benefits
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let engine = WaiverEngine::new(accounts);
+///
+/// // Disability claim approved
+/// engine.start(&mut policy, &claim)?;
+///
+/// // Billing run
+/// engine.waive_invoice(&mut ledger, &mut policy, &mut invoice, today)?;
+///
+/// // Insured returns to work
+/// engine.record_recovery(&mut policy, recovery_date)?;
+/// ```
+pub struct WaiverEngine {
+    accounts: WaiverAccounts,
+}
+
+impl WaiverEngine {
+    /// Creates an engine posting to the given accounts
+    pub fn new(accounts: WaiverAccounts) -> Self {
+        Self { accounts }
+    }
+
+    /// Starts waiving premiums for an approved disability claim
+    ///
+    /// Premiums are waived from the date of disability.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the claim is for another policy, is not an approved
+    /// disability claim, or the policy has no waiver of premium cover
+    pub fn start(&self, policy: &mut Policy, claim: &Claim) -> Result<(), PolicyError> {
+        if claim.policy_id != policy.id() {
+            return Err(PolicyError::Waiver(format!(
+                "Claim {} does not belong to policy {}",
+                claim.claim_number,
+                policy.policy_number()
+            )));
+        }
+        if claim.loss_type != LossType::Disability {
+            return Err(PolicyError::Waiver(format!(
+                "Claim {} is not a disability claim",
+                claim.claim_number
+            )));
+        }
+        if !matches!(claim.status, ClaimStatus::Approved | ClaimStatus::PartiallyApproved) {
+            return Err(PolicyError::Waiver(format!(
+                "Claim {} is not approved ({:?})",
+                claim.claim_number, claim.status
+            )));
+        }
+
+        policy.start_premium_waiver(claim.id, claim.loss_date)?;
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            claim_number = %claim.claim_number,
+            start_date = %claim.loss_date,
+            "Premium waiver started"
+        );
+
+        Ok(())
+    }
+
+    /// Ends the waiver once its claim is closed, withdrawn or denied
+    ///
+    /// Returns whether the waiver ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the waiver cannot be ended
+    pub fn sync_claim(&self, policy: &mut Policy, claim: &Claim, as_of: NaiveDate) -> Result<bool, PolicyError> {
+        let open_for_claim = policy.active_waiver().is_some_and(|w| w.claim_id == claim.id);
+        if !open_for_claim
+            || !matches!(claim.status, ClaimStatus::Closed | ClaimStatus::Withdrawn | ClaimStatus::Denied)
+        {
+            return Ok(false);
+        }
+        policy.end_premium_waiver(as_of, WaiverEndReason::ClaimClosed)?;
+        Ok(true)
+    }
+
+    /// Ends the waiver on the insured's recovery
+    ///
+    /// Premiums due on or after `recovery_date` are payable again.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no waiver is open
+    pub fn record_recovery(&self, policy: &mut Policy, recovery_date: NaiveDate) -> Result<(), PolicyError> {
+        policy.end_premium_waiver(recovery_date, WaiverEndReason::Recovery)
+    }
+
+    /// Settles a premium invoice falling due in a waiver period
+    ///
+    /// Returns `None` if the invoice is settled or not due in a waiver
+    /// period.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the invoice belongs to another policy or the
+    /// ledger posting fails
+    pub fn waive_invoice(
+        &self,
+        ledger: &mut Ledger,
+        policy: &mut Policy,
+        invoice: &mut Invoice,
+        as_of: NaiveDate,
+    ) -> Result<Option<JournalEntryId>, PolicyError> {
+        if invoice.policy_id != policy.id() {
+            return Err(PolicyError::validation(format!(
+                "Invoice {} does not belong to policy {}",
+                invoice.invoice_number,
+                policy.policy_number()
+            )));
+        }
+        let premium = invoice.balance_due();
+        if !premium.is_positive()
+            || matches!(invoice.status, InvoiceStatus::Paid | InvoiceStatus::Cancelled)
+            || !policy.is_premium_waived(invoice.due_date)
+        {
+            return Ok(None);
+        }
+
+        let entry_id = ledger
+            .post(
+                Transaction::new(format!("Premium waived for invoice {}", invoice.invoice_number))
+                    .with_reference("policy", *policy.id().as_uuid())
+                    .dated(utc_start_of_day(as_of))
+                    .debit(self.accounts.wop_reserve, premium)
+                    .credit(self.accounts.premium_receivable, premium),
+            )
+            .map_err(|e| PolicyError::Financial(e.to_string()))?;
+        invoice.record_payment(premium);
+        policy.record_waived_premium(invoice.due_date, premium)?;
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            invoice_number = %invoice.invoice_number,
+            amount = %premium,
+            "Premium waived"
+        );
+
+        Ok(Some(entry_id))
+    }
+}
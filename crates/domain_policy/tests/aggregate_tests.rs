@@ -439,6 +439,43 @@ mod policy_lifecycle {
         assert!(matches!(policy.state(), PolicyState::Terminated { .. }));
     }
 
+    /// Verifies cancellation of an in-force policy records the refund
+    #[test]
+    fn test_cancel_from_in_force() {
+        let mut policy = create_test_policy();
+        let effective_date = Utc::now().date_naive();
+        policy.issue(effective_date, "UW001").unwrap();
+        policy.take_events();
+
+        let result = policy.cancel(
+            "Free look",
+            Utc::now(),
+            Some(Money::new(dec!(1000), Currency::USD)),
+        );
+
+        assert!(result.is_ok(), "Cancel from in force should succeed");
+        assert_eq!(policy.inception_date(), Some(effective_date));
+        assert!(matches!(
+            policy.state(),
+            PolicyState::Cancelled { premium_refunded: true, .. }
+        ));
+        assert_eq!(policy.take_events()[0].event_type(), "PolicyCancelled");
+    }
+
+    /// Verifies a cancelled quote has no refund and cannot be cancelled again
+    #[test]
+    fn test_cancel_quote_without_refund() {
+        let mut policy = create_test_policy();
+
+        policy.cancel("Not taken up", Utc::now(), None).unwrap();
+
+        assert!(matches!(
+            policy.state(),
+            PolicyState::Cancelled { premium_refunded: false, .. }
+        ));
+        assert!(policy.cancel("Again", Utc::now(), None).is_err());
+    }
+
     /// Verifies invalid state transitions are rejected
     #[test]
     fn test_invalid_state_transitions() {
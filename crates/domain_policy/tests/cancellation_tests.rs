@@ -0,0 +1,435 @@
+//! Cancellation Tests
+//!
+//! This module contains tests for the `CancellationEngine`, covering the
+//! refund calculation and processing when a policy is cancelled.
+//!
+//! # Test Coverage
+//!
+//! - Refund rules loaded from the product catalog
+//! - Free-look refunds with medical cost and NAV adjustments
+//! - Pro-rata and short-rate mid-term refunds
+//! - Refund payment, ledger postings and commission reversal
+//!
+//! # Test Organization
+//!
+//! - `refund_rules` - Catalog parsing and defaults
+//! - `free_look` - Cancellation within the free-look window
+//! - `mid_term` - Pro-rata and short-rate refunds
+//! - `processing` - Policy cancellation, payment and postings
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_billing::account::{Account, AccountType};
+use domain_billing::{Ledger, PaymentStatus};
+use domain_party::agent::Agent;
+use domain_policy::aggregate::{Policy, PolicyBuilder, PolicyState};
+use domain_policy::cancellation::{
+    CancellationAccounts, CancellationEngine, CancellationRequest, RefundBasis, RefundMethod,
+    RefundRules,
+};
+use domain_policy::commission::{CommissionAccounts, CommissionEngine, PremiumReceipt};
+use domain_policy::coverage::Coverage;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+struct Books {
+    ledger: Ledger,
+    accounts: CancellationAccounts,
+    commission_accounts: CommissionAccounts,
+}
+
+fn books() -> Books {
+    let mut ledger = Ledger::new(Currency::USD);
+    let accounts = CancellationAccounts {
+        premium_income: AccountId::new(),
+        refunds_payable: AccountId::new(),
+        investment_gains: AccountId::new(),
+    };
+    let commission_accounts = CommissionAccounts {
+        expense: AccountId::new(),
+        payable: AccountId::new(),
+    };
+    for (id, code, name, account_type) in [
+        (accounts.premium_income, "4100", "Premium Income", AccountType::Revenue),
+        (accounts.refunds_payable, "2400", "Refunds Payable", AccountType::Liability),
+        (accounts.investment_gains, "4300", "Investment Gains", AccountType::Revenue),
+        (commission_accounts.expense, "5200", "Commission Expense", AccountType::Expense),
+        (commission_accounts.payable, "2300", "Commission Payable", AccountType::Liability),
+    ] {
+        ledger.add_account(Account::new(id, code, name, account_type)).unwrap();
+    }
+    Books { ledger, accounts, commission_accounts }
+}
+
+fn engine(books: &Books) -> CancellationEngine {
+    CancellationEngine::from_catalog(&catalog(), books.accounts).unwrap()
+}
+
+/// Issues a policy and records `payments` modal premiums
+fn paid_policy(product_code: &str, premium: Decimal, frequency: PremiumFrequency, inception: NaiveDate, payments: u32) -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code(product_code)
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(500000))))
+        .premium(Premium::new(usd(premium), frequency))
+        .term_years(20)
+        .build()
+        .unwrap();
+    policy.issue(inception, "UW001").unwrap();
+    for _ in 0..payments {
+        policy.record_payment(usd(premium)).unwrap();
+    }
+    policy
+}
+
+// ============================================================================
+// REFUND RULES TESTS
+// ============================================================================
+
+mod refund_rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let books = books();
+        let engine = engine(&books);
+
+        let whole_life = engine.latest_rules_for("WHOLE_LIFE_01");
+        assert_eq!(whole_life.mid_term_method, RefundMethod::ShortRate);
+        assert_eq!(whole_life.short_rate_penalty, dec!(0.1));
+
+        let ulip = engine.latest_rules_for("ULIP_01");
+        assert_eq!(ulip.free_look_days, 30);
+        assert!(ulip.nav_adjustment);
+        assert_eq!(ulip.mid_term_method, RefundMethod::None);
+    }
+
+    #[test]
+    fn test_unknown_product_uses_defaults() {
+        let books = books();
+        assert_eq!(engine(&books).latest_rules_for("UNKNOWN"), &RefundRules::default());
+    }
+
+    #[test]
+    fn test_malformed_rules_rejected() {
+        let product = serde_json::json!({ "code": "X", "refund_rules": { "mid_term_method": "half" } });
+        assert!(RefundRules::from_catalog_product(&product).is_err());
+    }
+}
+
+// ============================================================================
+// FREE LOOK TESTS
+// ============================================================================
+
+mod free_look {
+    use super::*;
+
+    #[test]
+    fn test_full_refund_less_medical_costs() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+        let request = CancellationRequest::new(date(2024, 1, 10), "Changed mind")
+            .with_medical_costs(usd(dec!(150)));
+
+        let quote = engine(&books).quote(&policy, &request).unwrap();
+
+        assert_eq!(quote.basis, RefundBasis::FreeLook);
+        assert_eq!(quote.unearned_premium, usd(dec!(1200)));
+        assert_eq!(quote.medical_costs, usd(dec!(150)));
+        assert_eq!(quote.refund_amount, usd(dec!(1050)));
+    }
+
+    #[test]
+    fn test_last_day_of_free_look() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+
+        let on_day = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 1, 16), "Changed mind"))
+            .unwrap();
+        let after = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 1, 17), "Changed mind"))
+            .unwrap();
+
+        assert_eq!(on_day.basis, RefundBasis::FreeLook);
+        assert_eq!(after.basis, RefundBasis::ProRata);
+    }
+
+    #[test]
+    fn test_nav_movement_on_unit_linked_product() {
+        let books = books();
+        let policy = paid_policy("ULIP_01", dec!(5000), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+
+        let gain = engine(&books)
+            .quote(
+                &policy,
+                &CancellationRequest::new(date(2024, 1, 25), "Changed mind").with_nav_movement(usd(dec!(120))),
+            )
+            .unwrap();
+        let loss = engine(&books)
+            .quote(
+                &policy,
+                &CancellationRequest::new(date(2024, 1, 25), "Changed mind").with_nav_movement(usd(dec!(-300))),
+            )
+            .unwrap();
+
+        assert_eq!(gain.refund_amount, usd(dec!(5120)));
+        assert_eq!(loss.nav_adjustment, usd(dec!(-300)));
+        assert_eq!(loss.refund_amount, usd(dec!(4700)));
+    }
+
+    #[test]
+    fn test_nav_movement_ignored_for_traditional_product() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+        let request = CancellationRequest::new(date(2024, 1, 5), "Changed mind").with_nav_movement(usd(dec!(50)));
+
+        let quote = engine(&books).quote(&policy, &request).unwrap();
+
+        assert!(quote.nav_adjustment.is_zero());
+        assert_eq!(quote.refund_amount, usd(dec!(1200)));
+    }
+}
+
+// ============================================================================
+// MID-TERM TESTS
+// ============================================================================
+
+mod mid_term {
+    use super::*;
+
+    #[test]
+    fn test_pro_rata_annual_premium() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+
+        let quote = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 7, 1), "No longer needed"))
+            .unwrap();
+
+        // 184 of 366 days unexpired
+        assert_eq!(quote.basis, RefundBasis::ProRata);
+        assert_eq!(quote.refund_amount, usd(dec!(603.28)));
+    }
+
+    #[test]
+    fn test_prepaid_future_periods_refunded_in_full() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(100), PremiumFrequency::Monthly, date(2024, 1, 1), 3);
+
+        let quote = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 1, 20), "No longer needed"))
+            .unwrap();
+
+        // 12 of 31 days of January plus February and March
+        assert_eq!(quote.refund_amount, usd(dec!(238.71)));
+    }
+
+    #[test]
+    fn test_short_rate_penalty() {
+        let books = books();
+        let policy = paid_policy("WHOLE_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+
+        let quote = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 7, 1), "No longer needed"))
+            .unwrap();
+
+        assert_eq!(quote.basis, RefundBasis::ShortRate);
+        assert_eq!(quote.unearned_premium, usd(dec!(603.28)));
+        assert_eq!(quote.short_rate_charge, usd(dec!(60.33)));
+        assert_eq!(quote.refund_amount, usd(dec!(542.95)));
+    }
+
+    #[test]
+    fn test_no_refund_beyond_paid_to_date() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(100), PremiumFrequency::Monthly, date(2024, 1, 1), 2);
+
+        let quote = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 3, 10), "No longer needed"))
+            .unwrap();
+
+        assert_eq!(quote.basis, RefundBasis::NoRefund);
+        assert!(quote.refund_amount.is_zero());
+    }
+
+    #[test]
+    fn test_no_mid_term_refund_for_unit_linked_product() {
+        let books = books();
+        let policy = paid_policy("ULIP_01", dec!(5000), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+
+        let quote = engine(&books)
+            .quote(&policy, &CancellationRequest::new(date(2024, 6, 1), "Surrender"))
+            .unwrap();
+
+        assert_eq!(quote.basis, RefundBasis::NoRefund);
+    }
+
+    #[test]
+    fn test_quote_requires_in_force_policy() {
+        let books = books();
+        let policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(500000))))
+            .premium(Premium::new(usd(dec!(1200)), PremiumFrequency::Annual))
+            .build()
+            .unwrap();
+
+        let result = engine(&books).quote(&policy, &CancellationRequest::new(date(2024, 1, 5), "Changed mind"));
+
+        assert!(matches!(result, Err(PolicyError::Cancellation(_))));
+    }
+
+    #[test]
+    fn test_medical_costs_in_other_currency_rejected() {
+        let books = books();
+        let policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+        let request = CancellationRequest::new(date(2024, 1, 5), "Changed mind")
+            .with_medical_costs(Money::new(dec!(100), Currency::EUR));
+
+        assert!(matches!(
+            engine(&books).quote(&policy, &request),
+            Err(PolicyError::CurrencyMismatch { .. })
+        ));
+    }
+}
+
+// ============================================================================
+// PROCESSING TESTS
+// ============================================================================
+
+mod processing {
+    use super::*;
+
+    fn commission_engine(books: &Books) -> CommissionEngine {
+        CommissionEngine::from_catalog(&catalog(), books.commission_accounts).unwrap()
+    }
+
+    fn earn_first_year(books: &mut Books, commissions: &mut CommissionEngine, policy: &Policy, amount: Decimal) {
+        let agent = Agent::new(PartyId::new(), "AGT001");
+        let inception = policy.inception_date().unwrap();
+        let receipt = PremiumReceipt {
+            policy_id: policy.id(),
+            product_code: policy.product_code().to_string(),
+            product_version: policy.product_version().map(str::to_string),
+            writing_agent_id: agent.id,
+            inception_date: inception,
+            due_date: inception,
+            received_date: inception,
+            amount: usd(amount),
+        };
+        commissions.earn(&mut books.ledger, &receipt, &[agent]).unwrap();
+    }
+
+    #[test]
+    fn test_free_look_cancellation() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut commissions = commission_engine(&books);
+        let mut policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+        earn_first_year(&mut books, &mut commissions, &policy, dec!(1200));
+        let request = CancellationRequest::new(date(2024, 1, 10), "Changed mind")
+            .with_medical_costs(usd(dec!(150)));
+
+        let outcome = engine.cancel(&mut books.ledger, &mut policy, &request, &mut commissions).unwrap();
+
+        assert!(matches!(
+            policy.state(),
+            PolicyState::Cancelled { premium_refunded: true, .. }
+        ));
+        let payment = outcome.refund_payment.unwrap();
+        assert_eq!(payment.amount, usd(dec!(1050)));
+        assert!(payment.is_disbursement());
+        assert_eq!(payment.payee_id, Some(policy.policyholder_id()));
+        assert!(payment.payer_id.is_none());
+        assert_eq!(payment.status, PaymentStatus::Pending);
+        assert!(outcome.journal_entry_id.is_some());
+        assert_eq!(books.ledger.get_balance(&books.accounts.refunds_payable).unwrap(), usd(dec!(1050)));
+
+        // All commission is reversed on a free-look cancellation
+        assert_eq!(outcome.commission_reversals.len(), 1);
+        assert_eq!(outcome.commission_reversals[0].amount, usd(dec!(-600)));
+        assert!(books.ledger.get_balance(&books.commission_accounts.expense).unwrap().is_zero());
+    }
+
+    #[test]
+    fn test_mid_term_cancellation_claws_back_commission() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut commissions = commission_engine(&books);
+        let mut policy = paid_policy("TERM_LIFE_01", dec!(1200), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+        earn_first_year(&mut books, &mut commissions, &policy, dec!(1200));
+
+        let outcome = engine
+            .cancel(
+                &mut books.ledger,
+                &mut policy,
+                &CancellationRequest::new(date(2024, 7, 1), "No longer needed"),
+                &mut commissions,
+            )
+            .unwrap();
+
+        assert_eq!(outcome.quote.basis, RefundBasis::ProRata);
+        assert_eq!(outcome.refund_payment.unwrap().amount, usd(dec!(603.28)));
+        assert_eq!(outcome.commission_reversals.len(), 1);
+        assert_eq!(outcome.commission_reversals[0].amount, usd(dec!(-301.64)));
+    }
+
+    #[test]
+    fn test_nav_loss_posted_to_investment_gains() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut commissions = commission_engine(&books);
+        let mut policy = paid_policy("ULIP_01", dec!(5000), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+        let request = CancellationRequest::new(date(2024, 1, 25), "Changed mind")
+            .with_nav_movement(usd(dec!(-300)));
+
+        engine.cancel(&mut books.ledger, &mut policy, &request, &mut commissions).unwrap();
+
+        assert_eq!(books.ledger.get_balance(&books.accounts.refunds_payable).unwrap(), usd(dec!(4700)));
+        assert_eq!(books.ledger.get_balance(&books.accounts.investment_gains).unwrap(), usd(dec!(300)));
+    }
+
+    #[test]
+    fn test_cancellation_without_refund() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut commissions = commission_engine(&books);
+        let mut policy = paid_policy("ULIP_01", dec!(5000), PremiumFrequency::Annual, date(2024, 1, 1), 1);
+
+        let outcome = engine
+            .cancel(
+                &mut books.ledger,
+                &mut policy,
+                &CancellationRequest::new(date(2024, 6, 1), "Surrender"),
+                &mut commissions,
+            )
+            .unwrap();
+
+        assert!(outcome.refund_payment.is_none());
+        assert!(outcome.journal_entry_id.is_none());
+        assert!(matches!(
+            policy.state(),
+            PolicyState::Cancelled { premium_refunded: false, .. }
+        ));
+    }
+}
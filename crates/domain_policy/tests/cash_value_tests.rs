@@ -0,0 +1,388 @@
+//! Cash Value Tests
+//!
+//! This module contains tests for the `CashValueEngine`, covering cash
+//! value accumulation and dividends on participating whole life policies.
+//!
+//! # Test Coverage
+//!
+//! - Cash value rules loaded from the product catalog
+//! - Cash value and dividend rates from the whole life rules
+//! - Anniversary processing of guaranteed cash value
+//! - Each dividend option and its ledger postings
+//! - Guaranteed vs non-guaranteed values table
+//!
+//! # Test Organization
+//!
+//! - `rules` - Catalog parsing and rules evaluation
+//! - `anniversary` - Cash value accumulation
+//! - `dividend_options` - Cash, premium reduction, accumulation and PUA
+//! - `values_table` - Projected values by policy year
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_billing::account::{Account, AccountType};
+use domain_billing::invoice::InvoiceItemType;
+use domain_billing::{Invoice, InvoiceItem, InvoiceStatus, Ledger};
+use domain_policy::aggregate::{Policy, PolicyBuilder};
+use domain_policy::cash_value::{
+    CashValueAccount, CashValueEngine, CashValueFactors, DividendAccounts, DividendOption,
+};
+use domain_policy::coverage::Coverage;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::rules_engine::RulesEngine;
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+struct Books {
+    ledger: Ledger,
+    accounts: DividendAccounts,
+}
+
+fn books() -> Books {
+    let mut ledger = Ledger::new(Currency::USD);
+    let accounts = DividendAccounts {
+        dividend_expense: AccountId::new(),
+        dividends_payable: AccountId::new(),
+        dividend_accumulations: AccountId::new(),
+        paid_up_additions: AccountId::new(),
+        premium_receivable: AccountId::new(),
+    };
+    for (id, code, name, account_type) in [
+        (accounts.dividend_expense, "5400", "Policyholder Dividends", AccountType::Expense),
+        (accounts.dividends_payable, "2700", "Dividends Payable", AccountType::Liability),
+        (accounts.dividend_accumulations, "2710", "Dividend Accumulations", AccountType::Liability),
+        (accounts.paid_up_additions, "2720", "Paid-Up Additions Reserve", AccountType::Liability),
+        (accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset),
+    ] {
+        ledger.add_account(Account::new(id, code, name, account_type)).unwrap();
+    }
+    Books { ledger, accounts }
+}
+
+fn engine(books: &Books) -> CashValueEngine {
+    CashValueEngine::from_catalog(&catalog(), books.accounts).unwrap()
+}
+
+/// Rates for issue age 35 paying annually: 4.0% cash value, 2.25% dividend
+fn factors() -> CashValueFactors {
+    CashValueFactors {
+        cash_value_rate: dec!(4.0),
+        dividend_rate: dec!(2.25),
+    }
+}
+
+/// Issues a whole life policy on 1 January 2024 with a 2,400 annual premium
+fn whole_life_policy() -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("WHOLE_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+        .premium(Premium::new(usd(dec!(2400)), PremiumFrequency::Annual))
+        .term_years(99)
+        .build()
+        .unwrap();
+    policy.issue(date(2024, 1, 1), "UW001").unwrap();
+    policy
+}
+
+/// Pays the annual premium and processes the anniversary for `years` years
+fn run_years(engine: &CashValueEngine, books: &mut Books, policy: &mut Policy, account: &mut CashValueAccount, years: u32) {
+    for year in 1..=years {
+        policy.record_payment(usd(dec!(2400))).unwrap();
+        engine
+            .process_anniversary(&mut books.ledger, policy, account, date(2024 + year as i32, 1, 1))
+            .unwrap();
+    }
+}
+
+// ============================================================================
+// RULES TESTS
+// ============================================================================
+
+mod rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let books = books();
+        let engine = engine(&books);
+
+        let rules = engine.latest_rules_for("WHOLE_LIFE_01").unwrap();
+        assert_eq!(rules.allocation(1), dec!(0));
+        assert_eq!(rules.allocation(2), dec!(0.4));
+        assert_eq!(rules.allocation(30), dec!(0.9));
+        assert_eq!(rules.pua_single_premium(37), Some(dec!(220)));
+        assert_eq!(rules.pua_single_premium(80), Some(dec!(540)));
+        assert!(rules.paid_up_additions_available);
+
+        assert!(engine.latest_rules_for("TERM_LIFE_01").is_none());
+    }
+
+    #[test]
+    fn test_zero_pua_rate_rejected() {
+        let books = books();
+        let mut catalog = catalog();
+        let product = catalog["products"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|p| p["code"] == "WHOLE_LIFE_01")
+            .unwrap();
+        product["cash_value_rules"]["pua_rates"][0]["single_premium_per_thousand"] = json!(0);
+
+        let result = CashValueEngine::from_catalog(&catalog, books.accounts);
+
+        assert!(matches!(result, Err(PolicyError::Validation(_))));
+    }
+
+    #[test]
+    fn test_factors_from_whole_life_rules() {
+        let rules_engine = RulesEngine::new();
+        let rules = rules_engine
+            .load_rules_from_str(include_str!("../../../products/whole_life.json"))
+            .unwrap();
+
+        let annual = rules_engine
+            .evaluate(&rules, json!({ "applicant": { "age": 35 }, "payment": { "mode": "annual" } }))
+            .unwrap();
+        assert_eq!(CashValueFactors::from_evaluation(&annual).unwrap(), factors());
+
+        let monthly = rules_engine
+            .evaluate(&rules, json!({ "applicant": { "age": 35 }, "payment": { "mode": "monthly" } }))
+            .unwrap();
+        let monthly = CashValueFactors::from_evaluation(&monthly).unwrap();
+        assert_eq!(monthly.cash_value_rate, dec!(3.5));
+        assert_eq!(monthly.dividend_rate, dec!(1.75));
+    }
+
+    #[test]
+    fn test_product_without_cash_value_rejected() {
+        let books = books();
+        let engine = engine(&books);
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(300)), PremiumFrequency::Annual))
+            .term_years(20)
+            .build()
+            .unwrap();
+        policy.issue(date(2024, 1, 1), "UW001").unwrap();
+
+        let result = engine.open(&policy, 35, factors(), DividendOption::Cash);
+
+        assert!(matches!(result, Err(PolicyError::CashValue(_))));
+    }
+}
+
+// ============================================================================
+// ANNIVERSARY TESTS
+// ============================================================================
+
+mod anniversary {
+    use super::*;
+
+    #[test]
+    fn test_guaranteed_cash_value_accumulates() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::Cash).unwrap();
+
+        run_years(&engine, &mut books, &mut policy, &mut account, 3);
+
+        // Year 1 allocates nothing; year 2: 960 * 1.04; year 3: (998.40 + 1,440) * 1.04
+        assert_eq!(account.history[0].guaranteed_cash_value, usd(dec!(0)));
+        assert_eq!(account.history[1].guaranteed_cash_value, usd(dec!(998.40)));
+        assert_eq!(account.guaranteed_cash_value, usd(dec!(2535.94)));
+        assert_eq!(account.policy_year, 3);
+        assert_eq!(policy.financial_state().surrender_value, Some(usd(dec!(2535.94))));
+    }
+
+    #[test]
+    fn test_anniversary_before_due_date_rejected() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::Cash).unwrap();
+
+        let result = engine.process_anniversary(&mut books.ledger, &mut policy, &mut account, date(2024, 12, 31));
+
+        assert!(matches!(result, Err(PolicyError::CashValue(_))));
+        assert_eq!(account.policy_year, 0);
+    }
+}
+
+// ============================================================================
+// DIVIDEND OPTION TESTS
+// ============================================================================
+
+mod dividend_options {
+    use super::*;
+
+    #[test]
+    fn test_cash_dividend_paid_to_policyholder() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::Cash).unwrap();
+        run_years(&engine, &mut books, &mut policy, &mut account, 1);
+
+        policy.record_payment(usd(dec!(2400))).unwrap();
+        let outcome = engine
+            .process_anniversary(&mut books.ledger, &mut policy, &mut account, date(2026, 1, 1))
+            .unwrap();
+
+        // 998.40 * 2.25%
+        assert_eq!(outcome.values.dividend, usd(dec!(22.46)));
+        let payment = outcome.dividend_payment.unwrap();
+        assert_eq!(payment.amount, usd(dec!(22.46)));
+        assert!(payment.is_disbursement());
+        assert!(payment.payer_id.is_none());
+        assert_eq!(books.ledger.get_balance(&books.accounts.dividends_payable), Some(usd(dec!(22.46))));
+        assert_eq!(account.total_cash_value(), usd(dec!(998.40)));
+    }
+
+    #[test]
+    fn test_premium_reduction_credit_applied_to_invoice() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::PremiumReduction).unwrap();
+        run_years(&engine, &mut books, &mut policy, &mut account, 2);
+        assert_eq!(account.premium_credit, usd(dec!(22.46)));
+
+        let mut invoice = Invoice::new(policy.id(), policy.policyholder_id(), date(2026, 1, 1), Currency::USD);
+        invoice.add_item(InvoiceItem::new("Annual premium", InvoiceItemType::Premium, usd(dec!(2400))));
+        invoice.issue();
+
+        let applied = engine
+            .apply_premium_credit(&mut books.ledger, &mut policy, &mut account, &mut invoice, date(2026, 1, 5))
+            .unwrap();
+
+        assert_eq!(applied, Some(usd(dec!(22.46))));
+        assert_eq!(invoice.balance_due(), usd(dec!(2377.54)));
+        assert_eq!(invoice.status, InvoiceStatus::PartiallyPaid);
+        assert!(account.premium_credit.is_zero());
+        assert_eq!(books.ledger.get_balance(&books.accounts.dividends_payable), Some(usd(dec!(0))));
+        let entry = books.ledger.entries().last().unwrap();
+        assert_eq!(entry.transaction_date.date_naive(), date(2026, 1, 5));
+    }
+
+    #[test]
+    fn test_dividends_accumulate_at_interest() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine
+            .open(&policy, 35, factors(), DividendOption::AccumulateAtInterest)
+            .unwrap();
+
+        run_years(&engine, &mut books, &mut policy, &mut account, 3);
+
+        // Year 3: 22.46 earns 3% (0.67), plus the 57.06 dividend on 2,535.94
+        assert_eq!(account.history[2].accumulation_interest, usd(dec!(0.67)));
+        assert_eq!(account.history[2].dividend, usd(dec!(57.06)));
+        assert_eq!(account.dividend_accumulations, usd(dec!(80.19)));
+        assert_eq!(account.total_cash_value(), usd(dec!(2616.13)));
+        assert_eq!(account.history[2].total_death_benefit, usd(dec!(100080.19)));
+        assert_eq!(
+            books.ledger.get_balance(&books.accounts.dividend_accumulations),
+            Some(usd(dec!(80.19)))
+        );
+    }
+
+    #[test]
+    fn test_dividends_buy_paid_up_additions() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::PaidUpAdditions).unwrap();
+
+        run_years(&engine, &mut books, &mut policy, &mut account, 2);
+
+        // 22.46 buys 22.46 / 220 per thousand at age 37
+        assert_eq!(account.history[1].pua_face_purchased, usd(dec!(102.09)));
+        assert_eq!(account.pua_face_amount, usd(dec!(102.09)));
+        assert_eq!(account.pua_cash_value, usd(dec!(22.46)));
+        assert_eq!(account.history[1].total_death_benefit, usd(dec!(100102.09)));
+        assert_eq!(books.ledger.get_balance(&books.accounts.paid_up_additions), Some(usd(dec!(22.46))));
+    }
+
+    #[test]
+    fn test_change_dividend_option() {
+        let books = books();
+        let engine = engine(&books);
+        let policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::Cash).unwrap();
+
+        engine
+            .change_dividend_option(&policy, &mut account, DividendOption::PaidUpAdditions)
+            .unwrap();
+
+        assert_eq!(account.dividend_option, DividendOption::PaidUpAdditions);
+    }
+}
+
+// ============================================================================
+// VALUES TABLE TESTS
+// ============================================================================
+
+mod values_table {
+    use super::*;
+
+    #[test]
+    fn test_guaranteed_and_non_guaranteed_values_by_year() {
+        let books = books();
+        let engine = engine(&books);
+        let policy = whole_life_policy();
+        let account = engine.open(&policy, 35, factors(), DividendOption::PaidUpAdditions).unwrap();
+
+        let table = engine.values_table(&policy, &account, 20).unwrap();
+
+        assert_eq!(table.len(), 20);
+        assert_eq!(table[0].policy_year, 1);
+        assert_eq!(table[0].attained_age, 36);
+        assert_eq!(table[2].guaranteed_cash_value, usd(dec!(2535.94)));
+        assert_eq!(table[19].cumulative_premium, usd(dec!(48000)));
+        assert_eq!(table[19].guaranteed_death_benefit, usd(dec!(100000)));
+        for row in &table {
+            assert!(row.non_guaranteed_cash_value.amount() >= row.guaranteed_cash_value.amount());
+            assert!(row.non_guaranteed_death_benefit.amount() >= row.guaranteed_death_benefit.amount());
+        }
+        assert!(table[19].non_guaranteed_death_benefit.amount() > dec!(100000));
+    }
+
+    #[test]
+    fn test_values_table_continues_from_account() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = whole_life_policy();
+        let mut account = engine.open(&policy, 35, factors(), DividendOption::Cash).unwrap();
+        run_years(&engine, &mut books, &mut policy, &mut account, 2);
+
+        let table = engine.values_table(&policy, &account, 1).unwrap();
+
+        assert_eq!(table[0].policy_year, 3);
+        assert_eq!(table[0].guaranteed_cash_value, usd(dec!(2535.94)));
+        assert_eq!(table[0].projected_dividend, usd(dec!(57.06)));
+    }
+}
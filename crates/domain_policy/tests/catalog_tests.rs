@@ -0,0 +1,253 @@
+//! Product Catalog Tests
+//!
+//! This module contains tests for the `ProductCatalog`, covering loading
+//! the catalog, product versioning and effective dating.
+//!
+//! # Test Coverage
+//!
+//! - Loading `catalog.json` and its rules files
+//! - Catalog validation errors
+//! - Selecting the version in force on the application date
+//! - Policies pinned to the version they were sold under
+//! - Application checks against version limits
+//!
+//! # Test Organization
+//!
+//! - `loading` - Parsing and rules files
+//! - `validation` - Invalid catalogs
+//! - `versions` - Effective dating and pinning
+//! - `limits` - Application checks
+
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::PolicyBuilder;
+use domain_policy::catalog::ProductCatalog;
+use domain_policy::coverage::Coverage;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::{json, Value};
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn products_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../products")
+}
+
+fn catalog_json() -> Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+/// Catalog with a second version of TERM_LIFE_01 effective 1 July 2026
+/// that raises the minimum sum assured to 100,000
+fn versioned_catalog() -> ProductCatalog {
+    let mut catalog = catalog_json();
+    let mut term_v2 = catalog["products"][0].clone();
+    term_v2["version"] = json!("2.0.0");
+    term_v2["effective_date"] = json!("2026-07-01");
+    term_v2["limits"]["min_sum_assured"] = json!(100000);
+    catalog["products"].as_array_mut().unwrap().push(term_v2);
+    ProductCatalog::from_json(&catalog).unwrap()
+}
+
+fn catalog_error(catalog: &Value) -> bool {
+    matches!(ProductCatalog::from_json(catalog), Err(PolicyError::Catalog(_)))
+}
+
+// ============================================================================
+// LOADING TESTS
+// ============================================================================
+
+mod loading {
+    use super::*;
+
+    #[test]
+    fn test_catalog_loaded_with_rules_files() {
+        let catalog = ProductCatalog::load(&products_dir()).unwrap();
+
+        assert_eq!(catalog.version, "1.0.0");
+        assert_eq!(catalog.product_codes().count(), 5);
+
+        let term = catalog.version_on("TERM_LIFE_01", date(2026, 1, 1)).unwrap();
+        assert_eq!(term.version, "1.0.0");
+        assert_eq!(term.effective_date, date(2024, 1, 1));
+        assert_eq!(term.limits.max_sum_assured, Some(dec!(5000000)));
+        assert_eq!(term.riders.len(), 3);
+        assert!(term.offers_payment_mode(PremiumFrequency::SemiAnnual));
+        assert!(term.offers_currency(Currency::GBP));
+        assert_eq!(term.rules.as_ref().unwrap().metadata.product_code, "TERM_LIFE_01");
+    }
+
+    #[test]
+    fn test_inactive_product_without_rules_file_loads() {
+        let catalog = ProductCatalog::load(&products_dir()).unwrap();
+
+        let ulip = catalog.version_on("ULIP_01", date(2026, 1, 1)).unwrap();
+
+        assert!(!ulip.active);
+        assert!(ulip.rules.is_none());
+    }
+}
+
+// ============================================================================
+// VALIDATION TESTS
+// ============================================================================
+
+mod validation {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_version_rejected() {
+        let mut catalog = catalog_json();
+        let duplicate = catalog["products"][0].clone();
+        catalog["products"].as_array_mut().unwrap().push(duplicate);
+
+        assert!(catalog_error(&catalog));
+    }
+
+    #[test]
+    fn test_overlapping_versions_rejected() {
+        let mut catalog = catalog_json();
+        catalog["products"][0]["effective_to"] = json!("2026-12-31");
+        let mut term_v2 = catalog["products"][0].clone();
+        term_v2["version"] = json!("2.0.0");
+        term_v2["effective_date"] = json!("2026-07-01");
+        term_v2["effective_to"] = Value::Null;
+        catalog["products"].as_array_mut().unwrap().push(term_v2);
+
+        assert!(catalog_error(&catalog));
+    }
+
+    #[test]
+    fn test_unknown_payment_mode_rejected() {
+        let mut catalog = catalog_json();
+        catalog["products"][0]["payment_modes"] = json!(["annual", "fortnightly"]);
+
+        assert!(catalog_error(&catalog));
+    }
+
+    #[test]
+    fn test_inconsistent_limits_rejected() {
+        let mut catalog = catalog_json();
+        catalog["products"][0]["limits"]["min_term_years"] = json!(40);
+
+        assert!(catalog_error(&catalog));
+    }
+}
+
+// ============================================================================
+// VERSION TESTS
+// ============================================================================
+
+mod versions {
+    use super::*;
+
+    #[test]
+    fn test_version_in_force_on_application_date() {
+        let catalog = versioned_catalog();
+
+        let v1 = catalog.quote_version("TERM_LIFE_01", date(2026, 6, 30)).unwrap();
+        assert_eq!(v1.version, "1.0.0");
+        assert_eq!(v1.effective_to, Some(date(2026, 6, 30)));
+
+        let v2 = catalog.quote_version("TERM_LIFE_01", date(2026, 7, 1)).unwrap();
+        assert_eq!(v2.version, "2.0.0");
+        assert_eq!(v2.effective_to, None);
+        assert_eq!(catalog.versions("TERM_LIFE_01").len(), 2);
+    }
+
+    #[test]
+    fn test_policy_stays_pinned_to_version_sold() {
+        let catalog = versioned_catalog();
+        let mut policy = catalog
+            .start_quote("TERM_LIFE_01", date(2026, 6, 15))
+            .unwrap()
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(60000))))
+            .premium(Premium::new(usd(dec!(20)), PremiumFrequency::Monthly))
+            .term_years(20)
+            .build()
+            .unwrap();
+        policy.issue(date(2026, 7, 10), "UW001").unwrap();
+
+        assert_eq!(policy.product_version(), Some("1.0.0"));
+        assert_eq!(catalog.version_for(&policy).unwrap().version, "1.0.0");
+        assert_eq!(catalog.version_on("TERM_LIFE_01", date(2026, 7, 10)).unwrap().version, "2.0.0");
+    }
+
+    #[test]
+    fn test_unpinned_policy_uses_version_at_inception() {
+        let catalog = versioned_catalog();
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(150000))))
+            .premium(Premium::new(usd(dec!(40)), PremiumFrequency::Monthly))
+            .term_years(20)
+            .build()
+            .unwrap();
+        policy.issue(date(2026, 8, 1), "UW001").unwrap();
+
+        assert_eq!(catalog.version_for(&policy).unwrap().version, "2.0.0");
+    }
+
+    #[test]
+    fn test_closed_product_cannot_be_quoted() {
+        let catalog = versioned_catalog();
+
+        assert!(matches!(
+            catalog.quote_version("ULIP_01", date(2026, 1, 1)),
+            Err(PolicyError::Catalog(_))
+        ));
+        assert!(matches!(
+            catalog.quote_version("TERM_LIFE_01", date(2023, 12, 31)),
+            Err(PolicyError::Catalog(_))
+        ));
+    }
+}
+
+// ============================================================================
+// LIMIT TESTS
+// ============================================================================
+
+mod limits {
+    use super::*;
+
+    #[test]
+    fn test_application_checked_against_version_limits() {
+        let catalog = versioned_catalog();
+        let v1 = catalog.pinned("TERM_LIFE_01", "1.0.0").unwrap();
+        let v2 = catalog.pinned("TERM_LIFE_01", "2.0.0").unwrap();
+
+        assert!(v1.check_application(usd(dec!(60000)), 40, Some(20), PremiumFrequency::Monthly).is_ok());
+        assert!(matches!(
+            v2.check_application(usd(dec!(60000)), 40, Some(20), PremiumFrequency::Monthly),
+            Err(PolicyError::ProductRuleViolation(_))
+        ));
+    }
+
+    #[test]
+    fn test_maturity_age_and_payment_mode_checked() {
+        let catalog = versioned_catalog();
+        let term = catalog.pinned("TERM_LIFE_01", "1.0.0").unwrap();
+
+        assert!(term.check_application(usd(dec!(100000)), 60, Some(30), PremiumFrequency::Annual).is_err());
+        assert!(term.check_application(usd(dec!(100000)), 40, Some(20), PremiumFrequency::Single).is_err());
+        assert!(term
+            .check_application(Money::new(dec!(100000), Currency::JPY), 40, Some(20), PremiumFrequency::Annual)
+            .is_err());
+    }
+}
@@ -0,0 +1,437 @@
+//! Commission Tests
+//!
+//! This module contains tests for the `CommissionEngine`, covering agent
+//! commission from premium receipt through to statements.
+//!
+//! # Test Coverage
+//!
+//! - Commission schedules loaded from the product catalog
+//! - First-year and renewal rates by policy year
+//! - Override commission up the agent hierarchy
+//! - Pro-rata clawback on lapse within the clawback period
+//! - Ledger postings and agent statements
+//!
+//! # Test Organization
+//!
+//! - `schedules` - Catalog parsing and rate lookup
+//! - `earning` - Writing and override commission on receipt
+//! - `clawback` - Recovery of unearned commission
+//! - `statements` - Periodic agent statements
+
+use chrono::{Duration, Months, NaiveDate, Utc};
+use core_kernel::{AccountId, Currency, Money, PartyId, PolicyId};
+use domain_billing::account::{Account, AccountType};
+use domain_billing::Ledger;
+use domain_party::agent::{Agent, AgentStatus};
+use domain_policy::aggregate::{LapseReason, Policy, PolicyBuilder};
+use domain_policy::commission::{
+    CommissionAccounts, CommissionEngine, CommissionKind, CommissionSchedule, PremiumReceipt,
+    PremiumType, RenewalRate,
+};
+use domain_policy::coverage::Coverage;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::PolicyError;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: rust_decimal::Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog_engine(ledger: &mut Ledger) -> (CommissionEngine, CommissionAccounts) {
+    let accounts = CommissionAccounts {
+        expense: AccountId::new(),
+        payable: AccountId::new(),
+    };
+    ledger
+        .add_account(Account::new(accounts.expense, "5200", "Commission Expense", AccountType::Expense))
+        .unwrap();
+    ledger
+        .add_account(Account::new(accounts.payable, "2300", "Commission Payable", AccountType::Liability))
+        .unwrap();
+
+    let catalog: serde_json::Value =
+        serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap();
+    (CommissionEngine::from_catalog(&catalog, accounts).unwrap(), accounts)
+}
+
+/// Builds writer -> manager -> director
+fn hierarchy() -> Vec<Agent> {
+    let mut director = Agent::new(PartyId::new(), "DIR001");
+    let mut manager = Agent::new(PartyId::new(), "MGR001");
+    let mut writer = Agent::new(PartyId::new(), "AGT001");
+    director.manager_id = None;
+    manager.manager_id = Some(director.id);
+    writer.manager_id = Some(manager.id);
+    vec![writer, manager, director]
+}
+
+fn receipt(policy_id: PolicyId, agent: &Agent, inception: NaiveDate, due: NaiveDate, amount: rust_decimal::Decimal) -> PremiumReceipt {
+    PremiumReceipt {
+        policy_id,
+        product_code: "TERM_LIFE_01".to_string(),
+        product_version: None,
+        writing_agent_id: agent.id,
+        inception_date: inception,
+        due_date: due,
+        received_date: due,
+        amount: usd(amount),
+    }
+}
+
+fn in_force_policy(effective_date: NaiveDate) -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(500000))))
+        .premium(Premium::new(usd(dec!(1200)), PremiumFrequency::Annual))
+        .term_years(20)
+        .build()
+        .unwrap();
+    policy.issue(effective_date, "UW001").unwrap();
+    policy
+}
+
+fn lapse(policy: &mut Policy) {
+    policy
+        .lapse(
+            LapseReason::NonPayment {
+                grace_days_elapsed: 31,
+                outstanding_amount: dec!(1200),
+            },
+            Some(730),
+        )
+        .unwrap();
+}
+
+// ============================================================================
+// SCHEDULE TESTS
+// ============================================================================
+
+mod schedules {
+    use super::*;
+
+    #[test]
+    fn test_schedules_loaded_from_catalog() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (engine, _) = catalog_engine(&mut ledger);
+
+        let term = engine.schedule_for("TERM_LIFE_01").unwrap();
+        assert_eq!(term.first_year_rate, dec!(0.50));
+        assert_eq!(term.override_rates, vec![dec!(0.10), dec!(0.05)]);
+        assert_eq!(term.clawback_months, 12);
+        assert_eq!(engine.schedule_for("WHOLE_LIFE_01").unwrap().clawback_months, 24);
+        assert!(engine.schedule_for("UNKNOWN").is_none());
+    }
+
+    #[test]
+    fn test_rate_by_policy_year() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (engine, _) = catalog_engine(&mut ledger);
+        let term = engine.schedule_for("TERM_LIFE_01").unwrap();
+
+        assert_eq!(term.rate_for_year(1), dec!(0.50));
+        assert_eq!(term.rate_for_year(2), dec!(0.05));
+        assert_eq!(term.rate_for_year(10), dec!(0.05));
+        assert_eq!(term.rate_for_year(11), dec!(0.02));
+        assert_eq!(term.rate_for_year(30), dec!(0.02));
+    }
+
+    #[test]
+    fn test_gap_in_renewal_bands_pays_nothing() {
+        let schedule = CommissionSchedule {
+            first_year_rate: dec!(0.40),
+            renewal_rates: vec![RenewalRate { from_year: 2, to_year: Some(3), rate: dec!(0.05) }],
+            override_rates: vec![],
+            clawback_months: 0,
+        };
+
+        assert_eq!(schedule.rate_for_year(4), dec!(0));
+    }
+
+    #[test]
+    fn test_malformed_schedule_rejected() {
+        let product = serde_json::json!({ "code": "X", "commission": { "renewal_rates": [] } });
+
+        assert!(CommissionSchedule::from_catalog_product(&product).is_err());
+        assert!(CommissionSchedule::from_catalog_product(&serde_json::json!({ "code": "X" }))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_policy_year_from_due_date() {
+        let agent = Agent::new(PartyId::new(), "AGT001");
+        let inception = date(2024, 3, 15);
+
+        assert_eq!(receipt(PolicyId::new(), &agent, inception, date(2024, 3, 15), dec!(100)).policy_year(), 1);
+        assert_eq!(receipt(PolicyId::new(), &agent, inception, date(2025, 3, 14), dec!(100)).policy_year(), 1);
+        assert_eq!(receipt(PolicyId::new(), &agent, inception, date(2025, 3, 15), dec!(100)).policy_year(), 2);
+        assert_eq!(receipt(PolicyId::new(), &agent, inception, date(2034, 4, 1), dec!(100)).policy_year(), 11);
+    }
+}
+
+// ============================================================================
+// EARNING TESTS
+// ============================================================================
+
+mod earning {
+    use super::*;
+
+    #[test]
+    fn test_first_year_commission_with_overrides() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, accounts) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        let inception = date(2024, 1, 1);
+
+        let entries = engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), &agents[0], inception, inception, dec!(1000)), &agents)
+            .unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].agent_id, agents[0].id);
+        assert_eq!(entries[0].kind, CommissionKind::Writing);
+        assert_eq!(entries[0].premium_type, PremiumType::FirstYear);
+        assert_eq!(entries[0].amount, usd(dec!(500)));
+        assert_eq!(entries[1].agent_id, agents[1].id);
+        assert_eq!(entries[1].kind, CommissionKind::Override { level: 1 });
+        assert_eq!(entries[1].amount, usd(dec!(100)));
+        assert_eq!(entries[2].agent_id, agents[2].id);
+        assert_eq!(entries[2].kind, CommissionKind::Override { level: 2 });
+        assert_eq!(entries[2].amount, usd(dec!(50)));
+
+        assert!(entries.iter().all(|e| e.journal_entry_id.is_some()));
+        assert_eq!(ledger.get_balance(&accounts.expense).unwrap().amount(), dec!(650));
+        assert_eq!(ledger.get_balance(&accounts.payable).unwrap().amount(), dec!(650));
+    }
+
+    #[test]
+    fn test_renewal_commission() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+
+        let entries = engine
+            .earn(
+                &mut ledger,
+                &receipt(PolicyId::new(), &agents[0], date(2020, 1, 1), date(2024, 1, 1), dec!(1000)),
+                &agents,
+            )
+            .unwrap();
+
+        assert_eq!(entries[0].policy_year, 5);
+        assert_eq!(entries[0].premium_type, PremiumType::Renewal);
+        assert_eq!(entries[0].amount, usd(dec!(50)));
+        assert_eq!(entries[1].amount, usd(dec!(100)));
+    }
+
+    #[test]
+    fn test_inactive_manager_skipped_but_chain_continues() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let mut agents = hierarchy();
+        agents[1].status = AgentStatus::Terminated;
+        let inception = date(2024, 1, 1);
+
+        let entries = engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), &agents[0], inception, inception, dec!(1000)), &agents)
+            .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].agent_id, agents[2].id);
+        assert_eq!(entries[1].kind, CommissionKind::Override { level: 2 });
+    }
+
+    #[test]
+    fn test_hierarchy_cycle_does_not_loop() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let mut agents = hierarchy();
+        agents[2].manager_id = Some(agents[0].id);
+        let inception = date(2024, 1, 1);
+
+        let entries = engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), &agents[0], inception, inception, dec!(1000)), &agents)
+            .unwrap();
+
+        assert_eq!(entries.len(), 3);
+    }
+
+    #[test]
+    fn test_default_rate_used_without_schedule() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let mut agents = hierarchy();
+        agents[0].default_commission_rate = Some(dec!(0.15));
+        let mut premium = receipt(PolicyId::new(), &agents[0], date(2024, 1, 1), date(2024, 1, 1), dec!(1000));
+        premium.product_code = "UNKNOWN".to_string();
+
+        let entries = engine.earn(&mut ledger, &premium, &agents).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].amount, usd(dec!(150)));
+    }
+
+    #[test]
+    fn test_missing_schedule_and_rate_is_error() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        let mut premium = receipt(PolicyId::new(), &agents[0], date(2024, 1, 1), date(2024, 1, 1), dec!(1000));
+        premium.product_code = "UNKNOWN".to_string();
+
+        assert!(matches!(engine.earn(&mut ledger, &premium, &agents), Err(PolicyError::Commission(_))));
+    }
+
+    #[test]
+    fn test_unknown_writing_agent_is_error() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let stranger = Agent::new(PartyId::new(), "AGT999");
+        let premium = receipt(PolicyId::new(), &stranger, date(2024, 1, 1), date(2024, 1, 1), dec!(1000));
+
+        assert!(engine.earn(&mut ledger, &premium, &hierarchy()).is_err());
+        assert!(engine.entries().is_empty());
+    }
+}
+
+// ============================================================================
+// CLAWBACK TESTS
+// ============================================================================
+
+mod clawback {
+    use super::*;
+
+    #[test]
+    fn test_pro_rata_clawback_on_lapse() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, accounts) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        // Lapse takes effect today; 3 of the 12 clawback months have elapsed
+        let inception = today().checked_sub_months(Months::new(3)).unwrap();
+        let mut policy = in_force_policy(inception);
+        engine
+            .earn(&mut ledger, &receipt(policy.id(), &agents[0], inception, inception, dec!(1200)), &agents)
+            .unwrap();
+        lapse(&mut policy);
+
+        let clawbacks = engine.clawback(&mut ledger, &policy).unwrap();
+
+        let clawback_end = inception.checked_add_months(Months::new(12)).unwrap();
+        let fraction = rust_decimal::Decimal::from((clawback_end - today()).num_days())
+            / rust_decimal::Decimal::from((clawback_end - inception).num_days());
+        assert_eq!(clawbacks.len(), 3);
+        assert!(clawbacks.iter().all(|c| c.kind == CommissionKind::Clawback && c.amount.is_negative()));
+        assert_eq!(clawbacks[0].agent_id, agents[0].id);
+        assert_eq!(clawbacks[0].amount, -usd(dec!(600)).multiply(fraction).round_to_currency());
+        assert!(fraction > dec!(0.7) && fraction < dec!(0.8));
+
+        let recovered: rust_decimal::Decimal = clawbacks.iter().map(|c| -c.amount.amount()).sum();
+        assert_eq!(ledger.get_balance(&accounts.expense).unwrap().amount(), dec!(780) - recovered);
+
+        // A second call recovers nothing more
+        assert!(engine.clawback(&mut ledger, &policy).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_no_clawback_after_clawback_period() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        let inception = today() - Duration::days(400);
+        let mut policy = in_force_policy(inception);
+        engine
+            .earn(&mut ledger, &receipt(policy.id(), &agents[0], inception, inception, dec!(1200)), &agents)
+            .unwrap();
+        lapse(&mut policy);
+
+        assert!(engine.clawback(&mut ledger, &policy).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_renewal_commission_not_clawed_back() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        let inception = today() - Duration::days(30);
+        let mut policy = in_force_policy(inception);
+        let mut renewal = receipt(policy.id(), &agents[0], inception, inception, dec!(1200));
+        renewal.due_date = inception.checked_add_months(Months::new(12)).unwrap();
+        engine.earn(&mut ledger, &renewal, &agents).unwrap();
+        lapse(&mut policy);
+
+        assert!(engine.clawback(&mut ledger, &policy).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_clawback_requires_lapse_or_cancellation() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let policy = in_force_policy(today());
+
+        assert!(matches!(engine.clawback(&mut ledger, &policy), Err(PolicyError::Commission(_))));
+    }
+}
+
+// ============================================================================
+// STATEMENT TESTS
+// ============================================================================
+
+mod statements {
+    use super::*;
+
+    #[test]
+    fn test_agent_statement_totals() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        let writer = &agents[0];
+
+        engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), writer, date(2024, 1, 10), date(2024, 1, 10), dec!(1000)), &agents)
+            .unwrap();
+        engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), writer, date(2021, 1, 20), date(2024, 1, 20), dec!(2000)), &agents)
+            .unwrap();
+        engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), writer, date(2024, 2, 5), date(2024, 2, 5), dec!(1000)), &agents)
+            .unwrap();
+
+        let january = engine.statement(writer.id, date(2024, 1, 1), date(2024, 1, 31), Currency::USD);
+        assert_eq!(january.entries.len(), 2);
+        assert_eq!(january.first_year, usd(dec!(500)));
+        assert_eq!(january.renewal, usd(dec!(100)));
+        assert_eq!(january.overrides, usd(dec!(0)));
+        assert_eq!(january.net, usd(dec!(600)));
+
+        let manager = engine.statement(agents[1].id, date(2024, 1, 1), date(2024, 1, 31), Currency::USD);
+        assert_eq!(manager.overrides, usd(dec!(300)));
+        assert_eq!(manager.net, usd(dec!(300)));
+    }
+
+    #[test]
+    fn test_statements_for_all_agents() {
+        let mut ledger = Ledger::new(Currency::USD);
+        let (mut engine, _) = catalog_engine(&mut ledger);
+        let agents = hierarchy();
+        engine
+            .earn(&mut ledger, &receipt(PolicyId::new(), &agents[0], date(2024, 1, 10), date(2024, 1, 10), dec!(1000)), &agents)
+            .unwrap();
+
+        let statements = engine.statements(date(2024, 1, 1), date(2024, 1, 31), Currency::USD);
+        assert_eq!(statements.len(), 3);
+        assert!(engine.statements(date(2024, 2, 1), date(2024, 2, 29), Currency::USD).is_empty());
+    }
+}
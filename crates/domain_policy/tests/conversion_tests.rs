@@ -0,0 +1,275 @@
+//! Conversion Tests
+//!
+//! This module contains tests for the `ConversionService`, covering the
+//! conversion of term policies to whole life without new underwriting.
+//!
+//! # Test Coverage
+//!
+//! - Conversion rules loaded from the product catalog
+//! - Conversion window, maximum age, target product and sum assured limits
+//! - Attained-age pricing in the original risk class
+//! - Termination of the term policy, policy links and events
+//!
+//! # Test Organization
+//!
+//! - `rules` - Catalog parsing
+//! - `eligibility` - Conversion eligibility checks
+//! - `conversion` - Issuing the new policy and terminating the term policy
+
+use chrono::NaiveDate;
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::{Policy, PolicyBuilder, PolicyState, TerminationReason};
+use domain_policy::conversion::{ConversionRequest, ConversionService};
+use domain_policy::coverage::Coverage;
+use domain_policy::events::PolicyEvent;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::underwriting::{Gender, InsuredLife, RiskClass};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn service() -> ConversionService {
+    let catalog: serde_json::Value =
+        serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap();
+    ConversionService::from_catalog(&catalog).unwrap()
+}
+
+/// Issues a 20 year, 100,000 term policy rated Preferred on 2020-01-01
+fn term_policy() -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+        .premium(Premium::new(usd(dec!(25)), PremiumFrequency::Monthly))
+        .term_years(20)
+        .risk_class(RiskClass::Preferred)
+        .build()
+        .unwrap();
+    policy.issue(date(2020, 1, 1), "UW001").unwrap();
+    policy
+}
+
+fn request(conversion_date: NaiveDate, attained_age: u32) -> ConversionRequest {
+    ConversionRequest::new("WHOLE_LIFE_01", conversion_date, attained_age)
+}
+
+// ============================================================================
+// RULES TESTS
+// ============================================================================
+
+mod rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let service = service();
+
+        let rules = service.latest_rules_for("TERM_LIFE_01").unwrap();
+        assert_eq!(rules.target_products, vec!["WHOLE_LIFE_01".to_string()]);
+        assert_eq!(rules.conversion_period_years, 10);
+        assert_eq!(rules.max_conversion_age, 65);
+        assert_eq!(rules.max_sum_assured_percentage, dec!(1.00));
+
+        assert!(service.latest_rules_for("WHOLE_LIFE_01").is_none());
+    }
+
+    #[test]
+    fn test_deadline_is_end_of_conversion_period() {
+        let policy = term_policy();
+
+        assert_eq!(service().conversion_deadline(&policy).unwrap(), date(2029, 12, 31));
+    }
+}
+
+// ============================================================================
+// ELIGIBILITY TESTS
+// ============================================================================
+
+mod eligibility {
+    use super::*;
+
+    #[test]
+    fn test_within_window_is_eligible() {
+        let policy = term_policy();
+
+        assert!(service().check_eligibility(&policy, &request(date(2029, 12, 31), 45)).is_ok());
+    }
+
+    #[test]
+    fn test_after_window_rejected() {
+        let policy = term_policy();
+
+        let result = service().check_eligibility(&policy, &request(date(2030, 1, 1), 45));
+
+        assert!(matches!(result, Err(PolicyError::Conversion(_))));
+    }
+
+    #[test]
+    fn test_over_max_age_rejected() {
+        let policy = term_policy();
+
+        let result = service().check_eligibility(&policy, &request(date(2026, 1, 1), 66));
+
+        assert!(matches!(result, Err(PolicyError::Conversion(_))));
+    }
+
+    #[test]
+    fn test_unlisted_target_rejected() {
+        let policy = term_policy();
+        let request = ConversionRequest::new("ENDOWMENT_01", date(2026, 1, 1), 45);
+
+        let result = service().check_eligibility(&policy, &request);
+
+        assert!(matches!(result, Err(PolicyError::Conversion(_))));
+    }
+
+    #[test]
+    fn test_sum_assured_limits() {
+        let service = service();
+        let policy = term_policy();
+
+        let over = request(date(2026, 1, 1), 45).with_sum_assured(usd(dec!(100001)));
+        assert!(matches!(service.check_eligibility(&policy, &over), Err(PolicyError::Conversion(_))));
+
+        let under_minimum = request(date(2026, 1, 1), 45).with_sum_assured(usd(dec!(20000)));
+        assert!(matches!(
+            service.check_eligibility(&policy, &under_minimum),
+            Err(PolicyError::Conversion(_))
+        ));
+
+        let partial = request(date(2026, 1, 1), 45).with_sum_assured(usd(dec!(50000)));
+        assert!(service.check_eligibility(&policy, &partial).is_ok());
+    }
+
+    #[test]
+    fn test_policy_without_risk_class_rejected() {
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(25)), PremiumFrequency::Monthly))
+            .term_years(20)
+            .build()
+            .unwrap();
+        policy.issue(date(2020, 1, 1), "UW001").unwrap();
+
+        let result = service().check_eligibility(&policy, &request(date(2026, 1, 1), 45));
+
+        assert!(matches!(result, Err(PolicyError::Conversion(_))));
+    }
+
+    #[test]
+    fn test_terminated_policy_rejected() {
+        let mut policy = term_policy();
+        policy.terminate(TerminationReason::Surrender).unwrap();
+
+        let result = service().check_eligibility(&policy, &request(date(2026, 1, 1), 45));
+
+        assert!(matches!(result, Err(PolicyError::Conversion(_))));
+    }
+}
+
+// ============================================================================
+// CONVERSION TESTS
+// ============================================================================
+
+mod conversion {
+    use super::*;
+
+    #[test]
+    fn test_new_policy_issued_at_attained_age_in_original_class() {
+        let mut term = term_policy();
+
+        let outcome = service().convert(&mut term, &request(date(2026, 1, 1), 46)).unwrap();
+        let policy = &outcome.policy;
+
+        assert_eq!(policy.product_code(), "WHOLE_LIFE_01");
+        assert_eq!(policy.policyholder_id(), term.policyholder_id());
+        assert_eq!(policy.risk_class(), Some(RiskClass::Preferred));
+        assert_eq!(policy.inception_date(), Some(date(2026, 1, 1)));
+        assert_eq!(policy.coverages()[0].sum_assured, usd(dec!(100000)));
+        // 1.5 x (0.5 + 46 x 0.05) per thousand x 0.85 Preferred
+        assert_eq!(outcome.record.annual_premium, usd(dec!(357.00)));
+        assert_eq!(policy.premium().base_amount, usd(dec!(31.24)));
+        assert_eq!(policy.premium().frequency, PremiumFrequency::Monthly);
+    }
+
+    #[test]
+    fn test_insured_life_carried_to_new_policy() {
+        let insured = InsuredLife {
+            date_of_birth: date(1979, 6, 1),
+            gender: Gender::Female,
+            is_smoker: true,
+        };
+        let mut term = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(25)), PremiumFrequency::Monthly))
+            .term_years(20)
+            .risk_class(RiskClass::Preferred)
+            .insured(insured)
+            .build()
+            .unwrap();
+        term.issue(date(2020, 1, 1), "UW001").unwrap();
+        let mut unrecorded = term_policy();
+
+        // The request's age and smoker status are ignored for a recorded insured
+        let outcome = service().convert(&mut term, &request(date(2026, 1, 1), 30)).unwrap();
+        let expected = service()
+            .convert(&mut unrecorded, &request(date(2026, 1, 1), 46).smoker(true))
+            .unwrap();
+
+        assert_eq!(outcome.record.attained_age, 46);
+        assert_eq!(outcome.record.annual_premium, expected.record.annual_premium);
+        assert_eq!(outcome.policy.insured(), Some(&insured));
+    }
+
+    #[test]
+    fn test_term_policy_terminated_and_linked() {
+        let mut term = term_policy();
+        term.take_events();
+
+        let outcome = service().convert(&mut term, &request(date(2026, 1, 1), 46)).unwrap();
+
+        assert!(matches!(
+            term.state(),
+            PolicyState::Terminated { reason: TerminationReason::Conversion, .. }
+        ));
+        assert_eq!(term.converted_to(), Some(outcome.policy.id()));
+        assert_eq!(outcome.policy.converted_from(), Some(term.id()));
+        assert_eq!(outcome.record.original_policy_id, term.id());
+        assert_eq!(outcome.record.new_policy_id, outcome.policy.id());
+        assert_eq!(outcome.record.risk_class, RiskClass::Preferred);
+
+        let events = term.take_events();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            PolicyEvent::PolicyConverted { new_policy_id, conversion_date, .. }
+                if *new_policy_id == outcome.policy.id() && *conversion_date == date(2026, 1, 1)
+        )));
+    }
+
+    #[test]
+    fn test_converted_policy_cannot_be_converted_again() {
+        let service = service();
+        let mut term = term_policy();
+        service.convert(&mut term, &request(date(2026, 1, 1), 46)).unwrap();
+
+        let result = service.convert(&mut term, &request(date(2026, 2, 1), 46));
+
+        assert!(matches!(result, Err(PolicyError::Conversion(_))));
+    }
+}
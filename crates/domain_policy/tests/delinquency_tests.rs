@@ -0,0 +1,454 @@
+//! Delinquency Processing Tests
+//!
+//! This module contains tests for the `DelinquencyEngine`, covering the
+//! non-payment lifecycle of a policy.
+//!
+//! # Test Coverage
+//!
+//! - Lapse rules loaded from the product catalog
+//! - Overdue invoices starting the grace period
+//! - Automatic lapse once the grace period expires
+//! - Reinstatement quotes with arrears, interest and fee
+//! - Reinstatement gated on payment and evidence of insurability
+//!
+//! # Test Organization
+//!
+//! - `lapse_rules` - Catalog parsing and defaults
+//! - `grace_and_lapse` - Invoice processing through to lapse
+//! - `reinstatement` - Quotes, reinstatement invoices and reinstatement
+
+use chrono::{Duration, NaiveDate, Utc};
+use core_kernel::{Currency, Money, PartyId};
+use domain_billing::invoice::InvoiceItemType;
+use domain_billing::{Invoice, InvoiceItem, InvoiceStatus};
+use domain_policy::aggregate::{LapseReason, Policy, PolicyBuilder, PolicyState};
+use domain_policy::coverage::Coverage;
+use domain_policy::delinquency::{DelinquencyEngine, DelinquencyOutcome, LapseRules};
+use domain_policy::events::PolicyEvent;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::underwriting::{RiskClass, UnderwritingDecision};
+use domain_policy::PolicyError;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST FIXTURES
+// ============================================================================
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+fn test_rules() -> LapseRules {
+    LapseRules {
+        grace_period_days: 30,
+        reinstatement_period_days: 730,
+        reinstatement_interest_rate: dec!(0.0730),
+        reinstatement_fee: dec!(50),
+        evidence_required_after_days: Some(90),
+    }
+}
+
+fn create_engine() -> DelinquencyEngine {
+    DelinquencyEngine::new().with_product_rules("TERM_LIFE_01", test_rules())
+}
+
+fn create_in_force_policy() -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(Money::new(dec!(500000), Currency::USD)))
+        .premium(Premium::new(Money::new(dec!(100), Currency::USD), PremiumFrequency::Monthly))
+        .term_years(20)
+        .build()
+        .unwrap();
+    policy.issue(today() - Duration::days(365), "UW001").unwrap();
+    policy.take_events();
+    policy
+}
+
+fn create_premium_invoice(policy: &Policy, due_date: NaiveDate) -> Invoice {
+    let mut invoice = Invoice::new(policy.id(), policy.policyholder_id(), due_date, Currency::USD);
+    invoice.add_item(InvoiceItem::new(
+        "Monthly premium",
+        InvoiceItemType::Premium,
+        Money::new(dec!(100), Currency::USD),
+    ));
+    invoice.issue();
+    invoice
+}
+
+fn lapse_for_non_payment(policy: &mut Policy) {
+    policy
+        .lapse(
+            LapseReason::NonPayment {
+                grace_days_elapsed: 31,
+                outstanding_amount: dec!(100),
+            },
+            Some(730),
+        )
+        .unwrap();
+}
+
+fn paid(mut invoice: Invoice) -> Invoice {
+    let total = invoice.total;
+    invoice.record_payment(total);
+    invoice
+}
+
+// ============================================================================
+// LAPSE RULES TESTS
+// ============================================================================
+
+mod lapse_rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let catalog: serde_json::Value =
+            serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap();
+        let engine = DelinquencyEngine::from_catalog(&catalog).unwrap();
+
+        let rules = engine.latest_rules_for("CRITICAL_ILLNESS_01");
+        assert_eq!(rules.grace_period_days, 30);
+        assert_eq!(rules.reinstatement_fee, dec!(50));
+        assert_eq!(rules.evidence_required_after_days, Some(60));
+    }
+
+    #[test]
+    fn test_unknown_product_uses_defaults() {
+        let engine = create_engine();
+        assert_eq!(engine.latest_rules_for("UNKNOWN"), &LapseRules::default());
+    }
+
+    #[test]
+    fn test_malformed_rules_rejected() {
+        let product = serde_json::json!({ "code": "X", "lapse_rules": { "grace_period_days": "thirty" } });
+        assert!(LapseRules::from_catalog_product(&product).is_err());
+    }
+}
+
+// ============================================================================
+// GRACE PERIOD AND LAPSE TESTS
+// ============================================================================
+
+mod grace_and_lapse {
+    use super::*;
+
+    #[test]
+    fn test_invoice_not_yet_due_is_current() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut invoice = create_premium_invoice(&policy, today() + Duration::days(5));
+
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert_eq!(outcome, DelinquencyOutcome::Current);
+        assert_eq!(invoice.status, InvoiceStatus::Issued);
+    }
+
+    #[test]
+    fn test_grace_period_of_version_sold_applies() {
+        let mut catalog: serde_json::Value =
+            serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap();
+        let products = catalog["products"].as_array_mut().unwrap();
+        let mut revised = products.iter().find(|p| p["code"] == "TERM_LIFE_01").unwrap().clone();
+        revised["version"] = serde_json::json!("2.0.0");
+        revised["effective_date"] = serde_json::json!((today() - Duration::days(10)).to_string());
+        revised["lapse_rules"]["grace_period_days"] = serde_json::json!(90);
+        products.push(revised);
+        let engine = DelinquencyEngine::from_catalog(&catalog).unwrap();
+        let mut policy = create_in_force_policy();
+        let mut invoice = create_premium_invoice(&policy, today() - Duration::days(40));
+
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert!(matches!(outcome, DelinquencyOutcome::Lapsed { .. }));
+    }
+
+    #[test]
+    fn test_overdue_invoice_starts_grace_period() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let due_date = today() - Duration::days(3);
+        let mut invoice = create_premium_invoice(&policy, due_date);
+
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert_eq!(
+            outcome,
+            DelinquencyOutcome::GracePeriodStarted {
+                grace_end_date: due_date + Duration::days(30)
+            }
+        );
+        assert_eq!(invoice.status, InvoiceStatus::Overdue);
+        assert!(policy.is_in_force());
+
+        let events = policy.take_events();
+        assert!(events.iter().any(|e| matches!(e, PolicyEvent::PremiumOverdue { days_overdue: 3, .. })));
+        assert!(events.iter().any(|e| matches!(e, PolicyEvent::GracePeriodStarted { .. })));
+    }
+
+    #[test]
+    fn test_second_run_within_grace_reports_grace() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut invoice = create_premium_invoice(&policy, today() - Duration::days(10));
+
+        engine.process_invoice(&mut policy, &mut invoice, today() - Duration::days(5)).unwrap();
+        policy.take_events();
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert!(matches!(outcome, DelinquencyOutcome::InGracePeriod { days_overdue: 10, .. }));
+        assert!(policy.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_policy_lapses_when_grace_expires() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut invoice = create_premium_invoice(&policy, today() - Duration::days(31));
+
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert!(matches!(outcome, DelinquencyOutcome::Lapsed { grace_days_elapsed: 31, .. }));
+        match policy.state() {
+            PolicyState::Lapsed { reason, reinstatement_deadline, .. } => {
+                assert_eq!(
+                    reason,
+                    &LapseReason::NonPayment {
+                        grace_days_elapsed: 31,
+                        outstanding_amount: dec!(100),
+                    }
+                );
+                assert!(reinstatement_deadline.is_some());
+            }
+            other => panic!("Expected Lapsed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lapse_dated_on_processing_date() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let as_of = today() - Duration::days(5);
+        let mut invoice = create_premium_invoice(&policy, as_of - Duration::days(31));
+
+        engine.process_invoice(&mut policy, &mut invoice, as_of).unwrap();
+
+        match policy.state() {
+            PolicyState::Lapsed { effective_date, reinstatement_deadline, .. } => {
+                assert_eq!(effective_date.date_naive(), as_of);
+                assert_eq!(reinstatement_deadline.unwrap().date_naive(), as_of + Duration::days(730));
+            }
+            other => panic!("Expected Lapsed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_overdue_premiums_accumulate() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut first = create_premium_invoice(&policy, today() - Duration::days(20));
+        let mut second = create_premium_invoice(&policy, today() - Duration::days(3));
+
+        engine.process_invoice(&mut policy, &mut first, today()).unwrap();
+        engine.process_invoice(&mut policy, &mut second, today()).unwrap();
+
+        assert_eq!(policy.financial_state().premium_outstanding, Money::new(dec!(200), Currency::USD));
+    }
+
+    #[test]
+    fn test_paid_invoice_takes_no_action() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut invoice = paid(create_premium_invoice(&policy, today() - Duration::days(60)));
+
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert_eq!(outcome, DelinquencyOutcome::NoAction);
+        assert!(policy.is_in_force());
+    }
+
+    #[test]
+    fn test_invoice_for_other_policy_rejected() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let other = create_in_force_policy();
+        let mut invoice = create_premium_invoice(&other, today() - Duration::days(5));
+
+        assert!(engine.process_invoice(&mut policy, &mut invoice, today()).is_err());
+    }
+}
+
+// ============================================================================
+// REINSTATEMENT TESTS
+// ============================================================================
+
+mod reinstatement {
+    use super::*;
+
+    #[test]
+    fn test_quote_includes_arrears_interest_and_fee() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let arrears = vec![create_premium_invoice(&policy, today() - Duration::days(50))];
+        lapse_for_non_payment(&mut policy);
+
+        let quote = engine.quote_reinstatement(&policy, &arrears, today()).unwrap();
+
+        // 100 * 7.3% * 50 / 365 = 1.00
+        assert_eq!(quote.arrears.amount(), dec!(100));
+        assert_eq!(quote.interest.amount(), dec!(1.00));
+        assert_eq!(quote.fee.amount(), dec!(50));
+        assert_eq!(quote.total().amount(), dec!(151.00));
+        assert!(!quote.evidence_required);
+        assert_eq!(quote.invoice_ids, vec![arrears[0].id]);
+    }
+
+    #[test]
+    fn test_quote_requires_evidence_after_threshold() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        lapse_for_non_payment(&mut policy);
+
+        let quote = engine
+            .quote_reinstatement(&policy, &[], today() + Duration::days(91))
+            .unwrap();
+
+        assert!(quote.evidence_required);
+    }
+
+    #[test]
+    fn test_quote_rejected_after_reinstatement_period() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        lapse_for_non_payment(&mut policy);
+
+        let result = engine.quote_reinstatement(&policy, &[], today() + Duration::days(800));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_quote_rejected_for_in_force_policy() {
+        let engine = create_engine();
+        let policy = create_in_force_policy();
+
+        assert!(engine.quote_reinstatement(&policy, &[], today()).is_err());
+    }
+
+    #[test]
+    fn test_reinstatement_invoice_lines() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let arrears = vec![create_premium_invoice(&policy, today() - Duration::days(50))];
+        lapse_for_non_payment(&mut policy);
+        let quote = engine.quote_reinstatement(&policy, &arrears, today()).unwrap();
+
+        let invoice = engine.reinstatement_invoice(&policy, &quote, today() + Duration::days(14));
+
+        assert_eq!(invoice.status, InvoiceStatus::Issued);
+        assert_eq!(invoice.items.len(), 3);
+        assert!(invoice.items.iter().any(|i| i.item_type == InvoiceItemType::ReinstatementFee));
+        assert!(invoice.items.iter().any(|i| i.item_type == InvoiceItemType::Interest));
+        assert_eq!(invoice.total, quote.total());
+    }
+
+    #[test]
+    fn test_reinstate_after_payment() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut arrears = vec![create_premium_invoice(&policy, today() - Duration::days(50))];
+        lapse_for_non_payment(&mut policy);
+        let quote = engine.quote_reinstatement(&policy, &arrears, today()).unwrap();
+        let invoice = paid(engine.reinstatement_invoice(&policy, &quote, today()));
+
+        engine.reinstate(&mut policy, &quote, &invoice, &mut arrears, None, today()).unwrap();
+
+        assert!(matches!(policy.state(), PolicyState::Reinstated { .. }));
+        assert_eq!(arrears[0].status, InvoiceStatus::Paid);
+        assert_eq!(policy.financial_state().total_premium_paid.amount(), dec!(100));
+    }
+
+    #[test]
+    fn test_reinstate_rejected_after_deadline_on_processing_date() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        lapse_for_non_payment(&mut policy);
+        let quote = engine.quote_reinstatement(&policy, &[], today()).unwrap();
+        let invoice = paid(engine.reinstatement_invoice(&policy, &quote, today()));
+
+        let late = today() + Duration::days(800);
+        let result = engine.reinstate(&mut policy, &quote, &invoice, &mut [], None, late);
+
+        assert!(matches!(result, Err(PolicyError::ReinstatementPeriodExpired)));
+        assert!(matches!(policy.state(), PolicyState::Lapsed { .. }));
+
+        engine.reinstate(&mut policy, &quote, &invoice, &mut [], None, today()).unwrap();
+        let PolicyState::Reinstated { reinstatement_date, .. } = policy.state() else {
+            panic!("expected a reinstated policy");
+        };
+        assert_eq!(reinstatement_date.date_naive(), today());
+    }
+
+    #[test]
+    fn test_reinstate_rejected_when_unpaid() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        let mut arrears = vec![create_premium_invoice(&policy, today() - Duration::days(50))];
+        lapse_for_non_payment(&mut policy);
+        let quote = engine.quote_reinstatement(&policy, &arrears, today()).unwrap();
+        let invoice = engine.reinstatement_invoice(&policy, &quote, today());
+
+        let result = engine.reinstate(&mut policy, &quote, &invoice, &mut arrears, None, today());
+
+        assert!(result.is_err());
+        assert!(matches!(policy.state(), PolicyState::Lapsed { .. }));
+    }
+
+    #[test]
+    fn test_reinstate_requires_evidence_when_quoted() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        lapse_for_non_payment(&mut policy);
+        let mut quote = engine.quote_reinstatement(&policy, &[], today()).unwrap();
+        quote.evidence_required = true;
+        let invoice = paid(engine.reinstatement_invoice(&policy, &quote, today()));
+
+        assert!(engine.reinstate(&mut policy, &quote, &invoice, &mut [], None, today()).is_err());
+
+        let declined = UnderwritingDecision {
+            risk_class: RiskClass::Declined,
+            reasons: vec!["Adverse medical history".to_string()],
+            exclusions: vec![],
+            loading_percent: None,
+            flat_extra_per_mille: None,
+            coverage_modifications: vec![],
+            required_documents: vec![],
+            notes: None,
+            trace: None,
+            exposure: None,
+        };
+        assert!(engine.reinstate(&mut policy, &quote, &invoice, &mut [], Some(&declined), today()).is_err());
+
+        let accepted = UnderwritingDecision {
+            risk_class: RiskClass::Standard,
+            ..declined
+        };
+        engine.reinstate(&mut policy, &quote, &invoice, &mut [], Some(&accepted), today()).unwrap();
+        assert!(matches!(policy.state(), PolicyState::Reinstated { .. }));
+    }
+
+    #[test]
+    fn test_reinstated_policy_can_lapse_again() {
+        let engine = create_engine();
+        let mut policy = create_in_force_policy();
+        lapse_for_non_payment(&mut policy);
+        policy.reinstate().unwrap();
+        let mut invoice = create_premium_invoice(&policy, today() - Duration::days(40));
+
+        let outcome = engine.process_invoice(&mut policy, &mut invoice, today()).unwrap();
+
+        assert!(matches!(outcome, DelinquencyOutcome::Lapsed { .. }));
+    }
+}
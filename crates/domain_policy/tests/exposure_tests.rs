@@ -0,0 +1,353 @@
+//! Aggregate Exposure Tests
+//!
+//! This module contains tests for the aggregate exposure check, which
+//! totals the company's cover on a life before a new application is
+//! accepted.
+//!
+//! # Test Coverage
+//!
+//! - Sum at risk on in-force and pending policies
+//! - Matching duplicate party records for the same life
+//! - Per-life retention and income multiples by age band
+//! - Routing to facultative reinsurance or manual review
+//! - Referred cases opened for an underwriter instead of issued
+//!
+//! # Test Organization
+//!
+//! - `exposure` - Collecting the existing cover on a life
+//! - `limits` - Checking an application against the limits
+//! - `underwriting` - Exposure in underwriting decisions
+
+use chrono::{Datelike, NaiveDate, Utc};
+use core_kernel::{Currency, Money, PartyId, PolicyId};
+use domain_party::party::{Gender as PartyGender, Individual};
+use domain_party::Party;
+use domain_policy::aggregate::{Policy, PolicyBuilder, PolicyState};
+use domain_policy::coverage::{Coverage, CoverageType};
+use domain_policy::exposure::{
+    ExistingPolicy, ExposureRouting, ExposureRules, ExposureStatus, LifeExposure,
+};
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::services::UnderwritingService;
+use domain_policy::underwriting::{
+    AlcoholLevel, ApplicantInfo, FinancialInfo, Gender, InsurancePurpose, LifestyleInfo,
+    MedicalHistory, RiskClass, UnderwritingApplication,
+};
+use domain_policy::underwriting_case::{
+    CaseDecision, CaseRules, RequirementType, UnderwriterAuthority, UnderwritingCaseEngine,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn person(first_name: &str, tax_id: Option<&str>) -> Party {
+    Party::new_individual(Individual {
+        first_name: first_name.to_string(),
+        middle_name: None,
+        last_name: "Doe".to_string(),
+        date_of_birth: date(1984, 5, 1),
+        gender: Some(PartyGender::Female),
+        nationality: Some("US".to_string()),
+        tax_id: tax_id.map(str::to_string),
+        occupation: None,
+    })
+}
+
+fn quote(holder: PartyId, sum_assured: Decimal) -> Policy {
+    PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(holder)
+        .add_coverage(Coverage::death_benefit(usd(sum_assured)))
+        .premium(Premium::new(usd(dec!(100)), PremiumFrequency::Monthly))
+        .term_years(20)
+        .build()
+        .unwrap()
+}
+
+fn in_force(holder: PartyId, sum_assured: Decimal) -> Policy {
+    let mut policy = quote(holder, sum_assured);
+    policy.issue(date(2024, 1, 1), "UW001").unwrap();
+    policy
+}
+
+fn existing(sum_assured: Decimal, status: ExposureStatus) -> LifeExposure {
+    LifeExposure::new(vec![ExistingPolicy::new("POL-1", PartyId::new(), status, usd(sum_assured))])
+}
+
+/// Application from a 40-year-old for a death benefit
+fn application(sum_assured: Decimal, annual_income: Decimal, declared: Decimal) -> UnderwritingApplication {
+    let today = Utc::now().date_naive();
+    UnderwritingApplication {
+        applicant: ApplicantInfo {
+            date_of_birth: date(today.year() - 40, 1, 1),
+            gender: Gender::Female,
+            occupation: "Engineer".to_string(),
+            occupation_class: 1,
+            country: "US".to_string(),
+        },
+        medical_history: MedicalHistory {
+            height_cm: 165,
+            weight_kg: 60.0,
+            is_smoker: false,
+            is_former_smoker: false,
+            conditions: vec![],
+            family_history: vec![],
+        },
+        lifestyle: LifestyleInfo {
+            hazardous_sports: vec![],
+            aviation: None,
+            alcohol_consumption: AlcoholLevel::None,
+            travel_risk_countries: vec![],
+        },
+        financial: FinancialInfo {
+            annual_income,
+            net_worth: dec!(500000),
+            existing_coverage: declared,
+            purpose: InsurancePurpose::FamilyProtection,
+        },
+        coverages: vec![Coverage::death_benefit(usd(sum_assured))],
+    }
+}
+
+// ============================================================================
+// EXPOSURE TESTS
+// ============================================================================
+
+mod exposure {
+    use super::*;
+
+    #[test]
+    fn test_sum_at_risk_counts_death_cover_only() {
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(500000))))
+            .add_coverage(Coverage::new(CoverageType::WaiverOfPremium, usd(dec!(3000))))
+            .premium(Premium::new(usd(dec!(100)), PremiumFrequency::Monthly))
+            .build()
+            .unwrap();
+        policy.issue(date(2024, 1, 1), "UW001").unwrap();
+
+        let entry = ExistingPolicy::from_policy(&policy).unwrap();
+
+        assert_eq!(entry.status, ExposureStatus::InForce);
+        assert_eq!(entry.sum_at_risk, usd(dec!(500000)));
+    }
+
+    #[test]
+    fn test_pending_counted_and_quotes_ignored() {
+        let mut pending = quote(PartyId::new(), dec!(250000));
+        pending.submit_for_underwriting(vec![]).unwrap();
+
+        assert_eq!(ExistingPolicy::from_policy(&pending).unwrap().status, ExposureStatus::Pending);
+        assert!(ExistingPolicy::from_policy(&quote(PartyId::new(), dec!(250000))).is_none());
+    }
+
+    #[test]
+    fn test_duplicate_party_records_matched() {
+        let life = person("Jane", Some("123-45-6789"));
+        let duplicate = person("Janet", Some("123456789"));
+        let stranger = person("Jane", Some("987-65-4321"));
+        let policies = vec![
+            in_force(life.id, dec!(300000)),
+            in_force(duplicate.id, dec!(400000)),
+            in_force(stranger.id, dec!(1000000)),
+        ];
+
+        let exposure = LifeExposure::for_life(&life, &[duplicate, stranger], &policies, PolicyId::new());
+
+        assert_eq!(exposure.policies.len(), 2);
+        assert_eq!(exposure.total(Currency::USD), usd(dec!(700000)));
+    }
+
+    #[test]
+    fn test_policy_being_underwritten_excluded() {
+        let life = person("Jane", Some("123-45-6789"));
+        let mut pending = quote(life.id, dec!(500000));
+        pending.submit_for_underwriting(vec![]).unwrap();
+        let policies = vec![in_force(life.id, dec!(300000)), pending.clone()];
+
+        let exposure = LifeExposure::for_life(&life, &[], &policies, pending.id());
+
+        assert_eq!(exposure.policies.len(), 1);
+        assert_eq!(exposure.total_with_status(ExposureStatus::Pending, Currency::USD), usd(dec!(0)));
+    }
+}
+
+// ============================================================================
+// LIMIT TESTS
+// ============================================================================
+
+mod limits {
+    use super::*;
+
+    #[test]
+    fn test_income_multiple_by_age_band() {
+        let rules = ExposureRules::default();
+
+        assert_eq!(rules.income_multiple_for(35), Some(dec!(30)));
+        assert_eq!(rules.income_multiple_for(45), Some(dec!(20)));
+        assert_eq!(rules.income_multiple_for(70), Some(dec!(5)));
+    }
+
+    #[test]
+    fn test_within_limits_automatic() {
+        let check = ExposureRules::default().check(
+            &application(dec!(300000), dec!(100000), dec!(600000)),
+            &existing(dec!(600000), ExposureStatus::InForce),
+        );
+
+        assert_eq!(check.total, usd(dec!(900000)));
+        assert_eq!(check.routing, ExposureRouting::Automatic);
+        assert!(check.reasons.is_empty());
+    }
+
+    #[test]
+    fn test_over_retention_routed_to_facultative() {
+        let check = ExposureRules::default().check(
+            &application(dec!(500000), dec!(100000), dec!(600000)),
+            &existing(dec!(600000), ExposureStatus::Pending),
+        );
+
+        assert_eq!(check.pending, usd(dec!(600000)));
+        assert_eq!(
+            check.routing,
+            ExposureRouting::FacultativeReinsurance { excess: usd(dec!(100000)) }
+        );
+    }
+
+    #[test]
+    fn test_over_income_multiple_routed_to_manual_review() {
+        let check = ExposureRules::default().check(
+            &application(dec!(300000), dec!(30000), dec!(600000)),
+            &existing(dec!(600000), ExposureStatus::InForce),
+        );
+
+        assert_eq!(check.financial_limit, Some(usd(dec!(600000))));
+        assert_eq!(check.routing, ExposureRouting::ManualReview);
+    }
+
+    #[test]
+    fn test_undisclosed_in_house_cover_reported() {
+        let check = ExposureRules::default().check(
+            &application(dec!(300000), dec!(100000), dec!(0)),
+            &existing(dec!(600000), ExposureStatus::InForce),
+        );
+
+        assert!(check.reasons[0].contains("declared"));
+    }
+}
+
+// ============================================================================
+// UNDERWRITING TESTS
+// ============================================================================
+
+mod underwriting {
+    use super::*;
+
+    #[test]
+    fn test_decision_records_exposure_routing() {
+        let service = UnderwritingService::new();
+
+        let decision = service
+            .evaluate_with_exposure(
+                &application(dec!(500000), dec!(100000), dec!(600000)),
+                &existing(dec!(600000), ExposureStatus::InForce),
+                date(2024, 3, 1),
+            )
+            .unwrap();
+
+        let check = decision.exposure.unwrap();
+        assert!(matches!(check.routing, ExposureRouting::FacultativeReinsurance { .. }));
+        assert!(decision
+            .required_documents
+            .contains(&"Facultative Reinsurance Placement".to_string()));
+        let trace = decision.trace.unwrap();
+        assert!(trace.rule_results.iter().any(|r| r.rule_name == "aggregate_exposure" && !r.passed));
+    }
+
+    #[test]
+    fn test_custom_retention() {
+        let service = UnderwritingService::new().with_exposure_rules(ExposureRules {
+            per_life_retention: dec!(2000000),
+            ..ExposureRules::default()
+        });
+
+        let decision = service
+            .evaluate_with_exposure(
+                &application(dec!(500000), dec!(100000), dec!(600000)),
+                &existing(dec!(600000), ExposureStatus::InForce),
+                date(2024, 3, 1),
+            )
+            .unwrap();
+
+        assert_eq!(decision.exposure.unwrap().routing, ExposureRouting::Automatic);
+    }
+
+    #[test]
+    fn test_referred_case_cannot_be_approved_until_placed() {
+        let engine = UnderwritingCaseEngine::new(CaseRules {
+            authorities: vec![UnderwriterAuthority {
+                underwriter: "UW-CHIEF".to_string(),
+                max_sum_assured: dec!(5000000),
+                max_loading_percent: dec!(300),
+            }],
+            ..CaseRules::default()
+        });
+        let mut policy = quote(PartyId::new(), dec!(500000));
+        let decision = UnderwritingService::new()
+            .evaluate_with_exposure(
+                &application(dec!(500000), dec!(100000), dec!(600000)),
+                &existing(dec!(600000), ExposureStatus::InForce),
+                date(2024, 3, 1),
+            )
+            .unwrap();
+
+        let mut case = engine.refer(&mut policy, &decision, date(2024, 3, 1)).unwrap().unwrap();
+        let approve = CaseDecision::Approve {
+            risk_class: decision.risk_class,
+            effective_date: date(2024, 4, 1),
+        };
+
+        assert_eq!(case.requirements.len(), 1);
+        assert_eq!(case.requirements[0].requirement_type, RequirementType::FacultativePlacement);
+        assert!(matches!(policy.state(), PolicyState::PendingUnderwriting { .. }));
+        assert!(engine.decide(&mut case, &mut policy, "UW-CHIEF", approve.clone()).is_err());
+
+        case.receive(RequirementType::FacultativePlacement, date(2024, 3, 20)).unwrap();
+        case.satisfy(RequirementType::FacultativePlacement).unwrap();
+        engine.decide(&mut case, &mut policy, "UW-CHIEF", approve).unwrap();
+        assert!(matches!(policy.state(), PolicyState::InForce { .. }));
+        assert_ne!(policy.risk_class(), Some(RiskClass::Declined));
+    }
+
+    #[test]
+    fn test_automatic_case_not_referred() {
+        let mut policy = quote(PartyId::new(), dec!(500000));
+        let decision = UnderwritingService::new()
+            .evaluate_with_exposure(
+                &application(dec!(500000), dec!(100000), dec!(0)),
+                &LifeExposure::default(),
+                date(2024, 3, 1),
+            )
+            .unwrap();
+
+        let case = UnderwritingCaseEngine::new(CaseRules::default())
+            .refer(&mut policy, &decision, date(2024, 3, 1))
+            .unwrap();
+
+        assert!(case.is_none());
+        assert!(matches!(policy.state(), PolicyState::Quoted { .. }));
+    }
+}
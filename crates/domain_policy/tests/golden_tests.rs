@@ -0,0 +1,147 @@
+//! Golden-Case Harness Tests
+//!
+//! This module runs the golden cases shipped with the product rules and
+//! tests the harness that compares evaluation results with them.
+//!
+//! # Test Coverage
+//!
+//! - Shipped `*.cases.json` files pass against their rules files
+//! - Field diffs reported for mismatched outputs
+//! - Number and string normalisation when comparing
+//! - Evaluation errors reported per case
+//!
+//! # Test Organization
+//!
+//! - `shipped` - Golden cases next to the product rules
+//! - `comparison` - Diffing expected and actual results
+//! - `reporting` - Suite reports
+
+use std::path::PathBuf;
+
+use domain_policy::golden::{compare, GoldenRunner, GoldenSuite};
+use domain_policy::rules_engine::EvaluationResult;
+use rust_decimal_macros::dec;
+use serde_json::{json, Map, Value};
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn products_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../products")
+}
+
+fn expected(fields: Value) -> Map<String, Value> {
+    fields.as_object().unwrap().clone()
+}
+
+fn result() -> EvaluationResult {
+    let mut result = EvaluationResult {
+        eligible: true,
+        base_rate_per_thousand: Some(dec!(1.65)),
+        smoker_loading_percent: Some(dec!(100)),
+        ..Default::default()
+    };
+    result.additional.insert("smoker_class".to_string(), json!("\"smoker\""));
+    result
+}
+
+/// Writes a case file next to a copy of the term life rules
+fn case_dir(name: &str, suite: &GoldenSuite) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("golden-{}-{}", name, uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::copy(products_dir().join("term_life.json"), dir.join("term_life.json")).unwrap();
+    std::fs::write(dir.join("term_life.cases.json"), serde_json::to_string(suite).unwrap()).unwrap();
+    dir
+}
+
+// ============================================================================
+// SHIPPED CASE TESTS
+// ============================================================================
+
+mod shipped {
+    use super::*;
+
+    #[test]
+    fn test_shipped_golden_cases_pass() {
+        let reports = GoldenRunner::new().run_dir(&products_dir()).unwrap();
+
+        let products: Vec<_> = reports.iter().map(|r| r.product_code.as_str()).collect();
+        assert_eq!(products, vec!["CRITICAL_ILLNESS_01", "TERM_LIFE_01"]);
+        for report in &reports {
+            assert!(report.passed(), "{}", report);
+        }
+    }
+}
+
+// ============================================================================
+// COMPARISON TESTS
+// ============================================================================
+
+mod comparison {
+    use super::*;
+
+    #[test]
+    fn test_matching_fields_produce_no_diffs() {
+        let diffs = compare(
+            &expected(json!({ "eligible": true, "base_rate_per_thousand": 1.65, "smoker_class": "smoker" })),
+            &result(),
+        );
+
+        assert!(diffs.is_empty(), "{:?}", diffs);
+    }
+
+    #[test]
+    fn test_mismatched_field_reported() {
+        let diffs = compare(&expected(json!({ "smoker_loading_percent": 75 })), &result());
+
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].field, "smoker_loading_percent");
+        assert_eq!(diffs[0].expected, json!(75));
+        assert_eq!(diffs[0].actual, Some(json!("100")));
+    }
+
+    #[test]
+    fn test_missing_field_reported() {
+        let diffs = compare(&expected(json!({ "bmi_action": "accept" })), &result());
+
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].actual, None);
+    }
+}
+
+// ============================================================================
+// REPORTING TESTS
+// ============================================================================
+
+mod reporting {
+    use super::*;
+
+    #[test]
+    fn test_failing_case_in_report() {
+        let mut suite = GoldenSuite::from_file(&products_dir().join("term_life.cases.json")).unwrap();
+        suite.cases[0].expected.insert("base_rate_per_thousand".to_string(), json!(1.5));
+        let dir = case_dir("failing", &suite);
+
+        let report = GoldenRunner::new().run_file(&dir.join("term_life.cases.json")).unwrap();
+
+        assert!(!report.passed());
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].name, suite.cases[0].name);
+        assert!(report.to_string().contains("base_rate_per_thousand: expected 1.5, got \"1.65\""));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_rules_file_is_an_error() {
+        let mut suite = GoldenSuite::from_file(&products_dir().join("term_life.cases.json")).unwrap();
+        suite.rules_file = "missing.json".to_string();
+        let dir = case_dir("missing", &suite);
+
+        let result = GoldenRunner::new().run_dir(&dir);
+
+        assert!(result.is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
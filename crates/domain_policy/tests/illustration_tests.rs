@@ -0,0 +1,338 @@
+//! Benefit Illustration Tests
+//!
+//! This module contains tests for the `IllustrationEngine`, which projects
+//! quoted policies year by year for the illustration given at sale.
+//!
+//! # Test Coverage
+//!
+//! - Unit-linked charges loaded from the product catalog
+//! - Fund projection with allocation, admin, mortality and fund charges
+//! - One scenario per prescribed growth rate
+//! - Guaranteed and non-guaranteed whole life values
+//! - Attaching illustrations to quotes
+//!
+//! # Test Organization
+//!
+//! - `charges` - Catalog parsing
+//! - `unit_linked` - Unit-linked projections
+//! - `whole_life` - Whole life projections
+//! - `attachment` - Illustrations on the policy aggregate
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_policy::aggregate::{Policy, PolicyBuilder};
+use domain_policy::cash_value::{CashValueEngine, CashValueFactors, DividendAccounts, DividendOption};
+use domain_policy::coverage::Coverage;
+use domain_policy::endorsement::{Endorsement, EndorsementType};
+use domain_policy::illustration::{IllustrationEngine, IllustrationTable, UlipCharges, UlipScenario};
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::{PolicyError, PolicyEvent};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+fn engine() -> IllustrationEngine {
+    IllustrationEngine::from_catalog(&catalog()).unwrap()
+}
+
+fn cash_value_engine() -> CashValueEngine {
+    CashValueEngine::from_catalog(
+        &catalog(),
+        DividendAccounts {
+            dividend_expense: AccountId::new(),
+            dividends_payable: AccountId::new(),
+            dividend_accumulations: AccountId::new(),
+            paid_up_additions: AccountId::new(),
+            premium_receivable: AccountId::new(),
+        },
+    )
+    .unwrap()
+}
+
+/// Quotes a 10-year unit-linked plan with a 12,000 annual premium
+fn ulip_quote(sum_assured: Decimal) -> Policy {
+    PolicyBuilder::new()
+        .product_code("ULIP_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(sum_assured)))
+        .premium(Premium::new(usd(dec!(12000)), PremiumFrequency::Annual))
+        .term_years(10)
+        .build()
+        .unwrap()
+}
+
+/// Quotes a whole life plan with a 2,400 annual premium
+fn whole_life_quote() -> Policy {
+    PolicyBuilder::new()
+        .product_code("WHOLE_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+        .premium(Premium::new(usd(dec!(2400)), PremiumFrequency::Annual))
+        .term_years(99)
+        .build()
+        .unwrap()
+}
+
+/// Rates for issue age 35 paying annually: 4.0% cash value, 2.25% dividend
+fn factors() -> CashValueFactors {
+    CashValueFactors {
+        cash_value_rate: dec!(4.0),
+        dividend_rate: dec!(2.25),
+    }
+}
+
+fn scenarios(table: &IllustrationTable) -> &[UlipScenario] {
+    match table {
+        IllustrationTable::UnitLinked { scenarios } => scenarios,
+        IllustrationTable::WholeLife { .. } => panic!("expected a unit-linked table"),
+    }
+}
+
+// ============================================================================
+// CHARGES TESTS
+// ============================================================================
+
+mod charges {
+    use super::*;
+
+    #[test]
+    fn test_charges_loaded_from_catalog() {
+        let engine = engine();
+
+        let charges = engine.charges_for("ULIP_01").unwrap();
+        assert_eq!(charges.allocation_charge(1), dec!(0.06));
+        assert_eq!(charges.allocation_charge(9), dec!(0.02));
+        assert_eq!(charges.mortality_rate(35), Some(dec!(1.4)));
+        assert_eq!(charges.mortality_rate(85), Some(dec!(38)));
+        assert!(engine.charges_for("WHOLE_LIFE_01").is_none());
+        assert_eq!(engine.growth_rates(), &[dec!(0.04), dec!(0.08)]);
+    }
+
+    #[test]
+    fn test_malformed_charges_rejected() {
+        let product = json!({
+            "code": "ULIP_X",
+            "ulip_charges": { "premium_allocation_charges": "six percent" }
+        });
+
+        assert!(matches!(
+            UlipCharges::from_catalog_product(&product),
+            Err(PolicyError::Validation(_))
+        ));
+    }
+}
+
+// ============================================================================
+// UNIT-LINKED TESTS
+// ============================================================================
+
+mod unit_linked {
+    use super::*;
+
+    #[test]
+    fn test_first_year_charges_and_fund_value() {
+        let illustration = engine().illustrate_unit_linked(&ulip_quote(dec!(100000)), 35).unwrap();
+
+        let row = &scenarios(&illustration.table)[0].rows[0];
+        assert_eq!(row.attained_age, 35);
+        assert_eq!(row.allocation_charge, usd(dec!(720)));
+        assert_eq!(row.admin_charge, usd(dec!(60)));
+        // 1.4 per mille on 100,000 less the 11,220 allocated
+        assert_eq!(row.mortality_charge, usd(dec!(124.29)));
+        assert_eq!(row.fund_management_charge, usd(dec!(155.78)));
+        assert_eq!(row.fund_value, usd(dec!(11383.76)));
+        assert_eq!(row.death_benefit, usd(dec!(100000)));
+    }
+
+    #[test]
+    fn test_scenario_per_growth_rate_over_term() {
+        let illustration = engine().illustrate_unit_linked(&ulip_quote(dec!(100000)), 35).unwrap();
+
+        let scenarios = scenarios(&illustration.table);
+        assert_eq!(scenarios.len(), 2);
+        assert_eq!(illustration.years(), 10);
+        let low = scenarios[0].rows.last().unwrap();
+        let high = scenarios[1].rows.last().unwrap();
+        assert_eq!(low.cumulative_premium, usd(dec!(120000)));
+        assert!(high.fund_value.amount() > low.fund_value.amount());
+    }
+
+    #[test]
+    fn test_death_benefit_is_higher_of_fund_and_sum_assured() {
+        let illustration = engine().illustrate_unit_linked(&ulip_quote(dec!(20000)), 35).unwrap();
+
+        let last = scenarios(&illustration.table)[1].rows.last().unwrap();
+        assert_eq!(last.mortality_charge, usd(dec!(0)));
+        assert_eq!(last.death_benefit, last.fund_value);
+    }
+
+    #[test]
+    fn test_custom_growth_rates() {
+        let engine = engine().with_growth_rates(vec![dec!(0.0)]);
+
+        let illustration = engine.illustrate_unit_linked(&ulip_quote(dec!(100000)), 35).unwrap();
+
+        let scenarios = scenarios(&illustration.table);
+        assert_eq!(scenarios.len(), 1);
+        assert!(scenarios[0].rows.last().unwrap().fund_value.amount() < dec!(120000));
+    }
+
+    #[test]
+    fn test_product_without_charges_rejected() {
+        let result = engine().illustrate_unit_linked(&whole_life_quote(), 35);
+
+        assert!(matches!(result, Err(PolicyError::Illustration(_))));
+    }
+
+    #[test]
+    fn test_issued_policy_rejected() {
+        let mut policy = ulip_quote(dec!(100000));
+        policy.issue(date(2024, 1, 1), "UW001").unwrap();
+
+        let result = engine().illustrate_unit_linked(&policy, 35);
+
+        assert!(matches!(result, Err(PolicyError::Illustration(_))));
+    }
+}
+
+// ============================================================================
+// WHOLE LIFE TESTS
+// ============================================================================
+
+mod whole_life {
+    use super::*;
+
+    #[test]
+    fn test_projects_to_age_100() {
+        let illustration = engine()
+            .illustrate_whole_life(
+                &cash_value_engine(),
+                &whole_life_quote(),
+                35,
+                factors(),
+                DividendOption::PaidUpAdditions,
+            )
+            .unwrap();
+
+        let IllustrationTable::WholeLife { dividend_option, rows } = &illustration.table else {
+            panic!("expected a whole life table");
+        };
+        assert_eq!(*dividend_option, DividendOption::PaidUpAdditions);
+        assert_eq!(rows.len(), 65);
+        assert_eq!(rows[0].cumulative_premium, usd(dec!(2400)));
+        assert_eq!(rows.last().unwrap().attained_age, 100);
+    }
+
+    #[test]
+    fn test_non_guaranteed_values_include_dividends() {
+        let illustration = engine()
+            .illustrate_whole_life(
+                &cash_value_engine(),
+                &whole_life_quote(),
+                35,
+                factors(),
+                DividendOption::AccumulateAtInterest,
+            )
+            .unwrap();
+
+        let IllustrationTable::WholeLife { rows, .. } = &illustration.table else {
+            panic!("expected a whole life table");
+        };
+        let row = &rows[19];
+        assert_eq!(row.guaranteed_death_benefit, usd(dec!(100000)));
+        assert!(row.non_guaranteed_cash_value.amount() > row.guaranteed_cash_value.amount());
+        assert!(row.non_guaranteed_death_benefit.amount() > row.guaranteed_death_benefit.amount());
+    }
+
+    #[test]
+    fn test_product_without_cash_value_rejected() {
+        let result = engine().illustrate_whole_life(
+            &cash_value_engine(),
+            &ulip_quote(dec!(100000)),
+            35,
+            factors(),
+            DividendOption::Cash,
+        );
+
+        assert!(matches!(result, Err(PolicyError::CashValue(_))));
+    }
+}
+
+// ============================================================================
+// ATTACHMENT TESTS
+// ============================================================================
+
+mod attachment {
+    use super::*;
+
+    #[test]
+    fn test_illustration_attached_to_quote() {
+        let mut policy = ulip_quote(dec!(100000));
+        let illustration = engine().illustrate_unit_linked(&policy, 35).unwrap();
+        let illustration_id = illustration.id;
+
+        policy.attach_illustration(illustration).unwrap();
+
+        assert_eq!(policy.illustration().unwrap().id, illustration_id);
+        assert!(policy
+            .take_events()
+            .iter()
+            .any(|e| matches!(e, PolicyEvent::IllustrationAttached { .. })));
+
+        let restored: Policy = serde_json::from_str(&serde_json::to_string(&policy).unwrap()).unwrap();
+        assert_eq!(restored.illustration().unwrap().years(), 10);
+    }
+
+    #[test]
+    fn test_endorsing_quote_clears_illustration() {
+        let mut policy = ulip_quote(dec!(100000));
+        let illustration = engine().illustrate_unit_linked(&policy, 35).unwrap();
+        policy.attach_illustration(illustration).unwrap();
+
+        let premium = Premium::new(Money::new(dec!(2000), Currency::USD), PremiumFrequency::Annual);
+        policy
+            .apply_endorsement(Endorsement::new(
+                EndorsementType::PremiumChange { new_premium: premium },
+                date(2024, 1, 1),
+            ))
+            .unwrap();
+
+        assert!(policy.illustration().is_none());
+    }
+
+    #[test]
+    fn test_illustration_for_another_quote_rejected() {
+        let illustration = engine().illustrate_unit_linked(&ulip_quote(dec!(100000)), 35).unwrap();
+
+        let result = ulip_quote(dec!(100000)).attach_illustration(illustration);
+
+        assert!(matches!(result, Err(PolicyError::Illustration(_))));
+    }
+
+    #[test]
+    fn test_attach_after_issue_rejected() {
+        let mut policy = ulip_quote(dec!(100000));
+        let illustration = engine().illustrate_unit_linked(&policy, 35).unwrap();
+        policy.issue(date(2024, 1, 1), "UW001").unwrap();
+
+        let result = policy.attach_illustration(illustration);
+
+        assert!(matches!(result, Err(PolicyError::NotModifiable)));
+    }
+}
@@ -0,0 +1,223 @@
+//! Rule-Change Impact Simulation Tests
+//!
+//! This module contains tests for the `ImpactSimulator`, which compares
+//! stored business under the current and candidate product rules.
+//!
+//! # Test Coverage
+//!
+//! - Premium deltas from changed loadings
+//! - Eligibility flips from changed limits
+//! - Risk-class distribution before and after
+//! - Sampling and read-only evaluation of policies on their base coverage
+//!
+//! # Test Organization
+//!
+//! - `premiums` - Premium deltas
+//! - `eligibility` - Eligibility flips
+//! - `distribution` - Risk classes
+//! - `scope` - Sampling and policy cases
+
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::PolicyBuilder;
+use domain_policy::coverage::{Coverage, CoverageType};
+use domain_policy::impact::{ImpactCase, ImpactReport, ImpactSimulator, SimulationScope};
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::rules_engine::{ProductRules, RulesEngine};
+use domain_policy::underwriting::RiskClass;
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::{json, Value};
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn current_rules() -> ProductRules {
+    RulesEngine::new()
+        .load_rules_from_str(include_str!("../../../products/term_life.json"))
+        .unwrap()
+}
+
+/// Candidate term rules: maximum entry age lowered from 70 to 60 and the
+/// loading for smokers who quit 3-5 years ago raised from 25% to 50%
+fn candidate_rules() -> ProductRules {
+    let mut jdm = current_rules().jdm;
+    for node in jdm["nodes"].as_array_mut().unwrap() {
+        if node["id"] == "eligibility_check" {
+            node["content"]["rules"][1]["inputs"][0]["value"] = json!("> 60");
+        }
+        if node["id"] == "smoker_loading" {
+            node["content"]["rules"][3]["outputs"][0]["value"] = json!("50");
+        }
+    }
+    jdm["metadata"]["version"] = json!("1.1.0");
+    ProductRules::from_jdm(jdm).unwrap()
+}
+
+fn context(age: u32, former_smoker: bool) -> Value {
+    json!({
+        "applicant": { "age": age, "gender": "male", "occupation_class": 1 },
+        "medical": { "bmi": 24.0, "is_smoker": false, "is_former_smoker": former_smoker, "years_since_quit": 3 },
+        "coverage": { "sum_assured": 100000, "term_years": 10 }
+    })
+}
+
+/// A former smoker aged 35, a non-smoker aged 65 and an applicant aged 16
+fn book() -> Vec<ImpactCase> {
+    vec![
+        ImpactCase::new("TER-001", context(35, true), usd(dec!(100000))),
+        ImpactCase::new("TER-002", context(65, false), usd(dec!(100000))),
+        ImpactCase::new("TER-003", context(16, false), usd(dec!(100000))),
+    ]
+}
+
+fn simulate(cases: &[ImpactCase], scope: SimulationScope) -> ImpactReport {
+    ImpactSimulator::new()
+        .simulate(&current_rules(), &candidate_rules(), cases, scope)
+        .unwrap()
+}
+
+// ============================================================================
+// PREMIUM TESTS
+// ============================================================================
+
+mod premiums {
+    use super::*;
+
+    #[test]
+    fn test_loading_change_reported_as_premium_delta() {
+        let report = simulate(&book(), SimulationScope::Full);
+
+        let former_smoker = &report.cases[0];
+        assert_eq!(former_smoker.current.annual_premium, Some(usd(dec!(118.75))));
+        assert_eq!(former_smoker.candidate.annual_premium, Some(usd(dec!(142.50))));
+        assert_eq!(former_smoker.premium_delta(), Some(usd(dec!(23.75))));
+    }
+
+    #[test]
+    fn test_totals_cover_cases_eligible_under_both_versions() {
+        let report = simulate(&book(), SimulationScope::Full);
+
+        assert_eq!(report.premium_totals(), Some((usd(dec!(118.75)), usd(dec!(142.50)))));
+        assert_eq!(report.premium_change_percent(), Some(dec!(20.00)));
+        assert_eq!(report.current_version, "1.0.0");
+        assert_eq!(report.candidate_version, "1.1.0");
+        assert_ne!(report.current_revision, report.candidate_revision);
+    }
+}
+
+// ============================================================================
+// ELIGIBILITY TESTS
+// ============================================================================
+
+mod eligibility {
+    use super::*;
+
+    #[test]
+    fn test_lower_entry_age_flips_eligibility() {
+        let report = simulate(&book(), SimulationScope::Full);
+
+        let declined: Vec<_> = report.newly_ineligible().map(|c| c.reference.as_str()).collect();
+        assert_eq!(declined, vec!["TER-002"]);
+        assert_eq!(report.newly_eligible().count(), 0);
+        assert!(!report.cases[2].eligibility_flipped());
+    }
+
+    #[test]
+    fn test_candidate_for_other_product_rejected() {
+        let mut jdm = candidate_rules().jdm;
+        jdm["metadata"]["product_code"] = json!("WHOLE_LIFE_01");
+        let other = ProductRules::from_jdm(jdm).unwrap();
+
+        let result = ImpactSimulator::new().simulate(&current_rules(), &other, &book(), SimulationScope::Full);
+
+        assert!(matches!(result, Err(PolicyError::Simulation(_))));
+    }
+}
+
+// ============================================================================
+// DISTRIBUTION TESTS
+// ============================================================================
+
+mod distribution {
+    use super::*;
+
+    #[test]
+    fn test_risk_class_distribution_shift() {
+        let report = simulate(&book(), SimulationScope::Full);
+
+        assert_eq!(report.cases[0].current.risk_class, RiskClass::Standard);
+        assert_eq!(report.cases[0].candidate.risk_class, RiskClass::Substandard);
+
+        let current = report.current_distribution();
+        assert_eq!(current.get("Standard"), Some(&1));
+        assert_eq!(current.get("PreferredPlus"), Some(&1));
+        assert_eq!(current.get("Declined"), Some(&1));
+
+        let candidate = report.candidate_distribution();
+        assert_eq!(candidate.get("Substandard"), Some(&1));
+        assert_eq!(candidate.get("Declined"), Some(&2));
+    }
+}
+
+// ============================================================================
+// SCOPE TESTS
+// ============================================================================
+
+mod scope {
+    use super::*;
+
+    #[test]
+    fn test_sample_spread_across_book() {
+        let cases: Vec<_> = (0..10)
+            .map(|i| ImpactCase::new(format!("TER-{:03}", i), context(30 + i, false), usd(dec!(100000))))
+            .collect();
+
+        let report = simulate(&cases, SimulationScope::Sample(4));
+
+        let references: Vec<_> = report.cases.iter().map(|c| c.reference.as_str()).collect();
+        assert_eq!(references, vec!["TER-000", "TER-002", "TER-005", "TER-007"]);
+    }
+
+    #[test]
+    fn test_policy_case_leaves_policy_unchanged() {
+        let policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(118.75)), PremiumFrequency::Annual))
+            .term_years(10)
+            .build()
+            .unwrap();
+        let before = serde_json::to_value(&policy).unwrap();
+
+        let case = ImpactCase::from_policy(&policy, context(35, true)).unwrap();
+        let report = simulate(&[case], SimulationScope::Full);
+
+        assert_eq!(report.cases[0].reference, policy.policy_number());
+        assert_eq!(report.cases[0].current.annual_premium, Some(usd(dec!(118.75))));
+        assert_eq!(serde_json::to_value(&policy).unwrap(), before);
+    }
+
+    #[test]
+    fn test_policy_case_uses_base_coverage() {
+        let policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::new(CoverageType::AccidentalDeath, usd(dec!(50000))))
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(118.75)), PremiumFrequency::Annual))
+            .term_years(10)
+            .build()
+            .unwrap();
+
+        let case = ImpactCase::from_policy(&policy, context(35, true)).unwrap();
+
+        assert_eq!(case.sum_assured, usd(dec!(100000)));
+    }
+}
@@ -0,0 +1,334 @@
+//! Impairment Rating Manual Tests
+//!
+//! This module contains tests for the `ImpairmentManual`, which rates
+//! disclosed medical conditions with debits and credits.
+//!
+//! # Test Coverage
+//!
+//! - Matching entries by code, status, severity and time since diagnosis
+//! - Netting debits and credits into a table rating
+//! - Declines, flat extras and exclusions
+//! - Applying the assessment to coverages and underwriting decisions
+//!
+//! # Test Organization
+//!
+//! - `matching` - Finding the manual entry for a condition
+//! - `rating` - Table ratings and declines
+//! - `application` - Coverages, underwriting and rating
+
+use chrono::{Datelike, NaiveDate, Utc};
+use core_kernel::{Currency, Money};
+use domain_policy::coverage::{Coverage, ExclusionType};
+use domain_policy::impairment::ImpairmentManual;
+use domain_policy::services::{RatingService, UnderwritingService};
+use domain_policy::underwriting::{
+    AlcoholLevel, ApplicantInfo, ConditionStatus, FinancialInfo, Gender, InsurancePurpose,
+    LifestyleInfo, MedicalCondition, MedicalHistory, RiskClass, RuleImpact, Severity,
+    UnderwritingApplication,
+};
+use domain_policy::PolicyError;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn as_of() -> NaiveDate {
+    date(2024, 6, 1)
+}
+
+fn manual() -> ImpairmentManual {
+    ImpairmentManual::from_json(
+        r#"{
+            "name": "Test Impairment Manual",
+            "version": "2024.1",
+            "ratings": [
+                { "code": "E11", "name": "Type 2 diabetes, mild", "statuses": ["Controlled"], "severity": "mild", "debits": 25 },
+                { "code": "E11", "name": "Type 2 diabetes, controlled", "statuses": ["Controlled"], "debits": 50 },
+                { "code": "E11", "name": "Type 2 diabetes, uncontrolled", "decline": true },
+                { "code": "I10", "name": "Hypertension, controlled", "statuses": ["Controlled"], "debits": 25 },
+                { "code": "I10", "name": "Hypertension, active", "debits": 75 },
+                { "code": "K21", "name": "Reflux, resolved", "statuses": ["Resolved"], "debits": -15 },
+                { "code": "C50", "name": "Breast cancer, over 5 years", "min_years_since_diagnosis": 5,
+                  "flat_extra_per_mille": 5, "exclusion": "Recurrence of breast cancer" },
+                { "code": "C50", "name": "Breast cancer, under 5 years", "decline": true }
+            ]
+        }"#,
+    )
+    .unwrap()
+}
+
+fn condition(code: &str, status: ConditionStatus, diagnosed: Option<NaiveDate>) -> MedicalCondition {
+    MedicalCondition {
+        code: code.to_string(),
+        name: format!("Condition {}", code),
+        diagnosed_date: diagnosed,
+        status,
+        severity: None,
+        treatment: None,
+    }
+}
+
+fn application(conditions: Vec<MedicalCondition>) -> UnderwritingApplication {
+    let today = Utc::now().date_naive();
+    UnderwritingApplication {
+        applicant: ApplicantInfo {
+            date_of_birth: date(today.year() - 40, 1, 1),
+            gender: Gender::Female,
+            occupation: "Teacher".to_string(),
+            occupation_class: 1,
+            country: "US".to_string(),
+        },
+        medical_history: MedicalHistory {
+            height_cm: 165,
+            weight_kg: 60.0,
+            is_smoker: false,
+            is_former_smoker: false,
+            conditions,
+            family_history: vec![],
+        },
+        lifestyle: LifestyleInfo {
+            hazardous_sports: vec![],
+            aviation: None,
+            alcohol_consumption: AlcoholLevel::None,
+            travel_risk_countries: vec![],
+        },
+        financial: FinancialInfo {
+            annual_income: dec!(80000),
+            net_worth: dec!(300000),
+            existing_coverage: dec!(0),
+            purpose: InsurancePurpose::FamilyProtection,
+        },
+        coverages: vec![Coverage::death_benefit(Money::new(dec!(250000), Currency::USD))],
+    }
+}
+
+// ============================================================================
+// MATCHING TESTS
+// ============================================================================
+
+mod matching {
+    use super::*;
+
+    #[test]
+    fn test_code_prefix_and_status_select_entry() {
+        let manual = manual();
+
+        let controlled = condition("E11.9", ConditionStatus::Controlled, None);
+        let active = condition("E11.65", ConditionStatus::Active, None);
+
+        assert_eq!(manual.rating_for(&controlled, as_of()).unwrap().name, "Type 2 diabetes, controlled");
+        assert_eq!(manual.rating_for(&active, as_of()).unwrap().name, "Type 2 diabetes, uncontrolled");
+    }
+
+    #[test]
+    fn test_severity_selects_more_specific_entry() {
+        let mut mild = condition("E11", ConditionStatus::Controlled, None);
+        mild.severity = Some(Severity::Mild);
+
+        assert_eq!(manual().rating_for(&mild, as_of()).unwrap().debits, 25);
+    }
+
+    #[test]
+    fn test_time_since_diagnosis() {
+        let manual = manual();
+        let old = condition("C50.9", ConditionStatus::Remission, Some(date(2017, 3, 1)));
+        let recent = condition("C50.9", ConditionStatus::Remission, Some(date(2021, 3, 1)));
+        let undated = condition("C50.9", ConditionStatus::Remission, None);
+
+        assert_eq!(manual.rating_for(&old, as_of()).unwrap().name, "Breast cancer, over 5 years");
+        assert!(manual.rating_for(&recent, as_of()).unwrap().decline);
+        assert!(manual.rating_for(&undated, as_of()).unwrap().decline);
+    }
+
+    #[test]
+    fn test_unrated_condition_referred() {
+        let assessment = manual().assess(&[condition("J45", ConditionStatus::Controlled, None)], as_of());
+
+        assert_eq!(assessment.unrated, vec!["J45".to_string()]);
+        assert!(assessment.findings.is_empty());
+        let results = assessment.rule_results();
+        assert_eq!(results[0].impact, RuleImpact::Referral);
+    }
+}
+
+// ============================================================================
+// RATING TESTS
+// ============================================================================
+
+mod rating {
+    use super::*;
+
+    #[test]
+    fn test_debits_convert_to_table() {
+        let assessment = manual().assess(&[condition("E11", ConditionStatus::Controlled, None)], as_of());
+
+        assert_eq!(assessment.total_debits, 50);
+        assert_eq!(assessment.table, Some(2));
+        assert_eq!(assessment.loading_percent, dec!(50));
+        assert_eq!(assessment.risk_class(), RiskClass::TableRated(2));
+    }
+
+    #[test]
+    fn test_credits_offset_debits_and_round_to_nearest_table() {
+        let conditions = [
+            condition("E11", ConditionStatus::Controlled, None),
+            condition("K21", ConditionStatus::Resolved, None),
+        ];
+
+        let assessment = manual().assess(&conditions, as_of());
+
+        assert_eq!(assessment.total_debits, 35);
+        assert_eq!(assessment.table, Some(1));
+        assert_eq!(assessment.loading_percent, dec!(25));
+    }
+
+    #[test]
+    fn test_net_credits_rate_standard() {
+        let assessment = manual().assess(&[condition("K21", ConditionStatus::Resolved, None)], as_of());
+
+        assert_eq!(assessment.total_debits, 0);
+        assert_eq!(assessment.table, None);
+        assert_eq!(assessment.risk_class(), RiskClass::Standard);
+    }
+
+    #[test]
+    fn test_rating_beyond_max_table_declined() {
+        let mut manual = manual();
+        manual.max_table = 4;
+        let conditions = [
+            condition("E11", ConditionStatus::Controlled, None),
+            condition("I10", ConditionStatus::Active, None),
+        ];
+
+        let assessment = manual.assess(&conditions, as_of());
+
+        assert_eq!(assessment.total_debits, 125);
+        assert!(assessment.declined);
+        assert_eq!(assessment.risk_class(), RiskClass::Declined);
+    }
+
+    #[test]
+    fn test_invalid_manual_rejected() {
+        let result = ImpairmentManual::from_json(r#"{ "name": "Bad", "version": "1", "debits_per_table": 0, "ratings": [] }"#);
+
+        assert!(matches!(result, Err(PolicyError::Impairment(_))));
+    }
+}
+
+// ============================================================================
+// APPLICATION TESTS
+// ============================================================================
+
+mod application {
+    use super::*;
+
+    #[test]
+    fn test_assessment_applied_to_coverage() {
+        let conditions = [
+            condition("E11", ConditionStatus::Controlled, None),
+            condition("C50.9", ConditionStatus::Remission, Some(date(2017, 3, 1))),
+        ];
+        let assessment = manual().assess(&conditions, as_of());
+
+        let coverage = assessment.apply_to(Coverage::death_benefit(Money::new(dec!(250000), Currency::USD)));
+
+        assert_eq!(coverage.loading_percent, None);
+        assert_eq!(coverage.flat_extra_per_mille, Some(dec!(5)));
+        assert_eq!(coverage.exclusions.len(), 1);
+        assert_eq!(coverage.exclusions[0].code, "C50.9");
+        assert_eq!(coverage.exclusions[0].exclusion_type, ExclusionType::Underwriting);
+    }
+
+    #[test]
+    fn test_underwriting_service_rates_conditions() {
+        let service = UnderwritingService::new().with_impairment_manual(manual());
+        let application = application(vec![
+            condition("E11", ConditionStatus::Controlled, None),
+            condition("C50.9", ConditionStatus::Remission, Some(date(2010, 3, 1))),
+        ]);
+
+        let decision = service.evaluate_on(&application, as_of()).unwrap();
+
+        assert_eq!(decision.risk_class, RiskClass::TableRated(2));
+        assert_eq!(decision.loading_percent, None);
+        assert_eq!(decision.flat_extra_per_mille, Some(dec!(5)));
+        assert_eq!(decision.exclusions[0].description, "Recurrence of breast cancer");
+        let trace = decision.trace.unwrap();
+        assert!(trace.rule_results.iter().any(|r| r.rule_name == "impairment_E11"));
+    }
+
+    #[test]
+    fn test_time_since_diagnosis_measured_to_application_date() {
+        let service = UnderwritingService::new().with_impairment_manual(manual());
+        let application = application(vec![condition(
+            "C50.9",
+            ConditionStatus::Remission,
+            Some(date(2017, 3, 1)),
+        )]);
+
+        let at_application = service.evaluate_on(&application, date(2021, 6, 1)).unwrap();
+        let later = service.evaluate_on(&application, date(2024, 6, 1)).unwrap();
+
+        assert_eq!(at_application.risk_class, RiskClass::Declined);
+        assert_ne!(later.risk_class, RiskClass::Declined);
+    }
+
+    #[test]
+    fn test_table_rating_priced_once() {
+        let service = UnderwritingService::new().with_impairment_manual(manual());
+        let application = application(vec![condition("E11", ConditionStatus::Controlled, None)]);
+        let decision = service.evaluate_on(&application, as_of()).unwrap();
+        let assessment = manual().assess(&application.medical_history.conditions, as_of());
+        let standard = Coverage::death_benefit(Money::new(dec!(100000), Currency::USD));
+        let rated = assessment.apply_to(standard.clone());
+
+        let rating = RatingService::new();
+        let base = rating
+            .calculate_premium(&[standard], 40, false, RiskClass::Standard, Currency::USD)
+            .unwrap();
+        let table_2 = rating
+            .calculate_premium(&[rated], 40, false, decision.risk_class, Currency::USD)
+            .unwrap();
+
+        // Table 2 is +50% mortality: 1.5x the standard premium, not 2.25x
+        assert_eq!(decision.risk_class, RiskClass::TableRated(2));
+        assert_eq!(
+            table_2.total_per_payment().amount(),
+            base.total_per_payment().amount() * dec!(1.5)
+        );
+    }
+
+    #[test]
+    fn test_underwriting_service_declines_uninsurable_condition() {
+        let service = UnderwritingService::new().with_impairment_manual(manual());
+        let application = application(vec![condition("E11", ConditionStatus::Active, None)]);
+
+        let decision = service.evaluate(&application).unwrap();
+
+        assert_eq!(decision.risk_class, RiskClass::Declined);
+    }
+
+    #[test]
+    fn test_flat_extra_added_to_premium() {
+        let service = RatingService::new();
+        let standard = Coverage::death_benefit(Money::new(dec!(100000), Currency::USD));
+        let rated = standard.clone().with_flat_extra(dec!(5));
+
+        let base = service
+            .calculate_premium(&[standard], 40, false, RiskClass::Standard, Currency::USD)
+            .unwrap();
+        let extra = service
+            .calculate_premium(&[rated], 40, false, RiskClass::Standard, Currency::USD)
+            .unwrap();
+
+        assert_eq!(
+            extra.total_per_payment().amount() - base.total_per_payment().amount(),
+            dec!(500)
+        );
+    }
+}
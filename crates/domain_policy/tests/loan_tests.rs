@@ -0,0 +1,468 @@
+//! Policy Loan Tests
+//!
+//! This module contains tests for the `LoanEngine`, covering loans secured
+//! on the cash value of permanent life policies.
+//!
+//! # Test Coverage
+//!
+//! - Loan rules loaded from the product catalog
+//! - Loan limits, minimums and ledger postings for advances
+//! - Daily interest accrual and capitalisation at the policy anniversary
+//! - Repayments allocated to interest before principal
+//! - Automatic premium loans at the end of the grace period
+//! - Lapse when the loan balance exceeds the cash value
+//!
+//! # Test Organization
+//!
+//! - `loan_rules` - Catalog parsing
+//! - `advances` - Loan limits and advances
+//! - `interest` - Accrual, capitalisation and loan lapse
+//! - `repayments` - Repayment allocation
+//! - `automatic_premium_loans` - Premiums paid by loan
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_billing::account::{Account, AccountType};
+use domain_billing::{Invoice, InvoiceItem, InvoiceStatus, Ledger};
+use domain_billing::invoice::InvoiceItemType;
+use domain_policy::aggregate::{LapseReason, Policy, PolicyBuilder, PolicyState};
+use domain_policy::coverage::Coverage;
+use domain_policy::delinquency::DelinquencyEngine;
+use domain_policy::endorsement::{Endorsement, EndorsementType};
+use domain_policy::events::PolicyEvent;
+use domain_policy::loan::{LoanAccounts, LoanEngine, LoanTransactionType, PolicyLoan};
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+struct Books {
+    ledger: Ledger,
+    accounts: LoanAccounts,
+}
+
+fn books() -> Books {
+    let mut ledger = Ledger::new(Currency::USD);
+    let accounts = LoanAccounts {
+        cash: AccountId::new(),
+        loan_receivable: AccountId::new(),
+        interest_receivable: AccountId::new(),
+        interest_income: AccountId::new(),
+        premium_receivable: AccountId::new(),
+        cash_value: AccountId::new(),
+    };
+    for (id, code, name, account_type) in [
+        (accounts.cash, "1000", "Cash", AccountType::Asset),
+        (accounts.loan_receivable, "1400", "Policy Loans", AccountType::Asset),
+        (accounts.interest_receivable, "1410", "Policy Loan Interest Receivable", AccountType::Asset),
+        (accounts.interest_income, "4400", "Policy Loan Interest", AccountType::Revenue),
+        (accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset),
+        (accounts.cash_value, "2300", "Policy Cash Values", AccountType::Liability),
+    ] {
+        ledger.add_account(Account::new(id, code, name, account_type)).unwrap();
+    }
+    Books { ledger, accounts }
+}
+
+fn engine(books: &Books) -> LoanEngine {
+    LoanEngine::from_catalog(&catalog(), books.accounts).unwrap()
+}
+
+/// Issues a whole life policy on 1 January 2024 with the given cash value
+fn policy_with_cash_value(cash_value: Decimal) -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("WHOLE_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(250000))))
+        .premium(Premium::new(usd(dec!(250)), PremiumFrequency::Monthly))
+        .term_years(99)
+        .build()
+        .unwrap();
+    policy.issue(date(2024, 1, 1), "UW001").unwrap();
+    policy.record_cash_value(usd(cash_value)).unwrap();
+    policy
+}
+
+fn premium_invoice(policy: &Policy, due_date: NaiveDate) -> Invoice {
+    let mut invoice = Invoice::new(policy.id(), policy.policyholder_id(), due_date, Currency::USD);
+    invoice.add_item(InvoiceItem::new(
+        "Monthly premium",
+        InvoiceItemType::Premium,
+        usd(dec!(250)),
+    ));
+    invoice.issue();
+    invoice
+}
+
+/// Advances a loan on 1 January 2025 and returns the loan account
+fn loan_of(engine: &LoanEngine, books: &mut Books, policy: &mut Policy, amount: Decimal) -> PolicyLoan {
+    let mut loan = engine.open(policy, date(2025, 1, 1)).unwrap();
+    engine
+        .advance(&mut books.ledger, policy, &mut loan, usd(amount), date(2025, 1, 1))
+        .unwrap();
+    loan
+}
+
+// ============================================================================
+// LOAN RULES TESTS
+// ============================================================================
+
+mod loan_rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let books = books();
+        let engine = engine(&books);
+
+        let whole_life = engine.latest_rules_for("WHOLE_LIFE_01").unwrap();
+        assert_eq!(whole_life.max_loan_percentage, dec!(0.9));
+        assert_eq!(whole_life.interest_rate, dec!(0.06));
+        assert_eq!(whole_life.minimum_loan, dec!(500));
+        assert!(whole_life.automatic_premium_loan);
+
+        let endowment = engine.latest_rules_for("ENDOWMENT_01").unwrap();
+        assert!(!endowment.automatic_premium_loan);
+    }
+
+    #[test]
+    fn test_products_without_loan_rules_do_not_allow_loans() {
+        let books = books();
+        let engine = engine(&books);
+
+        assert!(engine.latest_rules_for("TERM_LIFE_01").is_none());
+
+        let policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(250000))))
+            .premium(Premium::new(usd(dec!(50)), PremiumFrequency::Monthly))
+            .term_years(20)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            engine.open(&policy, date(2025, 1, 1)),
+            Err(PolicyError::PolicyLoan(_))
+        ));
+    }
+}
+
+// ============================================================================
+// ADVANCE TESTS
+// ============================================================================
+
+mod advances {
+    use super::*;
+
+    #[test]
+    fn test_max_loan_is_percentage_of_cash_value() {
+        let books = books();
+        let engine = engine(&books);
+        let policy = policy_with_cash_value(dec!(20000));
+
+        assert_eq!(engine.max_loan(&policy).unwrap(), usd(dec!(18000)));
+    }
+
+    #[test]
+    fn test_advance_posts_loan_receivable_and_updates_policy() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+
+        let loan = loan_of(&engine, &mut books, &mut policy, dec!(5000));
+
+        assert_eq!(loan.principal, usd(dec!(5000)));
+        assert_eq!(loan.transactions[0].transaction_type, LoanTransactionType::Advance);
+        assert_eq!(policy.financial_state().loan_outstanding, Some(usd(dec!(5000))));
+        assert_eq!(books.ledger.get_balance(&books.accounts.loan_receivable), Some(usd(dec!(5000))));
+        assert_eq!(engine.available(&policy, &loan).unwrap(), usd(dec!(13000)));
+        assert!(policy
+            .take_events()
+            .iter()
+            .any(|e| matches!(e, PolicyEvent::PolicyLoanTaken { amount, .. } if *amount == dec!(5000))));
+    }
+
+    #[test]
+    fn test_advance_above_limit_is_rejected() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = engine.open(&policy, date(2025, 1, 1)).unwrap();
+
+        let result = engine.advance(&mut books.ledger, &mut policy, &mut loan, usd(dec!(18001)), date(2025, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::PolicyLoan(_))));
+        assert!(policy.financial_state().loan_outstanding.is_none());
+        assert!(books.ledger.entries().is_empty());
+    }
+
+    #[test]
+    fn test_advance_below_minimum_is_rejected() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = engine.open(&policy, date(2025, 1, 1)).unwrap();
+
+        let result = engine.advance(&mut books.ledger, &mut policy, &mut loan, usd(dec!(100)), date(2025, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::PolicyLoan(_))));
+    }
+
+    #[test]
+    fn test_advance_requires_cash_value() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = engine.open(&policy, date(2025, 1, 1)).unwrap();
+        let mut other = PolicyBuilder::new()
+            .product_code("WHOLE_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(250000))))
+            .premium(Premium::new(usd(dec!(250)), PremiumFrequency::Monthly))
+            .term_years(99)
+            .build()
+            .unwrap();
+        other.issue(date(2024, 1, 1), "UW001").unwrap();
+        let mut other_loan = engine.open(&other, date(2025, 1, 1)).unwrap();
+
+        assert!(engine.max_loan(&other).is_err());
+        assert!(engine
+            .advance(&mut books.ledger, &mut other, &mut other_loan, usd(dec!(1000)), date(2025, 1, 1))
+            .is_err());
+        // A loan account cannot be used against another policy
+        assert!(engine
+            .advance(&mut books.ledger, &mut other, &mut loan, usd(dec!(1000)), date(2025, 1, 1))
+            .is_err());
+        assert!(engine
+            .advance(&mut books.ledger, &mut policy, &mut loan, usd(dec!(1000)), date(2025, 1, 1))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_over_limit_loan_endorsement_rejected() {
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let endorsement = Endorsement::new(
+            EndorsementType::PolicyLoan {
+                amount: dec!(50000),
+                currency: "USD".to_string(),
+            },
+            date(2025, 1, 1),
+        );
+
+        let result = policy.apply_endorsement(endorsement);
+
+        assert!(matches!(result, Err(PolicyError::PolicyLoan(_))));
+        assert_eq!(policy.financial_state().loan_outstanding, None);
+    }
+}
+
+// ============================================================================
+// INTEREST TESTS
+// ============================================================================
+
+mod interest {
+    use super::*;
+
+    #[test]
+    fn test_interest_accrues_daily() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = loan_of(&engine, &mut books, &mut policy, dec!(10000));
+
+        // 31 days at 6% on 10,000 = 50.958...
+        let accrual = engine
+            .accrue(&mut books.ledger, &mut policy, &mut loan, date(2025, 2, 1))
+            .unwrap();
+
+        assert_eq!(accrual.interest, usd(dec!(50.96)));
+        assert!(accrual.capitalised.is_zero());
+        assert!(!accrual.lapsed);
+        assert_eq!(loan.accrued_interest, usd(dec!(50.96)));
+        assert_eq!(books.ledger.get_balance(&books.accounts.interest_receivable), Some(usd(dec!(50.96))));
+    }
+
+    #[test]
+    fn test_interest_capitalised_at_anniversary() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = loan_of(&engine, &mut books, &mut policy, dec!(10000));
+
+        let accrual = engine
+            .accrue(&mut books.ledger, &mut policy, &mut loan, date(2026, 1, 31))
+            .unwrap();
+
+        // A full year to the anniversary is capitalised, then 30 days accrue on 10,600
+        assert_eq!(accrual.capitalised, usd(dec!(600)));
+        assert_eq!(loan.principal, usd(dec!(10600)));
+        assert_eq!(loan.accrued_interest, usd(dec!(52.27)));
+        assert_eq!(policy.financial_state().loan_outstanding, Some(usd(dec!(10600))));
+        assert_eq!(books.ledger.get_balance(&books.accounts.loan_receivable), Some(usd(dec!(10600))));
+        assert!(loan
+            .transactions
+            .iter()
+            .any(|t| t.transaction_type == LoanTransactionType::InterestCapitalised && t.date == date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_policy_lapses_when_loan_exceeds_cash_value() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = loan_of(&engine, &mut books, &mut policy, dec!(17500));
+
+        policy.record_cash_value(usd(dec!(17600))).unwrap();
+        let accrual = engine
+            .accrue(&mut books.ledger, &mut policy, &mut loan, date(2025, 3, 1))
+            .unwrap();
+
+        assert!(accrual.lapsed);
+        match policy.state() {
+            PolicyState::Lapsed { reason: LapseReason::LoanExceedsCashValue { cash_value, .. }, .. } => {
+                assert_eq!(*cash_value, dec!(17600));
+            }
+            other => panic!("expected loan lapse, got {:?}", other),
+        }
+    }
+}
+
+// ============================================================================
+// REPAYMENT TESTS
+// ============================================================================
+
+mod repayments {
+    use super::*;
+
+    #[test]
+    fn test_repayment_settles_interest_before_principal() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = loan_of(&engine, &mut books, &mut policy, dec!(10000));
+
+        engine
+            .repay(&mut books.ledger, &mut policy, &mut loan, usd(dec!(1050.96)), date(2025, 2, 1))
+            .unwrap();
+
+        assert!(loan.accrued_interest.is_zero());
+        assert_eq!(loan.principal, usd(dec!(9000)));
+        assert_eq!(policy.financial_state().loan_outstanding, Some(usd(dec!(9000))));
+        assert_eq!(books.ledger.get_balance(&books.accounts.interest_receivable), Some(usd(dec!(0))));
+        assert!(policy
+            .take_events()
+            .iter()
+            .any(|e| matches!(e, PolicyEvent::PolicyLoanRepaid { amount, .. } if *amount == dec!(1000))));
+    }
+
+    #[test]
+    fn test_full_repayment_clears_loan() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = loan_of(&engine, &mut books, &mut policy, dec!(10000));
+
+        let balance = usd(dec!(10050.96));
+        engine
+            .repay(&mut books.ledger, &mut policy, &mut loan, balance, date(2025, 2, 1))
+            .unwrap();
+
+        assert!(loan.balance().is_zero());
+        assert!(policy.financial_state().loan_outstanding.is_none());
+    }
+
+    #[test]
+    fn test_overpayment_is_rejected() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = loan_of(&engine, &mut books, &mut policy, dec!(10000));
+
+        let result = engine.repay(&mut books.ledger, &mut policy, &mut loan, usd(dec!(20000)), date(2025, 2, 1));
+
+        assert!(matches!(result, Err(PolicyError::PolicyLoan(_))));
+        assert_eq!(loan.principal, usd(dec!(10000)));
+    }
+}
+
+// ============================================================================
+// AUTOMATIC PREMIUM LOAN TESTS
+// ============================================================================
+
+mod automatic_premium_loans {
+    use super::*;
+
+    #[test]
+    fn test_unpaid_premium_paid_by_loan_after_grace() {
+        let mut books = books();
+        let engine = engine(&books);
+        let delinquency = DelinquencyEngine::from_catalog(&catalog()).unwrap();
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = engine.open(&policy, date(2025, 1, 1)).unwrap();
+        let mut invoice = premium_invoice(&policy, date(2025, 3, 1));
+        let grace_end = delinquency.grace_end_date(&policy, invoice.due_date);
+        let as_of = grace_end.succ_opt().unwrap();
+
+        let entry = engine
+            .automatic_premium_loan(&mut books.ledger, &mut policy, &mut loan, &mut invoice, grace_end, as_of)
+            .unwrap();
+
+        assert!(entry.is_some());
+        assert_eq!(invoice.status, InvoiceStatus::Paid);
+        assert_eq!(loan.principal, usd(dec!(250)));
+        assert_eq!(loan.transactions[0].transaction_type, LoanTransactionType::AutomaticPremiumLoan);
+        assert_eq!(policy.financial_state().total_premium_paid, usd(dec!(250)));
+
+        // Delinquency processing now leaves the policy in force
+        delinquency.process_invoice(&mut policy, &mut invoice, as_of).unwrap();
+        assert!(matches!(policy.state(), PolicyState::InForce { .. }));
+    }
+
+    #[test]
+    fn test_no_loan_within_grace_period() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(20000));
+        let mut loan = engine.open(&policy, date(2025, 1, 1)).unwrap();
+        let mut invoice = premium_invoice(&policy, date(2025, 3, 1));
+
+        let entry = engine
+            .automatic_premium_loan(&mut books.ledger, &mut policy, &mut loan, &mut invoice, date(2025, 4, 1), date(2025, 3, 15))
+            .unwrap();
+
+        assert!(entry.is_none());
+        assert!(loan.principal.is_zero());
+    }
+
+    #[test]
+    fn test_no_loan_when_cash_value_insufficient() {
+        let mut books = books();
+        let engine = engine(&books);
+        let mut policy = policy_with_cash_value(dec!(200));
+        let mut loan = engine.open(&policy, date(2025, 1, 1)).unwrap();
+        let mut invoice = premium_invoice(&policy, date(2025, 3, 1));
+
+        let entry = engine
+            .automatic_premium_loan(&mut books.ledger, &mut policy, &mut loan, &mut invoice, date(2025, 4, 1), date(2025, 4, 2))
+            .unwrap();
+
+        assert!(entry.is_none());
+        assert_ne!(invoice.status, InvoiceStatus::Paid);
+    }
+}
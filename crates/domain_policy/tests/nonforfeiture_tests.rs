@@ -0,0 +1,474 @@
+//! Nonforfeiture Tests
+//!
+//! This module contains tests for the `NonforfeitureEngine`, covering the
+//! conversion of whole life policies to paid-up cover when premiums stop.
+//!
+//! # Test Coverage
+//!
+//! - Nonforfeiture rules loaded from the product catalog, by the product
+//!   version a policy was sold under
+//! - Reduced paid-up sum assured from the net cash value
+//! - Extended term period from the net cash value
+//! - Loan offset through the `LoanEngine`, coverage changes, state
+//!   transitions and events
+//! - Default option applied when the `DelinquencyEngine` lapses a policy
+//! - Expiry of extended term cover
+//!
+//! # Test Organization
+//!
+//! - `rules` - Catalog parsing
+//! - `reduced_paid_up` - Reduced paid-up insurance
+//! - `extended_term` - Extended term insurance
+//! - `transitions` - States, lapse processing and expiry
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_billing::invoice::InvoiceItemType;
+use domain_billing::account::{Account, AccountType};
+use domain_billing::{Invoice, InvoiceItem, Ledger};
+use domain_policy::aggregate::{LapseReason, NonforfeitureOption, Policy, PolicyBuilder, PolicyState};
+use domain_policy::coverage::{Coverage, CoverageType};
+use domain_policy::delinquency::{DelinquencyEngine, DelinquencyOutcome};
+use domain_policy::events::PolicyEvent;
+use domain_policy::loan::{LoanAccounts, LoanEngine, LoanTransactionType, PolicyLoan};
+use domain_policy::nonforfeiture::NonforfeitureEngine;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::underwriting::{Gender, InsuredLife};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+fn engine() -> NonforfeitureEngine {
+    NonforfeitureEngine::from_catalog(&catalog()).unwrap()
+}
+
+struct Books {
+    ledger: Ledger,
+    loans: LoanEngine,
+}
+
+/// Builds a ledger with the policy loan accounts
+fn books() -> Books {
+    let mut ledger = Ledger::new(Currency::USD);
+    let accounts = LoanAccounts {
+        cash: AccountId::new(),
+        loan_receivable: AccountId::new(),
+        interest_receivable: AccountId::new(),
+        interest_income: AccountId::new(),
+        premium_receivable: AccountId::new(),
+        cash_value: AccountId::new(),
+    };
+    for (id, code, name, account_type) in [
+        (accounts.cash, "1000", "Cash", AccountType::Asset),
+        (accounts.loan_receivable, "1400", "Policy Loans", AccountType::Asset),
+        (accounts.interest_receivable, "1410", "Policy Loan Interest Receivable", AccountType::Asset),
+        (accounts.interest_income, "4400", "Policy Loan Interest", AccountType::Revenue),
+        (accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset),
+        (accounts.cash_value, "2300", "Policy Cash Values", AccountType::Liability),
+    ] {
+        ledger.add_account(Account::new(id, code, name, account_type)).unwrap();
+    }
+    let loans = LoanEngine::from_catalog(&catalog(), accounts).unwrap();
+    Books { ledger, loans }
+}
+
+/// Advances a loan on 1 January 2026 and returns the loan account
+fn loan_of(books: &mut Books, policy: &mut Policy, amount: Decimal) -> PolicyLoan {
+    let mut loan = books.loans.open(policy, date(2026, 1, 1)).unwrap();
+    books
+        .loans
+        .advance(&mut books.ledger, policy, &mut loan, usd(amount), date(2026, 1, 1))
+        .unwrap();
+    loan
+}
+
+/// Annual premium invoice due on 1 January 2026
+fn premium_invoice(policy: &Policy) -> Invoice {
+    let mut invoice = Invoice::new(policy.id(), policy.policyholder_id(), date(2026, 1, 1), Currency::USD);
+    invoice.add_item(InvoiceItem::new("Annual premium", InvoiceItemType::Premium, usd(dec!(2400))));
+    invoice.issue();
+    invoice
+}
+
+/// Issues a 100,000 whole life policy with an accidental death rider and
+/// the given cash value
+fn whole_life_policy(cash_value: Decimal) -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("WHOLE_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+        .add_coverage(Coverage::new(CoverageType::AccidentalDeath, usd(dec!(100000))))
+        .premium(Premium::new(usd(dec!(2400)), PremiumFrequency::Annual))
+        .term_years(99)
+        .insured(InsuredLife {
+            date_of_birth: date(1980, 6, 15),
+            gender: Gender::Female,
+            is_smoker: false,
+        })
+        .build()
+        .unwrap();
+    policy.issue(date(2016, 1, 1), "UW001").unwrap();
+    policy.record_cash_value(usd(cash_value)).unwrap();
+    policy
+}
+
+// ============================================================================
+// RULES TESTS
+// ============================================================================
+
+mod rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let engine = engine();
+
+        let rules = engine.latest_rules_for("WHOLE_LIFE_01").unwrap();
+        assert_eq!(rules.default_option, NonforfeitureOption::ExtendedTerm);
+        assert_eq!(rules.interest_rate, dec!(0.04));
+        assert_eq!(rules.rate_for(45).unwrap().whole_life_nsp_per_thousand, dec!(320));
+        assert_eq!(rules.rate_for(85).unwrap().term_cost_per_thousand, dec!(45));
+
+        assert!(engine.latest_rules_for("TERM_LIFE_01").is_none());
+    }
+
+    #[test]
+    fn test_zero_rate_rejected() {
+        let mut catalog = catalog();
+        let product = catalog["products"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|p| p["code"] == "WHOLE_LIFE_01")
+            .unwrap();
+        product["nonforfeiture_rules"]["rates"][1]["whole_life_nsp_per_thousand"] = serde_json::json!(0);
+
+        let result = NonforfeitureEngine::from_catalog(&catalog);
+
+        assert!(matches!(result, Err(PolicyError::Validation(_))));
+    }
+
+    #[test]
+    fn test_rules_follow_product_version_sold() {
+        let mut catalog = catalog();
+        let products = catalog["products"].as_array_mut().unwrap();
+        let mut revised = products.iter().find(|p| p["code"] == "WHOLE_LIFE_01").unwrap().clone();
+        revised["version"] = serde_json::json!("2.0.0");
+        revised["effective_date"] = serde_json::json!("2026-01-01");
+        revised["nonforfeiture_rules"]["default_option"] = serde_json::json!("reduced_paid_up");
+        products.push(revised);
+        let engine = NonforfeitureEngine::from_catalog(&catalog).unwrap();
+
+        let sold_earlier = whole_life_policy(dec!(20000));
+        let mut pinned = PolicyBuilder::new()
+            .product_code("WHOLE_LIFE_01")
+            .product_version("1.0.0")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(2400)), PremiumFrequency::Annual))
+            .term_years(99)
+            .build()
+            .unwrap();
+        pinned.issue(date(2026, 6, 1), "UW001").unwrap();
+
+        let latest = engine.latest_rules_for("WHOLE_LIFE_01").unwrap();
+        assert_eq!(latest.default_option, NonforfeitureOption::ReducedPaidUp);
+        let earlier = engine.rules_for_policy(&sold_earlier).unwrap();
+        assert_eq!(earlier.default_option, NonforfeitureOption::ExtendedTerm);
+        let pinned = engine.rules_for_policy(&pinned).unwrap();
+        assert_eq!(pinned.default_option, NonforfeitureOption::ExtendedTerm);
+    }
+}
+
+// ============================================================================
+// REDUCED PAID-UP TESTS
+// ============================================================================
+
+mod reduced_paid_up {
+    use super::*;
+
+    #[test]
+    fn test_sum_assured_from_net_single_premium() {
+        let policy = whole_life_policy(dec!(11000));
+
+        let quote = engine()
+            .quote(&policy, NonforfeitureOption::ReducedPaidUp, 45, date(2026, 1, 1))
+            .unwrap();
+
+        // 11,000 / 320 per thousand
+        assert_eq!(quote.sum_assured, usd(dec!(34375)));
+        assert!(quote.expiry_date.is_none());
+    }
+
+    #[test]
+    fn test_loan_offset_through_loan_engine() {
+        let mut books = books();
+        let mut policy = whole_life_policy(dec!(11000));
+        let mut loan = loan_of(&mut books, &mut policy, dec!(1000));
+        let engine = engine().with_loans(books.loans.clone());
+
+        let quote = engine
+            .apply_with_loan(
+                &mut books.ledger,
+                &mut policy,
+                &mut loan,
+                Some(NonforfeitureOption::ReducedPaidUp),
+                45,
+                date(2026, 1, 1),
+            )
+            .unwrap();
+
+        assert_eq!(quote.loan_settled, usd(dec!(1000)));
+        assert_eq!(quote.sum_assured, usd(dec!(31250)));
+        assert!(loan.balance().is_zero());
+        assert_eq!(loan.transactions.last().unwrap().transaction_type, LoanTransactionType::CashValueOffset);
+        assert!(policy.financial_state().loan_outstanding.is_none());
+        assert_eq!(policy.financial_state().surrender_value, Some(usd(dec!(10000))));
+    }
+
+    #[test]
+    fn test_loan_not_dropped_without_offset() {
+        let mut books = books();
+        let mut policy = whole_life_policy(dec!(11000));
+        loan_of(&mut books, &mut policy, dec!(1000));
+
+        let result = engine().apply(&mut policy, Some(NonforfeitureOption::ReducedPaidUp), 45, date(2026, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::Nonforfeiture(_))));
+        assert_eq!(policy.financial_state().loan_outstanding, Some(usd(dec!(1000))));
+    }
+
+    #[test]
+    fn test_coverage_reduced_and_premiums_stop() {
+        let mut policy = whole_life_policy(dec!(11000));
+
+        engine()
+            .apply(&mut policy, Some(NonforfeitureOption::ReducedPaidUp), 45, date(2026, 1, 1))
+            .unwrap();
+
+        let death = &policy.coverages()[0];
+        assert_eq!(death.sum_assured, usd(dec!(34375)));
+        assert!(death.is_active);
+        assert!(death.expiry_date.is_none());
+        assert!(!policy.coverages()[1].is_active);
+        assert!(policy.premium().total_per_payment().is_zero());
+        assert!(matches!(
+            policy.state(),
+            PolicyState::PaidUp { option: NonforfeitureOption::ReducedPaidUp, expiry_date: None, .. }
+        ));
+    }
+}
+
+// ============================================================================
+// EXTENDED TERM TESTS
+// ============================================================================
+
+mod extended_term {
+    use super::*;
+
+    #[test]
+    fn test_term_period_from_net_cash_value() {
+        let policy = whole_life_policy(dec!(11000));
+
+        let quote = engine()
+            .quote(&policy, NonforfeitureOption::ExtendedTerm, 45, date(2026, 1, 1))
+            .unwrap();
+
+        assert_eq!(quote.sum_assured, usd(dec!(100000)));
+        assert_eq!(quote.expiry_date, Some(date(2046, 11, 19)));
+    }
+
+    #[test]
+    fn test_loan_reduces_sum_assured_and_term() {
+        let mut policy = whole_life_policy(dec!(11000));
+        policy.take_loan(usd(dec!(1000))).unwrap();
+
+        let quote = engine()
+            .quote(&policy, NonforfeitureOption::ExtendedTerm, 45, date(2026, 1, 1))
+            .unwrap();
+
+        assert_eq!(quote.sum_assured, usd(dec!(99000)));
+        assert_eq!(quote.expiry_date, Some(date(2045, 8, 31)));
+    }
+
+    #[test]
+    fn test_no_cash_value_rejected() {
+        let mut policy = whole_life_policy(dec!(1000));
+        policy.take_loan(usd(dec!(1000))).unwrap();
+
+        let result = engine().quote(&policy, NonforfeitureOption::ExtendedTerm, 45, date(2026, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::Nonforfeiture(_))));
+    }
+
+    #[test]
+    fn test_every_death_benefit_coverage_extended() {
+        let mut policy = PolicyBuilder::new()
+            .product_code("WHOLE_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .add_coverage(Coverage::death_benefit(usd(dec!(50000))))
+            .premium(Premium::new(usd(dec!(3600)), PremiumFrequency::Annual))
+            .term_years(99)
+            .build()
+            .unwrap();
+        policy.issue(date(2016, 1, 1), "UW001").unwrap();
+        policy.record_cash_value(usd(dec!(11000))).unwrap();
+
+        let quote = engine()
+            .quote(&policy, NonforfeitureOption::ExtendedTerm, 45, date(2026, 1, 1))
+            .unwrap();
+
+        assert_eq!(quote.sum_assured, usd(dec!(150000)));
+    }
+
+    #[test]
+    fn test_loan_above_death_benefit_rejected() {
+        let mut policy = whole_life_policy(dec!(150000));
+        policy.take_loan(usd(dec!(120000))).unwrap();
+
+        let result = engine().quote(&policy, NonforfeitureOption::ExtendedTerm, 45, date(2026, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::Nonforfeiture(_))));
+    }
+}
+
+// ============================================================================
+// TRANSITION TESTS
+// ============================================================================
+
+mod transitions {
+    use super::*;
+
+    #[test]
+    fn test_lapsed_policy_takes_default_option() {
+        let mut policy = whole_life_policy(dec!(11000));
+        policy
+            .lapse(
+                LapseReason::NonPayment {
+                    grace_days_elapsed: 31,
+                    outstanding_amount: dec!(2400),
+                },
+                Some(730),
+            )
+            .unwrap();
+
+        engine().apply(&mut policy, None, 45, date(2026, 2, 1)).unwrap();
+
+        match policy.state() {
+            PolicyState::PaidUp { option, effective_date, expiry_date } => {
+                assert_eq!(*option, NonforfeitureOption::ExtendedTerm);
+                assert_eq!(*effective_date, date(2026, 2, 1));
+                assert_eq!(policy.coverages()[0].expiry_date, *expiry_date);
+            }
+            other => panic!("expected paid-up policy, got {:?}", other),
+        }
+        assert!(policy
+            .take_events()
+            .iter()
+            .any(|e| matches!(e, PolicyEvent::NonforfeitureApplied { option, .. } if option == "ExtendedTerm")));
+    }
+
+    #[test]
+    fn test_extended_term_expires_after_term() {
+        let engine = engine();
+        let mut policy = whole_life_policy(dec!(300));
+        let quote = engine
+            .apply(&mut policy, Some(NonforfeitureOption::ExtendedTerm), 45, date(2026, 1, 1))
+            .unwrap();
+        let expiry_date = quote.expiry_date.unwrap();
+        assert_eq!(expiry_date, date(2027, 1, 1));
+
+        assert!(!engine.process_expiry(&mut policy, expiry_date).unwrap());
+        assert!(engine.process_expiry(&mut policy, date(2027, 1, 2)).unwrap());
+        assert!(matches!(policy.state(), PolicyState::Expired { expiry_date: d } if *d == expiry_date));
+    }
+
+    #[test]
+    fn test_paid_up_policy_cannot_be_converted_again() {
+        let engine = engine();
+        let mut policy = whole_life_policy(dec!(11000));
+        engine
+            .apply(&mut policy, Some(NonforfeitureOption::ReducedPaidUp), 45, date(2026, 1, 1))
+            .unwrap();
+
+        let result = engine.apply(&mut policy, Some(NonforfeitureOption::ExtendedTerm), 45, date(2026, 6, 1));
+
+        assert!(matches!(result, Err(PolicyError::Nonforfeiture(_))));
+    }
+
+    #[test]
+    fn test_delinquency_lapse_applies_default_option() {
+        let mut policy = whole_life_policy(dec!(11000));
+        let mut invoice = premium_invoice(&policy);
+        let delinquency = DelinquencyEngine::from_catalog(&catalog()).unwrap().with_nonforfeiture(engine());
+
+        let outcome = delinquency.process_invoice(&mut policy, &mut invoice, date(2026, 2, 15)).unwrap();
+
+        match outcome {
+            DelinquencyOutcome::PaidUp { nonforfeiture, .. } => {
+                assert_eq!(nonforfeiture.option, NonforfeitureOption::ExtendedTerm);
+                assert_eq!(nonforfeiture.sum_assured, usd(dec!(100000)));
+            }
+            other => panic!("expected paid-up outcome, got {:?}", other),
+        }
+        assert!(matches!(
+            policy.state(),
+            PolicyState::PaidUp { option: NonforfeitureOption::ExtendedTerm, .. }
+        ));
+    }
+
+    #[test]
+    fn test_delinquency_lapse_offsets_loan() {
+        let mut books = books();
+        let mut policy = whole_life_policy(dec!(11000));
+        let mut loan = loan_of(&mut books, &mut policy, dec!(1000));
+        let mut invoice = premium_invoice(&policy);
+        let delinquency = DelinquencyEngine::from_catalog(&catalog())
+            .unwrap()
+            .with_nonforfeiture(engine().with_loans(books.loans.clone()));
+
+        let outcome = delinquency
+            .process_invoice_with_loan(&mut books.ledger, &mut loan, &mut policy, &mut invoice, date(2026, 2, 15))
+            .unwrap();
+
+        match outcome {
+            DelinquencyOutcome::PaidUp { nonforfeiture, .. } => {
+                assert!(nonforfeiture.loan_settled.amount() > dec!(1000));
+            }
+            other => panic!("expected paid-up outcome, got {:?}", other),
+        }
+        assert!(loan.balance().is_zero());
+        assert!(policy.financial_state().loan_outstanding.is_none());
+    }
+
+    #[test]
+    fn test_delinquency_lapse_without_engine_stays_lapsed() {
+        let mut policy = whole_life_policy(dec!(11000));
+        let mut invoice = premium_invoice(&policy);
+
+        let outcome = DelinquencyEngine::from_catalog(&catalog())
+            .unwrap()
+            .process_invoice(&mut policy, &mut invoice, date(2026, 2, 15))
+            .unwrap();
+
+        assert!(matches!(outcome, DelinquencyOutcome::Lapsed { .. }));
+        assert!(matches!(policy.state(), PolicyState::Lapsed { .. }));
+    }
+}
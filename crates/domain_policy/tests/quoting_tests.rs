@@ -0,0 +1,379 @@
+//! Multi-Product Quoting Tests
+//!
+//! This module contains tests for the `QuotingService`, which prices one
+//! application across every product in the catalog.
+//!
+//! # Test Coverage
+//!
+//! - Fanning an application out to each active product and scenario
+//! - Ranking by annualized premium per 1,000 of cover
+//! - Modal premiums from the payment frequency
+//! - Eligibility reasons from catalog limits and product rules
+//! - Loadings from the product rules
+//! - Impairment manual ratings from the underwriting service
+//! - Quoted policies with an expiry
+//!
+//! # Test Organization
+//!
+//! - `comparison` - Candidates, ranking and modal premiums
+//! - `eligibility` - Ineligible options and loadings
+//! - `quotes` - Quoted policies and retrieval
+
+use std::path::PathBuf;
+
+use chrono::{Duration, NaiveDate, Utc};
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::PolicyState;
+use domain_policy::catalog::ProductCatalog;
+use domain_policy::impairment::ImpairmentManual;
+use domain_policy::premium::PremiumFrequency;
+use domain_policy::quoting::{QuoteComparison, QuoteRequest, QuoteScenario, QuotingService};
+use domain_policy::services::UnderwritingService;
+use domain_policy::underwriting::{
+    AlcoholLevel, ApplicantInfo, ConditionStatus, FinancialInfo, Gender, InsurancePurpose, LifestyleInfo,
+    MedicalCondition, MedicalHistory, RiskClass, UnderwritingApplication,
+};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn service() -> QuotingService {
+    let products = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../products");
+    QuotingService::new(ProductCatalog::load(&products).unwrap())
+}
+
+fn manual() -> ImpairmentManual {
+    ImpairmentManual::from_json(
+        r#"{
+            "name": "Test Impairment Manual",
+            "version": "2024.1",
+            "ratings": [
+                { "code": "E11", "name": "Type 2 diabetes, controlled", "statuses": ["Controlled"], "debits": 50 },
+                { "code": "E11", "name": "Type 2 diabetes, uncontrolled", "decline": true },
+                { "code": "I10", "name": "Hypertension, controlled", "statuses": ["Controlled"],
+                  "flat_extra_per_mille": 2 }
+            ]
+        }"#,
+    )
+    .unwrap()
+}
+
+fn condition(code: &str, status: ConditionStatus) -> MedicalCondition {
+    MedicalCondition {
+        code: code.to_string(),
+        name: format!("Condition {}", code),
+        diagnosed_date: Some(date(2020, 1, 1)),
+        status,
+        severity: None,
+        treatment: None,
+    }
+}
+
+/// Application from a healthy 35-year-old on 1 June 2025
+fn application() -> UnderwritingApplication {
+    UnderwritingApplication {
+        applicant: ApplicantInfo {
+            date_of_birth: date(1990, 1, 15),
+            gender: Gender::Female,
+            occupation: "Engineer".to_string(),
+            occupation_class: 1,
+            country: "US".to_string(),
+        },
+        medical_history: MedicalHistory {
+            height_cm: 170,
+            weight_kg: 63.0,
+            is_smoker: false,
+            is_former_smoker: false,
+            conditions: vec![],
+            family_history: vec![],
+        },
+        lifestyle: LifestyleInfo {
+            hazardous_sports: vec![],
+            aviation: None,
+            alcohol_consumption: AlcoholLevel::None,
+            travel_risk_countries: vec![],
+        },
+        financial: FinancialInfo {
+            annual_income: dec!(120000),
+            net_worth: dec!(400000),
+            existing_coverage: dec!(0),
+            purpose: InsurancePurpose::FamilyProtection,
+        },
+        coverages: vec![],
+    }
+}
+
+fn request(application: UnderwritingApplication, scenarios: Vec<QuoteScenario>) -> QuoteRequest {
+    QuoteRequest {
+        policyholder_id: PartyId::new(),
+        application,
+        application_date: date(2025, 6, 1),
+        scenarios,
+    }
+}
+
+fn monthly(sum_assured: Decimal, term_years: Option<u32>) -> QuoteScenario {
+    QuoteScenario::new(usd(sum_assured), term_years, PremiumFrequency::Monthly)
+}
+
+fn compare(scenarios: Vec<QuoteScenario>) -> QuoteComparison {
+    service().compare(&request(application(), scenarios)).unwrap()
+}
+
+// ============================================================================
+// COMPARISON TESTS
+// ============================================================================
+
+mod comparison {
+    use super::*;
+
+    #[test]
+    fn test_every_active_product_quoted() {
+        let comparison = compare(vec![monthly(dec!(250000), Some(20))]);
+
+        let mut codes: Vec<_> = comparison.options.iter().map(|o| o.product_code.as_str()).collect();
+        codes.sort();
+        assert_eq!(codes, ["CRITICAL_ILLNESS_01", "TERM_LIFE_01", "WHOLE_LIFE_01"]);
+        assert_eq!(comparison.applicant_age, 35);
+        assert!(comparison.options.iter().all(|o| o.eligible));
+    }
+
+    #[test]
+    fn test_ranked_by_cost_per_thousand() {
+        let comparison = compare(vec![
+            monthly(dec!(250000), Some(20)),
+            QuoteScenario::new(usd(dec!(500000)), Some(20), PremiumFrequency::Annual),
+        ]);
+
+        let ranked: Vec<_> = comparison.eligible().collect();
+        for (index, option) in ranked.iter().enumerate() {
+            assert_eq!(option.rank, Some(index as u32 + 1));
+        }
+        for pair in ranked.windows(2) {
+            assert!(pair[0].cost_per_thousand() <= pair[1].cost_per_thousand());
+        }
+        assert_eq!(comparison.best().unwrap().rank, Some(1));
+    }
+
+    #[test]
+    fn test_modal_premium_uses_modal_factor() {
+        let comparison = compare(vec![monthly(dec!(250000), Some(20))]);
+
+        let option = comparison.options.iter().find(|o| o.product_code == "TERM_LIFE_01").unwrap();
+        let annual = option.annual_premium.unwrap();
+        let modal = option.modal_premium.unwrap();
+        assert_eq!(modal, annual.multiply(dec!(0.0875)).round_to_currency());
+        assert_eq!(option.annualized_premium.unwrap(), modal.multiply(dec!(12)));
+        assert!(option.annualized_premium.unwrap().amount() > annual.amount());
+    }
+
+    #[test]
+    fn test_term_ignored_for_products_without_term() {
+        let comparison = compare(vec![monthly(dec!(250000), Some(20)), monthly(dec!(250000), Some(25))]);
+
+        let count = |code: &str| comparison.options.iter().filter(|o| o.product_code == code).count();
+        assert_eq!(count("TERM_LIFE_01"), 2);
+        assert_eq!(count("WHOLE_LIFE_01"), 1);
+        let whole_life = comparison.options.iter().find(|o| o.product_code == "WHOLE_LIFE_01").unwrap();
+        assert_eq!(whole_life.scenario.term_years, None);
+    }
+
+    #[test]
+    fn test_no_scenarios_rejected() {
+        let result = service().compare(&request(application(), vec![]));
+
+        assert!(matches!(result, Err(PolicyError::Quoting(_))));
+    }
+}
+
+// ============================================================================
+// ELIGIBILITY TESTS
+// ============================================================================
+
+mod eligibility {
+    use super::*;
+
+    #[test]
+    fn test_catalog_limits_reported() {
+        let comparison = compare(vec![monthly(dec!(3000000), Some(20))]);
+
+        let ineligible: Vec<_> = comparison.ineligible().map(|o| o.product_code.as_str()).collect();
+        assert_eq!(ineligible.len(), 2);
+        assert!(!ineligible.contains(&"TERM_LIFE_01"));
+        let whole_life = comparison.options.iter().find(|o| o.product_code == "WHOLE_LIFE_01").unwrap();
+        assert_eq!(whole_life.rank, None);
+        assert!(whole_life.reasons[0].contains("Sum assured"));
+        assert!(whole_life.quote.is_none());
+    }
+
+    #[test]
+    fn test_term_product_without_term_ineligible() {
+        let comparison = compare(vec![monthly(dec!(250000), None)]);
+
+        let term = comparison.options.iter().find(|o| o.product_code == "TERM_LIFE_01").unwrap();
+        assert!(!term.eligible);
+        assert!(term.reasons[0].contains("requires a policy term"));
+    }
+
+    #[test]
+    fn test_product_rules_decline_with_reason() {
+        let mut application = application();
+        application.medical_history.conditions.push(MedicalCondition {
+            code: "C50".to_string(),
+            name: "Breast cancer".to_string(),
+            diagnosed_date: Some(date(2015, 3, 1)),
+            status: ConditionStatus::Remission,
+            severity: None,
+            treatment: None,
+        });
+
+        let comparison = service()
+            .compare(&request(application, vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+
+        let ci = comparison.options.iter().find(|o| o.product_code == "CRITICAL_ILLNESS_01").unwrap();
+        assert!(!ci.eligible);
+        assert_eq!(ci.reasons, vec!["Prior critical illness history".to_string()]);
+    }
+
+    #[test]
+    fn test_smoker_loadings_applied() {
+        let mut smoker = application();
+        smoker.medical_history.is_smoker = true;
+
+        let standard = compare(vec![monthly(dec!(250000), Some(20))]);
+        let loaded = service()
+            .compare(&request(smoker, vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+
+        let term = |c: &QuoteComparison| {
+            c.options.iter().find(|o| o.product_code == "TERM_LIFE_01").cloned().unwrap()
+        };
+        let (standard, loaded) = (term(&standard), term(&loaded));
+        assert!(loaded.loadings.smoker > Decimal::ZERO);
+        assert_eq!(loaded.loadings.total, loaded.loadings.smoker + loaded.loadings.bmi + loaded.loadings.occupation);
+        assert_ne!(loaded.risk_class, Some(RiskClass::PreferredPlus));
+        assert!(loaded.annual_premium.unwrap().amount() > standard.annual_premium.unwrap().amount());
+    }
+
+    #[test]
+    fn test_impairment_manual_rates_conditions() {
+        let mut diabetic = application();
+        diabetic.medical_history.conditions.push(condition("E11", ConditionStatus::Controlled));
+
+        let comparison = service()
+            .with_underwriting_service(UnderwritingService::new().with_impairment_manual(manual()))
+            .compare(&request(diabetic, vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+
+        let term = comparison.options.iter().find(|o| o.product_code == "TERM_LIFE_01").unwrap();
+        assert!(term.eligible);
+        assert_eq!(term.risk_class, Some(RiskClass::TableRated(2)));
+        assert_eq!(term.quote.as_ref().unwrap().risk_class(), Some(RiskClass::TableRated(2)));
+    }
+
+    #[test]
+    fn test_impairment_manual_flat_extra_priced() {
+        let mut hypertensive = application();
+        hypertensive.medical_history.conditions.push(condition("I10", ConditionStatus::Controlled));
+        let underwritten = || {
+            service().with_underwriting_service(UnderwritingService::new().with_impairment_manual(manual()))
+        };
+
+        let standard = underwritten()
+            .compare(&request(application(), vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+        let rated = underwritten()
+            .compare(&request(hypertensive, vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+
+        let term = |c: &QuoteComparison| {
+            c.options.iter().find(|o| o.product_code == "TERM_LIFE_01").cloned().unwrap()
+        };
+        let (standard, rated) = (term(&standard), term(&rated));
+        let coverage = &rated.quote.as_ref().unwrap().coverages()[0];
+        assert_eq!(coverage.flat_extra_per_mille, Some(dec!(2)));
+        assert!(rated.annual_premium.unwrap().amount() - standard.annual_premium.unwrap().amount() > dec!(500));
+    }
+
+    #[test]
+    fn test_impairment_manual_decline_reported() {
+        let mut diabetic = application();
+        diabetic.medical_history.conditions.push(condition("E11", ConditionStatus::Active));
+
+        let comparison = service()
+            .with_underwriting_service(UnderwritingService::new().with_impairment_manual(manual()))
+            .compare(&request(diabetic, vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+
+        let term = comparison.options.iter().find(|o| o.product_code == "TERM_LIFE_01").unwrap();
+        assert!(!term.eligible);
+        assert_eq!(term.reasons[0], "Declined on underwriting");
+    }
+}
+
+// ============================================================================
+// QUOTE TESTS
+// ============================================================================
+
+mod quotes {
+    use super::*;
+
+    #[test]
+    fn test_eligible_options_quoted_with_expiry() {
+        let comparison = service()
+            .with_quote_validity_days(14)
+            .compare(&request(application(), vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+
+        let option = comparison.best().unwrap();
+        let quote = option.quote.as_ref().unwrap();
+        assert_eq!(quote.product_code(), option.product_code);
+        assert_eq!(quote.product_version(), Some(option.product_version.as_str()));
+        assert_eq!(quote.premium().base_amount, option.modal_premium.unwrap());
+        assert_eq!(quote.premium().frequency, PremiumFrequency::Monthly);
+        let PolicyState::Quoted { quote_date, quote_expiry } = quote.state() else {
+            panic!("expected a quote");
+        };
+        assert_eq!(*quote_expiry - *quote_date, Duration::days(14));
+    }
+
+    #[test]
+    fn test_quote_retrievable_until_expiry() {
+        let comparison = service()
+            .with_quote_validity_days(14)
+            .compare(&request(application(), vec![monthly(dec!(250000), Some(20))]))
+            .unwrap();
+        let quote_id = comparison.best().unwrap().quote_id().unwrap();
+
+        assert!(comparison.quote(quote_id, Utc::now()).is_ok());
+        assert!(matches!(
+            comparison.quote(quote_id, Utc::now() + Duration::days(15)),
+            Err(PolicyError::Quoting(_))
+        ));
+    }
+
+    #[test]
+    fn test_comparison_round_trips_through_json() {
+        let comparison = compare(vec![monthly(dec!(250000), Some(20))]);
+
+        let restored: QuoteComparison =
+            serde_json::from_str(&serde_json::to_string(&comparison).unwrap()).unwrap();
+
+        let quote_id = comparison.best().unwrap().quote_id().unwrap();
+        assert_eq!(restored.options.len(), comparison.options.len());
+        assert!(restored.quote(quote_id, Utc::now()).is_ok());
+    }
+}
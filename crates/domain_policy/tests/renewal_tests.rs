@@ -0,0 +1,356 @@
+//! Renewal Tests
+//!
+//! This module contains tests for the `RenewalEngine`, covering the renewal
+//! of term policies at the end of each term.
+//!
+//! # Test Coverage
+//!
+//! - Renewal rules loaded from the product catalog
+//! - Selection of policies within the notice period
+//! - Attained-age re-rating on the recorded insured life, riders and
+//!   maximum maturity age
+//! - Renewal on payment and the `PolicyRenewed` event
+//! - Natural expiry when renewal is not taken up
+//!
+//! # Test Organization
+//!
+//! - `rules` - Catalog parsing
+//! - `offers` - Notice periods and re-rating
+//! - `renewal` - Renewing on payment
+//! - `expiry` - Expiry at the end of the term
+
+use chrono::NaiveDate;
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::{LapseReason, Policy, PolicyBuilder, PolicyState};
+use domain_policy::coverage::Coverage;
+use domain_policy::events::PolicyEvent;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::renewal::{RenewalDecision, RenewalEngine, RenewalOffer};
+use domain_policy::rider::{RiderEngine, RiderRequest};
+use domain_policy::underwriting::{Gender, InsuredLife, RiskClass};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+fn engine() -> RenewalEngine {
+    RenewalEngine::from_catalog(&catalog()).unwrap()
+}
+
+/// Quotes a 10 year, 100,000 renewable term policy
+fn term_quote() -> Policy {
+    PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+        .premium(Premium::new(usd(dec!(300)), PremiumFrequency::Annual))
+        .term_years(10)
+        .renewable(true)
+        .risk_class(RiskClass::Standard)
+        .build()
+        .unwrap()
+}
+
+/// Issues a 10 year, 100,000 term policy on 2016-01-01 expiring 2026-01-01
+fn term_policy() -> Policy {
+    let mut policy = term_quote();
+    policy.issue(date(2016, 1, 1), "UW001").unwrap();
+    policy
+}
+
+fn offer(engine: &RenewalEngine, policy: &Policy, attained_age: u32) -> RenewalOffer {
+    match engine.prepare(policy, attained_age, false, date(2025, 11, 20)).unwrap() {
+        RenewalDecision::Offer(offer) => offer,
+        other => panic!("expected renewal offer, got {:?}", other),
+    }
+}
+
+// ============================================================================
+// RULES TESTS
+// ============================================================================
+
+mod rules {
+    use super::*;
+
+    #[test]
+    fn test_rules_loaded_from_catalog() {
+        let engine = engine();
+
+        let rules = engine.latest_rules_for("TERM_LIFE_01").unwrap();
+        assert_eq!(rules.renewal_term_years, 5);
+        assert_eq!(rules.notice_period_days, 45);
+        assert_eq!(rules.max_maturity_age, Some(85));
+        assert_eq!(rules.term_at_age(82), 3);
+
+        assert!(engine.latest_rules_for("WHOLE_LIFE_01").is_none());
+    }
+
+    #[test]
+    fn test_term_policy_expires_at_end_of_term() {
+        let policy = term_policy();
+
+        assert_eq!(policy.expiry_date(), Some(date(2026, 1, 1)));
+        assert_eq!(policy.renewal_date(), Some(date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_non_renewable_term_keeps_premium_renewal_date() {
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(300)), PremiumFrequency::Annual))
+            .term_years(10)
+            .build()
+            .unwrap();
+        policy.issue(date(2016, 1, 1), "UW001").unwrap();
+
+        assert!(!policy.is_renewable());
+        assert_eq!(policy.expiry_date(), Some(date(2026, 1, 1)));
+        assert_eq!(policy.renewal_date(), Some(date(2016, 12, 31)));
+    }
+}
+
+// ============================================================================
+// OFFER TESTS
+// ============================================================================
+
+mod offers {
+    use super::*;
+
+    #[test]
+    fn test_policies_selected_within_notice_period() {
+        let engine = engine();
+        let policies = vec![term_policy()];
+
+        assert!(engine.due_for_offer(&policies, date(2025, 11, 16)).is_empty());
+        assert_eq!(engine.due_for_offer(&policies, date(2025, 11, 17)).len(), 1);
+        assert_eq!(engine.due_for_offer(&policies, date(2025, 12, 31)).len(), 1);
+        assert!(engine.due_for_offer(&policies, date(2026, 1, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_notice_period_of_version_sold_applies() {
+        let mut catalog = catalog();
+        let products = catalog["products"].as_array_mut().unwrap();
+        let mut revised = products.iter().find(|p| p["code"] == "TERM_LIFE_01").unwrap().clone();
+        revised["version"] = serde_json::json!("2.0.0");
+        revised["effective_date"] = serde_json::json!("2025-01-01");
+        revised["renewal_rules"]["notice_period_days"] = serde_json::json!(90);
+        products.push(revised);
+        let engine = RenewalEngine::from_catalog(&catalog).unwrap();
+        let policies = vec![term_policy()];
+
+        assert_eq!(engine.latest_rules_for("TERM_LIFE_01").unwrap().notice_period_days, 90);
+        assert!(engine.due_for_offer(&policies, date(2025, 11, 16)).is_empty());
+        assert_eq!(engine.due_for_offer(&policies, date(2025, 11, 17)).len(), 1);
+    }
+
+    #[test]
+    fn test_rerated_at_attained_age() {
+        let engine = engine();
+        let policy = term_policy();
+
+        let offer = offer(&engine, &policy, 50);
+
+        // 1.5 x (0.5 + 50 x 0.05) per thousand, Standard class
+        assert_eq!(offer.annual_premium, usd(dec!(450.00)));
+        assert_eq!(offer.premium.base_amount, usd(dec!(450.00)));
+        assert_eq!(offer.risk_class, RiskClass::Standard);
+        assert_eq!(offer.renewal_date, date(2026, 1, 1));
+        assert_eq!(offer.new_expiry_date, date(2031, 1, 1));
+    }
+
+    #[test]
+    fn test_rerated_on_recorded_insured_life() {
+        let engine = engine();
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(300)), PremiumFrequency::Annual))
+            .term_years(10)
+            .renewable(true)
+            .risk_class(RiskClass::Standard)
+            .insured(InsuredLife {
+                date_of_birth: date(1975, 6, 15),
+                gender: Gender::Male,
+                is_smoker: false,
+            })
+            .build()
+            .unwrap();
+        policy.issue(date(2016, 1, 1), "UW001").unwrap();
+
+        // The age and smoker status passed in are ignored for a recorded insured
+        let offer = match engine.prepare(&policy, 30, true, date(2025, 11, 20)).unwrap() {
+            RenewalDecision::Offer(offer) => offer,
+            other => panic!("expected renewal offer, got {:?}", other),
+        };
+
+        assert_eq!(offer.attained_age, 50);
+        assert_eq!(offer.annual_premium, usd(dec!(450.00)));
+    }
+
+    #[test]
+    fn test_riders_rerated_separately() {
+        let engine = engine();
+        let catalog: serde_json::Value =
+            serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap();
+        let mut policy = term_quote();
+        RiderEngine::from_catalog(&catalog)
+            .unwrap()
+            .add_to_quote(&mut policy, &RiderRequest::new("AD", usd(dec!(200000)), date(1976, 1, 1)), date(2016, 1, 1))
+            .unwrap();
+        policy.issue(date(2016, 1, 1), "UW001").unwrap();
+
+        let offer = offer(&engine, &policy, 50);
+
+        assert_eq!(offer.annual_premium, usd(dec!(450.00)));
+        assert_eq!(offer.premium.base_amount, usd(dec!(450.00)));
+        // 0.5 x (0.5 + 50 x 0.05) per thousand on 200,000
+        assert_eq!(offer.premium.rider_premiums.len(), 1);
+        assert_eq!(offer.premium.rider_premiums[0].rider_code, "AD");
+        assert_eq!(offer.premium.rider_premiums[0].amount, usd(dec!(300.00)));
+        assert_eq!(offer.premium.total_per_payment(), usd(dec!(750.00)));
+    }
+
+    #[test]
+    fn test_term_shortened_near_max_maturity_age() {
+        let engine = engine();
+        let policy = term_policy();
+
+        let offer = offer(&engine, &policy, 82);
+
+        assert_eq!(offer.term_years, 3);
+        assert_eq!(offer.new_expiry_date, date(2029, 1, 1));
+    }
+
+    #[test]
+    fn test_max_maturity_age_reached_expires() {
+        let decision = engine().prepare(&term_policy(), 85, false, date(2025, 11, 20)).unwrap();
+
+        assert!(matches!(decision, RenewalDecision::Expire { expiry_date, .. } if expiry_date == date(2026, 1, 1)));
+    }
+}
+
+// ============================================================================
+// RENEWAL TESTS
+// ============================================================================
+
+mod renewal {
+    use super::*;
+
+    #[test]
+    fn test_payment_renews_for_new_term() {
+        let engine = engine();
+        let mut policy = term_policy();
+        let offer = offer(&engine, &policy, 50);
+        policy.take_events();
+
+        let new_expiry = engine.renew_on_payment(&mut policy, &offer, usd(dec!(450))).unwrap();
+
+        assert_eq!(new_expiry, date(2031, 1, 1));
+        assert_eq!(policy.expiry_date(), Some(date(2031, 1, 1)));
+        assert_eq!(policy.premium().base_amount, usd(dec!(450.00)));
+        assert!(matches!(
+            policy.state(),
+            PolicyState::InForce { effective_date, renewal_date, .. }
+                if *effective_date == date(2026, 1, 1) && *renewal_date == date(2031, 1, 1)
+        ));
+        assert!(policy.take_events().iter().any(|e| matches!(
+            e,
+            PolicyEvent::PolicyRenewed { new_effective_date, new_expiry_date, .. }
+                if *new_effective_date == date(2026, 1, 1) && *new_expiry_date == date(2031, 1, 1)
+        )));
+    }
+
+    #[test]
+    fn test_reinstated_policy_keeps_inception_as_issue_date() {
+        let mut policy = term_policy();
+        policy
+            .lapse(
+                LapseReason::NonPayment {
+                    grace_days_elapsed: 30,
+                    outstanding_amount: dec!(300),
+                },
+                Some(30),
+            )
+            .unwrap();
+        policy.reinstate().unwrap();
+
+        policy.renew(5, Premium::new(usd(dec!(450)), PremiumFrequency::Annual)).unwrap();
+
+        assert!(matches!(
+            policy.state(),
+            PolicyState::InForce { issue_date, .. } if issue_date.date_naive() == date(2016, 1, 1)
+        ));
+    }
+
+    #[test]
+    fn test_short_payment_rejected() {
+        let engine = engine();
+        let mut policy = term_policy();
+        let offer = offer(&engine, &policy, 50);
+
+        let result = engine.renew_on_payment(&mut policy, &offer, usd(dec!(449.99)));
+
+        assert!(matches!(result, Err(PolicyError::Renewal(_))));
+        assert_eq!(policy.expiry_date(), Some(date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_offer_cannot_be_used_twice() {
+        let engine = engine();
+        let mut policy = term_policy();
+        let offer = offer(&engine, &policy, 50);
+        engine.renew_on_payment(&mut policy, &offer, usd(dec!(450))).unwrap();
+
+        let result = engine.renew_on_payment(&mut policy, &offer, usd(dec!(450)));
+
+        assert!(matches!(result, Err(PolicyError::Renewal(_))));
+    }
+}
+
+// ============================================================================
+// EXPIRY TESTS
+// ============================================================================
+
+mod expiry {
+    use super::*;
+
+    #[test]
+    fn test_unrenewed_policy_expires_after_term() {
+        let engine = engine();
+        let mut policy = term_policy();
+
+        assert!(!engine.process_expiry(&mut policy, date(2026, 1, 1)).unwrap());
+        assert!(engine.process_expiry(&mut policy, date(2026, 1, 2)).unwrap());
+        assert!(matches!(policy.state(), PolicyState::Expired { expiry_date } if *expiry_date == date(2026, 1, 1)));
+    }
+
+    #[test]
+    fn test_renewed_policy_does_not_expire() {
+        let engine = engine();
+        let mut policy = term_policy();
+        let offer = offer(&engine, &policy, 50);
+        engine.renew_on_payment(&mut policy, &offer, usd(dec!(450))).unwrap();
+
+        assert!(!engine.process_expiry(&mut policy, date(2026, 1, 2)).unwrap());
+        assert!(policy.is_in_force());
+    }
+}
@@ -0,0 +1,364 @@
+//! Rider Tests
+//!
+//! This module contains tests for the `RiderEngine`, covering riders
+//! attached to base coverages and validated against the product catalog.
+//!
+//! # Test Coverage
+//!
+//! - Rider definitions loaded from `available_riders` of the version sold
+//! - Catalog limits: `max_multiple`, `max_percentage` and `max_age`
+//! - Separate rating and attachment at quote and by endorsement
+//! - Automatic termination at the rider expiry age
+//!
+//! # Test Organization
+//!
+//! - `definitions` - Catalog parsing
+//! - `validation` - Limit checks
+//! - `attachment` - Quote and endorsement flows
+//! - `expiry` - Termination of riders
+
+use chrono::NaiveDate;
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::{Policy, PolicyBuilder};
+use domain_policy::coverage::{Coverage, CoverageModification, CoverageType};
+use domain_policy::endorsement::{Endorsement, EndorsementType};
+use domain_policy::events::PolicyEvent;
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::rider::{RiderEngine, RiderRequest};
+use domain_policy::underwriting::{Gender, InsuredLife, RiskClass};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+fn catalog() -> serde_json::Value {
+    serde_json::from_str(include_str!("../../../products/catalog.json")).unwrap()
+}
+
+fn engine() -> RiderEngine {
+    RiderEngine::from_catalog(&catalog()).unwrap()
+}
+
+/// Insured aged 45 on 2026-01-01
+fn birth_date() -> NaiveDate {
+    date(1980, 6, 15)
+}
+
+/// Quotes a 100,000 term policy with an annual premium of 300
+fn term_quote() -> Policy {
+    PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+        .premium(Premium::new(usd(dec!(300)), PremiumFrequency::Annual))
+        .term_years(20)
+        .risk_class(RiskClass::Standard)
+        .build()
+        .unwrap()
+}
+
+fn term_policy() -> Policy {
+    let mut policy = term_quote();
+    policy.issue(date(2026, 1, 1), "UW001").unwrap();
+    policy
+}
+
+// ============================================================================
+// DEFINITION TESTS
+// ============================================================================
+
+mod definitions {
+    use super::*;
+
+    #[test]
+    fn test_riders_loaded_from_catalog() {
+        let engine = engine();
+        let policy = term_quote();
+
+        let ad = engine.definition(&policy, "AD").unwrap();
+        assert_eq!(ad.max_multiple, Some(dec!(2)));
+        assert_eq!(ad.coverage_type(), CoverageType::AccidentalDeath);
+        assert_eq!(engine.definition(&policy, "WOP").unwrap().max_age, Some(60));
+        assert_eq!(engine.definition(&policy, "CI_RIDER").unwrap().max_percentage, Some(dec!(50)));
+        let esc = engine
+            .latest_riders_for("CRITICAL_ILLNESS_01")
+            .iter()
+            .find(|r| r.code == "ESC")
+            .unwrap();
+        assert_eq!(esc.payout_percentage, Some(dec!(25)));
+
+        assert!(engine.latest_riders_for("ULIP_01").is_empty());
+    }
+
+    #[test]
+    fn test_riders_follow_product_version_sold() {
+        let mut catalog = catalog();
+        let products = catalog["products"].as_array_mut().unwrap();
+        let mut revised = products.iter().find(|p| p["code"] == "TERM_LIFE_01").unwrap().clone();
+        revised["version"] = serde_json::json!("2.0.0");
+        revised["effective_date"] = serde_json::json!("2027-01-01");
+        for rider in revised["available_riders"].as_array_mut().unwrap() {
+            if rider["code"] == "WOP" {
+                rider["max_age"] = serde_json::json!(65);
+            }
+        }
+        products.push(revised);
+        let engine = RiderEngine::from_catalog(&catalog).unwrap();
+
+        let latest = engine.latest_riders_for("TERM_LIFE_01").iter().find(|r| r.code == "WOP").unwrap();
+        assert_eq!(latest.max_age, Some(65));
+        assert_eq!(engine.definition(&term_policy(), "WOP").unwrap().max_age, Some(60));
+    }
+}
+
+// ============================================================================
+// VALIDATION TESTS
+// ============================================================================
+
+mod validation {
+    use super::*;
+
+    #[test]
+    fn test_accidental_death_limited_to_multiple() {
+        let engine = engine();
+        let policy = term_quote();
+
+        let at_limit = RiderRequest::new("AD", usd(dec!(200000)), birth_date());
+        assert!(engine.quote(&policy, &at_limit, date(2026, 1, 1)).is_ok());
+
+        let over = RiderRequest::new("AD", usd(dec!(200001)), birth_date());
+        assert!(matches!(engine.quote(&policy, &over, date(2026, 1, 1)), Err(PolicyError::Rider(_))));
+    }
+
+    #[test]
+    fn test_critical_illness_limited_to_percentage() {
+        let engine = engine();
+        let policy = term_quote();
+
+        let at_limit = RiderRequest::new("CI_RIDER", usd(dec!(50000)), birth_date());
+        assert!(engine.quote(&policy, &at_limit, date(2026, 1, 1)).is_ok());
+
+        let over = RiderRequest::new("CI_RIDER", usd(dec!(50001)), birth_date());
+        assert!(matches!(engine.quote(&policy, &over, date(2026, 1, 1)), Err(PolicyError::Rider(_))));
+    }
+
+    #[test]
+    fn test_waiver_not_available_past_max_age() {
+        let request = RiderRequest::new("WOP", usd(dec!(300)), date(1965, 1, 1));
+
+        let result = engine().quote(&term_quote(), &request, date(2026, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+    }
+
+    #[test]
+    fn test_age_taken_from_recorded_insured_life() {
+        let policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(100000))))
+            .premium(Premium::new(usd(dec!(300)), PremiumFrequency::Annual))
+            .term_years(20)
+            .insured(InsuredLife {
+                date_of_birth: date(1965, 1, 1),
+                gender: Gender::Female,
+                is_smoker: false,
+            })
+            .build()
+            .unwrap();
+        // The request's date of birth is ignored for a recorded insured
+        let request = RiderRequest::new("WOP", usd(dec!(300)), birth_date());
+
+        let result = engine().quote(&policy, &request, date(2026, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+    }
+
+    #[test]
+    fn test_rider_not_offered_by_product_rejected() {
+        let request = RiderRequest::new("PUA", usd(dec!(10000)), birth_date());
+
+        let result = engine().quote(&term_quote(), &request, date(2026, 1, 1));
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+    }
+
+    #[test]
+    fn test_base_reduction_breaches_rider_limit() {
+        let engine = engine();
+        let mut policy = term_policy();
+        engine
+            .endorse(&mut policy, &RiderRequest::new("AD", usd(dec!(200000)), birth_date()), date(2026, 2, 1))
+            .unwrap();
+        assert!(engine.check_limits(&policy).is_ok());
+
+        let base_id = policy.coverages()[0].id;
+        let result = policy.apply_endorsement(Endorsement::new(
+            EndorsementType::CoverageChange {
+                add: vec![],
+                remove: vec![],
+                modify: vec![CoverageModification {
+                    coverage_id: base_id,
+                    new_sum_assured: Some(usd(dec!(50000))),
+                    new_benefits: None,
+                    new_exclusions: None,
+                }],
+            },
+            date(2026, 3, 1),
+        ));
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+        assert_eq!(policy.coverages()[0].sum_assured, usd(dec!(100000)));
+        assert!(engine.check_limits(&policy).is_ok());
+    }
+
+    #[test]
+    fn test_rider_endorsement_outside_engine_rejected() {
+        let engine = engine();
+        let mut policy = term_policy();
+        let quote = engine
+            .quote(&policy, &RiderRequest::new("AD", usd(dec!(100000)), birth_date()), date(2026, 2, 1))
+            .unwrap();
+        let mut coverage = quote.coverage.clone();
+        coverage.sum_assured = usd(dec!(5000000));
+
+        let result = policy.apply_endorsement(Endorsement::new(
+            EndorsementType::RiderAddition {
+                rider: Box::new(quote.rider),
+                coverage,
+                premium: quote.premium,
+            },
+            date(2026, 2, 1),
+        ));
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+        assert!(policy.riders().is_empty());
+    }
+}
+
+// ============================================================================
+// ATTACHMENT TESTS
+// ============================================================================
+
+mod attachment {
+    use super::*;
+
+    #[test]
+    fn test_rider_rated_separately_at_quote() {
+        let mut policy = term_quote();
+
+        let quote = engine()
+            .add_to_quote(&mut policy, &RiderRequest::new("AD", usd(dec!(200000)), birth_date()), date(2026, 1, 1))
+            .unwrap();
+
+        // 0.5 x (0.5 + 45 x 0.05) per thousand on 200,000
+        assert_eq!(quote.annual_premium, usd(dec!(275.00)));
+        assert_eq!(policy.premium().base_amount, usd(dec!(300)));
+        assert_eq!(policy.premium().rider_premiums.len(), 1);
+        assert_eq!(policy.premium().total_per_payment(), usd(dec!(575.00)));
+
+        let rider = &policy.riders()[0];
+        assert_eq!(rider.base_coverage_id, policy.coverages()[0].id);
+        assert_eq!(policy.coverages()[1].id, rider.coverage_id);
+        assert_eq!(policy.coverages()[1].coverage_type, CoverageType::AccidentalDeath);
+    }
+
+    #[test]
+    fn test_add_to_quote_rejects_issued_policy() {
+        let mut policy = term_policy();
+
+        let result = engine().add_to_quote(
+            &mut policy,
+            &RiderRequest::new("AD", usd(dec!(100000)), birth_date()),
+            date(2026, 2, 1),
+        );
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+    }
+
+    #[test]
+    fn test_rider_added_by_endorsement() {
+        let mut policy = term_policy();
+        policy.take_events();
+
+        engine()
+            .endorse(&mut policy, &RiderRequest::new("WOP", usd(dec!(1000)), birth_date()), date(2026, 2, 1))
+            .unwrap();
+
+        assert_eq!(policy.riders()[0].expiry_date, Some(date(2040, 6, 15)));
+        let events = policy.take_events();
+        assert!(events.iter().any(|e| matches!(e, PolicyEvent::RiderAttached { rider_code, .. } if rider_code == "WOP")));
+        assert!(events.iter().any(|e| matches!(e, PolicyEvent::EndorsementApplied { .. })));
+    }
+
+    #[test]
+    fn test_same_rider_cannot_be_attached_twice() {
+        let engine = engine();
+        let mut policy = term_policy();
+        let request = RiderRequest::new("AD", usd(dec!(100000)), birth_date());
+        engine.endorse(&mut policy, &request, date(2026, 2, 1)).unwrap();
+
+        let result = engine.endorse(&mut policy, &request, date(2026, 3, 1));
+
+        assert!(matches!(result, Err(PolicyError::Rider(_))));
+    }
+}
+
+// ============================================================================
+// EXPIRY TESTS
+// ============================================================================
+
+mod expiry {
+    use super::*;
+
+    #[test]
+    fn test_rider_ends_at_expiry_age() {
+        let engine = engine();
+        let mut policy = term_policy();
+        engine
+            .endorse(&mut policy, &RiderRequest::new("WOP", usd(dec!(1000)), birth_date()), date(2026, 2, 1))
+            .unwrap();
+        policy.take_events();
+
+        assert!(engine.process_expiries(&mut policy, date(2040, 6, 14)).unwrap().is_empty());
+        assert_eq!(engine.process_expiries(&mut policy, date(2040, 6, 15)).unwrap(), vec!["WOP".to_string()]);
+
+        assert!(!policy.riders()[0].is_active());
+        assert!(!policy.coverages()[1].is_active);
+        assert!(policy.coverages()[0].is_active);
+        assert!(policy.premium().rider_premiums.is_empty());
+        assert!(policy.take_events().iter().any(|e| matches!(
+            e,
+            PolicyEvent::RiderTerminated { effective_date, .. } if *effective_date == date(2040, 6, 15)
+        )));
+    }
+
+    #[test]
+    fn test_rider_removed_by_endorsement() {
+        let mut policy = term_policy();
+        engine()
+            .endorse(&mut policy, &RiderRequest::new("AD", usd(dec!(100000)), birth_date()), date(2026, 2, 1))
+            .unwrap();
+
+        policy
+            .apply_endorsement(Endorsement::new(
+                EndorsementType::RiderRemoval { rider_code: "AD".to_string() },
+                date(2026, 6, 1),
+            ))
+            .unwrap();
+
+        assert_eq!(policy.riders()[0].terminated_on, Some(date(2026, 6, 1)));
+        assert!(policy.premium().rider_premiums.is_empty());
+    }
+}
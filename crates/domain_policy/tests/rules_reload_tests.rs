@@ -0,0 +1,309 @@
+//! Rules Hot Reload Tests
+//!
+//! This module contains tests for reloading product rules into a running
+//! `RulesEngine` from a rules repository.
+//!
+//! # Test Coverage
+//!
+//! - Loading rules documents from a directory
+//! - Swapping changed rules while earlier evaluations keep their version
+//! - Rejecting invalid documents without disturbing current rules
+//! - Rule version and revision recorded on every evaluation
+//! - Background watcher picking up changes
+//! - Catalog product versions resolving reloaded rules
+//!
+//! # Test Organization
+//!
+//! - `repository` - Directory repository
+//! - `reload` - Reloading and atomic swap
+//! - `audit` - Rules recorded on evaluations
+//! - `watcher` - Background polling
+//! - `catalog` - Rules resolved for catalog product versions
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use domain_policy::catalog::ProductCatalog;
+use domain_policy::rules_engine::{RulesEngine, RulesError};
+use domain_policy::rules_reload::{DirectoryRulesRepository, RulesDocument, RulesRepository, RulesWatcher};
+use serde_json::json;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+/// In-memory repository whose documents can be replaced between reloads
+#[derive(Clone, Default)]
+struct MemoryRepository {
+    documents: Arc<Mutex<Vec<RulesDocument>>>,
+}
+
+impl MemoryRepository {
+    fn publish(&self, source: &str, content: String) {
+        let mut documents = self.documents.lock().unwrap();
+        documents.retain(|d| d.source != source);
+        documents.push(RulesDocument {
+            source: source.to_string(),
+            content,
+        });
+    }
+}
+
+impl RulesRepository for MemoryRepository {
+    fn documents(&self) -> Result<Vec<RulesDocument>, RulesError> {
+        Ok(self.documents.lock().unwrap().clone())
+    }
+}
+
+/// Rules for TEST_01 accepting applicants up to `max_age`
+fn rules(version: &str, max_age: u32) -> String {
+    json!({
+        "name": "Test Product",
+        "nodes": [{
+            "id": "age_check",
+            "type": "decisionTableNode",
+            "content": {
+                "hitPolicy": "first",
+                "inputs": [{ "id": "age", "field": "applicant.age" }],
+                "outputs": [{ "id": "eligible", "field": "eligible" }],
+                "rules": [
+                    { "inputs": [{ "id": "age", "value": format!("<= {}", max_age) }], "outputs": [{ "id": "eligible", "value": "true" }] },
+                    { "inputs": [{ "id": "age", "value": format!("> {}", max_age) }], "outputs": [{ "id": "eligible", "value": "false" }] }
+                ]
+            }
+        }],
+        "edges": [],
+        "metadata": {
+            "product_code": "TEST_01",
+            "product_name": "Test Product",
+            "version": version,
+            "effective_date": "2024-01-01",
+            "currency": "USD",
+            "coverages": ["death_benefit"]
+        }
+    })
+    .to_string()
+}
+
+fn products_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../products")
+}
+
+fn applicant(age: u32) -> serde_json::Value {
+    json!({ "applicant": { "age": age } })
+}
+
+// ============================================================================
+// REPOSITORY TESTS
+// ============================================================================
+
+mod repository {
+    use super::*;
+
+    #[test]
+    fn test_directory_rules_loaded_without_catalog() {
+        let engine = RulesEngine::new();
+
+        let report = engine.reload(&DirectoryRulesRepository::new(products_dir())).unwrap();
+
+        let mut codes: Vec<_> = report.loaded.iter().map(|l| l.product_code.as_str()).collect();
+        codes.sort();
+        assert_eq!(codes, vec!["CRITICAL_ILLNESS_01", "TERM_LIFE_01", "WHOLE_LIFE_01"]);
+        assert!(report.rejected.is_empty());
+        assert!(engine.get_product("TERM_LIFE_01").is_some());
+    }
+
+    #[test]
+    fn test_unchanged_directory_not_reloaded() {
+        let engine = RulesEngine::new();
+        let repository = DirectoryRulesRepository::new(products_dir());
+        engine.reload(&repository).unwrap();
+
+        let report = engine.reload(&repository).unwrap();
+
+        assert!(!report.has_changes());
+        assert_eq!(report.unchanged, 3);
+    }
+}
+
+// ============================================================================
+// RELOAD TESTS
+// ============================================================================
+
+mod reload {
+    use super::*;
+
+    #[test]
+    fn test_changed_rules_swapped_in() {
+        let engine = RulesEngine::new();
+        let repository = MemoryRepository::default();
+        repository.publish("test.json", rules("1.0.0", 65));
+        engine.reload(&repository).unwrap();
+        let original = engine.get_product("TEST_01").unwrap();
+
+        repository.publish("test.json", rules("1.1.0", 70));
+        let report = engine.reload(&repository).unwrap();
+
+        assert_eq!(report.loaded.len(), 1);
+        assert_eq!(report.loaded[0].version, "1.1.0");
+        assert_eq!(report.loaded[0].replaced_revision.as_ref(), Some(&original.revision));
+        let current = engine.get_product("TEST_01").unwrap();
+        assert!(engine.evaluate(&current, applicant(68)).unwrap().eligible);
+    }
+
+    #[test]
+    fn test_in_flight_evaluation_keeps_old_rules() {
+        let engine = RulesEngine::new();
+        let repository = MemoryRepository::default();
+        repository.publish("test.json", rules("1.0.0", 65));
+        engine.reload(&repository).unwrap();
+
+        // Rules taken by an evaluation before the swap
+        let in_flight = engine.get_product("TEST_01").unwrap();
+        repository.publish("test.json", rules("1.1.0", 70));
+        engine.reload(&repository).unwrap();
+
+        let result = engine.evaluate(&in_flight, applicant(68)).unwrap();
+        assert!(!result.eligible);
+        assert_eq!(result.rules_version.as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected_and_current_kept() {
+        let engine = RulesEngine::new();
+        let repository = MemoryRepository::default();
+        repository.publish("test.json", rules("1.0.0", 65));
+        engine.reload(&repository).unwrap();
+
+        let mut broken: serde_json::Value = serde_json::from_str(&rules("1.1.0", 70)).unwrap();
+        broken["nodes"][0]["content"]["rules"][0]["outputs"] = json!([]);
+        repository.publish("test.json", broken.to_string());
+        let report = engine.reload(&repository).unwrap();
+
+        assert!(!report.has_changes());
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].source, "test.json");
+        assert_eq!(engine.get_product("TEST_01").unwrap().metadata.version, "1.0.0");
+    }
+
+    #[test]
+    fn test_product_in_two_documents_rejected() {
+        let engine = RulesEngine::new();
+        let repository = MemoryRepository::default();
+        repository.publish("a.json", rules("1.0.0", 65));
+        repository.publish("b.json", rules("2.0.0", 70));
+
+        let report = engine.reload(&repository).unwrap();
+
+        assert_eq!(report.loaded.len(), 1);
+        assert_eq!(report.rejected[0].source, "b.json");
+        assert_eq!(engine.get_product("TEST_01").unwrap().metadata.version, "1.0.0");
+    }
+}
+
+// ============================================================================
+// AUDIT TESTS
+// ============================================================================
+
+mod audit {
+    use super::*;
+
+    #[test]
+    fn test_evaluation_records_rules_used() {
+        let engine = RulesEngine::new();
+        let rules = engine.load_rules_from_str(&rules("1.0.0", 65)).unwrap();
+
+        let result = engine.evaluate(&rules, applicant(40)).unwrap();
+
+        assert_eq!(result.product_code.as_deref(), Some("TEST_01"));
+        assert_eq!(result.rules_version.as_deref(), Some("1.0.0"));
+        assert_eq!(result.rules_revision.as_ref(), Some(&rules.revision));
+    }
+
+    #[test]
+    fn test_revision_identifies_content() {
+        let engine = RulesEngine::new();
+
+        let first = engine.load_rules_from_str(&rules("1.0.0", 65)).unwrap();
+        let same = engine.load_rules_from_str(&rules("1.0.0", 65)).unwrap();
+        let edited = engine.load_rules_from_str(&rules("1.0.0", 66)).unwrap();
+
+        assert_eq!(first.revision, same.revision);
+        assert_ne!(first.revision, edited.revision);
+    }
+}
+
+// ============================================================================
+// WATCHER TESTS
+// ============================================================================
+
+mod watcher {
+    use super::*;
+
+    #[test]
+    fn test_watcher_picks_up_published_rules() {
+        let engine = Arc::new(RulesEngine::new());
+        let repository = MemoryRepository::default();
+        repository.publish("test.json", rules("1.0.0", 65));
+        let watcher = RulesWatcher::start(engine.clone(), repository.clone(), Duration::from_millis(10));
+
+        repository.publish("test.json", rules("1.1.0", 70));
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while engine.get_product("TEST_01").map(|r| r.metadata.version.clone()).as_deref() != Some("1.1.0") {
+            assert!(Instant::now() < deadline, "watcher did not reload rules");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        watcher.stop();
+    }
+}
+
+// ============================================================================
+// CATALOG TESTS
+// ============================================================================
+
+mod catalog {
+    use super::*;
+
+    /// The term life rules file with its metadata version replaced
+    fn term_life_rules(name: &str, version: &str) -> String {
+        let content = std::fs::read_to_string(products_dir().join("term_life.json")).unwrap();
+        let mut jdm: serde_json::Value = serde_json::from_str(&content).unwrap();
+        jdm["name"] = json!(name);
+        jdm["metadata"]["version"] = json!(version);
+        jdm.to_string()
+    }
+
+    #[test]
+    fn test_revised_rules_replace_catalog_snapshot() {
+        let catalog = ProductCatalog::load(&products_dir()).unwrap();
+        let product = catalog.versions("TERM_LIFE_01")[0].clone();
+        let loaded = product.rules.clone().unwrap();
+        let engine = RulesEngine::new();
+        let repository = MemoryRepository::default();
+        repository.publish("term_life.json", term_life_rules("Term Life (revised)", &loaded.metadata.version));
+        engine.reload(&repository).unwrap();
+
+        let current = product.current_rules(&engine).unwrap();
+
+        assert_ne!(current.revision, loaded.revision);
+        assert_eq!(current.revision, engine.get_product("TERM_LIFE_01").unwrap().revision);
+    }
+
+    #[test]
+    fn test_other_rules_version_keeps_catalog_snapshot() {
+        let catalog = ProductCatalog::load(&products_dir()).unwrap();
+        let product = catalog.versions("TERM_LIFE_01")[0].clone();
+        let loaded = product.rules.clone().unwrap();
+        let engine = RulesEngine::new();
+        let repository = MemoryRepository::default();
+        repository.publish("term_life.json", term_life_rules("Term Life", "9.0.0"));
+        engine.reload(&repository).unwrap();
+
+        let current = product.current_rules(&engine).unwrap();
+
+        assert_eq!(current.revision, loaded.revision);
+        assert_eq!(current.metadata.version, loaded.metadata.version);
+    }
+}
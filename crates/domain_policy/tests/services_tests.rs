@@ -11,6 +11,8 @@
 //! - Risk class determination based on various factors
 //! - Required document generation based on applicant profile
 //! - Service configuration with custom rules
+//! - Decision trace of basic rules and product decision tables
+//! - Product rule loadings applied to the decision
 //!
 //! ## RatingService Tests
 //! - Premium calculation for different coverage types
@@ -22,19 +24,24 @@
 //!
 //! - `underwriting_validation` - Application validation tests
 //! - `underwriting_evaluation` - Risk evaluation and document requirements
+//! - `underwriting_trace` - Decision trace
 //! - `rating_calculation` - Premium calculation tests
 //! - `rating_factors` - Tests for factors affecting premium
 
+use std::sync::Arc;
+
 use chrono::Utc;
 use core_kernel::{Currency, Money};
 use domain_policy::coverage::{Coverage, CoverageType};
+use domain_policy::rules_engine::RulesEngine;
 use domain_policy::services::{RatingService, UnderwritingService};
 use domain_policy::underwriting::{
     AlcoholLevel, ApplicantInfo, ConditionStatus, FinancialInfo, Gender,
     InsurancePurpose, LifestyleInfo, MedicalCondition, MedicalHistory, RiskClass,
-    UnderwritingApplication,
+    RuleImpact, UnderwritingApplication, UnderwritingDecision,
 };
 use rust_decimal_macros::dec;
+use serde_json::json;
 
 // ============================================================================
 // TEST FIXTURES
@@ -261,6 +268,7 @@ mod underwriting_evaluation {
                     name: "Type 2 Diabetes".to_string(),
                     diagnosed_date: None,
                     status: ConditionStatus::Controlled,
+                    severity: None,
                     treatment: Some("Metformin 500mg twice daily".to_string()),
                 }],
                 family_history: vec![],
@@ -354,6 +362,120 @@ mod underwriting_configuration {
     }
 }
 
+// ============================================================================
+// UNDERWRITING SERVICE - TRACE TESTS
+// ============================================================================
+
+mod underwriting_trace {
+    use super::*;
+
+    fn term_life_service() -> UnderwritingService {
+        let jdm = serde_json::from_str(include_str!("../../../products/term_life.json")).unwrap();
+        UnderwritingService::new().with_rules(jdm)
+    }
+
+    fn context(age: u32) -> serde_json::Value {
+        json!({
+            "applicant": { "age": age, "gender": "male", "occupation_class": 1 },
+            "medical": { "bmi": 24.0, "is_smoker": false, "is_former_smoker": true, "years_since_quit": 3 },
+            "coverage": { "sum_assured": 500000, "term_years": 20 }
+        })
+    }
+
+    /// Verifies the basic rule results are kept with the decision
+    #[test]
+    fn test_trace_records_basic_rules() {
+        let mut application = create_valid_application(35, dec!(500000));
+        application.medical_history.is_former_smoker = true;
+
+        let decision = UnderwritingService::new().evaluate(&application).unwrap();
+
+        let trace = decision.trace.expect("Decision should carry a trace");
+        let applied: Vec<_> = trace.applied_rules().collect();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].rule_name, "former_smoker");
+        assert_eq!(applied[0].impact, RuleImpact::Loading(dec!(25)));
+        assert!(trace.decision_tables.is_empty());
+    }
+
+    /// Verifies each decision table records the rule that fired, its inputs
+    /// and its outputs
+    #[test]
+    fn test_trace_records_matched_rows() {
+        let application = create_valid_application(35, dec!(500000));
+
+        let decision = term_life_service().evaluate_with_context(&application, context(35)).unwrap();
+
+        let trace = decision.trace.unwrap();
+        assert_eq!(trace.product_code.as_deref(), Some("TERM_LIFE_01"));
+        assert_eq!(trace.rules_version.as_deref(), Some("1.0.0"));
+        assert!(trace.rules_revision.is_some());
+
+        let smoker = trace
+            .decision_tables
+            .iter()
+            .find(|t| t.node_id == "smoker_loading")
+            .unwrap();
+        assert_eq!(smoker.node_name, "Smoker Loading");
+        assert_eq!(smoker.matched_rule, Some(3));
+        assert_eq!(smoker.inputs["medical.years_since_quit"], json!(3));
+        assert_eq!(smoker.outputs["smoker_loading_percent"], json!(25.0));
+        assert_eq!(trace.matched_tables().count(), trace.decision_tables.len());
+    }
+
+    /// Verifies a decline by the product rules is explained by the trace
+    #[test]
+    fn test_product_decline_explained() {
+        let application = create_valid_application(35, dec!(500000));
+
+        let decision = term_life_service().evaluate_with_context(&application, context(16)).unwrap();
+
+        assert_eq!(decision.risk_class, RiskClass::Declined);
+        assert!(decision.reasons.contains(&"Minimum age is 18".to_string()));
+        let trace = decision.trace.unwrap();
+        let eligibility = &trace.decision_tables[0];
+        assert_eq!(eligibility.node_id, "eligibility_check");
+        assert_eq!(eligibility.matched_rule, Some(0));
+        assert_eq!(eligibility.outputs["eligible"], json!(false));
+    }
+
+    /// Verifies the trace survives persistence as JSON
+    #[test]
+    fn test_trace_round_trips() {
+        let application = create_valid_application(35, dec!(500000));
+        let decision = term_life_service().evaluate_with_context(&application, context(35)).unwrap();
+
+        let stored = serde_json::to_string(&decision).unwrap();
+        let restored: UnderwritingDecision = serde_json::from_str(&stored).unwrap();
+
+        let (before, after) = (decision.trace.unwrap(), restored.trace.unwrap());
+        assert_eq!(after.decision_tables, before.decision_tables);
+        assert_eq!(after.rule_results.len(), before.rule_results.len());
+    }
+
+    /// Verifies the product rules' loading is added to the decision and
+    /// priced through the risk class
+    #[test]
+    fn test_product_loading_applied() {
+        let application = create_valid_application(35, dec!(500000));
+        let engine = Arc::new(RulesEngine::new());
+        let rules = engine
+            .load_rules_from_str(include_str!("../../../products/term_life.json"))
+            .unwrap();
+        let expected = engine.evaluate(&rules, context(35)).unwrap().total_loading_percent.unwrap();
+        assert!(expected > dec!(0));
+
+        let decision = UnderwritingService::new()
+            .with_rules_engine(engine)
+            .evaluate_with_rules(&application, &rules, context(35), Utc::now().date_naive())
+            .unwrap();
+
+        assert_eq!(decision.loading_percent, Some(expected));
+        assert_ne!(decision.risk_class, RiskClass::PreferredPlus);
+        assert!(decision.reasons.iter().any(|r| r.contains("Product rules loading")));
+    }
+}
+
 // ============================================================================
 // RATING SERVICE - CALCULATION TESTS
 // ============================================================================
@@ -0,0 +1,387 @@
+//! Underwriting Case Tests
+//!
+//! This module contains tests for the `UnderwritingCaseEngine`, covering
+//! manual underwriting from requirement ordering to final decision.
+//!
+//! # Test Coverage
+//!
+//! - Requirements generated from rule outputs and sum assured
+//! - Routing to underwriters by authority limits
+//! - Requirement status and due dates
+//! - Final decisions issuing or declining the policy
+//!
+//! # Test Organization
+//!
+//! - `requirements` - Ordering requirements
+//! - `routing` - Authority limits and worklists
+//! - `tracking` - Requirement status
+//! - `decision` - Approving and declining cases
+
+use chrono::NaiveDate;
+use core_kernel::{Currency, Money, PartyId};
+use domain_policy::aggregate::{Policy, PolicyBuilder, PolicyState};
+use domain_policy::coverage::Coverage;
+use domain_policy::events::{PolicyEvent, UnderwritingDecisionType};
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::rules_engine::EvaluationResult;
+use domain_policy::underwriting::RiskClass;
+use domain_policy::underwriting_case::{
+    CaseDecision, CaseRules, CaseStatus, RequirementStatus, RequirementType, UnderwriterAuthority,
+    UnderwritingCase, UnderwritingCaseEngine,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn authority(underwriter: &str, max_sum_assured: Decimal, max_loading_percent: Decimal) -> UnderwriterAuthority {
+    UnderwriterAuthority {
+        underwriter: underwriter.to_string(),
+        max_sum_assured,
+        max_loading_percent,
+    }
+}
+
+fn engine() -> UnderwritingCaseEngine {
+    UnderwritingCaseEngine::new(CaseRules {
+        authorities: vec![
+            authority("UW-CHIEF", dec!(5000000), dec!(300)),
+            authority("UW-JUNIOR", dec!(250000), dec!(50)),
+            authority("UW-SENIOR", dec!(1000000), dec!(100)),
+        ],
+        ..CaseRules::default()
+    })
+}
+
+fn quote(sum_assured: Decimal) -> Policy {
+    PolicyBuilder::new()
+        .product_code("WHOLE_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(Money::new(sum_assured, Currency::USD)))
+        .premium(Premium::new(Money::new(dec!(1200), Currency::USD), PremiumFrequency::Annual))
+        .build()
+        .unwrap()
+}
+
+fn full_underwriting(loading: Decimal) -> EvaluationResult {
+    EvaluationResult {
+        eligible: true,
+        underwriting_type: Some("full".to_string()),
+        total_loading_percent: Some(loading),
+        ..Default::default()
+    }
+}
+
+/// Opens a full underwriting case for 400,000 on 1 March 2024
+fn open_case(policy: &mut Policy) -> UnderwritingCase {
+    engine().open_case(policy, &full_underwriting(dec!(25)), date(2024, 3, 1)).unwrap()
+}
+
+/// Receives and satisfies every requirement of a case
+fn complete(case: &mut UnderwritingCase) {
+    let types: Vec<_> = case.requirements.iter().map(|r| r.requirement_type).collect();
+    for requirement_type in types {
+        case.receive(requirement_type, date(2024, 3, 15)).unwrap();
+        case.satisfy(requirement_type).unwrap();
+    }
+}
+
+// ============================================================================
+// REQUIREMENT TESTS
+// ============================================================================
+
+mod requirements {
+    use super::*;
+
+    #[test]
+    fn test_full_underwriting_orders_medical_evidence() {
+        let requirements = engine().requirements_for(&full_underwriting(dec!(0)), dec!(400000));
+
+        assert_eq!(
+            requirements,
+            vec![
+                RequirementType::ParamedicalExam,
+                RequirementType::LabPanel,
+                RequirementType::AttendingPhysicianStatement,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_medical_exam_flag_and_large_sum_assured() {
+        let result = EvaluationResult {
+            eligible: true,
+            medical_exam_required: Some(true),
+            ..Default::default()
+        };
+
+        let requirements = engine().requirements_for(&result, dec!(750000));
+
+        assert_eq!(
+            requirements,
+            vec![
+                RequirementType::ParamedicalExam,
+                RequirementType::LabPanel,
+                RequirementType::FinancialQuestionnaire,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_case_opened_with_due_dates_and_policy_pending() {
+        let mut policy = quote(dec!(400000));
+
+        let case = open_case(&mut policy);
+
+        assert_eq!(case.status, CaseStatus::AwaitingRequirements);
+        assert!(case.requirements.iter().all(|r| r.due_date == date(2024, 3, 31)));
+        match policy.state() {
+            PolicyState::PendingUnderwriting { required_documents, .. } => {
+                assert_eq!(required_documents.len(), 3);
+                assert!(required_documents.contains(&"Attending Physician Statement".to_string()));
+            }
+            other => panic!("Expected pending underwriting, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_simplified_issue_ready_without_requirements() {
+        let mut policy = quote(dec!(100000));
+        let result = EvaluationResult {
+            eligible: true,
+            underwriting_type: Some("simplified".to_string()),
+            ..Default::default()
+        };
+
+        let case = engine().open_case(&mut policy, &result, date(2024, 3, 1)).unwrap();
+
+        assert!(case.requirements.is_empty());
+        assert_eq!(case.status, CaseStatus::ReadyForDecision);
+    }
+
+    #[test]
+    fn test_ineligible_application_not_opened() {
+        let mut policy = quote(dec!(100000));
+        let result = EvaluationResult {
+            eligible: false,
+            eligibility_reason: Some("Maximum entry age is 65".to_string()),
+            ..Default::default()
+        };
+
+        let error = engine().open_case(&mut policy, &result, date(2024, 3, 1)).unwrap_err();
+
+        assert!(error.to_string().contains("Maximum entry age is 65"));
+        assert!(matches!(policy.state(), PolicyState::Quoted { .. }));
+    }
+}
+
+// ============================================================================
+// ROUTING TESTS
+// ============================================================================
+
+mod routing {
+    use super::*;
+
+    #[test]
+    fn test_routed_to_least_authority_that_covers_case() {
+        let engine = engine();
+
+        assert_eq!(engine.route(dec!(200000), dec!(25)).unwrap().underwriter, "UW-JUNIOR");
+        assert_eq!(engine.route(dec!(400000), dec!(25)).unwrap().underwriter, "UW-SENIOR");
+        assert_eq!(engine.route(dec!(200000), dec!(150)).unwrap().underwriter, "UW-CHIEF");
+        assert!(engine.route(dec!(10000000), dec!(0)).is_none());
+    }
+
+    #[test]
+    fn test_case_beyond_all_authority_rejected() {
+        let mut policy = quote(dec!(10000000));
+
+        let result = engine().open_case(&mut policy, &full_underwriting(dec!(0)), date(2024, 3, 1));
+
+        assert!(result.is_err());
+        assert!(matches!(policy.state(), PolicyState::Quoted { .. }));
+    }
+
+    #[test]
+    fn test_worklist_puts_ready_cases_first() {
+        let engine = engine();
+        let mut first = quote(dec!(400000));
+        let mut second = quote(dec!(500000));
+        let mut junior = quote(dec!(100000));
+        let older = engine.open_case(&mut first, &full_underwriting(dec!(0)), date(2024, 3, 1)).unwrap();
+        let mut newer = engine.open_case(&mut second, &full_underwriting(dec!(0)), date(2024, 3, 5)).unwrap();
+        complete(&mut newer);
+        let other = engine.open_case(&mut junior, &full_underwriting(dec!(0)), date(2024, 3, 1)).unwrap();
+        let cases = vec![older, newer, other];
+
+        let worklist = engine.worklist(&cases, "UW-SENIOR");
+
+        let numbers: Vec<_> = worklist.iter().map(|c| c.policy_number.as_str()).collect();
+        assert_eq!(numbers, vec![second.policy_number(), first.policy_number()]);
+    }
+}
+
+// ============================================================================
+// TRACKING TESTS
+// ============================================================================
+
+mod tracking {
+    use super::*;
+
+    #[test]
+    fn test_requirement_received_then_satisfied() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+
+        case.receive(RequirementType::LabPanel, date(2024, 3, 10)).unwrap();
+        assert_eq!(case.requirement(RequirementType::LabPanel).unwrap().status, RequirementStatus::Received);
+
+        case.satisfy(RequirementType::LabPanel).unwrap();
+
+        let lab = case.requirement(RequirementType::LabPanel).unwrap();
+        assert_eq!(lab.status, RequirementStatus::Satisfied);
+        assert_eq!(lab.received_date, Some(date(2024, 3, 10)));
+        assert_eq!(case.outstanding().count(), 2);
+    }
+
+    #[test]
+    fn test_cannot_satisfy_before_receipt() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+
+        assert!(case.satisfy(RequirementType::ParamedicalExam).is_err());
+        assert!(case.receive(RequirementType::FinancialQuestionnaire, date(2024, 3, 10)).is_err());
+    }
+
+    #[test]
+    fn test_overdue_requirements() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+        case.receive(RequirementType::LabPanel, date(2024, 3, 10)).unwrap();
+
+        assert_eq!(case.overdue(date(2024, 3, 31)).count(), 0);
+        let overdue: Vec<_> = case.overdue(date(2024, 4, 1)).map(|r| r.requirement_type).collect();
+        assert_eq!(
+            overdue,
+            vec![RequirementType::ParamedicalExam, RequirementType::AttendingPhysicianStatement]
+        );
+    }
+
+    #[test]
+    fn test_ready_once_all_satisfied_or_waived() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+
+        case.waive(RequirementType::AttendingPhysicianStatement, "No GP visits in five years").unwrap();
+        for requirement_type in [RequirementType::ParamedicalExam, RequirementType::LabPanel] {
+            assert_eq!(case.status, CaseStatus::AwaitingRequirements);
+            case.receive(requirement_type, date(2024, 3, 12)).unwrap();
+            case.satisfy(requirement_type).unwrap();
+        }
+
+        assert_eq!(case.status, CaseStatus::ReadyForDecision);
+        let aps = case.requirement(RequirementType::AttendingPhysicianStatement).unwrap();
+        assert_eq!(aps.note.as_deref(), Some("No GP visits in five years"));
+    }
+}
+
+// ============================================================================
+// DECISION TESTS
+// ============================================================================
+
+mod decision {
+    use super::*;
+
+    fn approve() -> CaseDecision {
+        CaseDecision::Approve {
+            risk_class: RiskClass::Substandard,
+            effective_date: date(2024, 4, 1),
+        }
+    }
+
+    #[test]
+    fn test_decision_blocked_by_outstanding_requirements() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+
+        let result = engine().decide(&mut case, &mut policy, "UW-SENIOR", approve());
+
+        assert!(result.is_err());
+        assert!(matches!(policy.state(), PolicyState::PendingUnderwriting { .. }));
+    }
+
+    #[test]
+    fn test_underwriter_without_authority_cannot_decide() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+        complete(&mut case);
+
+        assert!(engine().decide(&mut case, &mut policy, "UW-JUNIOR", approve()).is_err());
+        assert!(engine().decide(&mut case, &mut policy, "UW-UNKNOWN", approve()).is_err());
+        assert_eq!(case.status, CaseStatus::ReadyForDecision);
+    }
+
+    #[test]
+    fn test_approval_issues_policy() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+        complete(&mut case);
+        policy.take_events();
+
+        engine().decide(&mut case, &mut policy, "UW-CHIEF", approve()).unwrap();
+
+        assert_eq!(case.status, CaseStatus::Approved);
+        assert_eq!(case.decided_by.as_deref(), Some("UW-CHIEF"));
+        assert!(policy.is_in_force());
+        assert_eq!(policy.inception_date(), Some(date(2024, 4, 1)));
+        assert_eq!(policy.risk_class(), Some(RiskClass::Substandard));
+        let events = policy.take_events();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            PolicyEvent::UnderwritingDecision { decision: UnderwritingDecisionType::ApprovedWithRating, .. }
+        )));
+        assert!(events.iter().any(|e| matches!(e, PolicyEvent::PolicyIssued { .. })));
+    }
+
+    #[test]
+    fn test_decline_closes_policy() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+        complete(&mut case);
+
+        let decline = CaseDecision::Decline {
+            reason: "Abnormal liver function".to_string(),
+        };
+        engine().decide(&mut case, &mut policy, "UW-SENIOR", decline).unwrap();
+
+        assert_eq!(case.status, CaseStatus::Declined);
+        assert_eq!(policy.risk_class(), Some(RiskClass::Declined));
+        match policy.state() {
+            PolicyState::Declined { reason, .. } => assert_eq!(reason, "Abnormal liver function"),
+            other => panic!("Expected decline, got {:?}", other),
+        }
+        assert!(policy.issue(date(2024, 4, 1), "UW-SENIOR").is_err());
+        assert!(case.waive(RequirementType::LabPanel, "closed").is_err());
+    }
+
+    #[test]
+    fn test_decline_with_requirements_outstanding() {
+        let mut policy = quote(dec!(400000));
+        let mut case = open_case(&mut policy);
+
+        let decline = CaseDecision::Decline {
+            reason: "Declined on medical history".to_string(),
+        };
+        engine().decide(&mut case, &mut policy, "UW-SENIOR", decline.clone()).unwrap();
+
+        assert_eq!(case.status, CaseStatus::Declined);
+        assert!(matches!(policy.state(), PolicyState::Declined { .. }));
+        assert!(engine().decide(&mut case, &mut policy, "UW-SENIOR", decline).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+//! Waiver of Premium Tests
+//!
+//! This module contains tests for the `WaiverEngine`, covering premiums
+//! waived while the insured is disabled.
+//!
+//! # Test Coverage
+//!
+//! - Starting a waiver from an approved disability claim
+//! - Waived instalments settled against the WOP reserve
+//! - Suspension of delinquency processing during a waiver
+//! - Ending the waiver on claim closure or recovery
+//! - Waived instalments on the premium schedule
+//!
+//! # Test Organization
+//!
+//! - `start` - Opening a waiver period
+//! - `billing` - Waived invoices and ledger postings
+//! - `end` - Closing a waiver period
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_billing::account::{Account, AccountType};
+use domain_billing::invoice::InvoiceItemType;
+use domain_billing::{Invoice, InvoiceItem, InvoiceStatus, Ledger};
+use domain_claims::{Claim, ClaimStatus, LossType};
+use domain_policy::aggregate::{Policy, PolicyBuilder};
+use domain_policy::coverage::{Coverage, CoverageType};
+use domain_policy::delinquency::{DelinquencyEngine, DelinquencyOutcome};
+use domain_policy::events::PolicyEvent;
+use domain_policy::premium::{PaymentStatus, Premium, PremiumFrequency};
+use domain_policy::waiver::{WaiverAccounts, WaiverEndReason, WaiverEngine};
+use domain_policy::PolicyError;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+struct Books {
+    ledger: Ledger,
+    accounts: WaiverAccounts,
+}
+
+fn books() -> Books {
+    let mut ledger = Ledger::new(Currency::USD);
+    let accounts = WaiverAccounts {
+        wop_reserve: AccountId::new(),
+        premium_receivable: AccountId::new(),
+    };
+    for (id, code, name, account_type) in [
+        (accounts.wop_reserve, "2150", "Waiver of Premium Reserve", AccountType::Liability),
+        (accounts.premium_receivable, "1100", "Premium Receivable", AccountType::Asset),
+    ] {
+        ledger.add_account(Account::new(id, code, name, account_type)).unwrap();
+    }
+    Books { ledger, accounts }
+}
+
+/// Issues a term policy on 1 January 2024 with a monthly premium of 250
+fn policy(with_waiver: bool) -> Policy {
+    let mut builder = PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(dec!(250000))))
+        .premium(Premium::new(usd(dec!(250)), PremiumFrequency::Monthly))
+        .term_years(20);
+    if with_waiver {
+        builder = builder.add_coverage(Coverage::new(CoverageType::WaiverOfPremium, usd(dec!(3000))));
+    }
+    let mut policy = builder.build().unwrap();
+    policy.issue(date(2024, 1, 1), "UW001").unwrap();
+    policy
+}
+
+/// Approved disability claim with disability starting 20 March 2024
+fn disability_claim(policy: &Policy) -> Claim {
+    let mut claim = Claim::fnol(
+        policy.id(),
+        policy.policyholder_id(),
+        date(2024, 3, 20),
+        LossType::Disability,
+        Currency::USD,
+    );
+    claim.update_status(ClaimStatus::UnderInvestigation).unwrap();
+    claim.update_status(ClaimStatus::UnderReview).unwrap();
+    claim.update_status(ClaimStatus::Approved).unwrap();
+    claim
+}
+
+fn premium_invoice(policy: &Policy, due_date: NaiveDate) -> Invoice {
+    let mut invoice = Invoice::new(policy.id(), policy.policyholder_id(), due_date, Currency::USD);
+    invoice.add_item(InvoiceItem::new("Monthly premium", InvoiceItemType::Premium, usd(dec!(250))));
+    invoice.issue();
+    invoice
+}
+
+fn waived_policy(books: &Books) -> Policy {
+    let mut policy = policy(true);
+    let claim = disability_claim(&policy);
+    WaiverEngine::new(books.accounts).start(&mut policy, &claim).unwrap();
+    policy
+}
+
+// ============================================================================
+// START TESTS
+// ============================================================================
+
+mod start {
+    use super::*;
+
+    #[test]
+    fn test_approved_disability_claim_starts_waiver() {
+        let books = books();
+        let mut policy = policy(true);
+        let claim = disability_claim(&policy);
+        policy.take_events();
+
+        WaiverEngine::new(books.accounts).start(&mut policy, &claim).unwrap();
+
+        let waiver = policy.active_waiver().unwrap();
+        assert_eq!(waiver.claim_id, claim.id);
+        assert_eq!(waiver.start_date, date(2024, 3, 20));
+        assert!(!policy.is_premium_waived(date(2024, 3, 1)));
+        assert!(policy.is_premium_waived(date(2024, 4, 1)));
+        assert!(policy
+            .take_events()
+            .iter()
+            .any(|e| matches!(e, PolicyEvent::PremiumWaiverStarted { claim_id, .. } if *claim_id == claim.id)));
+    }
+
+    #[test]
+    fn test_unapproved_claim_rejected() {
+        let books = books();
+        let mut policy = policy(true);
+        let claim = Claim::fnol(policy.id(), policy.policyholder_id(), date(2024, 3, 20), LossType::Disability, Currency::USD);
+
+        let result = WaiverEngine::new(books.accounts).start(&mut policy, &claim);
+
+        assert!(matches!(result, Err(PolicyError::Waiver(_))));
+    }
+
+    #[test]
+    fn test_policy_without_waiver_cover_rejected() {
+        let books = books();
+        let mut policy = policy(false);
+        let claim = disability_claim(&policy);
+
+        let result = WaiverEngine::new(books.accounts).start(&mut policy, &claim);
+
+        assert!(matches!(result, Err(PolicyError::Waiver(_))));
+        assert!(policy.waiver_periods().is_empty());
+    }
+}
+
+// ============================================================================
+// BILLING TESTS
+// ============================================================================
+
+mod billing {
+    use super::*;
+
+    #[test]
+    fn test_waived_invoice_charged_to_reserve() {
+        let mut books = books();
+        let mut policy = waived_policy(&books);
+        let mut invoice = premium_invoice(&policy, date(2024, 4, 1));
+
+        let entry = WaiverEngine::new(books.accounts)
+            .waive_invoice(&mut books.ledger, &mut policy, &mut invoice, date(2024, 4, 1))
+            .unwrap();
+
+        assert!(entry.is_some());
+        assert_eq!(invoice.status, InvoiceStatus::Paid);
+        assert_eq!(books.ledger.get_balance(&books.accounts.wop_reserve), Some(usd(dec!(-250))));
+        assert_eq!(books.ledger.get_balance(&books.accounts.premium_receivable), Some(usd(dec!(-250))));
+        assert_eq!(policy.financial_state().total_premium_paid, usd(dec!(250)));
+        assert_eq!(policy.active_waiver().unwrap().premiums_waived, usd(dec!(250)));
+    }
+
+    #[test]
+    fn test_invoice_before_disability_not_waived() {
+        let mut books = books();
+        let mut policy = waived_policy(&books);
+        let mut invoice = premium_invoice(&policy, date(2024, 3, 1));
+
+        let entry = WaiverEngine::new(books.accounts)
+            .waive_invoice(&mut books.ledger, &mut policy, &mut invoice, date(2024, 4, 1))
+            .unwrap();
+
+        assert!(entry.is_none());
+        assert_eq!(invoice.status, InvoiceStatus::Issued);
+    }
+
+    #[test]
+    fn test_waived_invoice_not_delinquent() {
+        let books = books();
+        let mut policy = waived_policy(&books);
+        let mut invoice = premium_invoice(&policy, date(2024, 4, 1));
+
+        let outcome = DelinquencyEngine::new()
+            .process_invoice(&mut policy, &mut invoice, date(2024, 6, 1))
+            .unwrap();
+
+        assert_eq!(outcome, DelinquencyOutcome::NoAction);
+        assert!(policy.is_in_force());
+    }
+
+    #[test]
+    fn test_schedule_shows_waived_instalments() {
+        let mut books = books();
+        let engine = WaiverEngine::new(books.accounts);
+        let mut policy = policy(true);
+        let claim = disability_claim(&policy);
+        engine.start(&mut policy, &claim).unwrap();
+        engine.record_recovery(&mut policy, date(2024, 7, 1)).unwrap();
+        let mut invoice = premium_invoice(&policy, date(2024, 7, 1));
+        assert!(engine
+            .waive_invoice(&mut books.ledger, &mut policy, &mut invoice, date(2024, 7, 1))
+            .unwrap()
+            .is_none());
+
+        let schedule = policy.premium_schedule(1);
+
+        let waived: Vec<_> = schedule
+            .entries
+            .iter()
+            .filter(|e| e.status == PaymentStatus::Waived)
+            .map(|e| e.due_date)
+            .collect();
+        assert_eq!(waived, vec![date(2024, 4, 1), date(2024, 5, 1), date(2024, 6, 1)]);
+    }
+}
+
+// ============================================================================
+// END TESTS
+// ============================================================================
+
+mod end {
+    use super::*;
+
+    #[test]
+    fn test_recovery_ends_waiver() {
+        let books = books();
+        let mut policy = waived_policy(&books);
+        policy.take_events();
+
+        WaiverEngine::new(books.accounts)
+            .record_recovery(&mut policy, date(2024, 9, 15))
+            .unwrap();
+
+        assert!(policy.active_waiver().is_none());
+        assert_eq!(policy.waiver_periods()[0].end_reason, Some(WaiverEndReason::Recovery));
+        assert!(policy.is_premium_waived(date(2024, 9, 1)));
+        assert!(!policy.is_premium_waived(date(2024, 10, 1)));
+        assert!(policy
+            .take_events()
+            .iter()
+            .any(|e| matches!(e, PolicyEvent::PremiumWaiverEnded { reason, .. } if reason == "Recovery")));
+    }
+
+    #[test]
+    fn test_closed_claim_ends_waiver() {
+        let books = books();
+        let engine = WaiverEngine::new(books.accounts);
+        let mut policy = policy(true);
+        let mut claim = disability_claim(&policy);
+        engine.start(&mut policy, &claim).unwrap();
+
+        assert!(!engine.sync_claim(&mut policy, &claim, date(2024, 8, 1)).unwrap());
+
+        claim.update_status(ClaimStatus::Closed).unwrap();
+        assert!(engine.sync_claim(&mut policy, &claim, date(2024, 8, 1)).unwrap());
+        assert_eq!(policy.waiver_periods()[0].end_date, Some(date(2024, 8, 1)));
+        assert_eq!(policy.waiver_periods()[0].end_reason, Some(WaiverEndReason::ClaimClosed));
+    }
+
+    #[test]
+    fn test_recovery_without_open_waiver_rejected() {
+        let books = books();
+        let mut policy = policy(true);
+
+        let result = WaiverEngine::new(books.accounts).record_recovery(&mut policy, date(2024, 9, 15));
+
+        assert!(matches!(result, Err(PolicyError::Waiver(_))));
+    }
+}
@@ -0,0 +1,49 @@
+//! Reinsurance premium bordereaux
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{JournalEntryId, Money};
+
+use crate::cession::CessionBasis;
+
+/// A cession's premium in a bordereau
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BordereauLine {
+    /// Cession
+    pub cession_id: Uuid,
+    /// Treaty
+    pub treaty_code: String,
+    /// Automatic or facultative
+    pub basis: CessionBasis,
+    /// Policy number
+    pub policy_number: String,
+    /// Coverage
+    pub coverage_id: Uuid,
+    /// Amount ceded
+    pub ceded: Money,
+    /// Days of cover in the period
+    pub days: i64,
+    /// Premium for the period
+    pub premium: Money,
+}
+
+/// Reinsurance premium owed to a reinsurer for a period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PremiumBordereau {
+    /// Unique identifier
+    pub id: Uuid,
+    /// Reinsurer
+    pub reinsurer: String,
+    /// First day of the period
+    pub period_start: NaiveDate,
+    /// Last day of the period
+    pub period_end: NaiveDate,
+    /// Premium per cession
+    pub lines: Vec<BordereauLine>,
+    /// Total premium
+    pub total_premium: Money,
+    /// Ledger posting (None if no premium was due)
+    pub journal_entry_id: Option<JournalEntryId>,
+}
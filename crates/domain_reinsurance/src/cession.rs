@@ -0,0 +1,105 @@
+//! Cessions and facultative placements
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{Money, PolicyId};
+
+/// How a cession was placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CessionBasis {
+    /// Ceded automatically under a treaty
+    Automatic,
+    /// Individually accepted by a reinsurer
+    Facultative,
+}
+
+/// Part of a coverage ceded to a reinsurer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cession {
+    /// Unique identifier
+    pub id: Uuid,
+    /// Treaty the coverage was ceded under, or exceeded for facultative cessions
+    pub treaty_code: String,
+    /// Reinsurer
+    pub reinsurer: String,
+    /// Automatic or facultative
+    pub basis: CessionBasis,
+    /// Policy ceded
+    pub policy_id: PolicyId,
+    /// Policy number, for bordereaux
+    pub policy_number: String,
+    /// Coverage ceded
+    pub coverage_id: Uuid,
+    /// Sum assured of the coverage when ceded
+    pub sum_assured: Money,
+    /// Amount ceded
+    pub ceded: Money,
+    /// Annual reinsurance premium per 1,000 ceded
+    pub premium_rate_per_mille: Decimal,
+    /// Reinsurer's reference for facultative placements
+    pub reference: Option<String>,
+    /// First day of the cession
+    pub start_date: NaiveDate,
+    /// Day the cession ended (None while in force)
+    pub end_date: Option<NaiveDate>,
+}
+
+impl Cession {
+    /// Whether the cession is in force on a date
+    pub fn is_in_force_on(&self, date: NaiveDate) -> bool {
+        date >= self.start_date && self.end_date.is_none_or(|end| date < end)
+    }
+
+    /// Share of the coverage ceded
+    pub fn share(&self) -> Decimal {
+        if self.sum_assured.is_zero() {
+            Decimal::ZERO
+        } else {
+            self.ceded.amount() / self.sum_assured.amount()
+        }
+    }
+}
+
+/// Amount of a coverage awaiting facultative placement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacultativeRequirement {
+    /// Policy
+    pub policy_id: PolicyId,
+    /// Policy number
+    pub policy_number: String,
+    /// Coverage
+    pub coverage_id: Uuid,
+    /// Treaty the coverage exceeds
+    pub treaty_code: String,
+    /// Sum assured of the coverage
+    pub sum_assured: Money,
+    /// Amount still to be placed
+    pub amount: Money,
+}
+
+/// Terms on which a reinsurer accepted a facultative case
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacultativePlacement {
+    /// Reinsurer
+    pub reinsurer: String,
+    /// Amount accepted
+    pub amount: Money,
+    /// Annual reinsurance premium per 1,000 accepted
+    pub premium_rate_per_mille: Decimal,
+    /// Reinsurer's reference
+    pub reference: Option<String>,
+}
+
+/// Result of ceding a policy
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CessionReport {
+    /// Cessions opened
+    pub opened: Vec<Cession>,
+    /// Cessions ended
+    pub ended: Vec<Uuid>,
+    /// Amounts awaiting facultative placement
+    pub facultative: Vec<FacultativeRequirement>,
+}
@@ -0,0 +1,583 @@
+//! Reinsurance engine
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use core_kernel::{utc_start_of_day, AccountId, Currency, JournalEntryId, Money, PolicyId};
+use domain_billing::transaction::Transaction;
+use domain_billing::Ledger;
+use domain_claims::{Claim, ClaimPayment, LossType};
+use domain_policy::coverage::{Coverage, CoverageType};
+use domain_policy::underwriting::RiskClass;
+use domain_policy::Policy;
+
+use crate::bordereau::{BordereauLine, PremiumBordereau};
+use crate::cession::{Cession, CessionBasis, CessionReport, FacultativePlacement, FacultativeRequirement};
+use crate::error::ReinsuranceError;
+use crate::recovery::Recovery;
+use crate::treaty::Treaty;
+
+/// Ledger accounts used for reinsurance
+#[derive(Debug, Clone, Copy)]
+pub struct ReinsuranceAccounts {
+    /// Expense account for premium ceded
+    pub premium_ceded: AccountId,
+    /// Liability account for premium owed to reinsurers
+    pub payable: AccountId,
+    /// Asset account for recoveries due from reinsurers
+    pub recoverable: AccountId,
+    /// Revenue account for claim recoveries
+    pub recoveries: AccountId,
+}
+
+/// Engine ceding policies to reinsurers and accounting for the cessions
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut engine = ReinsuranceEngine::new(accounts).with_treaty(treaty);
+///
+/// // At issue and after each endorsement
+/// let report = engine.cede(&policy, insured_birth_date, today)?;
+/// for requirement in &report.facultative {
+///     engine.place_facultative(requirement.coverage_id, placement, today)?;
+/// }
+///
+/// // Month end
+/// engine.premium_bordereau(&mut ledger, "Global Re", month_start, month_end, Currency::USD)?;
+///
+/// // Claim paid
+/// engine.recover(&mut ledger, &policy, &claim, &payment)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReinsuranceEngine {
+    /// Treaties, checked in order
+    treaties: Vec<Treaty>,
+    /// Ledger accounts
+    accounts: ReinsuranceAccounts,
+    /// Cessions recorded so far
+    cessions: Vec<Cession>,
+    /// Amounts awaiting facultative placement
+    pending_facultative: Vec<FacultativeRequirement>,
+    /// Bordereaux produced so far
+    bordereaux: Vec<PremiumBordereau>,
+    /// Recoveries recorded so far
+    recoveries: Vec<Recovery>,
+}
+
+impl ReinsuranceEngine {
+    /// Creates an engine with no treaties
+    ///
+    /// # Arguments
+    ///
+    /// * `accounts` - Ledger accounts to post to
+    pub fn new(accounts: ReinsuranceAccounts) -> Self {
+        Self {
+            treaties: Vec::new(),
+            accounts,
+            cessions: Vec::new(),
+            pending_facultative: Vec::new(),
+            bordereaux: Vec::new(),
+            recoveries: Vec::new(),
+        }
+    }
+
+    /// Adds a treaty
+    pub fn with_treaty(mut self, treaty: Treaty) -> Self {
+        self.treaties.push(treaty);
+        self
+    }
+
+    /// Adds treaties from a JSON array
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the JSON is not a list of valid treaties
+    pub fn with_treaties_json(mut self, json: &str) -> Result<Self, ReinsuranceError> {
+        let treaties: Vec<Treaty> =
+            serde_json::from_str(json).map_err(|e| ReinsuranceError::InvalidTreaty(e.to_string()))?;
+        self.treaties.extend(treaties);
+        Ok(self)
+    }
+
+    /// Returns the treaties
+    pub fn treaties(&self) -> &[Treaty] {
+        &self.treaties
+    }
+
+    /// Returns all cessions recorded
+    pub fn cessions(&self) -> &[Cession] {
+        &self.cessions
+    }
+
+    /// Returns the cessions of a policy in force on a date
+    pub fn cessions_in_force(&self, policy_id: PolicyId, date: NaiveDate) -> Vec<&Cession> {
+        self.cessions
+            .iter()
+            .filter(|c| c.policy_id == policy_id && c.is_in_force_on(date))
+            .collect()
+    }
+
+    /// Returns the amounts awaiting facultative placement
+    pub fn pending_facultative(&self) -> &[FacultativeRequirement] {
+        &self.pending_facultative
+    }
+
+    /// Returns the bordereaux produced
+    pub fn bordereaux(&self) -> &[PremiumBordereau] {
+        &self.bordereaux
+    }
+
+    /// Returns the recoveries recorded
+    pub fn recoveries(&self) -> &[Recovery] {
+        &self.recoveries
+    }
+
+    /// Cedes a policy's coverages under the applicable treaties
+    ///
+    /// This method:
+    /// 1. Ends cessions of coverages no longer active on the policy
+    /// 2. Splits each active coverage under the treaty in force on its
+    ///    effective date, using the retention for the policy's risk class
+    ///    and the insured's age at issue
+    /// 3. Replaces automatic cessions whose amount has changed
+    /// 4. Reports any amount not yet placed facultatively
+    ///
+    /// Coverages no treaty accepts are retained in full.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The in-force policy, as issued or endorsed
+    /// * `insured_birth_date` - Insured's date of birth
+    /// * `as_of` - Issue or endorsement date
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the policy is not in force or declined, or a treaty
+    /// has no retention for the policy's risk class and age
+    pub fn cede(
+        &mut self,
+        policy: &Policy,
+        insured_birth_date: NaiveDate,
+        as_of: NaiveDate,
+    ) -> Result<CessionReport, ReinsuranceError> {
+        if !policy.is_in_force() {
+            return Err(ReinsuranceError::NotCedable(format!(
+                "Policy {} is not in force",
+                policy.policy_number()
+            )));
+        }
+        let risk_class = policy.risk_class().unwrap_or(RiskClass::Standard);
+        if risk_class == RiskClass::Declined {
+            return Err(ReinsuranceError::NotCedable(format!(
+                "Policy {} is declined",
+                policy.policy_number()
+            )));
+        }
+        let issue_date = policy.inception_date().unwrap_or(as_of);
+        let issue_age = issue_date.years_since(insured_birth_date).unwrap_or(0);
+        let active: Vec<&Coverage> = policy.coverages().iter().filter(|c| c.is_active).collect();
+
+        let mut report = CessionReport::default();
+        for cession in self
+            .cessions
+            .iter_mut()
+            .filter(|c| c.policy_id == policy.id() && c.end_date.is_none())
+        {
+            if !active.iter().any(|coverage| coverage.id == cession.coverage_id) {
+                cession.end_date = Some(as_of);
+                report.ended.push(cession.id);
+            }
+        }
+        self.pending_facultative.retain(|r| r.policy_id != policy.id());
+
+        for coverage in active {
+            let effective_date = coverage.effective_date.unwrap_or(issue_date);
+            let Some(treaty) = self
+                .treaties
+                .iter()
+                .find(|t| t.covers(&coverage.coverage_type, effective_date))
+            else {
+                continue;
+            };
+            let retention = treaty.retention_for(risk_class, issue_age).ok_or_else(|| {
+                ReinsuranceError::InvalidTreaty(format!(
+                    "Treaty {} has no retention for {:?} at age {}",
+                    treaty.code, risk_class, issue_age
+                ))
+            })?;
+            let split = treaty.split(coverage.sum_assured.amount(), retention);
+            let currency = coverage.sum_assured.currency();
+
+            let current = self.cessions.iter().position(|c| {
+                c.coverage_id == coverage.id && c.basis == CessionBasis::Automatic && c.end_date.is_none()
+            });
+            let unchanged = current.is_some_and(|index| {
+                let cession = &self.cessions[index];
+                cession.treaty_code == treaty.code
+                    && cession.sum_assured == coverage.sum_assured
+                    && cession.ceded.amount() == split.automatic
+            });
+            if !unchanged {
+                if let Some(index) = current {
+                    self.cessions[index].end_date = Some(as_of);
+                    report.ended.push(self.cessions[index].id);
+                }
+                if split.automatic > Decimal::ZERO {
+                    let cession = Cession {
+                        id: Uuid::new_v4(),
+                        treaty_code: treaty.code.clone(),
+                        reinsurer: treaty.reinsurer.clone(),
+                        basis: CessionBasis::Automatic,
+                        policy_id: policy.id(),
+                        policy_number: policy.policy_number().to_string(),
+                        coverage_id: coverage.id,
+                        sum_assured: coverage.sum_assured,
+                        ceded: Money::new(split.automatic, currency),
+                        premium_rate_per_mille: treaty.premium_rate_per_mille * risk_class.rate_multiplier(),
+                        reference: None,
+                        start_date: as_of,
+                        end_date: None,
+                    };
+                    report.opened.push(cession.clone());
+                    self.cessions.push(cession);
+                }
+            }
+
+            let placed: Decimal = self
+                .cessions
+                .iter()
+                .filter(|c| c.coverage_id == coverage.id && c.basis == CessionBasis::Facultative && c.end_date.is_none())
+                .map(|c| c.ceded.amount())
+                .sum();
+            let outstanding = split.facultative - placed;
+            if outstanding > Decimal::ZERO {
+                let requirement = FacultativeRequirement {
+                    policy_id: policy.id(),
+                    policy_number: policy.policy_number().to_string(),
+                    coverage_id: coverage.id,
+                    treaty_code: treaty.code.clone(),
+                    sum_assured: coverage.sum_assured,
+                    amount: Money::new(outstanding, currency),
+                };
+                report.facultative.push(requirement.clone());
+                self.pending_facultative.push(requirement);
+            }
+        }
+
+        tracing::info!(
+            policy_number = %policy.policy_number(),
+            opened = report.opened.len(),
+            ended = report.ended.len(),
+            facultative = report.facultative.len(),
+            "Policy ceded"
+        );
+
+        Ok(report)
+    }
+
+    /// Records a facultative placement for a coverage
+    ///
+    /// # Arguments
+    ///
+    /// * `coverage_id` - Coverage awaiting placement
+    /// * `placement` - Terms accepted by the reinsurer
+    /// * `start_date` - First day of the cession
+    ///
+    /// # Errors
+    ///
+    /// Returns error if nothing is awaiting placement for the coverage, or
+    /// the placement is in another currency or exceeds the amount awaiting
+    pub fn place_facultative(
+        &mut self,
+        coverage_id: Uuid,
+        placement: FacultativePlacement,
+        start_date: NaiveDate,
+    ) -> Result<Cession, ReinsuranceError> {
+        let index = self
+            .pending_facultative
+            .iter()
+            .position(|r| r.coverage_id == coverage_id)
+            .ok_or_else(|| {
+                ReinsuranceError::InvalidPlacement(format!("No facultative placement required for coverage {}", coverage_id))
+            })?;
+        let requirement = &self.pending_facultative[index];
+        if placement.amount.currency() != requirement.amount.currency() {
+            return Err(ReinsuranceError::InvalidPlacement(format!(
+                "Placement in {} for coverage in {}",
+                placement.amount.currency(),
+                requirement.amount.currency()
+            )));
+        }
+        if !placement.amount.is_positive() || placement.amount.amount() > requirement.amount.amount() {
+            return Err(ReinsuranceError::InvalidPlacement(format!(
+                "Placement of {} does not fit the {} awaiting placement",
+                placement.amount, requirement.amount
+            )));
+        }
+
+        let cession = Cession {
+            id: Uuid::new_v4(),
+            treaty_code: requirement.treaty_code.clone(),
+            reinsurer: placement.reinsurer,
+            basis: CessionBasis::Facultative,
+            policy_id: requirement.policy_id,
+            policy_number: requirement.policy_number.clone(),
+            coverage_id,
+            sum_assured: requirement.sum_assured,
+            ceded: placement.amount,
+            premium_rate_per_mille: placement.premium_rate_per_mille,
+            reference: placement.reference,
+            start_date,
+            end_date: None,
+        };
+
+        let remaining = requirement.amount - placement.amount;
+        if remaining.is_zero() {
+            self.pending_facultative.remove(index);
+        } else {
+            self.pending_facultative[index].amount = remaining;
+        }
+        self.cessions.push(cession.clone());
+
+        tracing::info!(
+            policy_number = %cession.policy_number,
+            reinsurer = %cession.reinsurer,
+            amount = %cession.ceded,
+            "Facultative cession placed"
+        );
+
+        Ok(cession)
+    }
+
+    /// Produces and posts a reinsurer's premium bordereau for a period
+    ///
+    /// Premium is charged pro rata for the days each cession was in force
+    /// in the period, on a 365-day year.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `reinsurer` - The reinsurer
+    /// * `period_start` - First day of the period
+    /// * `period_end` - Last day of the period
+    /// * `currency` - Bordereau currency; cessions in other currencies are excluded
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the period is empty or overlaps a bordereau already
+    /// produced for the reinsurer, or the ledger posting fails
+    pub fn premium_bordereau(
+        &mut self,
+        ledger: &mut Ledger,
+        reinsurer: &str,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+        currency: Currency,
+    ) -> Result<PremiumBordereau, ReinsuranceError> {
+        if period_end < period_start {
+            return Err(ReinsuranceError::InvalidPeriod(format!(
+                "Period end {} is before start {}",
+                period_end, period_start
+            )));
+        }
+        if self.bordereaux.iter().any(|b| {
+            b.reinsurer == reinsurer
+                && b.total_premium.currency() == currency
+                && b.period_start <= period_end
+                && period_start <= b.period_end
+        }) {
+            return Err(ReinsuranceError::InvalidPeriod(format!(
+                "Premium for {} from {} to {} has already been billed",
+                reinsurer, period_start, period_end
+            )));
+        }
+
+        let mut lines = Vec::new();
+        let mut total_premium = Money::zero(currency);
+        for cession in self
+            .cessions
+            .iter()
+            .filter(|c| c.reinsurer == reinsurer && c.ceded.currency() == currency)
+        {
+            let first = cession.start_date.max(period_start);
+            let last = match cession.end_date.and_then(|end| end.pred_opt()) {
+                Some(last_day) => last_day.min(period_end),
+                None => period_end,
+            };
+            let days = (last - first).num_days() + 1;
+            if days <= 0 {
+                continue;
+            }
+            let premium = cession
+                .ceded
+                .multiply(cession.premium_rate_per_mille / Decimal::from(1000) * Decimal::from(days) / Decimal::from(365))
+                .round_to_currency();
+            total_premium = total_premium + premium;
+            lines.push(BordereauLine {
+                cession_id: cession.id,
+                treaty_code: cession.treaty_code.clone(),
+                basis: cession.basis,
+                policy_number: cession.policy_number.clone(),
+                coverage_id: cession.coverage_id,
+                ceded: cession.ceded,
+                days,
+                premium,
+            });
+        }
+
+        let id = Uuid::new_v4();
+        let journal_entry_id = if total_premium.is_positive() {
+            Some(post(
+                ledger,
+                Transaction::new(format!("Reinsurance premium ceded to {}", reinsurer))
+                    .with_reference("bordereau", id)
+                    .dated(utc_start_of_day(period_end))
+                    .debit(self.accounts.premium_ceded, total_premium)
+                    .credit(self.accounts.payable, total_premium),
+            )?)
+        } else {
+            None
+        };
+
+        let bordereau = PremiumBordereau {
+            id,
+            reinsurer: reinsurer.to_string(),
+            period_start,
+            period_end,
+            lines,
+            total_premium,
+            journal_entry_id,
+        };
+        self.bordereaux.push(bordereau.clone());
+
+        tracing::info!(
+            reinsurer = %reinsurer,
+            lines = bordereau.lines.len(),
+            total_premium = %bordereau.total_premium,
+            "Reinsurance premium bordereau produced"
+        );
+
+        Ok(bordereau)
+    }
+
+    /// Records reinsurers' shares of a claim payment
+    ///
+    /// The payment is apportioned over the active coverages paying the
+    /// claim's loss type by sum assured, and each cession of those
+    /// coverages in force at the loss date recovers its ceded share of its
+    /// coverage's part. A payment already recovered on is skipped, so
+    /// calling this twice has no further effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `ledger` - Ledger to post to
+    /// * `policy` - The policy claimed on
+    /// * `claim` - The claim
+    /// * `payment` - The claim payment
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the payment or claim belongs to another claim or
+    /// policy, or a ledger posting fails
+    pub fn recover(
+        &mut self,
+        ledger: &mut Ledger,
+        policy: &Policy,
+        claim: &Claim,
+        payment: &ClaimPayment,
+    ) -> Result<Vec<Recovery>, ReinsuranceError> {
+        if payment.claim_id != claim.id || claim.policy_id != policy.id() {
+            return Err(ReinsuranceError::InvalidRecovery(format!(
+                "Payment {} is not on a claim against policy {}",
+                payment.id,
+                policy.policy_number()
+            )));
+        }
+        if self.recoveries.iter().any(|r| r.payment_id == payment.id) {
+            return Ok(Vec::new());
+        }
+
+        let currency = payment.amount.currency();
+        let claimed: Vec<_> = policy
+            .coverages()
+            .iter()
+            .filter(|c| c.is_active && c.sum_assured.currency() == currency && pays(&c.coverage_type, claim.loss_type))
+            .collect();
+        let claimed_sum_assured: Decimal = claimed.iter().map(|c| c.sum_assured.amount()).sum();
+        if claimed_sum_assured.is_zero() {
+            return Ok(Vec::new());
+        }
+
+        let recovery_date = payment.paid_at.date_naive();
+        let mut recoveries = Vec::new();
+        for cession in self
+            .cessions
+            .iter()
+            .filter(|c| c.policy_id == policy.id() && c.is_in_force_on(claim.loss_date) && c.ceded.currency() == currency)
+        {
+            let Some(coverage) = claimed.iter().find(|c| c.id == cession.coverage_id) else {
+                continue;
+            };
+            let amount = payment
+                .amount
+                .multiply(coverage.sum_assured.amount() / claimed_sum_assured * cession.share())
+                .round_to_currency();
+            if !amount.is_positive() {
+                continue;
+            }
+            let journal_entry_id = post(
+                ledger,
+                Transaction::new(format!("Reinsurance recovery from {}", cession.reinsurer))
+                    .with_reference("claim", *claim.id.as_uuid())
+                    .dated(payment.paid_at)
+                    .debit(self.accounts.recoverable, amount)
+                    .credit(self.accounts.recoveries, amount),
+            )?;
+            recoveries.push(Recovery {
+                id: Uuid::new_v4(),
+                reinsurer: cession.reinsurer.clone(),
+                cession_id: cession.id,
+                claim_id: claim.id,
+                payment_id: payment.id,
+                amount,
+                recovery_date,
+                journal_entry_id,
+            });
+        }
+
+        tracing::info!(
+            claim_number = %claim.claim_number,
+            recoveries = recoveries.len(),
+            "Reinsurance recoveries recorded"
+        );
+
+        self.recoveries.extend(recoveries.iter().cloned());
+        Ok(recoveries)
+    }
+}
+
+/// Whether a coverage type pays claims for a loss type
+fn pays(coverage_type: &CoverageType, loss_type: LossType) -> bool {
+    match loss_type {
+        LossType::Death => matches!(
+            coverage_type,
+            CoverageType::DeathBenefit | CoverageType::TermRider | CoverageType::WholeLifeRider
+        ),
+        LossType::Accident => matches!(coverage_type, CoverageType::AccidentalDeath),
+        LossType::Disability => matches!(
+            coverage_type,
+            CoverageType::TotalPermanentDisability | CoverageType::IncomeBenefit
+        ),
+        LossType::CriticalIllness => matches!(coverage_type, CoverageType::CriticalIllness),
+        LossType::Hospitalization => matches!(coverage_type, CoverageType::Hospitalization),
+        LossType::Property | LossType::Liability | LossType::Other => false,
+    }
+}
+
+fn post(ledger: &mut Ledger, transaction: Transaction) -> Result<JournalEntryId, ReinsuranceError> {
+    ledger
+        .post(transaction)
+        .map_err(|e| ReinsuranceError::Financial(e.to_string()))
+}
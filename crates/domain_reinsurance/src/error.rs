@@ -0,0 +1,25 @@
+//! Reinsurance domain errors
+
+use thiserror::Error;
+
+/// Errors that can occur in the reinsurance domain
+#[derive(Debug, Error)]
+pub enum ReinsuranceError {
+    #[error("Invalid treaty: {0}")]
+    InvalidTreaty(String),
+
+    #[error("Policy cannot be ceded: {0}")]
+    NotCedable(String),
+
+    #[error("Invalid facultative placement: {0}")]
+    InvalidPlacement(String),
+
+    #[error("Invalid bordereau period: {0}")]
+    InvalidPeriod(String),
+
+    #[error("Invalid recovery: {0}")]
+    InvalidRecovery(String),
+
+    #[error("Financial error: {0}")]
+    Financial(String),
+}
@@ -0,0 +1,71 @@
+//! Reinsurance Domain
+//!
+//! This crate cedes part of each policy coverage to reinsurers under proportional
+//! treaties, produces reinsurance premium bordereaux and records recoveries
+//! on claim payments.
+//!
+//! # Treaties
+//!
+//! - **Quota share** cedes a fixed share of every coverage. Where the
+//!   retained part would exceed the retention limit, the excess is ceded too.
+//! - **Surplus** retains up to the retention limit and cedes the surplus,
+//!   up to a number of lines of the retention.
+//!
+//! Retention limits are set per risk class and issue age band; the first
+//! band matching the policy applies. Each coverage is ceded under the
+//! treaty in force on its effective date.
+//!
+//! # Facultative Placements
+//!
+//! A coverage above the treaty's automatic binding limit is not ceded
+//! automatically: everything above the retention must be placed
+//! facultatively. The same applies to the part of a surplus beyond the
+//! treaty's capacity. [`ReinsuranceEngine::cede`] reports these amounts
+//! and [`ReinsuranceEngine::place_facultative`] records the placements.
+//!
+//! # Endorsements
+//!
+//! `cede` is run at issue and again after every endorsement. Automatic
+//! cessions whose amount changed are ended on the endorsement date and
+//! replaced; facultative cessions stay in place until their coverage is
+//! removed.
+//!
+//! # Accounting
+//!
+//! - Bordereau premium: debit premium ceded, credit reinsurance payable
+//! - Claim recoveries: debit reinsurance recoverable, credit reinsurance
+//!   recoveries
+//!
+//! # Example treaty
+//!
+//! ```json
+//! {
+//!   "code": "SURPLUS_2024",
+//!   "reinsurer": "Global Re",
+//!   "treaty_type": { "type": "surplus", "lines": 9 },
+//!   "coverage_types": ["DeathBenefit"],
+//!   "retentions": [
+//!     { "risk_classes": ["PreferredPlus", "Preferred", "Standard"], "max_age": 65, "retention": 500000 },
+//!     { "retention": 250000 }
+//!   ],
+//!   "automatic_binding_limit": 5000000,
+//!   "premium_rate_per_mille": 1.2,
+//!   "effective_date": "2024-01-01"
+//! }
+//! ```
+
+pub mod treaty;
+pub mod cession;
+pub mod bordereau;
+pub mod recovery;
+pub mod engine;
+pub mod error;
+
+mod treaty_serde;
+
+pub use treaty::{Treaty, TreatyType, RetentionLimit, CessionSplit};
+pub use cession::{Cession, CessionBasis, CessionReport, FacultativePlacement, FacultativeRequirement};
+pub use bordereau::{PremiumBordereau, BordereauLine};
+pub use recovery::Recovery;
+pub use engine::{ReinsuranceEngine, ReinsuranceAccounts};
+pub use error::ReinsuranceError;
@@ -0,0 +1,28 @@
+//! Reinsurance recoveries on claim payments
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use core_kernel::{ClaimId, JournalEntryId, Money};
+
+/// A reinsurer's share of a claim payment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recovery {
+    /// Unique identifier
+    pub id: Uuid,
+    /// Reinsurer
+    pub reinsurer: String,
+    /// Cession the recovery is made under
+    pub cession_id: Uuid,
+    /// Claim
+    pub claim_id: ClaimId,
+    /// Claim payment recovered on
+    pub payment_id: Uuid,
+    /// Amount recoverable
+    pub amount: Money,
+    /// Date of the claim payment
+    pub recovery_date: NaiveDate,
+    /// Ledger posting
+    pub journal_entry_id: JournalEntryId,
+}
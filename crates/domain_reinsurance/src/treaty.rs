@@ -0,0 +1,150 @@
+//! Proportional treaties
+//!
+//! A treaty shares each coverage it accepts between the company and the
+//! reinsurer: retention limits by risk class and issue age decide what the
+//! company keeps, and the automatic binding limit what may be ceded without
+//! facultative placement.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use domain_policy::coverage::CoverageType;
+use domain_policy::underwriting::RiskClass;
+
+use crate::treaty_serde;
+
+/// Proportional treaty type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TreatyType {
+    /// Fixed share of every coverage ceded
+    QuotaShare {
+        /// Share ceded, as a fraction
+        #[serde(deserialize_with = "treaty_serde::rate")]
+        ceded_share: Decimal,
+    },
+    /// Amount above the retention ceded
+    Surplus {
+        /// Capacity in multiples of the retention
+        lines: u32,
+    },
+}
+
+/// Retention limit for a band of risk classes and issue ages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetentionLimit {
+    /// Risk classes in the band; any class if empty
+    #[serde(default)]
+    pub risk_classes: Vec<RiskClass>,
+    /// Lowest issue age in the band
+    #[serde(default)]
+    pub min_age: u32,
+    /// Highest issue age in the band (None for no limit)
+    #[serde(default)]
+    pub max_age: Option<u32>,
+    /// Most the company retains on one coverage
+    #[serde(deserialize_with = "treaty_serde::amount")]
+    pub retention: Decimal,
+}
+
+impl RetentionLimit {
+    /// Whether the band covers a risk class and issue age
+    pub fn applies_to(&self, risk_class: RiskClass, age: u32) -> bool {
+        (self.risk_classes.is_empty() || self.risk_classes.contains(&risk_class))
+            && age >= self.min_age
+            && self.max_age.is_none_or(|max| age <= max)
+    }
+}
+
+/// A proportional reinsurance treaty
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Treaty {
+    /// Treaty code
+    pub code: String,
+    /// Reinsurer name
+    pub reinsurer: String,
+    /// Quota share or surplus
+    pub treaty_type: TreatyType,
+    /// Coverage types ceded; all types if empty
+    #[serde(default)]
+    pub coverage_types: Vec<CoverageType>,
+    /// Retention limits, most specific first
+    pub retentions: Vec<RetentionLimit>,
+    /// Largest sum assured ceded without facultative placement
+    #[serde(deserialize_with = "treaty_serde::amount")]
+    pub automatic_binding_limit: Decimal,
+    /// Annual reinsurance premium per 1,000 ceded at standard rates
+    #[serde(deserialize_with = "treaty_serde::rate")]
+    pub premium_rate_per_mille: Decimal,
+    /// First day new business is ceded
+    pub effective_date: NaiveDate,
+    /// Day from which no new business is ceded
+    #[serde(default)]
+    pub expiry_date: Option<NaiveDate>,
+}
+
+impl Treaty {
+    /// Whether the treaty accepts a coverage type effective on a date
+    pub fn covers(&self, coverage_type: &CoverageType, date: NaiveDate) -> bool {
+        (self.coverage_types.is_empty() || self.coverage_types.contains(coverage_type))
+            && date >= self.effective_date
+            && self.expiry_date.is_none_or(|expiry| date < expiry)
+    }
+
+    /// Returns the retention for a risk class and issue age
+    pub fn retention_for(&self, risk_class: RiskClass, age: u32) -> Option<Decimal> {
+        self.retentions
+            .iter()
+            .find(|r| r.applies_to(risk_class, age))
+            .map(|r| r.retention)
+    }
+
+    /// Splits a sum assured between the company and reinsurers
+    ///
+    /// # Arguments
+    ///
+    /// * `sum_assured` - Sum assured of the coverage
+    /// * `retention` - Retention limit applying to the coverage
+    pub fn split(&self, sum_assured: Decimal, retention: Decimal) -> CessionSplit {
+        let retained = sum_assured.min(retention);
+        if sum_assured > self.automatic_binding_limit {
+            return CessionSplit {
+                retained,
+                automatic: Decimal::ZERO,
+                facultative: sum_assured - retained,
+            };
+        }
+
+        match self.treaty_type {
+            TreatyType::QuotaShare { ceded_share } => {
+                let retained = (sum_assured * (Decimal::ONE - ceded_share)).min(retention);
+                CessionSplit {
+                    retained,
+                    automatic: sum_assured - retained,
+                    facultative: Decimal::ZERO,
+                }
+            }
+            TreatyType::Surplus { lines } => {
+                let surplus = sum_assured - retained;
+                let automatic = surplus.min(retention * Decimal::from(lines));
+                CessionSplit {
+                    retained,
+                    automatic,
+                    facultative: surplus - automatic,
+                }
+            }
+        }
+    }
+}
+
+/// How a sum assured is shared out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CessionSplit {
+    /// Amount the company keeps
+    pub retained: Decimal,
+    /// Amount ceded automatically under the treaty
+    pub automatic: Decimal,
+    /// Amount needing facultative placement
+    pub facultative: Decimal,
+}
@@ -0,0 +1,25 @@
+//! Serde helpers for treaty values
+//!
+//! Rates are read through `f64`, as in the product catalog, so they parse
+//! regardless of how serde_json represents numbers. Monetary amounts are
+//! read from their decimal representation so they are exact.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use serde_json::Number;
+
+/// Reads a treaty rate as a decimal
+pub(crate) fn rate<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    let value = f64::deserialize(deserializer)?;
+    Decimal::try_from(value).map_err(serde::de::Error::custom)
+}
+
+/// Reads a treaty monetary amount as an exact decimal
+pub(crate) fn amount<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    let value = Number::deserialize(deserializer)?.to_string();
+    Decimal::from_str(&value)
+        .or_else(|_| Decimal::from_scientific(&value))
+        .map_err(serde::de::Error::custom)
+}
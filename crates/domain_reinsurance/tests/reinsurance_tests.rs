@@ -0,0 +1,590 @@
+//! Reinsurance Tests
+//!
+//! This module contains tests for the `ReinsuranceEngine`, covering
+//! treaty cessions, facultative placements, premium bordereaux and claim
+//! recoveries.
+//!
+//! # Test Coverage
+//!
+//! - Quota share and surplus splits with retention limits
+//! - Retention by risk class and issue age
+//! - Cessions at issue and on endorsement
+//! - Facultative placements above automatic binding limits
+//! - Premium bordereaux and recovery postings
+//! - Recoveries limited to the cessions of the coverage claimed
+//!
+//! # Test Organization
+//!
+//! - `treaties` - Splitting a sum assured under a treaty
+//! - `cessions` - Ceding policies and facultative placements
+//! - `accounting` - Bordereaux and recoveries
+
+use chrono::NaiveDate;
+use core_kernel::{AccountId, Currency, Money, PartyId};
+use domain_billing::account::{Account, AccountType};
+use domain_billing::Ledger;
+use domain_claims::payment::{PaymentMethod, PaymentType};
+use domain_claims::{Claim, ClaimPayment, LossType};
+use domain_policy::aggregate::{Policy, PolicyBuilder};
+use domain_policy::coverage::{Coverage, CoverageModification, CoverageType};
+use domain_policy::endorsement::{Endorsement, EndorsementType};
+use domain_policy::premium::{Premium, PremiumFrequency};
+use domain_policy::underwriting::RiskClass;
+use domain_reinsurance::{
+    CessionBasis, CessionSplit, FacultativePlacement, ReinsuranceAccounts, ReinsuranceEngine, ReinsuranceError,
+    RetentionLimit, Treaty, TreatyType,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// ============================================================================
+// TEST HELPERS
+// ============================================================================
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn usd(amount: Decimal) -> Money {
+    Money::new(amount, Currency::USD)
+}
+
+/// Insured aged 40 at issue
+fn birth_date() -> NaiveDate {
+    date(1984, 1, 1)
+}
+
+/// Surplus treaty: 500,000 retention at standard and better up to age 65,
+/// 250,000 otherwise, four lines and a 3,000,000 binding limit
+fn surplus_treaty() -> Treaty {
+    Treaty {
+        code: "SURPLUS_2024".to_string(),
+        reinsurer: "Global Re".to_string(),
+        treaty_type: TreatyType::Surplus { lines: 4 },
+        coverage_types: vec![CoverageType::DeathBenefit],
+        retentions: vec![
+            RetentionLimit {
+                risk_classes: vec![RiskClass::PreferredPlus, RiskClass::Preferred, RiskClass::Standard],
+                min_age: 0,
+                max_age: Some(65),
+                retention: dec!(500000),
+            },
+            RetentionLimit {
+                risk_classes: vec![],
+                min_age: 0,
+                max_age: None,
+                retention: dec!(250000),
+            },
+        ],
+        automatic_binding_limit: dec!(3000000),
+        premium_rate_per_mille: dec!(1.2),
+        effective_date: date(2024, 1, 1),
+        expiry_date: None,
+    }
+}
+
+fn quota_share_treaty() -> Treaty {
+    Treaty {
+        code: "QS_2024".to_string(),
+        reinsurer: "Mutual Re".to_string(),
+        treaty_type: TreatyType::QuotaShare { ceded_share: dec!(0.5) },
+        coverage_types: vec![],
+        retentions: vec![RetentionLimit {
+            risk_classes: vec![],
+            min_age: 0,
+            max_age: None,
+            retention: dec!(300000),
+        }],
+        automatic_binding_limit: dec!(2000000),
+        premium_rate_per_mille: dec!(1.0),
+        effective_date: date(2024, 1, 1),
+        expiry_date: None,
+    }
+}
+
+struct Books {
+    ledger: Ledger,
+    accounts: ReinsuranceAccounts,
+}
+
+fn books() -> Books {
+    let mut ledger = Ledger::new(Currency::USD);
+    let accounts = ReinsuranceAccounts {
+        premium_ceded: AccountId::new(),
+        payable: AccountId::new(),
+        recoverable: AccountId::new(),
+        recoveries: AccountId::new(),
+    };
+    for (id, code, name, account_type) in [
+        (accounts.premium_ceded, "5300", "Reinsurance Premium Ceded", AccountType::Expense),
+        (accounts.payable, "2300", "Reinsurance Payable", AccountType::Liability),
+        (accounts.recoverable, "1300", "Reinsurance Recoverable", AccountType::Asset),
+        (accounts.recoveries, "4300", "Reinsurance Recoveries", AccountType::Revenue),
+    ] {
+        ledger.add_account(Account::new(id, code, name, account_type)).unwrap();
+    }
+    Books { ledger, accounts }
+}
+
+fn engine(books: &Books) -> ReinsuranceEngine {
+    ReinsuranceEngine::new(books.accounts).with_treaty(surplus_treaty())
+}
+
+/// Issues a term policy on 1 January 2024 with one death benefit
+fn policy(sum_assured: Decimal, risk_class: RiskClass) -> Policy {
+    let mut policy = PolicyBuilder::new()
+        .product_code("TERM_LIFE_01")
+        .policyholder(PartyId::new())
+        .add_coverage(Coverage::death_benefit(usd(sum_assured)))
+        .premium(Premium::new(usd(dec!(250)), PremiumFrequency::Monthly))
+        .risk_class(risk_class)
+        .term_years(20)
+        .build()
+        .unwrap();
+    policy.issue(date(2024, 1, 1), "UW001").unwrap();
+    policy
+}
+
+fn change_sum_assured(policy: &mut Policy, sum_assured: Decimal, effective_date: NaiveDate) {
+    let modification = CoverageModification {
+        coverage_id: policy.coverages()[0].id,
+        new_sum_assured: Some(usd(sum_assured)),
+        new_benefits: None,
+        new_exclusions: None,
+    };
+    policy
+        .apply_endorsement(Endorsement::new(
+            EndorsementType::CoverageChange {
+                add: vec![],
+                remove: vec![],
+                modify: vec![modification],
+            },
+            effective_date,
+        ))
+        .unwrap();
+}
+
+fn death_claim(policy: &Policy) -> Claim {
+    claim(policy, LossType::Death)
+}
+
+fn claim(policy: &Policy, loss_type: LossType) -> Claim {
+    Claim::fnol(
+        policy.id(),
+        policy.policyholder_id(),
+        date(2024, 9, 10),
+        loss_type,
+        Currency::USD,
+    )
+}
+
+fn final_settlement(policy: &Policy, claim: &Claim, amount: Decimal) -> ClaimPayment {
+    ClaimPayment::new(
+        claim.id,
+        policy.policyholder_id(),
+        usd(amount),
+        PaymentType::FinalSettlement,
+        PaymentMethod::BankTransfer,
+    )
+}
+
+// ============================================================================
+// TREATY TESTS
+// ============================================================================
+
+mod treaties {
+    use super::*;
+
+    #[test]
+    fn test_surplus_cedes_above_retention() {
+        let split = surplus_treaty().split(dec!(1500000), dec!(500000));
+
+        assert_eq!(
+            split,
+            CessionSplit {
+                retained: dec!(500000),
+                automatic: dec!(1000000),
+                facultative: dec!(0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_surplus_beyond_capacity_needs_facultative() {
+        let split = surplus_treaty().split(dec!(2800000), dec!(500000));
+
+        assert_eq!(split.automatic, dec!(2000000));
+        assert_eq!(split.facultative, dec!(300000));
+    }
+
+    #[test]
+    fn test_above_binding_limit_not_ceded_automatically() {
+        let split = surplus_treaty().split(dec!(4000000), dec!(500000));
+
+        assert_eq!(split.retained, dec!(500000));
+        assert_eq!(split.automatic, dec!(0));
+        assert_eq!(split.facultative, dec!(3500000));
+    }
+
+    #[test]
+    fn test_quota_share_caps_retained_share() {
+        let treaty = quota_share_treaty();
+
+        let small = treaty.split(dec!(400000), dec!(300000));
+        let large = treaty.split(dec!(1000000), dec!(300000));
+
+        assert_eq!((small.retained, small.automatic), (dec!(200000), dec!(200000)));
+        assert_eq!((large.retained, large.automatic), (dec!(300000), dec!(700000)));
+    }
+
+    #[test]
+    fn test_retention_by_risk_class_and_age() {
+        let treaty = surplus_treaty();
+
+        assert_eq!(treaty.retention_for(RiskClass::Preferred, 40), Some(dec!(500000)));
+        assert_eq!(treaty.retention_for(RiskClass::TableRated(2), 40), Some(dec!(250000)));
+        assert_eq!(treaty.retention_for(RiskClass::Standard, 70), Some(dec!(250000)));
+    }
+
+    #[test]
+    fn test_treaties_from_json() {
+        let engine = ReinsuranceEngine::new(books().accounts)
+            .with_treaties_json(
+                r#"[{
+                    "code": "SURPLUS_2024",
+                    "reinsurer": "Global Re",
+                    "treaty_type": { "type": "surplus", "lines": 9 },
+                    "coverage_types": ["DeathBenefit"],
+                    "retentions": [
+                        { "risk_classes": ["Standard", { "TableRated": 2 }], "max_age": 65, "retention": 500000 },
+                        { "retention": 250000 }
+                    ],
+                    "automatic_binding_limit": 5000000,
+                    "premium_rate_per_mille": 1.2,
+                    "effective_date": "2024-01-01"
+                }]"#,
+            )
+            .unwrap();
+
+        let treaty = &engine.treaties()[0];
+        assert_eq!(treaty.treaty_type, TreatyType::Surplus { lines: 9 });
+        assert_eq!(treaty.retention_for(RiskClass::TableRated(2), 50), Some(dec!(500000)));
+        assert_eq!(treaty.premium_rate_per_mille, dec!(1.2));
+    }
+}
+
+// ============================================================================
+// CESSION TESTS
+// ============================================================================
+
+mod cessions {
+    use super::*;
+
+    #[test]
+    fn test_cede_at_issue() {
+        let books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(1500000), RiskClass::Standard);
+
+        let report = engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        assert_eq!(report.opened.len(), 1);
+        let cession = &report.opened[0];
+        assert_eq!(cession.basis, CessionBasis::Automatic);
+        assert_eq!(cession.reinsurer, "Global Re");
+        assert_eq!(cession.ceded, usd(dec!(1000000)));
+        assert_eq!(cession.premium_rate_per_mille, dec!(1.2));
+        assert!(report.facultative.is_empty());
+    }
+
+    #[test]
+    fn test_table_rated_policy_uses_lower_retention_and_rated_premium() {
+        let books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(1000000), RiskClass::TableRated(2));
+
+        let report = engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        assert_eq!(report.opened[0].ceded, usd(dec!(750000)));
+        assert_eq!(report.opened[0].premium_rate_per_mille, dec!(1.8));
+    }
+
+    #[test]
+    fn test_within_retention_not_ceded() {
+        let books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(400000), RiskClass::Standard);
+
+        let report = engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        assert!(report.opened.is_empty());
+        assert!(engine.cessions().is_empty());
+    }
+
+    #[test]
+    fn test_endorsement_replaces_changed_cession() {
+        let books = books();
+        let mut engine = engine(&books);
+        let mut policy = policy(dec!(1500000), RiskClass::Standard);
+        let original = engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap().opened[0].id;
+
+        change_sum_assured(&mut policy, dec!(2000000), date(2024, 7, 1));
+        let report = engine.cede(&policy, birth_date(), date(2024, 7, 1)).unwrap();
+
+        assert_eq!(report.ended, vec![original]);
+        assert_eq!(report.opened[0].ceded, usd(dec!(1500000)));
+        let in_force = engine.cessions_in_force(policy.id(), date(2024, 7, 1));
+        assert_eq!(in_force.len(), 1);
+        assert_eq!(in_force[0].id, report.opened[0].id);
+        assert_eq!(engine.cessions_in_force(policy.id(), date(2024, 6, 30))[0].id, original);
+    }
+
+    #[test]
+    fn test_unchanged_policy_not_re_ceded() {
+        let books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(1500000), RiskClass::Standard);
+        engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        let report = engine.cede(&policy, birth_date(), date(2024, 7, 1)).unwrap();
+
+        assert!(report.opened.is_empty());
+        assert!(report.ended.is_empty());
+    }
+
+    #[test]
+    fn test_removed_coverage_ends_cession() {
+        let books = books();
+        let mut engine = engine(&books);
+        let mut policy = policy(dec!(1500000), RiskClass::Standard);
+        engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        let coverage_id = policy.coverages()[0].id;
+        policy
+            .apply_endorsement(Endorsement::new(
+                EndorsementType::CoverageChange {
+                    add: vec![],
+                    remove: vec![coverage_id],
+                    modify: vec![],
+                },
+                date(2024, 7, 1),
+            ))
+            .unwrap();
+        let report = engine.cede(&policy, birth_date(), date(2024, 7, 1)).unwrap();
+
+        assert_eq!(report.ended.len(), 1);
+        assert!(engine.cessions_in_force(policy.id(), date(2024, 7, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_facultative_placement() {
+        let books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(2800000), RiskClass::Standard);
+
+        let report = engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+        assert_eq!(report.facultative[0].amount, usd(dec!(300000)));
+
+        let cession = engine
+            .place_facultative(
+                report.facultative[0].coverage_id,
+                FacultativePlacement {
+                    reinsurer: "Specialty Re".to_string(),
+                    amount: usd(dec!(300000)),
+                    premium_rate_per_mille: dec!(2.5),
+                    reference: Some("FAC-1001".to_string()),
+                },
+                date(2024, 1, 1),
+            )
+            .unwrap();
+
+        assert_eq!(cession.basis, CessionBasis::Facultative);
+        assert!(engine.pending_facultative().is_empty());
+        let again = engine.cede(&policy, birth_date(), date(2024, 7, 1)).unwrap();
+        assert!(again.facultative.is_empty());
+    }
+
+    #[test]
+    fn test_facultative_placement_cannot_exceed_requirement() {
+        let books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(2800000), RiskClass::Standard);
+        let report = engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        let result = engine.place_facultative(
+            report.facultative[0].coverage_id,
+            FacultativePlacement {
+                reinsurer: "Specialty Re".to_string(),
+                amount: usd(dec!(400000)),
+                premium_rate_per_mille: dec!(2.5),
+                reference: None,
+            },
+            date(2024, 1, 1),
+        );
+
+        assert!(matches!(result, Err(ReinsuranceError::InvalidPlacement(_))));
+    }
+
+    #[test]
+    fn test_policy_not_in_force_rejected() {
+        let books = books();
+        let mut engine = engine(&books);
+        let quote = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(1500000))))
+            .premium(Premium::new(usd(dec!(250)), PremiumFrequency::Monthly))
+            .build()
+            .unwrap();
+
+        let result = engine.cede(&quote, birth_date(), date(2024, 1, 1));
+
+        assert!(matches!(result, Err(ReinsuranceError::NotCedable(_))));
+    }
+}
+
+// ============================================================================
+// ACCOUNTING TESTS
+// ============================================================================
+
+mod accounting {
+    use super::*;
+
+    #[test]
+    fn test_premium_bordereau_posted() {
+        let mut books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(1500000), RiskClass::Standard);
+        engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+
+        let bordereau = engine
+            .premium_bordereau(&mut books.ledger, "Global Re", date(2024, 1, 1), date(2024, 1, 31), Currency::USD)
+            .unwrap();
+
+        // 1,000,000 ceded at 1.2 per mille for 31 days
+        assert_eq!(bordereau.lines.len(), 1);
+        assert_eq!(bordereau.total_premium, usd(dec!(101.92)));
+        assert_eq!(books.ledger.get_balance(&books.accounts.payable), Some(usd(dec!(101.92))));
+        assert_eq!(books.ledger.get_balance(&books.accounts.premium_ceded), Some(usd(dec!(101.92))));
+    }
+
+    #[test]
+    fn test_bordereau_prorates_endorsed_cessions() {
+        let mut books = books();
+        let mut engine = engine(&books);
+        let mut policy = policy(dec!(1500000), RiskClass::Standard);
+        engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+        change_sum_assured(&mut policy, dec!(2000000), date(2024, 7, 1));
+        engine.cede(&policy, birth_date(), date(2024, 7, 1)).unwrap();
+
+        let bordereau = engine
+            .premium_bordereau(&mut books.ledger, "Global Re", date(2024, 6, 15), date(2024, 7, 14), Currency::USD)
+            .unwrap();
+
+        let days: Vec<i64> = bordereau.lines.iter().map(|l| l.days).collect();
+        assert_eq!(days, vec![16, 14]);
+    }
+
+    #[test]
+    fn test_overlapping_bordereau_rejected() {
+        let mut books = books();
+        let mut engine = engine(&books);
+        engine
+            .premium_bordereau(&mut books.ledger, "Global Re", date(2024, 1, 1), date(2024, 1, 31), Currency::USD)
+            .unwrap();
+
+        let result =
+            engine.premium_bordereau(&mut books.ledger, "Global Re", date(2024, 1, 15), date(2024, 2, 14), Currency::USD);
+
+        assert!(matches!(result, Err(ReinsuranceError::InvalidPeriod(_))));
+    }
+
+    #[test]
+    fn test_claim_recovery_posted_once() {
+        let mut books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(1500000), RiskClass::Standard);
+        engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+        let claim = death_claim(&policy);
+        let payment = ClaimPayment::new(
+            claim.id,
+            policy.policyholder_id(),
+            usd(dec!(1500000)),
+            PaymentType::FinalSettlement,
+            PaymentMethod::BankTransfer,
+        );
+
+        let recoveries = engine.recover(&mut books.ledger, &policy, &claim, &payment).unwrap();
+        let repeated = engine.recover(&mut books.ledger, &policy, &claim, &payment).unwrap();
+
+        assert_eq!(recoveries.len(), 1);
+        assert_eq!(recoveries[0].amount, usd(dec!(1000000)));
+        assert!(repeated.is_empty());
+        assert_eq!(books.ledger.get_balance(&books.accounts.recoverable), Some(usd(dec!(1000000))));
+    }
+
+    #[test]
+    fn test_recovery_on_other_policy_rejected() {
+        let mut books = books();
+        let mut engine = engine(&books);
+        let policy = policy(dec!(1500000), RiskClass::Standard);
+        let other = super::policy(dec!(1500000), RiskClass::Standard);
+        let claim = death_claim(&other);
+        let payment = ClaimPayment::new(
+            claim.id,
+            other.policyholder_id(),
+            usd(dec!(1500000)),
+            PaymentType::FinalSettlement,
+            PaymentMethod::BankTransfer,
+        );
+
+        let result = engine.recover(&mut books.ledger, &policy, &claim, &payment);
+
+        assert!(matches!(result, Err(ReinsuranceError::InvalidRecovery(_))));
+    }
+
+    #[test]
+    fn test_recovery_limited_to_claimed_coverage() {
+        let mut books = books();
+        let mut accidental_death = quota_share_treaty();
+        accidental_death.coverage_types = vec![CoverageType::AccidentalDeath];
+        let mut engine = engine(&books).with_treaty(accidental_death);
+        let mut policy = PolicyBuilder::new()
+            .product_code("TERM_LIFE_01")
+            .policyholder(PartyId::new())
+            .add_coverage(Coverage::death_benefit(usd(dec!(1500000))))
+            .add_coverage(Coverage::new(CoverageType::AccidentalDeath, usd(dec!(1000000))))
+            .premium(Premium::new(usd(dec!(400)), PremiumFrequency::Monthly))
+            .term_years(20)
+            .build()
+            .unwrap();
+        policy.issue(date(2024, 1, 1), "UW001").unwrap();
+        engine.cede(&policy, birth_date(), date(2024, 1, 1)).unwrap();
+        let rider_cession = engine
+            .cessions()
+            .iter()
+            .find(|c| c.coverage_id == policy.coverages()[1].id)
+            .unwrap()
+            .clone();
+
+        let death = death_claim(&policy);
+        let death_recoveries = engine
+            .recover(&mut books.ledger, &policy, &death, &final_settlement(&policy, &death, dec!(1500000)))
+            .unwrap();
+        let accident = claim(&policy, LossType::Accident);
+        let accident_recoveries = engine
+            .recover(&mut books.ledger, &policy, &accident, &final_settlement(&policy, &accident, dec!(1000000)))
+            .unwrap();
+
+        // Death benefit: 1,000,000 of 1,500,000 ceded under the surplus treaty
+        assert_eq!(death_recoveries.len(), 1);
+        assert_eq!(death_recoveries[0].reinsurer, "Global Re");
+        assert_eq!(death_recoveries[0].amount, usd(dec!(1000000)));
+        assert_eq!(accident_recoveries.len(), 1);
+        assert_eq!(accident_recoveries[0].cession_id, rider_cession.id);
+        assert_eq!(
+            accident_recoveries[0].amount,
+            usd(dec!(1000000) * rider_cession.share()).round_to_currency()
+        );
+    }
+}
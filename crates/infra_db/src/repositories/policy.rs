@@ -40,6 +40,47 @@ impl PolicyRepository {
         Self { pool }
     }
 
+    /// Retrieves the current version of a policy
+    ///
+    /// # Arguments
+    ///
+    /// * `policy_id` - The policy identifier
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if there is no such policy
+    pub async fn find_current(&self, policy_id: Uuid) -> Result<PolicyRow, DatabaseError> {
+        let policy = sqlx::query_as!(
+            PolicyRow,
+            r#"
+            SELECT
+                version_id,
+                policy_id,
+                policy_number,
+                product_code,
+                product_version,
+                policyholder_id,
+                status as "status: PolicyStatus",
+                effective_date,
+                expiry_date,
+                premium,
+                sum_assured,
+                currency,
+                created_at,
+                updated_at
+            FROM policy_versions
+            WHERE policy_id = $1
+              AND upper(sys_period) IS NULL
+            "#,
+            policy_id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| DatabaseError::not_found("Policy", policy_id))?;
+
+        Ok(policy)
+    }
+
     /// Retrieves all policies for a given party (policyholder)
     ///
     /// # Arguments
@@ -58,6 +99,7 @@ impl PolicyRepository {
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status as "status: PolicyStatus",
                 effective_date,
@@ -101,6 +143,7 @@ impl PolicyRepository {
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status as "status: PolicyStatus",
                 effective_date,
@@ -125,6 +168,53 @@ impl PolicyRepository {
         Ok(policies)
     }
 
+    /// Retrieves current quotes and in-force policies for a product
+    ///
+    /// Used to select the book for rule-change impact simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `product_code` - The product code
+    /// * `limit` - Maximum number of policies to return; `None` for all
+    pub async fn find_by_product(
+        &self,
+        product_code: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<PolicyRow>, DatabaseError> {
+        let policies = sqlx::query_as!(
+            PolicyRow,
+            r#"
+            SELECT
+                version_id,
+                policy_id,
+                policy_number,
+                product_code,
+                product_version,
+                policyholder_id,
+                status as "status: PolicyStatus",
+                effective_date,
+                expiry_date,
+                premium,
+                sum_assured,
+                currency,
+                created_at,
+                updated_at
+            FROM policy_versions
+            WHERE product_code = $1
+              AND upper(sys_period) IS NULL
+              AND status IN ('quoted', 'in_force')
+            ORDER BY policy_number ASC
+            LIMIT $2
+            "#,
+            product_code,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(policies)
+    }
+
     /// Creates a new policy with initial version
     ///
     /// # Arguments
@@ -147,6 +237,7 @@ impl PolicyRepository {
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status,
                 effective_date,
@@ -159,16 +250,17 @@ impl PolicyRepository {
                 created_at,
                 updated_at
             ) VALUES (
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11,
-                tstzrange($7, $8),
-                tstzrange($12, NULL),
-                $12, $12
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12,
+                tstzrange($8, $9),
+                tstzrange($13, NULL),
+                $13, $13
             )
             RETURNING
                 version_id,
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status as "status: PolicyStatus",
                 effective_date,
@@ -183,6 +275,7 @@ impl PolicyRepository {
             policy_id,
             policy.policy_number,
             policy.product_code,
+            policy.product_version,
             policy.policyholder_id,
             policy.status as PolicyStatus,
             policy.effective_date,
@@ -239,6 +332,7 @@ impl PolicyRepository {
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status as "status: PolicyStatus",
                 effective_date,
@@ -268,6 +362,7 @@ impl PolicyRepository {
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status,
                 effective_date,
@@ -280,16 +375,17 @@ impl PolicyRepository {
                 created_at,
                 updated_at
             ) VALUES (
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11,
-                tstzrange($7, $8),
-                tstzrange($12, NULL),
-                $13, $12
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12,
+                tstzrange($8, $9),
+                tstzrange($13, NULL),
+                $14, $13
             )
             RETURNING
                 version_id,
                 policy_id,
                 policy_number,
                 product_code,
+                product_version,
                 policyholder_id,
                 status as "status: PolicyStatus",
                 effective_date,
@@ -304,6 +400,7 @@ impl PolicyRepository {
             policy_id,
             current.policy_number,
             current.product_code,
+            current.product_version,
             current.policyholder_id,
             update.status.unwrap_or(current.status) as PolicyStatus,
             effective_from,
@@ -320,6 +417,188 @@ impl PolicyRepository {
         tx.commit().await?;
         Ok(new_row)
     }
+
+    /// Records an underwriting decision with its rule trace
+    ///
+    /// # Arguments
+    ///
+    /// * `policy_id` - The policy identifier
+    /// * `decision` - The decision data, with the serialized decision and
+    ///   the context the product rules were evaluated with
+    pub async fn record_underwriting_decision(
+        &self,
+        policy_id: Uuid,
+        decision: NewUnderwritingDecision,
+    ) -> Result<UnderwritingDecisionRow, DatabaseError> {
+        let decision_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let row = sqlx::query_as!(
+            UnderwritingDecisionRow,
+            r#"
+            INSERT INTO underwriting_decisions (
+                decision_id, policy_id, risk_class, loading_percent,
+                product_code, rules_version, rules_revision,
+                decision, context, decided_by, decided_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8::text::jsonb, $9::text::jsonb, $10, $11)
+            RETURNING
+                decision_id, policy_id, risk_class, loading_percent,
+                product_code, rules_version, rules_revision,
+                decision::text as "decision!", context::text as context, decided_by, decided_at
+            "#,
+            decision_id,
+            policy_id,
+            decision.risk_class,
+            decision.loading_percent,
+            decision.product_code,
+            decision.rules_version,
+            decision.rules_revision,
+            decision.decision.to_string(),
+            decision.context.map(|c| c.to_string()),
+            decision.decided_by,
+            now
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// Retrieves the underwriting decisions for a policy, newest first
+    ///
+    /// # Arguments
+    ///
+    /// * `policy_id` - The policy identifier
+    pub async fn find_underwriting_decisions(
+        &self,
+        policy_id: Uuid,
+    ) -> Result<Vec<UnderwritingDecisionRow>, DatabaseError> {
+        let rows = sqlx::query_as!(
+            UnderwritingDecisionRow,
+            r#"
+            SELECT
+                decision_id, policy_id, risk_class, loading_percent,
+                product_code, rules_version, rules_revision,
+                decision::text as "decision!", context::text as context, decided_by, decided_at
+            FROM underwriting_decisions
+            WHERE policy_id = $1
+            ORDER BY decided_at DESC
+            "#,
+            policy_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// Stores the options of a quote comparison
+    ///
+    /// The options are stored in one transaction, so a comparison is never
+    /// left partly stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `quotes` - The quote data, with the serialized options
+    pub async fn insert_quotes(&self, quotes: Vec<NewQuote>) -> Result<Vec<QuoteRow>, DatabaseError> {
+        let mut tx = self.pool.begin().await?;
+        let now = Utc::now();
+        let mut rows = Vec::with_capacity(quotes.len());
+
+        for quote in quotes {
+            let row = sqlx::query_as!(
+                QuoteRow,
+                r#"
+                INSERT INTO quotes (
+                    quote_id, comparison_id, policyholder_id, product_code, product_version, rank,
+                    sum_assured, term_years, payment_frequency, modal_premium, annual_premium, currency,
+                    quote, quoted_at, expires_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13::text::jsonb, $14, $15)
+                RETURNING
+                    quote_id, comparison_id, policyholder_id, product_code, product_version, rank,
+                    sum_assured, term_years, payment_frequency, modal_premium, annual_premium, currency,
+                    quote::text as "quote!", quoted_at, expires_at
+                "#,
+                quote.quote_id,
+                quote.comparison_id,
+                quote.policyholder_id,
+                quote.product_code,
+                quote.product_version,
+                quote.rank,
+                quote.sum_assured,
+                quote.term_years,
+                quote.payment_frequency,
+                quote.modal_premium,
+                quote.annual_premium,
+                quote.currency,
+                quote.quote.to_string(),
+                now,
+                quote.expires_at
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            rows.push(row);
+        }
+
+        tx.commit().await?;
+
+        Ok(rows)
+    }
+
+    /// Retrieves a quote that has not expired
+    ///
+    /// # Arguments
+    ///
+    /// * `quote_id` - The quote identifier
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` if there is no such quote or it has expired
+    pub async fn find_quote(&self, quote_id: Uuid) -> Result<QuoteRow, DatabaseError> {
+        let row = sqlx::query_as!(
+            QuoteRow,
+            r#"
+            SELECT
+                quote_id, comparison_id, policyholder_id, product_code, product_version, rank,
+                sum_assured, term_years, payment_frequency, modal_premium, annual_premium, currency,
+                quote::text as "quote!", quoted_at, expires_at
+            FROM quotes
+            WHERE quote_id = $1
+              AND expires_at > CURRENT_TIMESTAMP
+            "#,
+            quote_id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| DatabaseError::not_found("Quote", quote_id))?;
+
+        Ok(row)
+    }
+
+    /// Retrieves the quotes from a comparison, best ranked first
+    ///
+    /// # Arguments
+    ///
+    /// * `comparison_id` - The comparison identifier
+    pub async fn find_quotes_by_comparison(&self, comparison_id: Uuid) -> Result<Vec<QuoteRow>, DatabaseError> {
+        let rows = sqlx::query_as!(
+            QuoteRow,
+            r#"
+            SELECT
+                quote_id, comparison_id, policyholder_id, product_code, product_version, rank,
+                sum_assured, term_years, payment_frequency, modal_premium, annual_premium, currency,
+                quote::text as "quote!", quoted_at, expires_at
+            FROM quotes
+            WHERE comparison_id = $1
+            ORDER BY rank
+            "#,
+            comparison_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
 }
 
 /// Policy status enumeration
@@ -351,6 +630,8 @@ pub struct PolicyRow {
     pub policy_id: Uuid,
     pub policy_number: String,
     pub product_code: String,
+    /// Catalog version the policy was sold under (None if not pinned)
+    pub product_version: Option<String>,
     pub policyholder_id: Uuid,
     pub status: PolicyStatus,
     pub effective_date: DateTime<Utc>,
@@ -367,6 +648,7 @@ pub struct PolicyRow {
 pub struct NewPolicy {
     pub policy_number: String,
     pub product_code: String,
+    pub product_version: Option<String>,
     pub policyholder_id: Uuid,
     pub status: PolicyStatus,
     pub effective_date: DateTime<Utc>,
@@ -384,3 +666,78 @@ pub struct PolicyUpdate {
     pub premium: Option<rust_decimal::Decimal>,
     pub sum_assured: Option<rust_decimal::Decimal>,
 }
+
+/// Database row representation of an underwriting decision
+#[derive(Debug, Clone)]
+pub struct UnderwritingDecisionRow {
+    pub decision_id: Uuid,
+    pub policy_id: Uuid,
+    pub risk_class: String,
+    pub loading_percent: Option<rust_decimal::Decimal>,
+    pub product_code: Option<String>,
+    pub rules_version: Option<String>,
+    pub rules_revision: Option<String>,
+    /// Serialized decision, including the rule trace
+    pub decision: String,
+    /// Serialized context the product rules were evaluated with
+    pub context: Option<String>,
+    pub decided_by: String,
+    pub decided_at: DateTime<Utc>,
+}
+
+/// Data for recording an underwriting decision
+#[derive(Debug, Clone)]
+pub struct NewUnderwritingDecision {
+    pub risk_class: String,
+    pub loading_percent: Option<rust_decimal::Decimal>,
+    pub product_code: Option<String>,
+    pub rules_version: Option<String>,
+    pub rules_revision: Option<String>,
+    /// Decision, including the rule trace, as JSON
+    pub decision: serde_json::Value,
+    /// Context the product rules were evaluated with (None if none were)
+    pub context: Option<serde_json::Value>,
+    pub decided_by: String,
+}
+
+/// Database row representation of a stored quote
+#[derive(Debug, Clone)]
+pub struct QuoteRow {
+    pub quote_id: Uuid,
+    pub comparison_id: Uuid,
+    pub policyholder_id: Uuid,
+    pub product_code: String,
+    pub product_version: String,
+    pub rank: i32,
+    pub sum_assured: rust_decimal::Decimal,
+    pub term_years: Option<i32>,
+    pub payment_frequency: String,
+    pub modal_premium: rust_decimal::Decimal,
+    pub annual_premium: rust_decimal::Decimal,
+    pub currency: String,
+    /// Serialized quote option, including the quoted policy
+    pub quote: String,
+    pub quoted_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Data for storing a quote
+#[derive(Debug, Clone)]
+pub struct NewQuote {
+    /// ID of the quoted policy
+    pub quote_id: Uuid,
+    pub comparison_id: Uuid,
+    pub policyholder_id: Uuid,
+    pub product_code: String,
+    pub product_version: String,
+    pub rank: i32,
+    pub sum_assured: rust_decimal::Decimal,
+    pub term_years: Option<i32>,
+    pub payment_frequency: String,
+    pub modal_premium: rust_decimal::Decimal,
+    pub annual_premium: rust_decimal::Decimal,
+    pub currency: String,
+    /// Quote option, including the quoted policy, as JSON
+    pub quote: serde_json::Value,
+    pub expires_at: DateTime<Utc>,
+}
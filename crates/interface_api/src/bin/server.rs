@@ -20,10 +20,17 @@
 //! * `API_JWT_EXPIRATION_SECS` - JWT token expiration in seconds (default: 3600)
 //! * `API_DATABASE_URL` - PostgreSQL connection string
 //! * `API_LOG_LEVEL` - Log level: trace, debug, info, warn, error (default: info)
+//! * `API_PRODUCTS_DIR` - Product catalog directory (default: products)
+//! * `API_RULES_RELOAD_SECS` - Seconds between checks for changed rules files (default: 30)
 
+use domain_policy::rules_engine::RulesEngine;
+use domain_policy::{DirectoryRulesRepository, ProductCatalog, RulesWatcher};
 use interface_api::{create_router, config::ApiConfig};
 use sqlx::postgres::PgPoolOptions;
 use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
@@ -37,6 +44,7 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 /// Returns an error if:
 /// - Configuration cannot be loaded from environment
 /// - Database connection fails
+/// - The product catalog or its rules cannot be read
 /// - Server fails to bind to the configured address
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -61,8 +69,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Run database migrations
     run_migrations(&pool).await?;
 
+    // Load the product catalog
+    let catalog = ProductCatalog::load(Path::new(&config.products_dir))?;
+
+    // Load the product rules and keep them current as rules files change
+    let rules_engine = Arc::new(RulesEngine::new());
+    let repository = DirectoryRulesRepository::new(&config.products_dir);
+    let report = rules_engine.reload(&repository)?;
+    tracing::info!(
+        loaded = report.loaded.len(),
+        rejected = report.rejected.len(),
+        "Product rules loaded"
+    );
+    let _watcher = RulesWatcher::start(
+        rules_engine.clone(),
+        repository,
+        Duration::from_secs(config.rules_reload_secs),
+    );
+
     // Create the API router
-    let app = create_router(pool, config.clone());
+    let app = create_router(pool, config.clone(), Arc::new(catalog), rules_engine);
 
     // Parse server address
     let addr: SocketAddr = config.server_addr().parse()?;
@@ -112,6 +138,12 @@ fn load_config() -> Result<ApiConfig, Box<dyn std::error::Error>> {
             log_level: std::env::var("API_LOG_LEVEL")
                 .or_else(|_| std::env::var("RUST_LOG"))
                 .unwrap_or_else(|_| "info".to_string()),
+            products_dir: std::env::var("API_PRODUCTS_DIR")
+                .unwrap_or_else(|_| "products".to_string()),
+            rules_reload_secs: std::env::var("API_RULES_RELOAD_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(30),
         }
     });
 
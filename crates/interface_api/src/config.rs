@@ -17,6 +17,20 @@ pub struct ApiConfig {
     pub database_url: String,
     /// Log level
     pub log_level: String,
+    /// Directory holding the product catalog and rules files
+    #[serde(default = "default_products_dir")]
+    pub products_dir: String,
+    /// Seconds between checks of the products directory for changed rules
+    #[serde(default = "default_rules_reload_secs")]
+    pub rules_reload_secs: u64,
+}
+
+fn default_products_dir() -> String {
+    "products".to_string()
+}
+
+fn default_rules_reload_secs() -> u64 {
+    30
 }
 
 impl Default for ApiConfig {
@@ -28,6 +42,8 @@ impl Default for ApiConfig {
             jwt_expiration_secs: 3600,
             database_url: "postgres://localhost/insurance".to_string(),
             log_level: "info".to_string(),
+            products_dir: default_products_dir(),
+            rules_reload_secs: default_rules_reload_secs(),
         }
     }
 }
@@ -4,3 +4,4 @@ pub mod policy;
 pub mod claims;
 pub mod party;
 pub mod fund;
+pub mod product;
@@ -1,6 +1,8 @@
 //! Policy DTOs
 
 use chrono::{DateTime, NaiveDate, Utc};
+use domain_policy::underwriting::{UnderwritingApplication, UnderwritingDecision};
+use infra_db::repositories::policy::PolicyRow;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -50,6 +52,21 @@ pub struct PolicyResponse {
     pub created_at: DateTime<Utc>,
 }
 
+impl From<PolicyRow> for PolicyResponse {
+    fn from(row: PolicyRow) -> Self {
+        Self {
+            id: row.policy_id,
+            policy_number: row.policy_number,
+            product_code: row.product_code,
+            status: format!("{:?}", row.status),
+            effective_date: Some(row.effective_date.date_naive()),
+            expiry_date: Some(row.expiry_date.date_naive()),
+            premium: row.premium,
+            created_at: row.created_at,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct EndorsementResponse {
     pub id: Uuid,
@@ -57,3 +74,24 @@ pub struct EndorsementResponse {
     pub status: String,
     pub effective_date: NaiveDate,
 }
+
+/// Application to underwrite for a policy
+#[derive(Debug, Deserialize)]
+pub struct UnderwriteRequest {
+    pub application: UnderwritingApplication,
+    pub application_date: NaiveDate,
+    /// Application in the shape the product rules expect
+    pub context: serde_json::Value,
+    pub underwriter: String,
+}
+
+/// Underwriting decision with the trace of the rules behind it
+#[derive(Debug, Serialize)]
+pub struct UnderwritingDecisionResponse {
+    pub decision_id: Uuid,
+    pub policy_id: Uuid,
+    pub decided_by: String,
+    pub decided_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub decision: UnderwritingDecision,
+}
@@ -0,0 +1,134 @@
+//! Product catalog DTOs
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use domain_policy::catalog::{payment_mode_name, ProductLimits, ProductVersion};
+use domain_policy::rider::RiderDefinition;
+use domain_policy::ImpactReport;
+
+#[derive(Debug, Deserialize)]
+pub struct ProductQuery {
+    /// Date to resolve product versions on (defaults to today)
+    pub as_of: Option<NaiveDate>,
+}
+
+/// Candidate rules to simulate against a product's stored business
+#[derive(Debug, Deserialize)]
+pub struct ImpactRequest {
+    /// Candidate rules document (JDM)
+    pub rules: serde_json::Value,
+    /// Number of cases to sample (defaults to the whole book)
+    pub sample: Option<usize>,
+}
+
+/// Impact of candidate rules on a product's stored business
+#[derive(Debug, Serialize)]
+pub struct ImpactResponse {
+    #[serde(flatten)]
+    pub report: ImpactReport,
+    pub premium_change_percent: Option<Decimal>,
+    pub current_distribution: BTreeMap<String, usize>,
+    pub candidate_distribution: BTreeMap<String, usize>,
+}
+
+impl From<ImpactReport> for ImpactResponse {
+    fn from(report: ImpactReport) -> Self {
+        Self {
+            premium_change_percent: report.premium_change_percent(),
+            current_distribution: report.current_distribution(),
+            candidate_distribution: report.candidate_distribution(),
+            report,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProductSummaryResponse {
+    pub code: String,
+    pub name: String,
+    pub category: String,
+    pub version: String,
+    pub effective_date: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProductResponse {
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub category: String,
+    pub version: String,
+    pub effective_date: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+    pub active: bool,
+    pub rules_file: Option<String>,
+    pub limits: ProductLimits,
+    pub riders: Vec<RiderResponse>,
+    pub payment_modes: Vec<String>,
+    pub currencies: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RiderResponse {
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub max_multiple: Option<Decimal>,
+    pub max_percentage: Option<Decimal>,
+    pub payout_percentage: Option<Decimal>,
+    pub max_age: Option<u32>,
+}
+
+impl From<&ProductVersion> for ProductSummaryResponse {
+    fn from(product: &ProductVersion) -> Self {
+        Self {
+            code: product.code.clone(),
+            name: product.name.clone(),
+            category: product.category.clone(),
+            version: product.version.clone(),
+            effective_date: product.effective_date,
+            effective_to: product.effective_to,
+            active: product.active,
+        }
+    }
+}
+
+impl From<&ProductVersion> for ProductResponse {
+    fn from(product: &ProductVersion) -> Self {
+        Self {
+            code: product.code.clone(),
+            name: product.name.clone(),
+            description: product.description.clone(),
+            category: product.category.clone(),
+            version: product.version.clone(),
+            effective_date: product.effective_date,
+            effective_to: product.effective_to,
+            active: product.active,
+            rules_file: product.rules_file.clone(),
+            limits: product.limits.clone(),
+            riders: product.riders.iter().map(RiderResponse::from).collect(),
+            payment_modes: product.payment_modes.iter().map(|m| payment_mode_name(*m).to_string()).collect(),
+            currencies: product.currencies.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+impl From<&RiderDefinition> for RiderResponse {
+    fn from(rider: &RiderDefinition) -> Self {
+        Self {
+            code: rider.code.clone(),
+            name: rider.name.clone(),
+            description: rider.description.clone(),
+            max_multiple: rider.max_multiple,
+            max_percentage: rider.max_percentage,
+            payout_percentage: rider.payout_percentage,
+            max_age: rider.max_age,
+        }
+    }
+}
@@ -73,3 +73,19 @@ impl From<sqlx::Error> for ApiError {
         ApiError::Database(err.to_string())
     }
 }
+
+impl From<infra_db::DatabaseError> for ApiError {
+    fn from(err: infra_db::DatabaseError) -> Self {
+        if err.is_not_found() {
+            ApiError::NotFound(err.to_string())
+        } else {
+            ApiError::Database(err.to_string())
+        }
+    }
+}
+
+impl From<domain_policy::PolicyError> for ApiError {
+    fn from(err: domain_policy::PolicyError) -> Self {
+        ApiError::Validation(err.to_string())
+    }
+}
@@ -5,3 +5,5 @@ pub mod policy;
 pub mod claims;
 pub mod party;
 pub mod fund;
+pub mod products;
+pub mod quotes;
@@ -1,6 +1,13 @@
 //! Policy handlers
 
 use axum::{extract::{Path, State}, Json};
+use chrono::NaiveDate;
+use domain_policy::rules_engine::ProductRules;
+use domain_policy::underwriting::UnderwritingDecision;
+use domain_policy::UnderwritingService;
+use infra_db::repositories::policy::{NewUnderwritingDecision, PolicyRow, UnderwritingDecisionRow};
+use infra_db::repositories::PolicyRepository;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::{AppState, error::ApiError};
@@ -56,3 +63,95 @@ pub async fn create_endorsement(
 ) -> Result<Json<EndorsementResponse>, ApiError> {
     Err(ApiError::Internal("Not implemented".to_string()))
 }
+
+/// Underwrites an application for a policy and records the decision
+///
+/// The application is evaluated against the rules of the product version
+/// the policy was sold under, falling back to the rules registered with
+/// the shared rules engine. The decision is stored with its rule trace and
+/// the context the rules were evaluated with.
+pub async fn underwrite_policy(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UnderwriteRequest>,
+) -> Result<Json<UnderwritingDecisionResponse>, ApiError> {
+    let repository = PolicyRepository::new(state.pool.clone());
+    let policy = repository.find_current(id).await?;
+
+    let service = UnderwritingService::new().with_rules_engine(state.rules_engine.clone());
+    let (decision, context) = match product_rules(&state, &policy, request.application_date) {
+        Some(rules) => (
+            service.evaluate_with_rules(
+                &request.application,
+                &rules,
+                request.context.clone(),
+                request.application_date,
+            )?,
+            Some(request.context),
+        ),
+        None => (service.evaluate_on(&request.application, request.application_date)?, None),
+    };
+
+    let trace = decision.trace.as_ref();
+    let row = repository
+        .record_underwriting_decision(
+            id,
+            NewUnderwritingDecision {
+                risk_class: format!("{:?}", decision.risk_class),
+                loading_percent: decision.loading_percent,
+                product_code: trace.and_then(|t| t.product_code.clone()),
+                rules_version: trace.and_then(|t| t.rules_version.clone()),
+                rules_revision: trace.and_then(|t| t.rules_revision.clone()),
+                decision: serde_json::to_value(&decision)
+                    .map_err(|e| ApiError::Internal(format!("Decision could not be serialized: {}", e)))?,
+                context,
+                decided_by: request.underwriter,
+            },
+        )
+        .await?;
+
+    Ok(Json(decision_response(row, decision)))
+}
+
+/// Rules of the product version a policy was sold under
+fn product_rules(state: &AppState, policy: &PolicyRow, application_date: NaiveDate) -> Option<Arc<ProductRules>> {
+    let version = match &policy.product_version {
+        Some(version) => state.catalog.pinned(&policy.product_code, version),
+        None => state.catalog.version_on(&policy.product_code, application_date),
+    };
+    match version {
+        Some(product) => product.current_rules(&state.rules_engine),
+        None => state.rules_engine.get_product(&policy.product_code),
+    }
+}
+
+fn decision_response(row: UnderwritingDecisionRow, decision: UnderwritingDecision) -> UnderwritingDecisionResponse {
+    UnderwritingDecisionResponse {
+        decision_id: row.decision_id,
+        policy_id: row.policy_id,
+        decided_by: row.decided_by,
+        decided_at: row.decided_at,
+        decision,
+    }
+}
+
+/// Lists the underwriting decisions for a policy with their rule traces
+pub async fn list_underwriting_decisions(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<UnderwritingDecisionResponse>>, ApiError> {
+    let rows = PolicyRepository::new(state.pool.clone())
+        .find_underwriting_decisions(id)
+        .await?;
+
+    let decisions = rows
+        .into_iter()
+        .map(|row| {
+            let decision = serde_json::from_str(&row.decision)
+                .map_err(|e| ApiError::Internal(format!("Invalid stored decision {}: {}", row.decision_id, e)))?;
+            Ok(decision_response(row, decision))
+        })
+        .collect::<Result<Vec<_>, ApiError>>()?;
+
+    Ok(Json(decisions))
+}
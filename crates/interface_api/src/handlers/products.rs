@@ -0,0 +1,152 @@
+//! Product catalog handlers
+
+use axum::{extract::{Path, Query, State}, Json};
+use chrono::{Duration, Utc};
+use core_kernel::{utc_start_of_day, Currency, Money};
+use domain_policy::renewal::RenewalRules;
+use domain_policy::rules_engine::ProductRules;
+use domain_policy::{ImpactCase, ImpactSimulator, SimulationScope};
+use infra_db::repositories::policy::PolicyRow;
+use infra_db::repositories::PolicyRepository;
+
+use crate::{AppState, error::ApiError};
+use crate::dto::policy::PolicyResponse;
+use crate::dto::product::*;
+
+/// Lists the product versions in force on a date
+pub async fn list_products(
+    State(state): State<AppState>,
+    Query(query): Query<ProductQuery>,
+) -> Result<Json<Vec<ProductSummaryResponse>>, ApiError> {
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let products = state.catalog.in_force_on(as_of);
+    Ok(Json(products.into_iter().map(ProductSummaryResponse::from).collect()))
+}
+
+/// Gets the version of a product in force on a date
+pub async fn get_product(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<ProductQuery>,
+) -> Result<Json<ProductResponse>, ApiError> {
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    state
+        .catalog
+        .version_on(&code, as_of)
+        .map(|product| Json(ProductResponse::from(product)))
+        .ok_or_else(|| ApiError::NotFound(format!("Product {} not available on {}", code, as_of)))
+}
+
+/// Lists all versions of a product
+pub async fn list_versions(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> Result<Json<Vec<ProductSummaryResponse>>, ApiError> {
+    let versions = state.catalog.versions(&code);
+    if versions.is_empty() {
+        return Err(ApiError::NotFound(format!("Product {} not found", code)));
+    }
+    Ok(Json(versions.iter().map(ProductSummaryResponse::from).collect()))
+}
+
+/// Gets a specific version of a product
+pub async fn get_version(
+    State(state): State<AppState>,
+    Path((code, version)): Path<(String, String)>,
+) -> Result<Json<ProductResponse>, ApiError> {
+    state
+        .catalog
+        .pinned(&code, &version)
+        .map(|product| Json(ProductResponse::from(product)))
+        .ok_or_else(|| ApiError::NotFound(format!("Product {} version {} not found", code, version)))
+}
+
+/// Lists a product's in-force policies due a renewal offer on a date
+///
+/// A policy is due when its term ends within the renewal notice period of
+/// the product version in force on the date.
+pub async fn renewals_due(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<ProductQuery>,
+) -> Result<Json<Vec<PolicyResponse>>, ApiError> {
+    let as_of = query.as_of.unwrap_or_else(|| Utc::now().date_naive());
+    let product = state
+        .catalog
+        .version_on(&code, as_of)
+        .ok_or_else(|| ApiError::NotFound(format!("Product {} not available on {}", code, as_of)))?;
+    let rules = RenewalRules::from_renewable_product(&product.definition)?
+        .ok_or_else(|| ApiError::Validation(format!("Product {} is not renewable", code)))?;
+    let (from, to) = rules.notice_window(as_of);
+
+    let policies = PolicyRepository::new(state.pool.clone())
+        .find_expiring_between(utc_start_of_day(from + Duration::days(1)), utc_start_of_day(to))
+        .await?;
+    Ok(Json(
+        policies
+            .into_iter()
+            .filter(|policy| policy.product_code == code)
+            .map(PolicyResponse::from)
+            .collect(),
+    ))
+}
+
+/// Simulates the impact of candidate rules on a product's stored business
+///
+/// Current quotes and in-force policies are re-evaluated under the rules
+/// in force today and under the candidate rules, with the context their
+/// latest underwriting decision was evaluated with. Nothing is changed.
+pub async fn simulate_impact(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+    Json(request): Json<ImpactRequest>,
+) -> Result<Json<ImpactResponse>, ApiError> {
+    let today = Utc::now().date_naive();
+    let current = state
+        .catalog
+        .version_on(&code, today)
+        .map_or_else(
+            || state.rules_engine.get_product(&code),
+            |product| product.current_rules(&state.rules_engine),
+        )
+        .ok_or_else(|| ApiError::NotFound(format!("No rules are loaded for {}", code)))?;
+    let candidate = ProductRules::from_jdm(request.rules)
+        .map_err(|e| ApiError::Validation(format!("Candidate rules are invalid: {}", e)))?;
+
+    let repository = PolicyRepository::new(state.pool.clone());
+    let cases = load_impact_cases(&repository, &code).await?;
+    let scope = request.sample.map_or(SimulationScope::Full, SimulationScope::Sample);
+    let report = ImpactSimulator::new().simulate(&current, &candidate, &cases, scope)?;
+
+    Ok(Json(ImpactResponse::from(report)))
+}
+
+/// Loads a product's current quotes and in-force policies as impact cases
+///
+/// Policies never evaluated against product rules have no stored context
+/// and are left out.
+async fn load_impact_cases(repository: &PolicyRepository, product_code: &str) -> Result<Vec<ImpactCase>, ApiError> {
+    let mut cases = Vec::new();
+    for policy in repository.find_by_product(product_code, None).await? {
+        let decisions = repository.find_underwriting_decisions(policy.policy_id).await?;
+        if let Some(context) = decisions.into_iter().find_map(|decision| decision.context) {
+            cases.push(impact_case(&policy, &context)?);
+        }
+    }
+    Ok(cases)
+}
+
+/// Adapts a stored policy and its underwriting context to an impact case
+fn impact_case(policy: &PolicyRow, context: &str) -> Result<ImpactCase, ApiError> {
+    let currency: Currency = policy.currency.parse().map_err(|e| {
+        ApiError::Internal(format!("Policy {} has an invalid currency: {}", policy.policy_number, e))
+    })?;
+    let context = serde_json::from_str(context).map_err(|e| {
+        ApiError::Internal(format!("Invalid stored context for policy {}: {}", policy.policy_number, e))
+    })?;
+    Ok(ImpactCase::new(
+        policy.policy_number.clone(),
+        context,
+        Money::new(policy.sum_assured, currency),
+    ))
+}
@@ -0,0 +1,94 @@
+//! Quote comparison handlers
+
+use axum::{extract::{Path, State}, Json};
+use domain_policy::catalog::payment_mode_name;
+use domain_policy::{QuoteComparison, QuoteOption, QuoteRequest};
+use infra_db::repositories::policy::{NewQuote, QuoteRow};
+use infra_db::repositories::PolicyRepository;
+use uuid::Uuid;
+
+use crate::{AppState, error::ApiError};
+
+/// Prices an application across the catalog and stores the eligible options
+///
+/// Each eligible option is stored until its quote expires, so the adviser
+/// can retrieve it by quote ID or with the rest of the comparison.
+pub async fn compare_quotes(
+    State(state): State<AppState>,
+    Json(request): Json<QuoteRequest>,
+) -> Result<Json<QuoteComparison>, ApiError> {
+    let comparison = state.quoting.compare(&request)?;
+
+    let quotes = comparison
+        .eligible()
+        .map(|option| new_quote(&comparison, option))
+        .collect::<Result<Vec<_>, _>>()?;
+    PolicyRepository::new(state.pool.clone()).insert_quotes(quotes).await?;
+
+    Ok(Json(comparison))
+}
+
+/// Gets a stored quote that has not expired
+pub async fn get_quote(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<QuoteOption>, ApiError> {
+    let row = PolicyRepository::new(state.pool.clone()).find_quote(id).await?;
+    Ok(Json(quote_option(&row)?))
+}
+
+/// Lists the stored options of a comparison, best ranked first
+pub async fn get_comparison(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<QuoteOption>>, ApiError> {
+    let rows = PolicyRepository::new(state.pool.clone())
+        .find_quotes_by_comparison(id)
+        .await?;
+    if rows.is_empty() {
+        return Err(ApiError::NotFound(format!("Comparison {} not found", id)));
+    }
+    let options = rows.iter().map(quote_option).collect::<Result<Vec<_>, _>>()?;
+    Ok(Json(options))
+}
+
+/// Adapts an eligible option to a stored quote
+fn new_quote(comparison: &QuoteComparison, option: &QuoteOption) -> Result<NewQuote, ApiError> {
+    let incomplete = || ApiError::Internal(format!("Option for {} is not a complete quote", option.product_code));
+    let quote_id = option.quote_id().ok_or_else(incomplete)?;
+    let rank = option.rank.ok_or_else(incomplete)?;
+    let modal_premium = option.modal_premium.ok_or_else(incomplete)?;
+    let annual_premium = option.annual_premium.ok_or_else(incomplete)?;
+    let expires_at = option.quote_expiry().ok_or_else(incomplete)?;
+
+    Ok(NewQuote {
+        quote_id: *quote_id.as_uuid(),
+        comparison_id: comparison.id,
+        policyholder_id: *comparison.policyholder_id.as_uuid(),
+        product_code: option.product_code.clone(),
+        product_version: option.product_version.clone(),
+        rank: rank as i32,
+        sum_assured: option.scenario.sum_assured.amount(),
+        term_years: option.scenario.term_years.map(|term| term as i32),
+        payment_frequency: payment_mode_name(option.scenario.frequency).to_string(),
+        modal_premium: modal_premium.amount(),
+        annual_premium: annual_premium.amount(),
+        currency: option.scenario.sum_assured.currency().code().to_string(),
+        quote: serde_json::to_value(option)
+            .map_err(|e| ApiError::Internal(format!("Quote could not be serialized: {}", e)))?,
+        expires_at,
+    })
+}
+
+/// Adapts a stored quote to the option it was stored from
+fn quote_option(row: &QuoteRow) -> Result<QuoteOption, ApiError> {
+    let option: QuoteOption = serde_json::from_str(&row.quote)
+        .map_err(|e| ApiError::Internal(format!("Invalid stored quote {}: {}", row.quote_id, e)))?;
+    if option.quote_id().map(|id| *id.as_uuid()) != Some(row.quote_id) {
+        return Err(ApiError::Internal(format!(
+            "Stored quote {} does not match its quoted policy",
+            row.quote_id
+        )));
+    }
+    Ok(option)
+}
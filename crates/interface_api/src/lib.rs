@@ -14,7 +14,10 @@
 //! ```rust,ignore
 //! use interface_api::create_router;
 //!
-//! let app = create_router(pool, config);
+//! let catalog = ProductCatalog::load(Path::new("products"))?;
+//! let rules_engine = Arc::new(RulesEngine::new());
+//! rules_engine.reload(&DirectoryRulesRepository::new("products"))?;
+//! let app = create_router(pool, config, Arc::new(catalog), rules_engine);
 //! axum::serve(listener, app).await?;
 //! ```
 
@@ -30,19 +33,27 @@ use axum::{
     routing::{get, post, put, delete},
     middleware as axum_middleware,
 };
+use domain_policy::rules_engine::RulesEngine;
+use domain_policy::{ProductCatalog, QuotingService};
 use sqlx::PgPool;
+use std::sync::Arc;
 use tower_http::trace::TraceLayer;
 use tower_http::cors::{CorsLayer, Any};
 
 use crate::config::ApiConfig;
 use crate::middleware::{auth_middleware, audit_middleware};
-use crate::handlers::{policy, claims, party, fund, health};
+use crate::handlers::{policy, claims, party, fund, health, products, quotes};
 
 /// Application state shared across handlers
 #[derive(Clone)]
 pub struct AppState {
     pub pool: PgPool,
     pub config: ApiConfig,
+    pub catalog: Arc<ProductCatalog>,
+    /// Engine evaluating product rules, shared by underwriting and quoting
+    pub rules_engine: Arc<RulesEngine>,
+    /// Service pricing applications across the catalog
+    pub quoting: Arc<QuotingService>,
 }
 
 /// Creates the main API router
@@ -51,12 +62,27 @@ pub struct AppState {
 ///
 /// * `pool` - Database connection pool
 /// * `config` - API configuration
+/// * `catalog` - Product catalog
+/// * `rules_engine` - Engine holding the product rules, kept current by a
+///   `RulesWatcher`
 ///
 /// # Returns
 ///
 /// Configured Axum router with all routes and middleware
-pub fn create_router(pool: PgPool, config: ApiConfig) -> Router {
-    let state = AppState { pool, config };
+pub fn create_router(
+    pool: PgPool,
+    config: ApiConfig,
+    catalog: Arc<ProductCatalog>,
+    rules_engine: Arc<RulesEngine>,
+) -> Router {
+    let quoting = QuotingService::new(catalog.as_ref().clone()).with_rules_engine(rules_engine.clone());
+    let state = AppState {
+        pool,
+        config,
+        catalog,
+        rules_engine,
+        quoting: Arc::new(quoting),
+    };
 
     // Public routes (no auth required)
     let public_routes = Router::new()
@@ -70,7 +96,9 @@ pub fn create_router(pool: PgPool, config: ApiConfig) -> Router {
         .route("/:id", get(policy::get_policy))
         .route("/:id", put(policy::update_policy))
         .route("/:id/issue", post(policy::issue_policy))
-        .route("/:id/endorsements", post(policy::create_endorsement));
+        .route("/:id/endorsements", post(policy::create_endorsement))
+        .route("/:id/underwriting", get(policy::list_underwriting_decisions))
+        .route("/:id/underwriting", post(policy::underwrite_policy));
 
     // Claims routes
     let claims_routes = Router::new()
@@ -95,12 +123,29 @@ pub fn create_router(pool: PgPool, config: ApiConfig) -> Router {
         .route("/:id/nav", get(fund::get_nav))
         .route("/:id/nav", post(fund::record_nav));
 
+    // Product catalog routes
+    let product_routes = Router::new()
+        .route("/", get(products::list_products))
+        .route("/:code", get(products::get_product))
+        .route("/:code/versions", get(products::list_versions))
+        .route("/:code/versions/:version", get(products::get_version))
+        .route("/:code/impact", post(products::simulate_impact))
+        .route("/:code/renewals", get(products::renewals_due));
+
+    // Quote comparison routes
+    let quote_routes = Router::new()
+        .route("/", post(quotes::compare_quotes))
+        .route("/:id", get(quotes::get_quote))
+        .route("/comparisons/:id", get(quotes::get_comparison));
+
     // Protected API routes
     let api_routes = Router::new()
         .nest("/policies", policy_routes)
         .nest("/claims", claims_routes)
         .nest("/parties", party_routes)
         .nest("/funds", fund_routes)
+        .nest("/products", product_routes)
+        .nest("/quotes", quote_routes)
         .layer(axum_middleware::from_fn_with_state(state.clone(), audit_middleware))
         .layer(axum_middleware::from_fn_with_state(state.clone(), auth_middleware));
 
@@ -0,0 +1,24 @@
+-- Underwriting Decisions Migration
+-- Stores underwriting decisions with the trace of the rules that produced them,
+-- so the reason for a loading or decline can be shown to underwriters and regulators
+
+CREATE TABLE underwriting_decisions (
+    decision_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    policy_id UUID NOT NULL,  -- References policy_versions.policy_id (via application logic)
+    risk_class VARCHAR(50) NOT NULL,
+    loading_percent NUMERIC(10, 4),
+
+    -- Product rules evaluated
+    product_code VARCHAR(50),
+    rules_version VARCHAR(50),
+    rules_revision VARCHAR(64),
+
+    -- Full decision, including the rule trace
+    decision JSONB NOT NULL,
+
+    decided_by VARCHAR(100) NOT NULL,
+    decided_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_underwriting_decisions_policy ON underwriting_decisions(policy_id, decided_at DESC);
+CREATE INDEX idx_underwriting_decisions_rules ON underwriting_decisions(product_code, rules_version);
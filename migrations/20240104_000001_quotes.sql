@@ -0,0 +1,30 @@
+-- Quotes Migration
+-- Stores the options from a multi-product quote comparison, so an adviser can
+-- retrieve a quote until it expires and bind it
+
+CREATE TABLE quotes (
+    quote_id UUID PRIMARY KEY,  -- ID of the quoted policy
+    comparison_id UUID NOT NULL,
+    policyholder_id UUID NOT NULL,
+    product_code VARCHAR(50) NOT NULL,
+    product_version VARCHAR(50) NOT NULL,
+    rank INTEGER NOT NULL,
+
+    -- Cover and price quoted
+    sum_assured NUMERIC(20, 4) NOT NULL,
+    term_years INTEGER,
+    payment_frequency VARCHAR(20) NOT NULL,
+    modal_premium NUMERIC(20, 4) NOT NULL,
+    annual_premium NUMERIC(20, 4) NOT NULL,
+    currency CHAR(3) NOT NULL,
+
+    -- Full option, including loadings and the quoted policy
+    quote JSONB NOT NULL,
+
+    quoted_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_quotes_comparison ON quotes(comparison_id, rank);
+CREATE INDEX idx_quotes_policyholder ON quotes(policyholder_id, quoted_at DESC);
+CREATE INDEX idx_quotes_expiry ON quotes(expires_at);
@@ -0,0 +1,7 @@
+-- Policy Product Version Migration
+-- Pins each policy to the catalog version it was sold under, so servicing uses
+-- that version's rules after the product is re-filed
+
+ALTER TABLE policy_versions ADD COLUMN product_version VARCHAR(50);  -- NULL for policies written before pinning
+
+CREATE INDEX idx_policy_versions_product ON policy_versions(product_code, product_version) WHERE upper(sys_period) IS NULL;
@@ -0,0 +1,5 @@
+-- Underwriting Context Migration
+-- Keeps the application data the product rules were evaluated with, so stored
+-- business can be re-evaluated when the rules change
+
+ALTER TABLE underwriting_decisions ADD COLUMN context JSONB;  -- NULL if no product rules were evaluated
@@ -121,6 +121,7 @@ run_unit_tests() {
         "domain_fund"
         "domain_claims"
         "domain_party"
+        "domain_reinsurance"
     )
 
     local failed=0